@@ -45,7 +45,9 @@ impl BenchmarkAlgorithm for LeviN13Algorithm {
 /// Rastrigin function - standard multimodal benchmark
 /// f(x, y) = 20 + (x^2 - 10 cos(2πx)) + (y^2 - 10 cos(2πy))
 /// Global minimum at (0,0) with f = 0.0, domain x, y ∈ [-5.12, 5.12]
-pub struct RastriginAlgorithm;
+pub struct RastriginAlgorithm {
+    pub dimensions: usize,
+}
 
 impl BenchmarkAlgorithm for RastriginAlgorithm {
     fn name(&self) -> &'static str {
@@ -53,14 +55,11 @@ impl BenchmarkAlgorithm for RastriginAlgorithm {
     }
 
     fn param_ranges(&self) -> Vec<RangeInclusive<f64>> {
-        vec![
-            RangeInclusive::new(-5.12, 5.12),
-            RangeInclusive::new(-5.12, 5.12),
-        ]
+        vec![RangeInclusive::new(-5.12, 5.12); self.dimensions]
     }
 
     fn function(&self) -> Box<dyn WorldFunction> {
-        Box::new(Rastrigin)
+        Box::new(Rastrigin::new(self.dimensions))
     }
 }
 
@@ -87,9 +86,11 @@ impl BenchmarkAlgorithm for SchafferN2Algorithm {
 }
 
 /// Styblinski–Tang function - multimodal with many local minima
-/// f(x,y) = (x^4 - 16x^2 + 5x)/2 + (y^4 - 16y^2 + 5y)/2, shifted so min is 0
-/// Global minimum at (-2.903534, -2.903534), domain x, y ∈ [-5, 5]
-pub struct StyblinskiTangAlgorithm;
+/// f(x,y) = (x^4 - 16x^2 + 5x)/2 + (y^4 - 16y^2 + 5y)/2
+/// Global minimum ≈ -78.332 at (-2.903534, -2.903534), domain x, y ∈ [-5, 5]
+pub struct StyblinskiTangAlgorithm {
+    pub dimensions: usize,
+}
 
 impl BenchmarkAlgorithm for StyblinskiTangAlgorithm {
     fn name(&self) -> &'static str {
@@ -97,22 +98,33 @@ impl BenchmarkAlgorithm for StyblinskiTangAlgorithm {
     }
 
     fn param_ranges(&self) -> Vec<RangeInclusive<f64>> {
-        vec![
-            RangeInclusive::new(-5.0, 5.0),
-            RangeInclusive::new(-5.0, 5.0),
-        ]
+        vec![RangeInclusive::new(-5.0, 5.0); self.dimensions]
     }
 
     fn function(&self) -> Box<dyn WorldFunction> {
-        Box::new(StyblinskiTang)
+        Box::new(StyblinskiTang::new(self.dimensions))
     }
 }
-use hill_descent_lib::world::single_valued_function::SingleValuedFunction;
+use hill_descent_lib::benchmarks::{
+    self, Ackley, BenchmarkFunction, BukinN6, Himmelblau, LeviN13, Rastrigin, SchafferN2,
+    StyblinskiTang,
+};
 use hill_descent_lib::WorldFunction;
 use std::ops::RangeInclusive;
 
+/// Algorithms run when none are selected, in the order they are run
+pub const DEFAULT_ALGORITHMS: &[&str] = &[
+    "styblinski_tang",
+    "ackley",
+    "himmelblau",
+    "bukin_n6",
+    "levi_n13",
+    "rastrigin",
+    "schaffer_n2",
+];
+
 /// Trait for benchmark algorithms that can be tested
-pub trait BenchmarkAlgorithm {
+pub trait BenchmarkAlgorithm: Sync {
     /// Get the name of the algorithm for file naming
     fn name(&self) -> &'static str;
 
@@ -123,6 +135,57 @@ pub trait BenchmarkAlgorithm {
     fn function(&self) -> Box<dyn WorldFunction>;
 }
 
+/// Look up an algorithm by name with the given number of dimensions
+///
+/// The default algorithms keep the domains used by earlier runs so results stay comparable;
+/// any other function in the library catalogue is run over its conventional domain.
+/// Returns `None` for unknown names and for two-dimensional functions given other dimensions.
+pub fn by_name(name: &str, dimensions: usize) -> Option<Box<dyn BenchmarkAlgorithm>> {
+    let fixed_2d = |algorithm: Box<dyn BenchmarkAlgorithm>| (dimensions == 2).then_some(algorithm);
+    match name {
+        "styblinski_tang" => Some(Box::new(StyblinskiTangAlgorithm { dimensions })),
+        "ackley" => Some(Box::new(AckleyAlgorithm { dimensions })),
+        "rastrigin" => Some(Box::new(RastriginAlgorithm { dimensions })),
+        "himmelblau" => fixed_2d(Box::new(HimmelblauAlgorithm)),
+        "bukin_n6" => fixed_2d(Box::new(BukinN6Algorithm)),
+        "levi_n13" => fixed_2d(Box::new(LeviN13Algorithm)),
+        "schaffer_n2" => fixed_2d(Box::new(SchafferN2Algorithm)),
+        _ => benchmarks::by_name(name, dimensions)
+            .map(|function| Box::new(CatalogueAlgorithm(function)) as Box<dyn BenchmarkAlgorithm>),
+    }
+}
+
+/// Names of every algorithm that [`by_name`] accepts
+pub fn available_names() -> Vec<&'static str> {
+    let mut names = DEFAULT_ALGORITHMS.to_vec();
+    for function in benchmarks::catalogue(2) {
+        if !names.contains(&function.name()) {
+            names.push(function.name());
+        }
+    }
+    names
+}
+
+/// Any other function from the library catalogue, over its conventional domain
+pub struct CatalogueAlgorithm(Box<dyn BenchmarkFunction>);
+
+impl BenchmarkAlgorithm for CatalogueAlgorithm {
+    fn name(&self) -> &'static str {
+        self.0.name()
+    }
+
+    fn param_ranges(&self) -> Vec<RangeInclusive<f64>> {
+        self.0.bounds()
+    }
+
+    fn function(&self) -> Box<dyn WorldFunction> {
+        Box::new(
+            benchmarks::by_name(self.0.name(), self.0.dimensions())
+                .expect("catalogue functions can be recreated"),
+        )
+    }
+}
+
 /// Himmelblau's function - standard test function with four identical local minima
 /// f(x, y) = (x^2 + y - 11)^2 + (x + y^2 - 7)^2
 /// Global minimum is 0, typically tested on domain [-5, 5] × [-5, 5]
@@ -148,7 +211,9 @@ impl BenchmarkAlgorithm for HimmelblauAlgorithm {
 /// Ackley function - widely-used multimodal benchmark for optimization
 /// f(x, y) = -20 * exp(-0.2 * sqrt(0.5 * (x² + y²))) - exp(0.5 * (cos(2πx) + cos(2πy))) + e + 20
 /// Global minimum at (0,0) with f = 0.0, typically tested on domain [-5, 5] × [-5, 5]
-pub struct AckleyAlgorithm;
+pub struct AckleyAlgorithm {
+    pub dimensions: usize,
+}
 
 impl BenchmarkAlgorithm for AckleyAlgorithm {
     fn name(&self) -> &'static str {
@@ -156,134 +221,47 @@ impl BenchmarkAlgorithm for AckleyAlgorithm {
     }
 
     fn param_ranges(&self) -> Vec<RangeInclusive<f64>> {
-        vec![
-            RangeInclusive::new(-5.0, 5.0),
-            RangeInclusive::new(-5.0, 5.0),
-        ]
+        vec![RangeInclusive::new(-5.0, 5.0); self.dimensions]
     }
 
     fn function(&self) -> Box<dyn WorldFunction> {
-        Box::new(Ackley)
-    }
-}
-
-// Function implementations
-#[derive(Debug)]
-struct Himmelblau;
-
-#[derive(Debug)]
-struct BukinN6;
-
-impl SingleValuedFunction for BukinN6 {
-    fn single_run(&self, phenotype_expressed_values: &[f64]) -> f64 {
-        assert_eq!(2, phenotype_expressed_values.len());
-        let x = phenotype_expressed_values[0];
-        let y = phenotype_expressed_values[1];
-        let term1 = 100.0 * (y - 0.01 * x * x).abs().sqrt();
-        let term2 = 0.01 * (x + 10.0).abs();
-        term1 + term2
-    }
-}
-
-#[derive(Debug)]
-struct LeviN13;
-
-impl SingleValuedFunction for LeviN13 {
-    fn single_run(&self, phenotype_expressed_values: &[f64]) -> f64 {
-        assert_eq!(2, phenotype_expressed_values.len());
-        let x = phenotype_expressed_values[0];
-        let y = phenotype_expressed_values[1];
-        let pi = std::f64::consts::PI;
-        let term1 = (3.0 * pi * x).sin().powi(2);
-        let term2 = (x - 1.0).powi(2) * (1.0 + (3.0 * pi * y).sin().powi(2));
-        let term3 = (y - 1.0).powi(2) * (1.0 + (2.0 * pi * y).sin().powi(2));
-        term1 + term2 + term3
-    }
-}
-
-#[derive(Debug)]
-struct Rastrigin;
-
-impl SingleValuedFunction for Rastrigin {
-    fn single_run(&self, phenotype_expressed_values: &[f64]) -> f64 {
-        assert_eq!(2, phenotype_expressed_values.len());
-        let x = phenotype_expressed_values[0];
-        let y = phenotype_expressed_values[1];
-        let two_pi = 2.0 * std::f64::consts::PI;
-        20.0 + (x * x - 10.0 * (two_pi * x).cos()) + (y * y - 10.0 * (two_pi * y).cos())
+        Box::new(Ackley::new(self.dimensions))
     }
 }
 
-#[derive(Debug)]
-struct SchafferN2;
-
-impl SingleValuedFunction for SchafferN2 {
-    fn single_run(&self, phenotype_expressed_values: &[f64]) -> f64 {
-        assert_eq!(2, phenotype_expressed_values.len());
-        let x = phenotype_expressed_values[0];
-        let y = phenotype_expressed_values[1];
-        let x2_plus_y2 = x * x + y * y;
-        let x2_minus_y2 = x * x - y * y;
-        let numerator = x2_minus_y2.sin().powi(2) - 0.5;
-        let denominator = (1.0 + 0.001 * x2_plus_y2).powi(2);
-        0.5 + numerator / denominator
-    }
-}
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-#[derive(Debug)]
-struct StyblinskiTang;
-
-impl SingleValuedFunction for StyblinskiTang {
-    fn single_run(&self, phenotype_expressed_values: &[f64]) -> f64 {
-        assert_eq!(2, phenotype_expressed_values.len());
-        let x = phenotype_expressed_values[0];
-        let y = phenotype_expressed_values[1];
-        // Original 1D minimum ≈ -39.16616570377142 at x≈-2.903534
-        const ST_1D_MIN: f64 = -39.166_165_703_771_42; // full precision constant
-        let term_x = (x.powi(4) - 16.0 * x.powi(2) + 5.0 * x) / 2.0;
-        let term_y = (y.powi(4) - 16.0 * y.powi(2) + 5.0 * y) / 2.0;
-        let original_value = term_x + term_y;
-        // Exact shift to make global 2D minimum 0.0
-        let shifted = original_value - 2.0 * ST_1D_MIN;
-        debug_assert!(
-            shifted >= -1e-9,
-            "Shifted Styblinski-Tang value unexpectedly negative: {shifted}"
-        );
-        if shifted < 0.0 {
-            0.0
-        } else {
-            shifted
+    #[test]
+    fn given_default_names_when_looked_up_in_2d_then_all_found() {
+        for name in DEFAULT_ALGORITHMS {
+            let algorithm = by_name(name, 2).unwrap();
+            assert_eq!(algorithm.name(), *name);
+            assert_eq!(algorithm.param_ranges().len(), 2);
         }
     }
-}
 
-impl SingleValuedFunction for Himmelblau {
-    fn single_run(&self, phenotype_expressed_values: &[f64]) -> f64 {
-        assert_eq!(2, phenotype_expressed_values.len());
-        let x = phenotype_expressed_values[0];
-        let y = phenotype_expressed_values[1];
+    #[test]
+    fn given_n_dimensional_algorithm_when_looked_up_then_ranges_match_dimensions() {
+        let algorithm = by_name("rastrigin", 5).unwrap();
 
-        let term1 = (x.powi(2) + y - 11.0).powi(2);
-        let term2 = (x + y.powi(2) - 7.0).powi(2);
-        term1 + term2
+        assert_eq!(algorithm.param_ranges(), vec![-5.12..=5.12; 5]);
+        assert_eq!(algorithm.function().run(&[0.0; 5], &[]), vec![0.0]);
     }
-}
 
-#[derive(Debug)]
-struct Ackley;
-
-impl SingleValuedFunction for Ackley {
-    fn single_run(&self, phenotype_expressed_values: &[f64]) -> f64 {
-        assert_eq!(2, phenotype_expressed_values.len());
-        let x = phenotype_expressed_values[0];
-        let y = phenotype_expressed_values[1];
-
-        let two_pi = 2.0 * std::f64::consts::PI;
-        let e = std::f64::consts::E;
+    #[test]
+    fn given_two_dimensional_algorithm_when_other_dimensions_then_none() {
+        assert!(by_name("himmelblau", 3).is_none());
+    }
 
-        let term1 = -20.0 * (-0.2 * (0.5 * (x * x + y * y)).sqrt()).exp();
-        let term2 = -(0.5 * (two_pi * x).cos() + 0.5 * (two_pi * y).cos()).exp();
+    #[test]
+    fn given_catalogue_name_when_looked_up_then_uses_library_domain() {
+        let algorithm = by_name("griewank", 3).unwrap();
 
-        term1 + term2 + e + 20.0
+        assert_eq!(algorithm.name(), "griewank");
+        assert_eq!(algorithm.param_ranges(), vec![-600.0..=600.0; 3]);
+        assert!(available_names().contains(&"griewank"));
+        assert!(by_name("nope", 2).is_none());
     }
 }
@@ -0,0 +1,327 @@
+//! Anytime performance: best score against evaluations, summarised as COCO-style ECDFs
+//!
+//! A run reaches a target once its best score is at or below it. The ECDF at a budget is
+//! the fraction of (run, target) pairs reached within that many evaluations, so
+//! configurations with different population sizes are compared on the same axis.
+
+use crate::runner::{AlgorithmResults, ConfigurationResults, SingleRunResult};
+use serde::{Deserialize, Serialize};
+use std::io::{self, Write};
+
+/// Targets per decade of score
+const TARGETS_PER_DECADE: i32 = 5;
+/// The loosest target is 10^2 and the tightest 10^-8, as in COCO
+const LOOSEST_TARGET_EXPONENT: i32 = 2;
+const TIGHTEST_TARGET_EXPONENT: i32 = -8;
+/// Budgets per decade of evaluations in the ECDF grid
+const BUDGETS_PER_DECADE: u32 = 10;
+
+/// The best score found after a number of evaluations
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct TrajectoryPoint {
+    pub evaluations: u64,
+    pub best_score: f64,
+}
+
+/// The score targets, loosest first: 51 values log-spaced from 10^2 to 10^-8
+pub fn targets() -> Vec<f64> {
+    (0..=(LOOSEST_TARGET_EXPONENT - TIGHTEST_TARGET_EXPONENT) * TARGETS_PER_DECADE)
+        .map(|k| 10f64.powf(LOOSEST_TARGET_EXPONENT as f64 - k as f64 / TARGETS_PER_DECADE as f64))
+        .collect()
+}
+
+/// Records the best-so-far trajectory of a run, keeping only the points that reach a new
+/// target so stored runs stay small while every target's first hit is exact
+#[derive(Debug, Clone)]
+pub struct TrajectoryRecorder {
+    targets: Vec<f64>,
+    points: Vec<TrajectoryPoint>,
+    reached: usize,
+    best_score: f64,
+    evaluations: u64,
+}
+
+impl Default for TrajectoryRecorder {
+    fn default() -> Self {
+        Self {
+            targets: targets(),
+            points: Vec::new(),
+            reached: 0,
+            best_score: f64::INFINITY,
+            evaluations: 0,
+        }
+    }
+}
+
+impl TrajectoryRecorder {
+    /// Note the best score found after `evaluations` evaluations in total
+    pub fn record(&mut self, evaluations: u64, best_score: f64) {
+        self.evaluations = evaluations;
+        if best_score >= self.best_score {
+            return;
+        }
+        self.best_score = best_score;
+        let reached = self.targets.iter().filter(|&&t| best_score <= t).count();
+        if self.points.is_empty() || reached > self.reached {
+            self.reached = reached;
+            self.points.push(TrajectoryPoint {
+                evaluations,
+                best_score,
+            });
+        }
+    }
+
+    /// The recorded points, ending with the final best score and evaluation count
+    pub fn finish(mut self) -> Vec<TrajectoryPoint> {
+        let last = TrajectoryPoint {
+            evaluations: self.evaluations,
+            best_score: self.best_score,
+        };
+        if self.best_score.is_finite() && self.points.last() != Some(&last) {
+            self.points.push(last);
+        }
+        self.points
+    }
+}
+
+/// Evaluations a run needed to reach `target`, or `None` if it never did
+pub fn evaluations_to_reach(trajectory: &[TrajectoryPoint], target: f64) -> Option<u64> {
+    trajectory
+        .iter()
+        .find(|point| point.best_score <= target)
+        .map(|point| point.evaluations)
+}
+
+/// Log-spaced budgets from 1 to `max_evaluations`, ending at `max_evaluations`
+pub fn budget_grid(max_evaluations: u64) -> Vec<u64> {
+    let mut budgets: Vec<u64> = (0..)
+        .map(|k| 10f64.powf(k as f64 / BUDGETS_PER_DECADE as f64).round() as u64)
+        .take_while(|&budget| budget < max_evaluations)
+        .collect();
+    budgets.dedup();
+    budgets.push(max_evaluations.max(1));
+    budgets
+}
+
+/// The fraction of (run, target) pairs reached within each budget
+pub fn ecdf(runs: &[SingleRunResult], budgets: &[u64]) -> Vec<f64> {
+    let targets = targets();
+    let hits: Vec<u64> = runs
+        .iter()
+        .flat_map(|run| {
+            targets
+                .iter()
+                .filter_map(|&target| evaluations_to_reach(&run.trajectory, target))
+        })
+        .collect();
+    let pairs = (runs.len() * targets.len()) as f64;
+    budgets
+        .iter()
+        .map(|&budget| {
+            if pairs == 0.0 {
+                0.0
+            } else {
+                hits.iter().filter(|&&hit| hit <= budget).count() as f64 / pairs
+            }
+        })
+        .collect()
+}
+
+/// The largest evaluation count of any run of a configuration
+fn max_evaluations(config: &ConfigurationResults) -> u64 {
+    config
+        .runs
+        .iter()
+        .map(|run| run.evaluations)
+        .max()
+        .unwrap_or(0)
+}
+
+/// Every optimiser's configurations, hill_descent first
+fn optimiser_configurations(
+    results: &AlgorithmResults,
+) -> impl Iterator<Item = (&str, &ConfigurationResults)> {
+    let hill_descent = results
+        .configurations
+        .iter()
+        .map(|config| ("hill_descent", config));
+    let baselines = results.baselines.iter().flat_map(|baseline| {
+        baseline
+            .configurations
+            .iter()
+            .map(move |config| (baseline.name.as_str(), config))
+    });
+    hill_descent.chain(baselines)
+}
+
+/// The CSV header written by [`write_ecdf_csv`]
+pub const ECDF_CSV_HEADER: &str = "optimiser,population,regions,evaluations,fraction_of_targets";
+
+/// Write the ECDF of every optimiser and configuration as CSV, one row per budget
+pub fn write_ecdf_csv(mut writer: impl Write, results: &AlgorithmResults) -> io::Result<()> {
+    writeln!(writer, "{ECDF_CSV_HEADER}")?;
+    for (optimiser, config) in optimiser_configurations(results) {
+        let budgets = budget_grid(max_evaluations(config));
+        for (budget, fraction) in budgets.iter().zip(ecdf(&config.runs, &budgets)) {
+            writeln!(
+                writer,
+                "{optimiser},{},{},{budget},{fraction}",
+                config.population, config.regions
+            )?;
+        }
+    }
+    Ok(())
+}
+
+/// Write a markdown table of the fraction of targets each optimiser reached within decade
+/// budgets
+pub fn write_anytime_table(mut writer: impl Write, results: &AlgorithmResults) -> io::Result<()> {
+    let max = optimiser_configurations(results)
+        .map(|(_, config)| max_evaluations(config))
+        .max()
+        .unwrap_or(0);
+    let budgets: Vec<u64> = std::iter::successors(Some(100u64), |b| b.checked_mul(10))
+        .take_while(|&budget| budget < max)
+        .chain(std::iter::once(max))
+        .collect();
+
+    writeln!(writer, "## Anytime Performance")?;
+    writeln!(writer)?;
+    writeln!(
+        writer,
+        "Fraction of the {} targets (10^{LOOSEST_TARGET_EXPONENT} to 10^{TIGHTEST_TARGET_EXPONENT}) reached within each number of evaluations.",
+        targets().len()
+    )?;
+    writeln!(writer)?;
+    let headings: Vec<String> = budgets.iter().map(|b| format!("≤ {b}")).collect();
+    writeln!(
+        writer,
+        "| Optimiser | Population | Regions | {} |",
+        headings.join(" | ")
+    )?;
+    writeln!(writer, "|{}", "---|".repeat(budgets.len() + 3))?;
+    for (optimiser, config) in optimiser_configurations(results) {
+        let fractions: Vec<String> = ecdf(&config.runs, &budgets)
+            .iter()
+            .map(|fraction| format!("{fraction:.3}"))
+            .collect();
+        writeln!(
+            writer,
+            "| {optimiser} | {} | {} | {} |",
+            config.population,
+            config.regions,
+            fractions.join(" | ")
+        )?;
+    }
+    writeln!(writer)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(trajectory: Vec<TrajectoryPoint>) -> SingleRunResult {
+        SingleRunResult {
+            seed: 2,
+            rounds_taken: 10,
+            resolution_limit_count: 0,
+            best_score: trajectory.last().map_or(f64::INFINITY, |p| p.best_score),
+            duration_secs: 0.1,
+            evaluations: trajectory.last().map_or(0, |p| p.evaluations),
+            trajectory,
+        }
+    }
+
+    fn point(evaluations: u64, best_score: f64) -> TrajectoryPoint {
+        TrajectoryPoint {
+            evaluations,
+            best_score,
+        }
+    }
+
+    #[test]
+    fn given_targets_then_51_from_100_to_1e_minus_8() {
+        let targets = targets();
+
+        assert_eq!(targets.len(), 51);
+        assert_eq!(targets[0], 100.0);
+        assert!((targets[50] - 1e-8).abs() < 1e-20);
+        assert!(targets.windows(2).all(|w| w[1] < w[0]));
+    }
+
+    #[test]
+    fn given_improvements_when_recorded_then_only_new_targets_kept() {
+        let mut recorder = TrajectoryRecorder::default();
+
+        recorder.record(10, 50.0);
+        recorder.record(20, 49.0); // same target band as 50
+        recorder.record(30, 9.0);
+        recorder.record(40, 20.0); // worse, ignored
+        recorder.record(50, 9.0);
+        let trajectory = recorder.finish();
+
+        assert_eq!(
+            trajectory,
+            vec![point(10, 50.0), point(30, 9.0), point(50, 9.0)]
+        );
+        assert_eq!(evaluations_to_reach(&trajectory, 49.5), Some(30));
+        assert_eq!(evaluations_to_reach(&trajectory, 1.0), None);
+    }
+
+    #[test]
+    fn given_max_evaluations_when_budget_grid_then_log_spaced_and_ends_at_max() {
+        let grid = budget_grid(1_000);
+
+        assert_eq!(grid[0], 1);
+        assert_eq!(*grid.last().unwrap(), 1_000);
+        assert!(grid.windows(2).all(|w| w[0] < w[1]));
+        assert!(grid.contains(&10) && grid.contains(&100));
+    }
+
+    #[test]
+    fn given_runs_when_ecdf_then_fraction_of_targets_reached_by_budget() {
+        // 1.0 reaches the 11 targets from 100 down to 1; 1e-9 reaches them all
+        let runs = vec![
+            run(vec![point(10, 1.0)]),
+            run(vec![point(5, 1e3), point(100, 1e-9)]),
+        ];
+
+        let fractions = ecdf(&runs, &[1, 10, 100]);
+
+        assert_eq!(fractions, vec![0.0, 11.0 / 102.0, 62.0 / 102.0]);
+    }
+
+    #[test]
+    fn given_results_when_written_then_csv_and_table_cover_every_optimiser() {
+        let mut config = ConfigurationResults::new(10, 2);
+        config
+            .runs
+            .push(run(vec![point(10, 1.0), point(1_000, 1e-9)]));
+        let results = AlgorithmResults {
+            algorithm_name: "sphere".to_string(),
+            dimensions: 2,
+            max_rounds: 100,
+            configurations: vec![config.clone()],
+            baselines: vec![crate::runner::BaselineResults {
+                name: "cma_es".to_string(),
+                configurations: vec![config],
+            }],
+        };
+        let mut csv = Vec::new();
+        let mut table = Vec::new();
+
+        write_ecdf_csv(&mut csv, &results).unwrap();
+        write_anytime_table(&mut table, &results).unwrap();
+
+        let csv = String::from_utf8(csv).unwrap();
+        assert!(csv.starts_with(ECDF_CSV_HEADER));
+        assert!(csv.contains("hill_descent,10,2,1000,1\n"), "{csv}");
+        assert!(csv.contains("cma_es,10,2,1000,1\n"), "{csv}");
+        let table = String::from_utf8(table).unwrap();
+        assert!(table.contains("| ≤ 100 | ≤ 1000 |"), "{table}");
+        assert!(
+            table.contains("| cma_es | 10 | 2 | 0.216 | 1.000 |"),
+            "{table}"
+        );
+    }
+}
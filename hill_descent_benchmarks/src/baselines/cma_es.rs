@@ -0,0 +1,234 @@
+use super::{standard_normal, Baseline, Objective};
+use rand::rngs::StdRng;
+use rand::Rng;
+
+/// Initial step size in the unit cube the bounds are mapped onto
+const INITIAL_SIGMA: f64 = 0.3;
+/// The fewest offspring per generation that still leave two parents to recombine
+const MIN_POPULATION: usize = 4;
+/// Smallest eigenvalue kept when decomposing the covariance, guarding against round-off
+const MIN_EIGENVALUE: f64 = 1e-20;
+
+/// A basic covariance matrix adaptation evolution strategy, (μ/μ_w, λ)-CMA-ES
+///
+/// Follows Hansen's tutorial: weighted recombination of the best half, cumulative step-size
+/// adaptation and rank-one plus rank-μ covariance updates. λ is the configuration's
+/// population size and the search runs in the bounds scaled to a unit cube, without restarts.
+pub struct CmaEs;
+
+impl Baseline for CmaEs {
+    fn name(&self) -> &'static str {
+        "cma_es"
+    }
+
+    fn minimise(&self, objective: &mut Objective, population: usize, rng: &mut StdRng) {
+        let n = objective.dimensions();
+        let nf = n as f64;
+        let lambda = population.max(MIN_POPULATION);
+        let mu = lambda / 2;
+
+        let raw: Vec<f64> = (1..=mu)
+            .map(|i| (mu as f64 + 0.5).ln() - (i as f64).ln())
+            .collect();
+        let total: f64 = raw.iter().sum();
+        let weights: Vec<f64> = raw.iter().map(|w| w / total).collect();
+        let mueff = 1.0 / weights.iter().map(|w| w * w).sum::<f64>();
+
+        let cc = (4.0 + mueff / nf) / (nf + 4.0 + 2.0 * mueff / nf);
+        let cs = (mueff + 2.0) / (nf + mueff + 5.0);
+        let c1 = 2.0 / ((nf + 1.3).powi(2) + mueff);
+        let cmu = (1.0 - c1).min(2.0 * (mueff - 2.0 + 1.0 / mueff) / ((nf + 2.0).powi(2) + mueff));
+        let damps = 1.0 + 2.0 * (((mueff - 1.0) / (nf + 1.0)).sqrt() - 1.0).max(0.0) + cs;
+        let chi_n = nf.sqrt() * (1.0 - 1.0 / (4.0 * nf) + 1.0 / (21.0 * nf * nf));
+
+        let lower: Vec<f64> = objective.bounds().iter().map(|r| *r.start()).collect();
+        let widths: Vec<f64> = objective
+            .bounds()
+            .iter()
+            .map(|r| r.end() - r.start())
+            .collect();
+
+        let mut mean: Vec<f64> = (0..n).map(|_| rng.random::<f64>()).collect();
+        let mut sigma = INITIAL_SIGMA;
+        let mut covariance = identity(n);
+        let mut path_sigma = vec![0.0; n];
+        let mut path_c = vec![0.0; n];
+        let mut generation = 0;
+
+        while objective.remaining() > 0 {
+            let (basis, scales) = eigen_decomposition(&covariance);
+
+            // Sample, evaluate and sort the offspring in unit-cube coordinates
+            let mut offspring: Vec<(f64, Vec<f64>)> = Vec::with_capacity(lambda);
+            for _ in 0..lambda.min(objective.remaining()) {
+                let z: Vec<f64> = (0..n).map(|_| standard_normal(rng)).collect();
+                let mut unit: Vec<f64> = (0..n)
+                    .map(|i| {
+                        let y: f64 = (0..n).map(|j| basis[i][j] * scales[j] * z[j]).sum();
+                        (mean[i] + sigma * y).clamp(0.0, 1.0)
+                    })
+                    .collect();
+                let mut point: Vec<f64> = unit
+                    .iter()
+                    .zip(lower.iter().zip(&widths))
+                    .map(|(u, (low, width))| low + u * width)
+                    .collect();
+                let score = objective.evaluate(&mut point);
+                // Clamping may move the point slightly, so map back what was evaluated
+                for (u, (x, (low, width))) in unit
+                    .iter_mut()
+                    .zip(point.iter().zip(lower.iter().zip(&widths)))
+                {
+                    if *width > 0.0 {
+                        *u = (x - low) / width;
+                    }
+                }
+                offspring.push((score, unit));
+            }
+            if offspring.len() < lambda {
+                return;
+            }
+            offspring.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+            let old_mean = mean;
+            mean = (0..n)
+                .map(|i| {
+                    weights
+                        .iter()
+                        .zip(&offspring)
+                        .map(|(w, (_, x))| w * x[i])
+                        .sum()
+                })
+                .collect();
+            let steps: Vec<Vec<f64>> = offspring[..mu]
+                .iter()
+                .map(|(_, x)| (0..n).map(|i| (x[i] - old_mean[i]) / sigma).collect())
+                .collect();
+            let mean_step: Vec<f64> = (0..n).map(|i| (mean[i] - old_mean[i]) / sigma).collect();
+
+            // Step-size path uses C^(-1/2) * mean_step = B D^-1 B^T mean_step
+            let rotated: Vec<f64> = (0..n)
+                .map(|j| (0..n).map(|i| basis[i][j] * mean_step[i]).sum::<f64>() / scales[j])
+                .collect();
+            let whitened: Vec<f64> = (0..n)
+                .map(|i| (0..n).map(|j| basis[i][j] * rotated[j]).sum())
+                .collect();
+            let sigma_rate = (cs * (2.0 - cs) * mueff).sqrt();
+            for (p, w) in path_sigma.iter_mut().zip(&whitened) {
+                *p = (1.0 - cs) * *p + sigma_rate * w;
+            }
+            let path_sigma_norm = norm(&path_sigma);
+
+            generation += 1;
+            let correction = (1.0 - (1.0 - cs).powi(2 * generation)).sqrt();
+            let stalled = path_sigma_norm / correction / chi_n >= 1.4 + 2.0 / (nf + 1.0);
+            let h_sigma = if stalled { 0.0 } else { 1.0 };
+            let c_rate = h_sigma * (cc * (2.0 - cc) * mueff).sqrt();
+            for (p, s) in path_c.iter_mut().zip(&mean_step) {
+                *p = (1.0 - cc) * *p + c_rate * s;
+            }
+
+            let keep = 1.0 - c1 - cmu + (1.0 - h_sigma) * c1 * cc * (2.0 - cc);
+            for i in 0..n {
+                for j in 0..n {
+                    let rank_mu: f64 = weights
+                        .iter()
+                        .zip(&steps)
+                        .map(|(w, y)| w * y[i] * y[j])
+                        .sum();
+                    covariance[i][j] =
+                        keep * covariance[i][j] + c1 * path_c[i] * path_c[j] + cmu * rank_mu;
+                }
+            }
+
+            // Steps beyond the unit cube are meaningless and below epsilon change nothing
+            sigma = (sigma * ((cs / damps) * (path_sigma_norm / chi_n - 1.0)).exp())
+                .clamp(f64::EPSILON, 1.0);
+        }
+    }
+}
+
+fn identity(n: usize) -> Vec<Vec<f64>> {
+    (0..n)
+        .map(|i| (0..n).map(|j| if i == j { 1.0 } else { 0.0 }).collect())
+        .collect()
+}
+
+fn norm(vector: &[f64]) -> f64 {
+    vector.iter().map(|v| v * v).sum::<f64>().sqrt()
+}
+
+/// Decompose a symmetric matrix as `B diag(D²) Bᵀ` with the cyclic Jacobi method, returning
+/// `B` (eigenvectors as columns) and `D` (square roots of the eigenvalues)
+fn eigen_decomposition(matrix: &[Vec<f64>]) -> (Vec<Vec<f64>>, Vec<f64>) {
+    let n = matrix.len();
+    let mut a: Vec<Vec<f64>> = matrix.to_vec();
+    let mut vectors = identity(n);
+
+    for _sweep in 0..100 {
+        let (mut diagonal, mut off_diagonal) = (0.0, 0.0);
+        for (i, row) in a.iter().enumerate() {
+            for (j, value) in row.iter().enumerate() {
+                if i == j {
+                    diagonal += value * value;
+                } else {
+                    off_diagonal += value * value;
+                }
+            }
+        }
+        if off_diagonal <= 1e-24 * diagonal {
+            break;
+        }
+        for p in 0..n {
+            for q in p + 1..n {
+                if a[p][q].abs() < 1e-300 {
+                    continue;
+                }
+                let theta = (a[q][q] - a[p][p]) / (2.0 * a[p][q]);
+                let t = theta.signum() / (theta.abs() + (theta * theta + 1.0).sqrt());
+                let c = 1.0 / (t * t + 1.0).sqrt();
+                let s = t * c;
+                // A' = Jᵀ A J and B' = B J for the rotation J in the (p, q) plane
+                for row in a.iter_mut().chain(vectors.iter_mut()) {
+                    let (kp, kq) = (row[p], row[q]);
+                    row[p] = c * kp - s * kq;
+                    row[q] = s * kp + c * kq;
+                }
+                let (upper, lower) = a.split_at_mut(q);
+                for (pk, qk) in upper[p].iter_mut().zip(lower[0].iter_mut()) {
+                    let (old_pk, old_qk) = (*pk, *qk);
+                    *pk = c * old_pk - s * old_qk;
+                    *qk = s * old_pk + c * old_qk;
+                }
+            }
+        }
+    }
+
+    let scales = (0..n).map(|i| a[i][i].max(MIN_EIGENVALUE).sqrt()).collect();
+    (vectors, scales)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn given_symmetric_matrix_when_decomposed_then_reconstructs() {
+        let matrix = vec![
+            vec![4.0, 1.0, 0.5],
+            vec![1.0, 3.0, 0.2],
+            vec![0.5, 0.2, 2.0],
+        ];
+
+        let (basis, scales) = eigen_decomposition(&matrix);
+
+        for i in 0..3 {
+            for j in 0..3 {
+                let rebuilt: f64 = (0..3)
+                    .map(|k| basis[i][k] * scales[k] * scales[k] * basis[j][k])
+                    .sum();
+                assert!((rebuilt - matrix[i][j]).abs() < 1e-10, "{i},{j}: {rebuilt}");
+            }
+        }
+    }
+}
@@ -0,0 +1,73 @@
+use super::{uniform_point, Baseline, Objective};
+use rand::rngs::StdRng;
+use rand::Rng;
+
+/// Differential weight
+const F: f64 = 0.5;
+/// Crossover probability
+const CR: f64 = 0.9;
+/// The fewest agents DE/rand/1 can pick three distinct partners from
+const MIN_POPULATION: usize = 4;
+
+/// Classic DE/rand/1/bin differential evolution with the configuration's population size
+pub struct DifferentialEvolution;
+
+impl Baseline for DifferentialEvolution {
+    fn name(&self) -> &'static str {
+        "differential_evolution"
+    }
+
+    fn minimise(&self, objective: &mut Objective, population: usize, rng: &mut StdRng) {
+        let size = population.max(MIN_POPULATION);
+        let dimensions = objective.dimensions();
+
+        let mut agents = Vec::with_capacity(size);
+        let mut scores = Vec::with_capacity(size);
+        while agents.len() < size && objective.remaining() > 0 {
+            let mut agent = uniform_point(objective, rng);
+            scores.push(objective.evaluate(&mut agent));
+            agents.push(agent);
+        }
+        if agents.len() < size {
+            return;
+        }
+
+        while objective.remaining() > 0 {
+            for i in 0..size {
+                if objective.remaining() == 0 {
+                    return;
+                }
+                let [a, b, c] = distinct_partners(i, size, rng);
+                let forced = rng.random_range(0..dimensions);
+                let mut trial: Vec<f64> = (0..dimensions)
+                    .map(|d| {
+                        if d == forced || rng.random::<f64>() < CR {
+                            agents[a][d] + F * (agents[b][d] - agents[c][d])
+                        } else {
+                            agents[i][d]
+                        }
+                    })
+                    .collect();
+                let score = objective.evaluate(&mut trial);
+                if score <= scores[i] {
+                    agents[i] = trial;
+                    scores[i] = score;
+                }
+            }
+        }
+    }
+}
+
+/// Three distinct agent indices, none of them `exclude`
+fn distinct_partners(exclude: usize, size: usize, rng: &mut StdRng) -> [usize; 3] {
+    let mut picked = [exclude; 3];
+    for slot in 0..3 {
+        picked[slot] = loop {
+            let candidate = rng.random_range(0..size);
+            if candidate != exclude && !picked[..slot].contains(&candidate) {
+                break candidate;
+            }
+        };
+    }
+    picked
+}
@@ -0,0 +1,115 @@
+//! Reference optimisers run alongside hill_descent on every function
+//!
+//! Each baseline gets the evaluation budget hill_descent uses for the same configuration,
+//! one evaluation per organism per round, so reports compare the algorithms on equal terms.
+
+mod cma_es;
+mod differential_evolution;
+mod objective;
+mod one_plus_one_es;
+mod random_search;
+
+pub use cma_es::CmaEs;
+pub use differential_evolution::DifferentialEvolution;
+pub use objective::Objective;
+pub use one_plus_one_es::OnePlusOneEs;
+pub use random_search::RandomSearch;
+
+use rand::rngs::StdRng;
+use rand::Rng;
+
+/// Names of every baseline, in report order
+pub const BASELINE_NAMES: &[&str] = &[
+    "random_search",
+    "one_plus_one_es",
+    "differential_evolution",
+    "cma_es",
+];
+
+/// An optimiser minimising an [`Objective`] within its evaluation budget
+pub trait Baseline: Sync {
+    fn name(&self) -> &'static str;
+
+    /// Minimise `objective` until its budget is spent. `population` is the population size of
+    /// the configuration being compared, used by population-based baselines.
+    fn minimise(&self, objective: &mut Objective, population: usize, rng: &mut StdRng);
+}
+
+/// Look up a baseline by name
+pub fn by_name(name: &str) -> Option<Box<dyn Baseline>> {
+    match name {
+        "random_search" => Some(Box::new(RandomSearch)),
+        "one_plus_one_es" => Some(Box::new(OnePlusOneEs)),
+        "differential_evolution" => Some(Box::new(DifferentialEvolution)),
+        "cma_es" => Some(Box::new(CmaEs)),
+        _ => None,
+    }
+}
+
+/// Draw from `N(0, 1)` using the Box-Muller transform
+fn standard_normal(rng: &mut StdRng) -> f64 {
+    let u1: f64 = 1.0 - rng.random::<f64>();
+    let u2: f64 = rng.random();
+    (-2.0 * u1.ln()).sqrt() * (2.0 * std::f64::consts::PI * u2).cos()
+}
+
+/// Draw a point uniformly from the objective's bounds
+fn uniform_point(objective: &Objective, rng: &mut StdRng) -> Vec<f64> {
+    objective
+        .bounds()
+        .iter()
+        .map(|range| rng.random_range(range.clone()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hill_descent_lib::benchmarks;
+    use rand::SeedableRng;
+
+    #[test]
+    fn given_every_name_when_looked_up_then_found() {
+        for name in BASELINE_NAMES {
+            assert_eq!(by_name(name).unwrap().name(), *name);
+        }
+        assert!(by_name("nope").is_none());
+    }
+
+    #[test]
+    fn given_sphere_when_minimised_then_every_baseline_improves_and_keeps_budget() {
+        let function = benchmarks::by_name("sphere", 3).unwrap();
+        let bounds = vec![-5.0..=5.0; 3];
+
+        for name in BASELINE_NAMES {
+            let mut objective = Objective::new(&function, &bounds, 2_000);
+            let mut rng = StdRng::seed_from_u64(7);
+
+            by_name(name)
+                .unwrap()
+                .minimise(&mut objective, 20, &mut rng);
+
+            assert_eq!(objective.remaining(), 0, "{name}");
+            assert!(
+                objective.best_score() < 0.5,
+                "{name}: {}",
+                objective.best_score()
+            );
+        }
+    }
+
+    #[test]
+    fn given_population_smaller_than_minimum_when_minimised_then_budget_still_spent() {
+        let function = benchmarks::by_name("rastrigin", 2).unwrap();
+        let bounds = vec![-5.12..=5.12; 2];
+
+        for name in BASELINE_NAMES {
+            let mut objective = Objective::new(&function, &bounds, 37);
+            let mut rng = StdRng::seed_from_u64(3);
+
+            by_name(name).unwrap().minimise(&mut objective, 1, &mut rng);
+
+            assert_eq!(objective.remaining(), 0, "{name}");
+        }
+    }
+}
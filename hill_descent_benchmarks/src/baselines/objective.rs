@@ -0,0 +1,130 @@
+use crate::anytime::{TrajectoryPoint, TrajectoryRecorder};
+use hill_descent_lib::WorldFunction;
+use std::ops::RangeInclusive;
+
+/// A function to minimise with a fixed evaluation budget
+///
+/// Points are clamped into the bounds before evaluation, and each evaluation is scored the
+/// way the world scores an organism: the distance of the outputs from the function floor.
+/// The best score is tracked against the evaluation count for anytime performance.
+pub struct Objective<'a> {
+    function: &'a dyn WorldFunction,
+    bounds: &'a [RangeInclusive<f64>],
+    floor: f64,
+    budget: usize,
+    evaluations: usize,
+    best_score: f64,
+    trajectory: TrajectoryRecorder,
+}
+
+impl<'a> Objective<'a> {
+    pub fn new(
+        function: &'a dyn WorldFunction,
+        bounds: &'a [RangeInclusive<f64>],
+        budget: usize,
+    ) -> Self {
+        assert!(!bounds.is_empty(), "An objective needs at least one bound");
+        Self {
+            function,
+            bounds,
+            floor: function.function_floor(),
+            budget,
+            evaluations: 0,
+            best_score: f64::INFINITY,
+            trajectory: TrajectoryRecorder::default(),
+        }
+    }
+
+    pub fn bounds(&self) -> &[RangeInclusive<f64>] {
+        self.bounds
+    }
+
+    pub fn dimensions(&self) -> usize {
+        self.bounds.len()
+    }
+
+    /// Evaluations left in the budget
+    pub fn remaining(&self) -> usize {
+        self.budget - self.evaluations
+    }
+
+    pub fn evaluations(&self) -> usize {
+        self.evaluations
+    }
+
+    /// The lowest score evaluated so far, infinite before the first evaluation
+    pub fn best_score(&self) -> f64 {
+        self.best_score
+    }
+
+    /// Clamp a point into the bounds
+    fn clamp(&self, point: &mut [f64]) {
+        for (value, range) in point.iter_mut().zip(self.bounds) {
+            *value = value.clamp(*range.start(), *range.end());
+        }
+    }
+
+    /// Clamp `point` into the bounds and score it. Non-finite outputs score infinity.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the budget has been spent.
+    pub fn evaluate(&mut self, point: &mut [f64]) -> f64 {
+        assert!(self.remaining() > 0, "The evaluation budget has been spent");
+        self.clamp(point);
+        self.evaluations += 1;
+
+        let outputs = self.function.run(point, &[]);
+        let score = outputs
+            .iter()
+            .map(|output| (output - self.floor).powi(2))
+            .sum::<f64>()
+            .sqrt();
+        let score = if score.is_finite() {
+            score
+        } else {
+            f64::INFINITY
+        };
+        self.best_score = self.best_score.min(score);
+        self.trajectory
+            .record(self.evaluations as u64, self.best_score);
+        score
+    }
+
+    /// The best-so-far trajectory of the evaluations made
+    pub fn into_trajectory(self) -> Vec<TrajectoryPoint> {
+        self.trajectory.finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hill_descent_lib::benchmarks;
+
+    #[test]
+    fn given_point_outside_bounds_when_evaluated_then_clamped_and_counted() {
+        let function = benchmarks::by_name("sphere", 2).unwrap();
+        let bounds = vec![-1.0..=1.0; 2];
+        let mut objective = Objective::new(&function, &bounds, 2);
+        let mut point = vec![3.0, 0.0];
+
+        let score = objective.evaluate(&mut point);
+
+        assert_eq!(point, vec![1.0, 0.0]);
+        assert_eq!(score, 1.0);
+        assert_eq!(objective.remaining(), 1);
+        assert_eq!(objective.best_score(), 1.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "budget has been spent")]
+    fn given_spent_budget_when_evaluated_then_panics() {
+        let function = benchmarks::by_name("sphere", 1).unwrap();
+        let bounds = vec![-1.0..=1.0];
+        let mut objective = Objective::new(&function, &bounds, 1);
+
+        objective.evaluate(&mut [0.0]);
+        objective.evaluate(&mut [0.0]);
+    }
+}
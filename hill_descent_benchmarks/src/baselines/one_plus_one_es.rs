@@ -0,0 +1,47 @@
+use super::{standard_normal, uniform_point, Baseline, Objective};
+use rand::rngs::StdRng;
+
+/// Initial step size as a fraction of each bound's width
+const INITIAL_STEP: f64 = 0.2;
+
+/// A (1+1) evolution strategy: one parent, one Gaussian offspring per step, kept if no worse
+///
+/// The step size follows the one-fifth success rule, growing after a success and shrinking
+/// after a failure so that about one offspring in five succeeds.
+pub struct OnePlusOneEs;
+
+impl Baseline for OnePlusOneEs {
+    fn name(&self) -> &'static str {
+        "one_plus_one_es"
+    }
+
+    fn minimise(&self, objective: &mut Objective, _population: usize, rng: &mut StdRng) {
+        if objective.remaining() == 0 {
+            return;
+        }
+        let widths: Vec<f64> = objective
+            .bounds()
+            .iter()
+            .map(|range| range.end() - range.start())
+            .collect();
+        let mut parent = uniform_point(objective, rng);
+        let mut parent_score = objective.evaluate(&mut parent);
+        let mut step = INITIAL_STEP;
+
+        while objective.remaining() > 0 {
+            let mut offspring: Vec<f64> = parent
+                .iter()
+                .zip(&widths)
+                .map(|(value, width)| value + step * width * standard_normal(rng))
+                .collect();
+            let score = objective.evaluate(&mut offspring);
+            if score <= parent_score {
+                parent = offspring;
+                parent_score = score;
+                step *= (1.0f64 / 3.0).exp();
+            } else {
+                step *= (-1.0f64 / 12.0).exp();
+            }
+        }
+    }
+}
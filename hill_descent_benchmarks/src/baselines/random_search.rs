@@ -0,0 +1,18 @@
+use super::{uniform_point, Baseline, Objective};
+use rand::rngs::StdRng;
+
+/// Uniform random sampling of the bounds, the floor any optimiser should beat
+pub struct RandomSearch;
+
+impl Baseline for RandomSearch {
+    fn name(&self) -> &'static str {
+        "random_search"
+    }
+
+    fn minimise(&self, objective: &mut Objective, _population: usize, rng: &mut StdRng) {
+        while objective.remaining() > 0 {
+            let mut point = uniform_point(objective, rng);
+            objective.evaluate(&mut point);
+        }
+    }
+}
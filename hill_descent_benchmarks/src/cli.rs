@@ -0,0 +1,466 @@
+use crate::algorithms::{self, BenchmarkAlgorithm};
+use crate::baselines;
+use crate::compare::Thresholds;
+use crate::runner::{seeds_for_runs, RunSettings};
+use std::path::PathBuf;
+
+pub const USAGE: &str = "\
+Usage: hill_descent_benchmarks [run] [options]
+       hill_descent_benchmarks list
+       hill_descent_benchmarks compare BASELINE CANDIDATE [compare options]
+
+Options:
+  --algorithms NAMES   Comma-separated algorithms to run, or `all` (default: the standard seven)
+  --configs GRID       Comma-separated POPULATIONxREGIONS pairs, e.g. 100x10,500x20
+  --seeds SEEDS        Comma-separated seeds, one run per seed for each configuration
+  --runs N             Runs per configuration using the first N prime seeds (default: 20)
+  --rounds N           Training rounds per run (default: 1000)
+  --dimensions N       Dimensions of each function (default: 2)
+  --baselines NAMES    Comma-separated baseline optimisers run with the same evaluation budget, `all` or
+                       `none` (default: all of random_search, one_plus_one_es, differential_evolution, cma_es)
+  --threads N          Worker threads (default: one per core)
+  --output-dir DIR     Directory receiving results (default: run_stats)
+  --keep-existing      Keep earlier results from today's run directory instead of cleaning it
+
+BASELINE and CANDIDATE are run directories or git hashes of runs under the run stats directory.
+Exits with status 2 if the candidate regressed.
+
+Compare options:
+  --run-stats DIR      Directory searched for git hashes (default: run_stats)
+  --alpha P            Mann-Whitney p-value below which a difference is significant (default: 0.05)
+  --score-tolerance F  Relative increase of the average best score tolerated (default: 0)
+  --time-tolerance F   Relative increase of the average run time tolerated (default: time not checked)";
+
+/// What the benchmark runner was asked to do
+#[derive(Debug, Clone, PartialEq)]
+pub enum Command {
+    Run(RunOptions),
+    /// List the algorithms that can be selected
+    List,
+    Compare(CompareOptions),
+    Help,
+}
+
+/// Options for comparing two benchmark runs
+#[derive(Debug, Clone, PartialEq)]
+pub struct CompareOptions {
+    pub baseline: String,
+    pub candidate: String,
+    pub run_stats_dir: PathBuf,
+    pub thresholds: Thresholds,
+}
+
+/// Options for a benchmark run
+#[derive(Debug, Clone, PartialEq)]
+pub struct RunOptions {
+    pub algorithms: Vec<String>,
+    pub settings: RunSettings,
+    pub threads: Option<usize>,
+    pub output_dir: PathBuf,
+    pub keep_existing: bool,
+}
+
+impl Default for RunOptions {
+    fn default() -> Self {
+        Self {
+            algorithms: algorithms::DEFAULT_ALGORITHMS
+                .iter()
+                .map(|name| name.to_string())
+                .collect(),
+            settings: RunSettings::default(),
+            threads: None,
+            output_dir: PathBuf::from("run_stats"),
+            keep_existing: false,
+        }
+    }
+}
+
+impl RunOptions {
+    /// Look up the selected algorithms with the configured dimensions
+    pub fn resolve_algorithms(&self) -> Result<Vec<Box<dyn BenchmarkAlgorithm>>, String> {
+        let dimensions = self.settings.dimensions;
+        self.algorithms
+            .iter()
+            .map(|name| {
+                algorithms::by_name(name, dimensions).ok_or_else(|| {
+                    format!(
+                        "No {dimensions}-dimensional algorithm named '{name}' (available: {})",
+                        algorithms::available_names().join(", ")
+                    )
+                })
+            })
+            .collect()
+    }
+}
+
+/// Parse the command-line arguments, excluding the program name
+pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Command, String> {
+    let mut args = args.into_iter().peekable();
+    match args.peek().map(String::as_str) {
+        Some("list") => {
+            args.next();
+            return match args.next() {
+                None => Ok(Command::List),
+                Some(arg) => Err(format!("Unexpected argument '{arg}'")),
+            };
+        }
+        Some("compare") => {
+            args.next();
+            return parse_compare(args);
+        }
+        Some("run") => {
+            args.next();
+        }
+        _ => {}
+    }
+
+    let mut options = RunOptions::default();
+    let mut seeds = None;
+    let mut runs = None;
+    while let Some(arg) = args.next() {
+        let (flag, inline_value) = match arg.split_once('=') {
+            Some((flag, value)) => (flag.to_string(), Some(value.to_string())),
+            None => (arg, None),
+        };
+        let mut value = || {
+            inline_value
+                .clone()
+                .or_else(|| args.next())
+                .ok_or_else(|| format!("{flag} needs a value"))
+        };
+        match flag.as_str() {
+            "--help" | "-h" => return Ok(Command::Help),
+            "--keep-existing" => options.keep_existing = true,
+            "--algorithms" => {
+                let names = value()?;
+                options.algorithms = if names == "all" {
+                    algorithms::available_names()
+                        .into_iter()
+                        .map(str::to_string)
+                        .collect()
+                } else {
+                    split_list(&names, &flag, |name| Ok(name.to_string()))?
+                };
+            }
+            "--baselines" => {
+                let names = value()?;
+                options.settings.baselines = match names.as_str() {
+                    "all" => baselines::BASELINE_NAMES
+                        .iter()
+                        .map(|name| name.to_string())
+                        .collect(),
+                    "none" => Vec::new(),
+                    _ => split_list(&names, &flag, parse_baseline)?,
+                };
+            }
+            "--configs" => {
+                options.settings.configs = split_list(&value()?, &flag, parse_config)?;
+            }
+            "--seeds" => {
+                seeds = Some(split_list(&value()?, &flag, |s| parse_number(s, &flag))?);
+            }
+            "--runs" => runs = Some(parse_positive(&value()?, &flag)?),
+            "--rounds" => options.settings.max_rounds = parse_positive(&value()?, &flag)?,
+            "--dimensions" => {
+                options.settings.dimensions = parse_positive(&value()?, &flag)?;
+            }
+            "--threads" => options.threads = Some(parse_positive(&value()?, &flag)?),
+            "--output-dir" => options.output_dir = PathBuf::from(value()?),
+            _ => return Err(format!("Unexpected argument '{flag}'")),
+        }
+    }
+
+    match (seeds, runs) {
+        (Some(_), Some(_)) => return Err("Give either --seeds or --runs, not both".to_string()),
+        (Some(seeds), None) => options.settings.seeds = seeds,
+        (None, Some(runs)) => options.settings.seeds = seeds_for_runs(runs),
+        (None, None) => {}
+    }
+    Ok(Command::Run(options))
+}
+
+/// Parse the arguments following `compare`
+fn parse_compare(mut args: impl Iterator<Item = String>) -> Result<Command, String> {
+    let mut runs = Vec::new();
+    let mut run_stats_dir = PathBuf::from("run_stats");
+    let mut thresholds = Thresholds::default();
+    while let Some(arg) = args.next() {
+        if !arg.starts_with('-') {
+            runs.push(arg);
+            continue;
+        }
+        let (flag, inline_value) = match arg.split_once('=') {
+            Some((flag, value)) => (flag.to_string(), Some(value.to_string())),
+            None => (arg, None),
+        };
+        let mut value = || {
+            inline_value
+                .clone()
+                .or_else(|| args.next())
+                .ok_or_else(|| format!("{flag} needs a value"))
+        };
+        match flag.as_str() {
+            "--help" | "-h" => return Ok(Command::Help),
+            "--run-stats" => run_stats_dir = PathBuf::from(value()?),
+            "--alpha" => {
+                thresholds.alpha = parse_number(&value()?, &flag)?;
+                if !(thresholds.alpha > 0.0 && thresholds.alpha <= 1.0) {
+                    return Err(format!("{flag} must be in (0, 1]"));
+                }
+            }
+            "--score-tolerance" => {
+                thresholds.score_tolerance = parse_tolerance(&value()?, &flag)?;
+            }
+            "--time-tolerance" => {
+                thresholds.time_tolerance = Some(parse_tolerance(&value()?, &flag)?);
+            }
+            _ => return Err(format!("Unexpected argument '{flag}'")),
+        }
+    }
+
+    let [baseline, candidate]: [String; 2] = runs
+        .try_into()
+        .map_err(|_| "compare needs a BASELINE and a CANDIDATE run".to_string())?;
+    Ok(Command::Compare(CompareOptions {
+        baseline,
+        candidate,
+        run_stats_dir,
+        thresholds,
+    }))
+}
+
+fn parse_tolerance(value: &str, flag: &str) -> Result<f64, String> {
+    let tolerance: f64 = parse_number(value, flag)?;
+    if !(tolerance.is_finite() && tolerance >= 0.0) {
+        return Err(format!("{flag} must be zero or more"));
+    }
+    Ok(tolerance)
+}
+
+/// Parse a non-empty comma-separated list
+fn split_list<T>(
+    list: &str,
+    flag: &str,
+    parse_item: impl Fn(&str) -> Result<T, String>,
+) -> Result<Vec<T>, String> {
+    let items: Vec<T> = list
+        .split(',')
+        .map(str::trim)
+        .filter(|item| !item.is_empty())
+        .map(parse_item)
+        .collect::<Result<_, _>>()?;
+    if items.is_empty() {
+        return Err(format!("{flag} needs at least one value"));
+    }
+    Ok(items)
+}
+
+fn parse_number<T: std::str::FromStr>(value: &str, flag: &str) -> Result<T, String> {
+    value
+        .parse()
+        .map_err(|_| format!("{flag}: '{value}' is not a valid number"))
+}
+
+fn parse_positive<T: std::str::FromStr + Default + PartialEq>(
+    value: &str,
+    flag: &str,
+) -> Result<T, String> {
+    let number = parse_number(value, flag)?;
+    if number == T::default() {
+        return Err(format!("{flag} must be greater than zero"));
+    }
+    Ok(number)
+}
+
+fn parse_baseline(name: &str) -> Result<String, String> {
+    match baselines::by_name(name) {
+        Some(_) => Ok(name.to_string()),
+        None => Err(format!(
+            "--baselines: no baseline named '{name}' (available: {})",
+            baselines::BASELINE_NAMES.join(", ")
+        )),
+    }
+}
+
+/// Parse a POPULATIONxREGIONS pair
+fn parse_config(config: &str) -> Result<(u32, u32), String> {
+    let invalid = || format!("--configs: '{config}' is not POPULATIONxREGIONS, e.g. 100x10");
+    let (population, regions) = config.split_once(['x', 'X']).ok_or_else(invalid)?;
+    let population: u32 = population.parse().map_err(|_| invalid())?;
+    let regions: u32 = regions.parse().map_err(|_| invalid())?;
+    if population == 0 || regions == 0 || regions > population {
+        return Err(format!(
+            "--configs: '{config}' needs 0 < regions <= population"
+        ));
+    }
+    Ok((population, regions))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_args(args: &[&str]) -> Result<Command, String> {
+        parse(args.iter().map(|arg| arg.to_string()))
+    }
+
+    fn run_options(args: &[&str]) -> RunOptions {
+        match parse_args(args).unwrap() {
+            Command::Run(options) => options,
+            other => panic!("expected run options, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn given_no_arguments_when_parsed_then_defaults_match_constants() {
+        let options = run_options(&[]);
+
+        assert_eq!(options, RunOptions::default());
+        assert_eq!(
+            options.algorithms.len(),
+            algorithms::DEFAULT_ALGORITHMS.len()
+        );
+        assert_eq!(options.settings, RunSettings::default());
+    }
+
+    #[test]
+    fn given_all_options_when_parsed_then_applied() {
+        let options = run_options(&[
+            "run",
+            "--algorithms",
+            "ackley,rastrigin",
+            "--configs=100x10,500X20",
+            "--seeds",
+            "1,2",
+            "--rounds",
+            "50",
+            "--dimensions",
+            "5",
+            "--threads",
+            "4",
+            "--output-dir",
+            "out",
+            "--keep-existing",
+        ]);
+
+        assert_eq!(options.algorithms, vec!["ackley", "rastrigin"]);
+        assert_eq!(options.settings.configs, vec![(100, 10), (500, 20)]);
+        assert_eq!(options.settings.seeds, vec![1, 2]);
+        assert_eq!(options.settings.max_rounds, 50);
+        assert_eq!(options.settings.dimensions, 5);
+        assert_eq!(options.threads, Some(4));
+        assert_eq!(options.output_dir, PathBuf::from("out"));
+        assert!(options.keep_existing);
+        assert_eq!(options.resolve_algorithms().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn given_baselines_when_parsed_then_selected_or_rejected() {
+        assert_eq!(
+            run_options(&["--baselines", "cma_es,random_search"])
+                .settings
+                .baselines,
+            vec!["cma_es", "random_search"]
+        );
+        assert!(run_options(&["--baselines", "none"])
+            .settings
+            .baselines
+            .is_empty());
+        assert_eq!(
+            run_options(&["--baselines", "all"])
+                .settings
+                .baselines
+                .len(),
+            baselines::BASELINE_NAMES.len()
+        );
+        let error = parse_args(&["--baselines", "simplex"]).unwrap_err();
+        assert!(error.contains("'simplex'"), "{error}");
+    }
+
+    #[test]
+    fn given_runs_when_parsed_then_first_prime_seeds_used() {
+        assert_eq!(
+            run_options(&["--runs", "4"]).settings.seeds,
+            vec![2, 3, 5, 7]
+        );
+    }
+
+    #[test]
+    fn given_seeds_and_runs_when_parsed_then_error() {
+        assert!(parse_args(&["--seeds", "1", "--runs", "2"]).is_err());
+    }
+
+    #[test]
+    fn given_invalid_values_when_parsed_then_errors_name_the_flag() {
+        let errors = [
+            parse_args(&["--configs", "10x20"]).unwrap_err(),
+            parse_args(&["--configs", "ten"]).unwrap_err(),
+            parse_args(&["--rounds", "0"]).unwrap_err(),
+            parse_args(&["--threads"]).unwrap_err(),
+        ];
+
+        assert!(errors[0].contains("regions <= population"), "{}", errors[0]);
+        assert!(errors[1].contains("POPULATIONxREGIONS"), "{}", errors[1]);
+        assert!(errors[2].contains("--rounds"), "{}", errors[2]);
+        assert!(errors[3].contains("needs a value"), "{}", errors[3]);
+        assert!(parse_args(&["--bogus"]).is_err());
+    }
+
+    #[test]
+    fn given_two_dimensional_algorithm_with_other_dimensions_when_resolved_then_error() {
+        let options = run_options(&["--algorithms", "himmelblau", "--dimensions", "3"]);
+
+        let error = options.resolve_algorithms().err().unwrap();
+
+        assert!(error.contains("'himmelblau'"), "{error}");
+    }
+
+    #[test]
+    fn given_compare_with_options_when_parsed_then_applied() {
+        let command = parse_args(&[
+            "compare",
+            "abc12345",
+            "--alpha",
+            "0.01",
+            "run_stats/2025-10/def67890-02",
+            "--score-tolerance=0.1",
+            "--time-tolerance",
+            "0.5",
+            "--run-stats",
+            "stats",
+        ])
+        .unwrap();
+
+        assert_eq!(
+            command,
+            Command::Compare(CompareOptions {
+                baseline: "abc12345".to_string(),
+                candidate: "run_stats/2025-10/def67890-02".to_string(),
+                run_stats_dir: PathBuf::from("stats"),
+                thresholds: Thresholds {
+                    alpha: 0.01,
+                    score_tolerance: 0.1,
+                    time_tolerance: Some(0.5),
+                },
+            })
+        );
+    }
+
+    #[test]
+    fn given_invalid_compare_arguments_when_parsed_then_errors() {
+        assert!(parse_args(&["compare", "abc12345"]).is_err());
+        assert!(parse_args(&["compare", "a", "b", "c"]).is_err());
+        assert!(parse_args(&["compare", "a", "b", "--alpha", "0"]).is_err());
+        assert!(parse_args(&["compare", "a", "b", "--score-tolerance", "-1"]).is_err());
+    }
+
+    #[test]
+    fn given_list_and_help_when_parsed_then_recognised() {
+        assert_eq!(parse_args(&["list"]).unwrap(), Command::List);
+        assert_eq!(parse_args(&["--help"]).unwrap(), Command::Help);
+        assert_eq!(
+            run_options(&["--algorithms", "all"]).algorithms.len(),
+            algorithms::available_names().len()
+        );
+    }
+}
@@ -0,0 +1,392 @@
+use crate::mann_whitney::mann_whitney_u;
+use crate::output::results_name;
+use crate::runner::{AlgorithmResults, ConfigurationResults};
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+/// When a difference between two runs counts as a regression
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Thresholds {
+    /// Largest Mann–Whitney p-value treated as significant
+    pub alpha: f64,
+    /// Relative increase of the average best score tolerated, e.g. 0.1 for 10%
+    pub score_tolerance: f64,
+    /// Relative increase of the average run time tolerated; time is not checked when `None`
+    /// since it depends on the machine
+    pub time_tolerance: Option<f64>,
+}
+
+impl Default for Thresholds {
+    fn default() -> Self {
+        Self {
+            alpha: 0.05,
+            score_tolerance: 0.0,
+            time_tolerance: None,
+        }
+    }
+}
+
+/// Averages of one configuration in one run
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Summary {
+    pub runs: usize,
+    pub average_best_score: f64,
+    pub average_rounds: f64,
+    pub average_time_secs: f64,
+}
+
+impl Summary {
+    fn new(config: &ConfigurationResults) -> Self {
+        Self {
+            runs: config.runs.len(),
+            average_best_score: config.average_best_score(),
+            average_rounds: config.average_rounds(),
+            average_time_secs: config.average_time_secs(),
+        }
+    }
+}
+
+/// One configuration present in both runs
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConfigComparison {
+    pub population: u32,
+    pub regions: u32,
+    pub baseline: Summary,
+    pub candidate: Summary,
+    /// Mann–Whitney p-value of the per-seed best scores
+    pub score_p_value: Option<f64>,
+    /// Mann–Whitney p-value of the per-seed run times
+    pub time_p_value: Option<f64>,
+    pub score_regressed: bool,
+    pub time_regressed: bool,
+}
+
+impl ConfigComparison {
+    pub fn regressed(&self) -> bool {
+        self.score_regressed || self.time_regressed
+    }
+}
+
+/// One algorithm present in both runs
+#[derive(Debug, Clone, PartialEq)]
+pub struct AlgorithmComparison {
+    pub name: String,
+    pub configurations: Vec<ConfigComparison>,
+    /// Configurations (population, regions) found in only one of the runs
+    pub only_in_baseline: Vec<(u32, u32)>,
+    pub only_in_candidate: Vec<(u32, u32)>,
+}
+
+/// Differences between a baseline and a candidate benchmark run
+#[derive(Debug, Clone, PartialEq)]
+pub struct Comparison {
+    pub algorithms: Vec<AlgorithmComparison>,
+    /// Algorithms found in only one of the runs
+    pub only_in_baseline: Vec<String>,
+    pub only_in_candidate: Vec<String>,
+}
+
+impl Comparison {
+    /// Count the configurations that regressed
+    pub fn regressions(&self) -> usize {
+        self.algorithms
+            .iter()
+            .flat_map(|algorithm| &algorithm.configurations)
+            .filter(|config| config.regressed())
+            .count()
+    }
+}
+
+/// Compare two runs, aligning algorithms by name and dimensions and configurations by
+/// population and regions
+pub fn compare_runs(
+    baseline: &[AlgorithmResults],
+    candidate: &[AlgorithmResults],
+    thresholds: &Thresholds,
+) -> Comparison {
+    let baseline: BTreeMap<String, &AlgorithmResults> =
+        baseline.iter().map(|r| (results_name(r), r)).collect();
+    let candidate: BTreeMap<String, &AlgorithmResults> =
+        candidate.iter().map(|r| (results_name(r), r)).collect();
+
+    let algorithms = baseline
+        .iter()
+        .filter_map(|(name, before)| {
+            let after = candidate.get(name)?;
+            Some(compare_algorithm(name, before, after, thresholds))
+        })
+        .collect();
+
+    Comparison {
+        algorithms,
+        only_in_baseline: missing_from(&baseline, &candidate),
+        only_in_candidate: missing_from(&candidate, &baseline),
+    }
+}
+
+fn missing_from<T>(from: &BTreeMap<String, T>, other: &BTreeMap<String, T>) -> Vec<String> {
+    from.keys()
+        .filter(|name| !other.contains_key(*name))
+        .cloned()
+        .collect()
+}
+
+fn compare_algorithm(
+    name: &str,
+    baseline: &AlgorithmResults,
+    candidate: &AlgorithmResults,
+    thresholds: &Thresholds,
+) -> AlgorithmComparison {
+    let key = |config: &ConfigurationResults| (config.population, config.regions);
+    let contains = |results: &AlgorithmResults, wanted: (u32, u32)| {
+        results
+            .configurations
+            .iter()
+            .any(|config| key(config) == wanted)
+    };
+
+    let configurations = baseline
+        .configurations
+        .iter()
+        .filter_map(|before| {
+            let after = candidate
+                .configurations
+                .iter()
+                .find(|config| key(config) == key(before))?;
+            Some(compare_configuration(before, after, thresholds))
+        })
+        .collect();
+
+    AlgorithmComparison {
+        name: name.to_string(),
+        configurations,
+        only_in_baseline: baseline
+            .configurations
+            .iter()
+            .map(key)
+            .filter(|&k| !contains(candidate, k))
+            .collect(),
+        only_in_candidate: candidate
+            .configurations
+            .iter()
+            .map(key)
+            .filter(|&k| !contains(baseline, k))
+            .collect(),
+    }
+}
+
+fn compare_configuration(
+    baseline: &ConfigurationResults,
+    candidate: &ConfigurationResults,
+    thresholds: &Thresholds,
+) -> ConfigComparison {
+    let scores = |config: &ConfigurationResults| -> Vec<f64> {
+        config.runs.iter().map(|run| run.best_score).collect()
+    };
+    let times = |config: &ConfigurationResults| -> Vec<f64> {
+        config.runs.iter().map(|run| run.duration_secs).collect()
+    };
+    let score_p_value = mann_whitney_u(&scores(baseline), &scores(candidate)).map(|t| t.p_value);
+    let time_p_value = mann_whitney_u(&times(baseline), &times(candidate)).map(|t| t.p_value);
+
+    let before = Summary::new(baseline);
+    let after = Summary::new(candidate);
+    let significant = |p: Option<f64>| p.is_some_and(|p| p < thresholds.alpha);
+    // Lower is better for both score and time
+    let worse = |before: f64, after: f64, tolerance: f64| after > before + before.abs() * tolerance;
+
+    ConfigComparison {
+        population: baseline.population,
+        regions: baseline.regions,
+        baseline: before,
+        candidate: after,
+        score_p_value,
+        time_p_value,
+        score_regressed: significant(score_p_value)
+            && worse(
+                before.average_best_score,
+                after.average_best_score,
+                thresholds.score_tolerance,
+            ),
+        time_regressed: thresholds.time_tolerance.is_some_and(|tolerance| {
+            significant(time_p_value)
+                && worse(before.average_time_secs, after.average_time_secs, tolerance)
+        }),
+    }
+}
+
+/// Format a comparison as a plain-text report
+pub fn format_report(comparison: &Comparison) -> String {
+    let mut report = String::new();
+    let p = |p: Option<f64>| p.map_or("-".to_string(), |p| format!("{p:.4}"));
+    for algorithm in &comparison.algorithms {
+        let _ = writeln!(report, "{}", algorithm.name);
+        for config in &algorithm.configurations {
+            let (before, after) = (&config.baseline, &config.candidate);
+            let _ = writeln!(
+                report,
+                "  Pop: {}, Regions: {} (runs {} vs {}){}",
+                config.population,
+                config.regions,
+                before.runs,
+                after.runs,
+                if config.regressed() {
+                    "  REGRESSION"
+                } else {
+                    ""
+                }
+            );
+            let _ = writeln!(
+                report,
+                "    Avg Score:  {:.6e} -> {:.6e} ({:+.6e}, p = {}){}",
+                before.average_best_score,
+                after.average_best_score,
+                after.average_best_score - before.average_best_score,
+                p(config.score_p_value),
+                if config.score_regressed { " *" } else { "" }
+            );
+            let _ = writeln!(
+                report,
+                "    Avg Rounds: {:.1} -> {:.1} ({:+.1})",
+                before.average_rounds,
+                after.average_rounds,
+                after.average_rounds - before.average_rounds
+            );
+            let _ = writeln!(
+                report,
+                "    Avg Time:   {:.3}s -> {:.3}s ({:+.3}s, p = {}){}",
+                before.average_time_secs,
+                after.average_time_secs,
+                after.average_time_secs - before.average_time_secs,
+                p(config.time_p_value),
+                if config.time_regressed { " *" } else { "" }
+            );
+        }
+        for (population, regions) in &algorithm.only_in_baseline {
+            let _ = writeln!(
+                report,
+                "  Pop: {population}, Regions: {regions} only in baseline"
+            );
+        }
+        for (population, regions) in &algorithm.only_in_candidate {
+            let _ = writeln!(
+                report,
+                "  Pop: {population}, Regions: {regions} only in candidate"
+            );
+        }
+    }
+    for name in &comparison.only_in_baseline {
+        let _ = writeln!(report, "{name} only in baseline");
+    }
+    for name in &comparison.only_in_candidate {
+        let _ = writeln!(report, "{name} only in candidate");
+    }
+    let _ = writeln!(report, "\n{} regression(s) found", comparison.regressions());
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runner::SingleRunResult;
+
+    fn results(name: &str, configs: &[(u32, u32, &[f64])]) -> AlgorithmResults {
+        AlgorithmResults {
+            algorithm_name: name.to_string(),
+            dimensions: 2,
+            max_rounds: 100,
+            configurations: configs
+                .iter()
+                .map(|&(population, regions, scores)| {
+                    let mut config = ConfigurationResults::new(population, regions);
+                    config.runs = scores
+                        .iter()
+                        .enumerate()
+                        .map(|(i, &best_score)| SingleRunResult {
+                            seed: i as u64,
+                            rounds_taken: 100,
+                            resolution_limit_count: 0,
+                            best_score,
+                            duration_secs: 1.0,
+                            evaluations: 0,
+                            trajectory: Vec::new(),
+                        })
+                        .collect();
+                    config
+                })
+                .collect(),
+            baselines: Vec::new(),
+        }
+    }
+
+    const LOW: &[f64] = &[0.1, 0.2, 0.3, 0.4, 0.5, 0.6, 0.7, 0.8];
+    const HIGH: &[f64] = &[1.1, 1.2, 1.3, 1.4, 1.5, 1.6, 1.7, 1.8];
+
+    #[test]
+    fn given_significantly_worse_candidate_when_compared_then_regression() {
+        let comparison = compare_runs(
+            &[results("ackley", &[(10, 2, LOW)])],
+            &[results("ackley", &[(10, 2, HIGH)])],
+            &Thresholds::default(),
+        );
+
+        let config = &comparison.algorithms[0].configurations[0];
+        assert!(config.score_regressed);
+        assert!(!config.time_regressed);
+        assert!(config.score_p_value.unwrap() < 0.01);
+        assert_eq!(comparison.regressions(), 1);
+    }
+
+    #[test]
+    fn given_improved_candidate_when_compared_then_no_regression() {
+        let comparison = compare_runs(
+            &[results("ackley", &[(10, 2, HIGH)])],
+            &[results("ackley", &[(10, 2, LOW)])],
+            &Thresholds::default(),
+        );
+
+        assert_eq!(comparison.regressions(), 0);
+    }
+
+    #[test]
+    fn given_worse_candidate_within_tolerance_when_compared_then_no_regression() {
+        let thresholds = Thresholds {
+            score_tolerance: 10.0,
+            ..Thresholds::default()
+        };
+
+        let comparison = compare_runs(
+            &[results("ackley", &[(10, 2, LOW)])],
+            &[results("ackley", &[(10, 2, HIGH)])],
+            &thresholds,
+        );
+
+        assert_eq!(comparison.regressions(), 0);
+    }
+
+    #[test]
+    fn given_mismatched_runs_when_compared_then_unmatched_listed() {
+        let comparison = compare_runs(
+            &[
+                results("ackley", &[(10, 2, LOW), (20, 4, LOW)]),
+                results("sphere", &[(10, 2, LOW)]),
+            ],
+            &[
+                results("ackley", &[(10, 2, LOW), (30, 5, LOW)]),
+                results("rastrigin", &[(10, 2, LOW)]),
+            ],
+            &Thresholds::default(),
+        );
+
+        let ackley = &comparison.algorithms[0];
+        assert_eq!(comparison.algorithms.len(), 1);
+        assert_eq!(ackley.configurations.len(), 1);
+        assert_eq!(ackley.only_in_baseline, vec![(20, 4)]);
+        assert_eq!(ackley.only_in_candidate, vec![(30, 5)]);
+        assert_eq!(comparison.only_in_baseline, vec!["sphere"]);
+        assert_eq!(comparison.only_in_candidate, vec!["rastrigin"]);
+        let report = format_report(&comparison);
+        assert!(report.contains("sphere only in baseline"), "{report}");
+        assert!(report.contains("0 regression(s) found"), "{report}");
+    }
+}
@@ -0,0 +1,255 @@
+use crate::output::{results_name, ResultsDocument};
+use crate::runner::{AlgorithmResults, ConfigurationResults, SingleRunResult, DIMENSIONS};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Find a run directory from a path or a git hash
+///
+/// `run` is used as-is if it is a directory; otherwise it is a git hash (or its first eight
+/// characters) matched against the `<hash>-DD` directories under `run_stats_dir`, taking the
+/// latest if the hash was benchmarked on several days.
+pub fn find_run_directory(run_stats_dir: &Path, run: &str) -> Result<PathBuf, String> {
+    let path = Path::new(run);
+    if path.is_dir() {
+        return Ok(path.to_path_buf());
+    }
+
+    let prefix: String = run.chars().take(8).collect();
+    let mut matches = Vec::new();
+    let months = fs::read_dir(run_stats_dir)
+        .map_err(|e| format!("Cannot read {}: {e}", run_stats_dir.display()))?;
+    for month in months.flatten() {
+        let Ok(days) = fs::read_dir(month.path()) else {
+            continue;
+        };
+        for day in days.flatten() {
+            let name = day.file_name().to_string_lossy().into_owned();
+            if day.path().is_dir() && name.starts_with(&format!("{prefix}-")) {
+                matches.push(day.path());
+            }
+        }
+    }
+    // Paths sort by month and then day, so the last is the latest run
+    matches.sort();
+    matches.pop().ok_or_else(|| {
+        format!(
+            "No run directory for '{run}' under {}",
+            run_stats_dir.display()
+        )
+    })
+}
+
+/// Load every algorithm's results from a run directory
+///
+/// Reads the JSON results, falling back to the markdown report for runs made before JSON
+/// was written. If an algorithm was run more than once, the latest results are used.
+pub fn load_run(run_dir: &Path) -> Result<Vec<AlgorithmResults>, String> {
+    let mut files: Vec<PathBuf> = fs::read_dir(run_dir)
+        .map_err(|e| format!("Cannot read {}: {e}", run_dir.display()))?
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|e| e == "json" || e == "md"))
+        .collect();
+    // File names start with a timestamp, so later runs replace earlier ones below
+    files.sort();
+
+    let mut by_name = BTreeMap::new();
+    for path in &files {
+        let is_markdown = path.extension().is_some_and(|e| e == "md");
+        if is_markdown && path.with_extension("json").exists() {
+            continue;
+        }
+        let text =
+            fs::read_to_string(path).map_err(|e| format!("Cannot read {}: {e}", path.display()))?;
+        let results = if is_markdown {
+            parse_markdown(&text)
+        } else {
+            serde_json::from_str::<ResultsDocument>(&text)
+                .map(|document| document.results)
+                .map_err(|e| e.to_string())
+        }
+        .map_err(|e| format!("Invalid results file {}: {e}", path.display()))?;
+        by_name.insert(results_name(&results), results);
+    }
+
+    if by_name.is_empty() {
+        return Err(format!("No results found in {}", run_dir.display()));
+    }
+    Ok(by_name.into_values().collect())
+}
+
+/// Parse a markdown report written by `write_results_to_file`
+fn parse_markdown(text: &str) -> Result<AlgorithmResults, String> {
+    let field = |label: &str| {
+        text.lines()
+            .find_map(|line| line.trim_start_matches("- ").strip_prefix(label))
+            .map(str::trim)
+    };
+    let algorithm_name = field("**Algorithm:**")
+        .ok_or("missing **Algorithm:** line")?
+        .to_string();
+    let max_rounds = field("**Maximum rounds:**")
+        .ok_or("missing **Maximum rounds:** line")?
+        .parse()
+        .map_err(|_| "invalid maximum rounds")?;
+    let dimensions = match field("**Dimensions:**") {
+        Some(dimensions) => dimensions.parse().map_err(|_| "invalid dimensions")?,
+        None => DIMENSIONS,
+    };
+
+    let mut configurations: Vec<ConfigurationResults> = Vec::new();
+    for line in text.lines() {
+        if let Some(heading) = line.strip_prefix("### Configuration ") {
+            let (population, regions) = parse_configuration_heading(heading)
+                .ok_or_else(|| format!("invalid configuration heading '{line}'"))?;
+            configurations.push(ConfigurationResults::new(population, regions));
+            continue;
+        }
+        let Some(config) = configurations.last_mut() else {
+            continue;
+        };
+        // | Run | Seed | Rounds | Resolution Hits | Score | Time (s) |
+        let cells: Vec<&str> = line
+            .trim()
+            .trim_matches('|')
+            .split('|')
+            .map(str::trim)
+            .collect();
+        if cells.len() != 6 || cells[0].parse::<usize>().is_err() {
+            continue;
+        }
+        let invalid = || format!("invalid run row '{line}'");
+        config.runs.push(SingleRunResult {
+            seed: cells[1].parse().map_err(|_| invalid())?,
+            rounds_taken: cells[2].parse().map_err(|_| invalid())?,
+            resolution_limit_count: cells[3].parse().map_err(|_| invalid())?,
+            best_score: cells[4].parse().map_err(|_| invalid())?,
+            duration_secs: cells[5].parse().map_err(|_| invalid())?,
+            evaluations: 0,
+            trajectory: Vec::new(),
+        });
+    }
+
+    Ok(AlgorithmResults {
+        algorithm_name,
+        dimensions,
+        max_rounds,
+        configurations,
+        baselines: Vec::new(),
+    })
+}
+
+/// Parse `N (Pop: 100, Regions: 10)` into (population, regions)
+fn parse_configuration_heading(heading: &str) -> Option<(u32, u32)> {
+    let details = heading.split_once("(Pop: ")?.1.strip_suffix(')')?;
+    let (population, regions) = details.split_once(", Regions: ")?;
+    Some((population.parse().ok()?, regions.parse().ok()?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const REPORT: &str = "\
+# ACKLEY Algorithm Benchmark Results
+
+**Run Started:** 2025-10-07 14:30:20
+**Algorithm:** ackley
+
+## Configuration
+- **Git Commit:** a1b2c3d4e5
+- **Runs per configuration:** 2
+- **Maximum rounds:** 1000
+- **Seeds used:** [2, 3]
+
+## Detailed Run Information
+
+### Configuration 1 (Pop: 10, Regions: 2)
+
+| Run | Seed | Rounds | Resolution Hits | Score | Time (s) |
+|-----|------|--------|-----------------|-------|----------|
+| 1 | 2 | 1000 | 0 | 1.500000e-3 | 0.120 |
+| 2 | 3 | 1000 | 4 | 2.500000e-3 | 0.130 |
+
+### Configuration 2 (Pop: 100, Regions: 10)
+
+| Run | Seed | Rounds | Resolution Hits | Score | Time (s) |
+|-----|------|--------|-----------------|-------|----------|
+| 1 | 2 | 1000 | 0 | 0.000000e0 | 1.000 |
+";
+
+    #[test]
+    fn given_markdown_report_when_parsed_then_runs_recovered() {
+        let results = parse_markdown(REPORT).unwrap();
+
+        assert_eq!(results.algorithm_name, "ackley");
+        assert_eq!(results.dimensions, 2);
+        assert_eq!(results.max_rounds, 1000);
+        assert_eq!(results.configurations.len(), 2);
+        let first = &results.configurations[0];
+        assert_eq!((first.population, first.regions), (10, 2));
+        assert_eq!(first.runs.len(), 2);
+        assert_eq!(first.runs[1].seed, 3);
+        assert_eq!(first.runs[1].resolution_limit_count, 4);
+        assert_eq!(first.runs[1].best_score, 2.5e-3);
+        assert_eq!(results.configurations[1].runs.len(), 1);
+    }
+
+    #[test]
+    fn given_report_without_algorithm_when_parsed_then_error() {
+        assert!(parse_markdown("# Nothing here").is_err());
+    }
+
+    #[test]
+    fn given_run_stats_when_hash_looked_up_then_latest_day_found() {
+        let root = Path::new("test_temp_find_run");
+        for dir in [
+            "2025-09/abcdef12-30",
+            "2025-10/abcdef12-02",
+            "2025-10/99999999-02",
+        ] {
+            fs::create_dir_all(root.join(dir)).unwrap();
+        }
+
+        let found = find_run_directory(root, "abcdef1234567890");
+        let missing = find_run_directory(root, "12345678");
+        fs::remove_dir_all(root).unwrap();
+
+        assert_eq!(found.unwrap(), root.join("2025-10/abcdef12-02"));
+        assert!(missing.is_err());
+    }
+
+    #[test]
+    fn given_run_directory_when_loaded_then_json_preferred_over_markdown() {
+        let dir = Path::new("test_temp_load_run");
+        fs::create_dir_all(dir).unwrap();
+        let mut from_json = parse_markdown(REPORT).unwrap();
+        from_json.max_rounds = 7;
+        let document = ResultsDocument {
+            run_started: String::new(),
+            git_commit: None,
+            git_branch: None,
+            results: from_json.clone(),
+        };
+        fs::write(dir.join("20251007_143020_ackley.md"), REPORT).unwrap();
+        fs::write(
+            dir.join("20251007_143020_ackley.json"),
+            serde_json::to_string(&document).unwrap(),
+        )
+        .unwrap();
+        fs::write(
+            dir.join("20251007_143021_himmelblau.md"),
+            REPORT.replace("ackley", "himmelblau"),
+        )
+        .unwrap();
+
+        let loaded = load_run(dir);
+        fs::remove_dir_all(dir).unwrap();
+
+        let loaded = loaded.unwrap();
+        assert_eq!(loaded.len(), 2);
+        assert_eq!(loaded[0], from_json);
+        assert_eq!(loaded[1].algorithm_name, "himmelblau");
+    }
+}
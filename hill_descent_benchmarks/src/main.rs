@@ -1,17 +1,21 @@
 mod algorithms;
+mod anytime;
+mod baselines;
+mod cli;
+mod compare;
 mod git_info;
+mod load;
+mod mann_whitney;
 mod output;
 mod runner;
 
-use algorithms::{
-    AckleyAlgorithm, BenchmarkAlgorithm, BukinN6Algorithm, HimmelblauAlgorithm, LeviN13Algorithm,
-    RastriginAlgorithm, SchafferN2Algorithm, StyblinskiTangAlgorithm,
-};
+use cli::{Command, CompareOptions, RunOptions};
 use git_info::get_hash_prefix_for_directory;
 use output::write_results_to_file;
 use runner::benchmark_algorithm;
 use std::fs;
 use std::path::{Path, PathBuf};
+use std::process::ExitCode;
 
 /// Get the current run directory path (YYYY-MM/<hashprefix>-DD)
 fn get_current_run_directory(run_stats_dir: &Path) -> PathBuf {
@@ -34,42 +38,69 @@ fn clean_run_directory(run_dir: &Path) -> Result<(), Box<dyn std::error::Error>>
     Ok(())
 }
 
-fn main() -> Result<(), Box<dyn std::error::Error>> {
+fn main() -> ExitCode {
+    let result = match cli::parse(std::env::args().skip(1)) {
+        Ok(Command::Run(options)) => run(&options),
+        Ok(Command::List) => {
+            for name in algorithms::available_names() {
+                println!("{name}");
+            }
+            Ok(())
+        }
+        Ok(Command::Compare(options)) => match compare(&options) {
+            Ok(0) => Ok(()),
+            Ok(_) => return ExitCode::from(2),
+            Err(e) => Err(e),
+        },
+        Ok(Command::Help) => {
+            println!("{}", cli::USAGE);
+            Ok(())
+        }
+        Err(e) => Err(format!("{e}\n{}", cli::USAGE).into()),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("Error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Run the selected benchmarks and write their results
+fn run(options: &RunOptions) -> Result<(), Box<dyn std::error::Error>> {
+    // Resolve everything up front so a typo fails before any results are cleaned
+    let algorithms = options.resolve_algorithms()?;
+    if let Some(threads) = options.threads {
+        rayon::ThreadPoolBuilder::new()
+            .num_threads(threads)
+            .build_global()?;
+    }
+
     println!("Hill Descent Algorithm Benchmarking Tool");
     println!("========================================");
     println!();
 
-    // Create run_stats directory
-    let run_stats_dir = Path::new("run_stats");
-
-    // Clean the current run directory before starting
-    let current_run_dir = get_current_run_directory(run_stats_dir);
-    clean_run_directory(&current_run_dir)?;
-    println!();
+    let run_stats_dir = options.output_dir.as_path();
+    if !options.keep_existing {
+        // Clean the current run directory before starting
+        let current_run_dir = get_current_run_directory(run_stats_dir);
+        clean_run_directory(&current_run_dir)?;
+        println!();
+    }
 
-    // List of algorithms to benchmark
-    let algorithms: Vec<Box<dyn BenchmarkAlgorithm>> = vec![
-        Box::new(StyblinskiTangAlgorithm),
-        Box::new(AckleyAlgorithm),
-        Box::new(HimmelblauAlgorithm),
-        Box::new(BukinN6Algorithm),
-        Box::new(LeviN13Algorithm),
-        Box::new(RastriginAlgorithm),
-        Box::new(SchafferN2Algorithm),
-    ];
-
-    // Run benchmarks for each algorithm
+    let settings = &options.settings;
     for algorithm in algorithms.iter() {
         println!("Running benchmarks for {} algorithm...", algorithm.name());
-        println!(
-            "Configurations to test: {:?}",
-            runner::POPULATION_REGION_CONFIGS
-        );
-        println!("Runs per configuration: {}", runner::RUNS_PER_CONFIG);
-        println!("Maximum rounds per run: {}", runner::MAX_ROUNDS);
+        println!("Configurations to test: {:?}", settings.configs);
+        println!("Seeds: {:?}", settings.seeds);
+        println!("Maximum rounds per run: {}", settings.max_rounds);
+        println!("Dimensions: {}", settings.dimensions);
+        println!("Baselines: {:?}", settings.baselines);
         println!();
 
-        let results = benchmark_algorithm(algorithm.as_ref());
+        let results = benchmark_algorithm(algorithm.as_ref(), settings);
 
         // Print summary to console
         println!("Results for {}:", algorithm.name());
@@ -86,6 +117,18 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                 config.average_time_secs()
             );
         }
+        for baseline in &results.baselines {
+            println!("  Baseline {}:", baseline.name);
+            for config in &baseline.configurations {
+                println!(
+                    "    Pop: {}, Best Score: {:.6}, Avg Score: {:.6}, Avg Time: {:.3}s",
+                    config.population,
+                    config.best_score(),
+                    config.average_best_score(),
+                    config.average_time_secs()
+                );
+            }
+        }
         println!();
 
         // Write results to file
@@ -100,6 +143,23 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
+/// Compare two benchmark runs, returning the number of regressed configurations
+fn compare(options: &CompareOptions) -> Result<usize, Box<dyn std::error::Error>> {
+    let baseline_dir = load::find_run_directory(&options.run_stats_dir, &options.baseline)?;
+    let candidate_dir = load::find_run_directory(&options.run_stats_dir, &options.candidate)?;
+    println!("Baseline:  {}", baseline_dir.display());
+    println!("Candidate: {}", candidate_dir.display());
+    println!();
+
+    let comparison = compare::compare_runs(
+        &load::load_run(&baseline_dir)?,
+        &load::load_run(&candidate_dir)?,
+        &options.thresholds,
+    );
+    print!("{}", compare::format_report(&comparison));
+    Ok(comparison.regressions())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,9 +1,31 @@
+use crate::anytime::{write_anytime_table, write_ecdf_csv};
 use crate::git_info::{get_git_info, get_hash_prefix_for_directory};
 use crate::runner::AlgorithmResults;
+use serde::{Deserialize, Serialize};
 use std::fs;
 use std::io::Write;
 use std::path::Path;
 
+/// Machine-readable results of one algorithm, written next to the markdown report
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ResultsDocument {
+    /// Local time the file was written, as `YYYY-MM-DD HH:MM:SS`
+    pub run_started: String,
+    pub git_commit: Option<String>,
+    pub git_branch: Option<String>,
+    #[serde(flatten)]
+    pub results: AlgorithmResults,
+}
+
+/// The name results are filed under: the algorithm, with its dimensions unless 2
+pub fn results_name(results: &AlgorithmResults) -> String {
+    if results.dimensions == crate::runner::DIMENSIONS {
+        results.algorithm_name.clone()
+    } else {
+        format!("{}_{}d", results.algorithm_name, results.dimensions)
+    }
+}
+
 /// Generate the full directory path and filename for algorithm results
 /// Returns (year_month_subdir, hash_day_subdir, filename)
 /// Example: ("2025-10", "a1b2c3d4-07", "20251007_143020_rastrigin.md")
@@ -17,14 +39,15 @@ pub fn generate_subdir_and_filename(algorithm_name: &str) -> (String, String, St
     (year_month, hash_day_subdir, filename)
 }
 
-/// Write algorithm results to a markdown file in the run_stats directory
+/// Write algorithm results to a markdown file in the run_stats directory, as JSON to a file
+/// of the same name with a `.json` extension, and their ECDF data to `.ecdf.csv`
 pub fn write_results_to_file(
     results: &AlgorithmResults,
     run_stats_dir: &Path,
 ) -> Result<(), std::io::Error> {
     // Determine year-month subdirectory, hash-day subdirectory, and filename
     let (year_month, hash_day_subdir, filename) =
-        generate_subdir_and_filename(&results.algorithm_name);
+        generate_subdir_and_filename(&results_name(results));
     let full_subdir_path = run_stats_dir.join(&year_month).join(&hash_day_subdir);
     fs::create_dir_all(&full_subdir_path)?;
     let filepath = full_subdir_path.join(filename);
@@ -32,6 +55,7 @@ pub fn write_results_to_file(
 
     // Write header with timestamp and algorithm info
     let now = chrono::Local::now();
+    let git_info = get_git_info();
     writeln!(
         file,
         "# {} Algorithm Benchmark Results",
@@ -46,7 +70,7 @@ pub fn write_results_to_file(
     writeln!(file, "## Configuration")?;
 
     // Add git information if available
-    if let Some(git_info) = get_git_info() {
+    if let Some(git_info) = &git_info {
         writeln!(file, "- **Git Commit:** {}", git_info.commit_hash)?;
         writeln!(file, "- **Git Branch:** {}", git_info.branch)?;
     } else {
@@ -56,17 +80,15 @@ pub fn write_results_to_file(
         )?;
     }
 
-    writeln!(
-        file,
-        "- **Runs per configuration:** {}",
-        crate::runner::RUNS_PER_CONFIG
-    )?;
-    writeln!(file, "- **Maximum rounds:** {}", crate::runner::MAX_ROUNDS)?;
-    writeln!(
-        file,
-        "- **Seeds used:** {:?}",
-        &crate::runner::PRIME_SEEDS[..crate::runner::RUNS_PER_CONFIG]
-    )?;
+    let seeds: Vec<u64> = results
+        .configurations
+        .first()
+        .map(|config| config.runs.iter().map(|run| run.seed).collect())
+        .unwrap_or_default();
+    writeln!(file, "- **Runs per configuration:** {}", seeds.len())?;
+    writeln!(file, "- **Maximum rounds:** {}", results.max_rounds)?;
+    writeln!(file, "- **Dimensions:** {}", results.dimensions)?;
+    writeln!(file, "- **Seeds used:** {:?}", seeds)?;
     writeln!(file)?;
 
     // Write results table
@@ -79,10 +101,10 @@ pub fn write_results_to_file(
         writeln!(
             file,
             "| {} | {} | {} | {} | {} | {:.6e} | {:.6e} | {:.6e} | {:.3} |",
-            crate::runner::RUNS_PER_CONFIG,
+            config.runs.len(),
             config.population,
             config.regions,
-            crate::runner::MAX_ROUNDS,
+            results.max_rounds,
             config.resolution_limit_hits(),
             config.best_score(),
             config.average_best_score(),
@@ -93,6 +115,11 @@ pub fn write_results_to_file(
 
     writeln!(file)?;
 
+    if !results.baselines.is_empty() {
+        write_baselines(&mut file, results)?;
+    }
+    write_anytime_table(&mut file, results)?;
+
     // Write detailed run information
     writeln!(file, "## Detailed Run Information")?;
     writeln!(file)?;
@@ -116,12 +143,11 @@ pub fn write_results_to_file(
         )?;
 
         for (run_idx, run) in config.runs.iter().enumerate() {
-            let seed = crate::runner::PRIME_SEEDS[run_idx % crate::runner::PRIME_SEEDS.len()];
             writeln!(
                 file,
                 "| {} | {} | {} | {} | {:.6e} | {:.3} |",
                 run_idx + 1,
-                seed,
+                run.seed,
                 run.rounds_taken,
                 run.resolution_limit_count,
                 run.best_score,
@@ -132,9 +158,66 @@ pub fn write_results_to_file(
     }
 
     println!("Results written to: {}", filepath.display());
+
+    let document = ResultsDocument {
+        run_started: now.format("%Y-%m-%d %H:%M:%S").to_string(),
+        git_commit: git_info.as_ref().map(|info| info.commit_hash.clone()),
+        git_branch: git_info.map(|info| info.branch),
+        results: results.clone(),
+    };
+    let json_path = filepath.with_extension("json");
+    fs::write(&json_path, serde_json::to_string_pretty(&document)?)?;
+    println!("Results written to: {}", json_path.display());
+
+    let ecdf_path = filepath.with_extension("ecdf.csv");
+    write_ecdf_csv(fs::File::create(&ecdf_path)?, results)?;
+    println!("ECDF data written to: {}", ecdf_path.display());
     Ok(())
 }
 
+/// Write the average best score of hill_descent and each baseline side by side
+fn write_baselines(file: &mut impl Write, results: &AlgorithmResults) -> std::io::Result<()> {
+    writeln!(file, "## Baselines")?;
+    writeln!(file)?;
+    writeln!(
+        file,
+        "Average best score of each optimiser, each run given the evaluations hill_descent made with the same seed."
+    )?;
+    writeln!(file)?;
+    let names: Vec<&str> = results.baselines.iter().map(|b| b.name.as_str()).collect();
+    writeln!(
+        file,
+        "| Population | Regions | Mean evaluations | hill_descent | {} |",
+        names.join(" | ")
+    )?;
+    writeln!(file, "|{}", "---|".repeat(names.len() + 4))?;
+
+    for (index, config) in results.configurations.iter().enumerate() {
+        let baseline_scores: Vec<String> = results
+            .baselines
+            .iter()
+            .map(|baseline| {
+                baseline
+                    .configurations
+                    .get(index)
+                    .map_or("-".to_string(), |c| {
+                        format!("{:.6e}", c.average_best_score())
+                    })
+            })
+            .collect();
+        writeln!(
+            file,
+            "| {} | {} | {:.0} | {:.6e} | {} |",
+            config.population,
+            config.regions,
+            config.average_evaluations(),
+            config.average_best_score(),
+            baseline_scores.join(" | ")
+        )?;
+    }
+    writeln!(file)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -169,6 +252,77 @@ mod tests {
         assert!(filename.ends_with(".md"));
     }
 
+    fn results(dimensions: usize) -> AlgorithmResults {
+        let mut config = crate::runner::ConfigurationResults::new(10, 2);
+        config.runs.push(crate::runner::SingleRunResult {
+            seed: 3,
+            rounds_taken: 5,
+            resolution_limit_count: 0,
+            best_score: 0.25,
+            duration_secs: 0.1,
+            evaluations: 42,
+            trajectory: Vec::new(),
+        });
+        AlgorithmResults {
+            algorithm_name: "rastrigin".to_string(),
+            dimensions,
+            max_rounds: 5,
+            configurations: vec![config],
+            baselines: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn given_dimensions_when_results_name_then_suffixed_unless_two() {
+        assert_eq!(results_name(&results(2)), "rastrigin");
+        assert_eq!(results_name(&results(10)), "rastrigin_10d");
+    }
+
+    #[test]
+    fn given_results_when_written_then_markdown_and_json_round_trip() {
+        let dir = Path::new("test_temp_output");
+        let results = results(4);
+
+        write_results_to_file(&results, dir).unwrap();
+
+        let files: Vec<_> = fs::read_dir(dir)
+            .unwrap()
+            .flat_map(|month| fs::read_dir(month.unwrap().path()).unwrap())
+            .flat_map(|day| fs::read_dir(day.unwrap().path()).unwrap())
+            .map(|file| file.unwrap().path())
+            .collect();
+        let json = files
+            .iter()
+            .find(|path| path.extension().is_some_and(|e| e == "json"))
+            .unwrap();
+        let document: ResultsDocument =
+            serde_json::from_str(&fs::read_to_string(json).unwrap()).unwrap();
+        let has_markdown = files
+            .iter()
+            .any(|path| path.extension().is_some_and(|e| e == "md"));
+        fs::remove_dir_all(dir).unwrap();
+
+        assert!(has_markdown);
+        assert_eq!(document.results, results);
+    }
+
+    #[test]
+    fn given_baseline_results_when_written_then_side_by_side_table_included() {
+        let mut results = results(2);
+        results.baselines.push(crate::runner::BaselineResults {
+            name: "cma_es".to_string(),
+            configurations: results.configurations.clone(),
+        });
+        let mut markdown = Vec::new();
+
+        write_baselines(&mut markdown, &results).unwrap();
+
+        let markdown = String::from_utf8(markdown).unwrap();
+        assert!(markdown
+            .contains("| Population | Regions | Mean evaluations | hill_descent | cma_es |"));
+        assert!(markdown.contains("| 10 | 2 | 42 | 2.500000e-1 | 2.500000e-1 |"));
+    }
+
     #[test]
     fn given_different_algorithm_names_when_generate_filename_then_includes_name() {
         let (_, _, filename1) = generate_subdir_and_filename("ackley");
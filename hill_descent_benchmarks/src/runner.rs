@@ -1,8 +1,16 @@
 use crate::algorithms::BenchmarkAlgorithm;
-use hill_descent_lib::{setup_world, GlobalConstants, TrainingData};
+use crate::anytime::{TrajectoryPoint, TrajectoryRecorder};
+use crate::baselines::{self, Baseline, Objective};
+use hill_descent_lib::{setup_world, GlobalConstants, TrainingData, WorldFunction};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
 use std::time::Instant;
 
-// Configuration constants
+// Default configuration, used for any setting not given on the command line
 pub const POPULATION_REGION_CONFIGS: &[(u32, u32)] = &[
     (10, 2),
     (15, 3),
@@ -29,18 +37,59 @@ pub const PRIME_SEEDS: &[u64] = &[
 
 pub const MAX_ROUNDS: u32 = 1000;
 pub const RUNS_PER_CONFIG: usize = 20;
+pub const DIMENSIONS: usize = 2;
+
+/// The grid of runs made for each algorithm
+#[derive(Debug, Clone, PartialEq)]
+pub struct RunSettings {
+    /// (population, regions) pairs to test
+    pub configs: Vec<(u32, u32)>,
+    /// One run per seed for each configuration
+    pub seeds: Vec<u64>,
+    pub max_rounds: u32,
+    pub dimensions: usize,
+    /// Names of the baselines run against hill_descent
+    pub baselines: Vec<String>,
+}
+
+impl Default for RunSettings {
+    fn default() -> Self {
+        Self {
+            configs: POPULATION_REGION_CONFIGS.to_vec(),
+            seeds: seeds_for_runs(RUNS_PER_CONFIG),
+            max_rounds: MAX_ROUNDS,
+            dimensions: DIMENSIONS,
+            baselines: baselines::BASELINE_NAMES
+                .iter()
+                .map(|name| name.to_string())
+                .collect(),
+        }
+    }
+}
+
+/// The seeds for `runs` runs per configuration, cycling through [`PRIME_SEEDS`]
+pub fn seeds_for_runs(runs: usize) -> Vec<u64> {
+    PRIME_SEEDS.iter().copied().cycle().take(runs).collect()
+}
 
 /// Results from a single benchmark run
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SingleRunResult {
+    pub seed: u64,
     pub rounds_taken: u32,
     pub resolution_limit_count: u32,
     pub best_score: f64,
     pub duration_secs: f64,
+    /// Fitness evaluations made by the run
+    #[serde(default)]
+    pub evaluations: u64,
+    /// Best score so far against evaluations, one point per newly reached anytime target
+    #[serde(default)]
+    pub trajectory: Vec<TrajectoryPoint>,
 }
 
 /// Aggregated results for a configuration across multiple runs
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ConfigurationResults {
     pub population: u32,
     pub regions: u32,
@@ -62,6 +111,12 @@ impl ConfigurationResults {
         total as f64 / self.runs.len() as f64
     }
 
+    /// Calculate average fitness evaluations across all runs
+    pub fn average_evaluations(&self) -> f64 {
+        let total: u64 = self.runs.iter().map(|r| r.evaluations).sum();
+        total as f64 / self.runs.len() as f64
+    }
+
     /// Calculate total resolution limit hits across all runs
     pub fn resolution_limit_hits(&self) -> u32 {
         self.runs.iter().map(|r| r.resolution_limit_count).sum()
@@ -109,57 +164,132 @@ impl ConfigurationResults {
 }
 
 /// Results for all configurations of a single algorithm
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct AlgorithmResults {
     pub algorithm_name: String,
+    pub dimensions: usize,
+    pub max_rounds: u32,
+    pub configurations: Vec<ConfigurationResults>,
+    /// The same configurations run by each baseline optimiser
+    #[serde(default)]
+    pub baselines: Vec<BaselineResults>,
+}
+
+/// Results of one baseline optimiser, each run given the evaluations hill_descent made with
+/// the same configuration and seed
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BaselineResults {
+    pub name: String,
     pub configurations: Vec<ConfigurationResults>,
 }
 
 /// Run benchmarks for a single algorithm across all configurations
-pub fn benchmark_algorithm(algorithm: &dyn BenchmarkAlgorithm) -> AlgorithmResults {
+pub fn benchmark_algorithm(
+    algorithm: &dyn BenchmarkAlgorithm,
+    settings: &RunSettings,
+) -> AlgorithmResults {
     let mut algorithm_results = AlgorithmResults {
         algorithm_name: algorithm.name().to_string(),
+        dimensions: settings.dimensions,
+        max_rounds: settings.max_rounds,
         configurations: Vec::new(),
+        baselines: Vec::new(),
     };
 
-    for &(population, regions) in POPULATION_REGION_CONFIGS {
+    for &(population, regions) in &settings.configs {
         let mut config_results = ConfigurationResults::new(population, regions);
 
-        for i in 0..RUNS_PER_CONFIG {
-            let seed = PRIME_SEEDS[i % PRIME_SEEDS.len()];
-            let run_result = run_single_benchmark(algorithm, population, regions, seed);
+        for &seed in &settings.seeds {
+            let run_result =
+                run_single_benchmark(algorithm, population, regions, seed, settings.max_rounds);
             config_results.runs.push(run_result);
         }
 
         algorithm_results.configurations.push(config_results);
     }
 
+    for name in &settings.baselines {
+        let baseline =
+            baselines::by_name(name).unwrap_or_else(|| panic!("Unknown baseline '{name}'"));
+        let configurations = settings
+            .configs
+            .iter()
+            .zip(&algorithm_results.configurations)
+            .map(|(&(population, regions), hill_descent)| {
+                let mut config_results = ConfigurationResults::new(population, regions);
+                // Baselines are cheap and single-threaded, so seeds run in parallel
+                config_results.runs = hill_descent
+                    .runs
+                    .par_iter()
+                    .map(|run| run_single_baseline(baseline.as_ref(), algorithm, population, run))
+                    .collect();
+                config_results
+            })
+            .collect();
+        algorithm_results.baselines.push(BaselineResults {
+            name: baseline.name().to_string(),
+            configurations,
+        });
+    }
+
     algorithm_results
 }
 
+/// Run a baseline with the seed of a hill_descent run and the evaluations it made
+fn run_single_baseline(
+    baseline: &dyn Baseline,
+    algorithm: &dyn BenchmarkAlgorithm,
+    population: u32,
+    hill_descent: &SingleRunResult,
+) -> SingleRunResult {
+    let param_ranges = algorithm.param_ranges();
+    let function = algorithm.function();
+    let budget = hill_descent.evaluations as usize;
+    let mut objective = Objective::new(function.as_ref(), &param_ranges, budget);
+    let seed = hill_descent.seed;
+    let mut rng = StdRng::seed_from_u64(seed);
+    let start_time = Instant::now();
+
+    baseline.minimise(&mut objective, population as usize, &mut rng);
+    let duration_secs = start_time.elapsed().as_secs_f64();
+
+    SingleRunResult {
+        seed,
+        rounds_taken: hill_descent.rounds_taken,
+        resolution_limit_count: 0,
+        best_score: objective.best_score(),
+        duration_secs,
+        evaluations: objective.evaluations() as u64,
+        trajectory: objective.into_trajectory(),
+    }
+}
+
 /// Run a single benchmark with specific parameters
 fn run_single_benchmark(
     algorithm: &dyn BenchmarkAlgorithm,
     population: u32,
     regions: u32,
     seed: u64,
+    max_rounds: u32,
 ) -> SingleRunResult {
     let param_ranges = algorithm.param_ranges();
-    let function = algorithm.function();
+    let function = CountingFunction::new(algorithm.function());
+    let evaluations = Arc::clone(&function.calls);
     let global_constants =
         GlobalConstants::new_with_seed(population as usize, regions as usize, seed);
 
     // Get the function floor value for scoring
     let floor = function.function_floor();
 
-    let mut world = setup_world(&param_ranges, global_constants, function);
+    let mut world = setup_world(&param_ranges, global_constants, Box::new(function));
+    let mut trajectory = TrajectoryRecorder::default();
     let start_time = Instant::now();
 
-    let rounds_taken = MAX_ROUNDS;
+    let rounds_taken = max_rounds;
     let mut resolution_limit_count = 0u32;
 
-    // Run the optimization for all MAX_ROUNDS
-    for _round in 0..MAX_ROUNDS {
+    // Run the optimization for all max_rounds
+    for _round in 0..max_rounds {
         if _round == 564 {
             // put breakpoint here to stop at round problem exists.
             resolution_limit_count += 1;
@@ -170,16 +300,47 @@ fn run_single_benchmark(
         if world.training_run(TrainingData::None { floor_value: floor }) {
             resolution_limit_count += 1;
         }
+        trajectory.record(evaluations.load(Ordering::Relaxed), world.get_best_score());
     }
 
     let duration = start_time.elapsed();
     let best_score = world.get_best_score();
 
     SingleRunResult {
+        seed,
         rounds_taken,
         resolution_limit_count,
         best_score,
         duration_secs: duration.as_secs_f64(),
+        evaluations: evaluations.load(Ordering::Relaxed),
+        trajectory: trajectory.finish(),
+    }
+}
+
+/// Wraps a benchmark function to count its evaluations, which regions make in parallel
+#[derive(Debug)]
+struct CountingFunction {
+    function: Box<dyn WorldFunction>,
+    calls: Arc<AtomicU64>,
+}
+
+impl CountingFunction {
+    fn new(function: Box<dyn WorldFunction>) -> Self {
+        Self {
+            function,
+            calls: Arc::new(AtomicU64::new(0)),
+        }
+    }
+}
+
+impl WorldFunction for CountingFunction {
+    fn run(&self, phenotype_expressed_values: &[f64], inputs: &[f64]) -> Vec<f64> {
+        self.calls.fetch_add(1, Ordering::Relaxed);
+        self.function.run(phenotype_expressed_values, inputs)
+    }
+
+    fn function_floor(&self) -> f64 {
+        self.function.function_floor()
     }
 }
 
@@ -187,14 +348,103 @@ fn run_single_benchmark(
 mod tests {
     use super::*;
 
+    #[test]
+    fn given_run_count_when_seeds_for_runs_then_cycles_through_primes() {
+        assert_eq!(seeds_for_runs(3), vec![2, 3, 5]);
+        assert_eq!(seeds_for_runs(PRIME_SEEDS.len() + 1)[PRIME_SEEDS.len()], 2);
+        assert_eq!(RunSettings::default().seeds.len(), RUNS_PER_CONFIG);
+    }
+
+    #[test]
+    fn given_small_grid_when_benchmark_algorithm_then_one_run_per_config_and_seed() {
+        let settings = RunSettings {
+            configs: vec![(20, 2), (30, 3)],
+            seeds: vec![7, 11],
+            max_rounds: 3,
+            dimensions: 3,
+            baselines: vec!["random_search".to_string(), "cma_es".to_string()],
+        };
+        let algorithm = crate::algorithms::by_name("rastrigin", 3).unwrap();
+
+        let results = benchmark_algorithm(algorithm.as_ref(), &settings);
+
+        assert_eq!(results.dimensions, 3);
+        assert_eq!(results.configurations.len(), 2);
+        let seeds: Vec<_> = results.configurations[1]
+            .runs
+            .iter()
+            .map(|r| r.seed)
+            .collect();
+        assert_eq!(seeds, vec![7, 11]);
+        assert!(results.configurations[0]
+            .runs
+            .iter()
+            .all(|r| r.rounds_taken == 3));
+        assert_eq!(results.baselines.len(), 2);
+        assert_eq!(results.baselines[1].name, "cma_es");
+        assert_eq!(results.baselines[1].configurations[1].runs.len(), 2);
+        assert_eq!(results.baselines[0].configurations[1].runs[1].seed, 11);
+        let budgets: Vec<_> = results.configurations[1]
+            .runs
+            .iter()
+            .map(|r| r.evaluations)
+            .collect();
+        let random_evaluations: Vec<_> = results.baselines[0].configurations[1]
+            .runs
+            .iter()
+            .map(|r| r.evaluations)
+            .collect();
+        assert_eq!(random_evaluations, budgets);
+    }
+
+    #[test]
+    fn given_run_when_benchmarked_then_evaluations_and_trajectory_recorded() {
+        let algorithm = crate::algorithms::by_name("ackley", 2).unwrap();
+        let baseline = baselines::by_name("random_search").unwrap();
+
+        let hill_descent = run_single_benchmark(algorithm.as_ref(), 20, 2, 3, 5);
+        let random = run_single_baseline(baseline.as_ref(), algorithm.as_ref(), 20, &hill_descent);
+
+        assert!(
+            hill_descent.evaluations >= 20,
+            "{}",
+            hill_descent.evaluations
+        );
+        let last = hill_descent.trajectory.last().unwrap();
+        assert_eq!(last.evaluations, hill_descent.evaluations);
+        assert_eq!(last.best_score, hill_descent.best_score);
+        assert_eq!(random.evaluations, hill_descent.evaluations);
+        assert_eq!(
+            random.trajectory.last().unwrap().best_score,
+            random.best_score
+        );
+    }
+
+    #[test]
+    fn given_seed_when_baseline_run_twice_then_reproducible() {
+        let algorithm = crate::algorithms::by_name("ackley", 2).unwrap();
+        let baseline = baselines::by_name("differential_evolution").unwrap();
+
+        let hill_descent = run_single_benchmark(algorithm.as_ref(), 10, 2, 5, 20);
+
+        let first = run_single_baseline(baseline.as_ref(), algorithm.as_ref(), 10, &hill_descent);
+        let second = run_single_baseline(baseline.as_ref(), algorithm.as_ref(), 10, &hill_descent);
+
+        assert_eq!(first.best_score, second.best_score);
+        assert!(first.best_score.is_finite());
+    }
+
     #[test]
     fn given_single_run_when_average_best_score_then_returns_that_score() {
         let mut config = ConfigurationResults::new(10, 2);
         config.runs.push(SingleRunResult {
+            seed: 2,
             rounds_taken: 100,
             resolution_limit_count: 5,
             best_score: 1.5,
             duration_secs: 0.5,
+            evaluations: 0,
+            trajectory: Vec::new(),
         });
 
         assert_eq!(config.average_best_score(), 1.5);
@@ -204,22 +454,31 @@ mod tests {
     fn given_multiple_runs_when_average_best_score_then_returns_mean() {
         let mut config = ConfigurationResults::new(10, 2);
         config.runs.push(SingleRunResult {
+            seed: 2,
             rounds_taken: 100,
             resolution_limit_count: 5,
             best_score: 1.0,
             duration_secs: 0.5,
+            evaluations: 0,
+            trajectory: Vec::new(),
         });
         config.runs.push(SingleRunResult {
+            seed: 2,
             rounds_taken: 100,
             resolution_limit_count: 5,
             best_score: 2.0,
             duration_secs: 0.5,
+            evaluations: 0,
+            trajectory: Vec::new(),
         });
         config.runs.push(SingleRunResult {
+            seed: 2,
             rounds_taken: 100,
             resolution_limit_count: 5,
             best_score: 3.0,
             duration_secs: 0.5,
+            evaluations: 0,
+            trajectory: Vec::new(),
         });
 
         assert_eq!(config.average_best_score(), 2.0);
@@ -229,10 +488,13 @@ mod tests {
     fn given_single_run_when_std_dev_best_score_then_returns_zero() {
         let mut config = ConfigurationResults::new(10, 2);
         config.runs.push(SingleRunResult {
+            seed: 2,
             rounds_taken: 100,
             resolution_limit_count: 5,
             best_score: 1.5,
             duration_secs: 0.5,
+            evaluations: 0,
+            trajectory: Vec::new(),
         });
 
         assert_eq!(config.std_dev_best_score(), 0.0);
@@ -242,22 +504,31 @@ mod tests {
     fn given_identical_scores_when_std_dev_best_score_then_returns_zero() {
         let mut config = ConfigurationResults::new(10, 2);
         config.runs.push(SingleRunResult {
+            seed: 2,
             rounds_taken: 100,
             resolution_limit_count: 5,
             best_score: 2.5,
             duration_secs: 0.5,
+            evaluations: 0,
+            trajectory: Vec::new(),
         });
         config.runs.push(SingleRunResult {
+            seed: 2,
             rounds_taken: 100,
             resolution_limit_count: 5,
             best_score: 2.5,
             duration_secs: 0.5,
+            evaluations: 0,
+            trajectory: Vec::new(),
         });
         config.runs.push(SingleRunResult {
+            seed: 2,
             rounds_taken: 100,
             resolution_limit_count: 5,
             best_score: 2.5,
             duration_secs: 0.5,
+            evaluations: 0,
+            trajectory: Vec::new(),
         });
 
         assert_eq!(config.std_dev_best_score(), 0.0);
@@ -272,22 +543,31 @@ mod tests {
         // Variance: (1 + 0 + 1) / 3 = 2/3
         // Std dev: sqrt(2/3) ≈ 0.8165
         config.runs.push(SingleRunResult {
+            seed: 2,
             rounds_taken: 100,
             resolution_limit_count: 5,
             best_score: 1.0,
             duration_secs: 0.5,
+            evaluations: 0,
+            trajectory: Vec::new(),
         });
         config.runs.push(SingleRunResult {
+            seed: 2,
             rounds_taken: 100,
             resolution_limit_count: 5,
             best_score: 2.0,
             duration_secs: 0.5,
+            evaluations: 0,
+            trajectory: Vec::new(),
         });
         config.runs.push(SingleRunResult {
+            seed: 2,
             rounds_taken: 100,
             resolution_limit_count: 5,
             best_score: 3.0,
             duration_secs: 0.5,
+            evaluations: 0,
+            trajectory: Vec::new(),
         });
 
         let std_dev = config.std_dev_best_score();
@@ -311,16 +591,22 @@ mod tests {
     fn given_large_variance_when_std_dev_best_score_then_returns_large_value() {
         let mut config = ConfigurationResults::new(10, 2);
         config.runs.push(SingleRunResult {
+            seed: 2,
             rounds_taken: 100,
             resolution_limit_count: 5,
             best_score: 0.0,
             duration_secs: 0.5,
+            evaluations: 0,
+            trajectory: Vec::new(),
         });
         config.runs.push(SingleRunResult {
+            seed: 2,
             rounds_taken: 100,
             resolution_limit_count: 5,
             best_score: 100.0,
             duration_secs: 0.5,
+            evaluations: 0,
+            trajectory: Vec::new(),
         });
 
         // Mean: 50.0, variance: ((0-50)^2 + (100-50)^2)/2 = (2500 + 2500)/2 = 2500
@@ -332,22 +618,31 @@ mod tests {
     fn given_negative_scores_when_statistics_calculated_then_correct() {
         let mut config = ConfigurationResults::new(10, 2);
         config.runs.push(SingleRunResult {
+            seed: 2,
             rounds_taken: 100,
             resolution_limit_count: 5,
             best_score: -2.0,
             duration_secs: 0.5,
+            evaluations: 0,
+            trajectory: Vec::new(),
         });
         config.runs.push(SingleRunResult {
+            seed: 2,
             rounds_taken: 100,
             resolution_limit_count: 5,
             best_score: 0.0,
             duration_secs: 0.5,
+            evaluations: 0,
+            trajectory: Vec::new(),
         });
         config.runs.push(SingleRunResult {
+            seed: 2,
             rounds_taken: 100,
             resolution_limit_count: 5,
             best_score: 2.0,
             duration_secs: 0.5,
+            evaluations: 0,
+            trajectory: Vec::new(),
         });
 
         assert_eq!(config.average_best_score(), 0.0);
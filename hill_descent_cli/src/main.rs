@@ -0,0 +1,165 @@
+//! `hill_descent`: runs an optimisation described by a run spec file.
+//!
+//! ```text
+//! hill_descent <spec.toml | spec.json> [--quiet]
+//! hill_descent snapshot-to-json <snapshot> [output.json]
+//! ```
+//!
+//! Exit status is 0 on success, 1 if the spec is invalid or an output cannot be written,
+//! and 2 if the spec sets a `target_score` that the run did not reach.
+//!
+//! `snapshot-to-json` converts a binary snapshot written by `output.snapshot` to JSON for
+//! debugging, writing to standard output when no output file is given.
+
+mod objective;
+mod output;
+mod run_spec;
+mod runner;
+
+use std::{
+    fs::File,
+    io::{self, BufReader, BufWriter, Write},
+    path::Path,
+    process::ExitCode,
+};
+
+use hill_descent_lib::format_score;
+use objective::Objective;
+use run_spec::RunSpec;
+
+const USAGE: &str = "Usage: hill_descent <spec.toml | spec.json> [--quiet]
+       hill_descent snapshot-to-json <snapshot> [output.json]";
+
+/// Exit status when the run completes without reaching its target score.
+const TARGET_MISSED: u8 = 2;
+
+fn main() -> ExitCode {
+    if std::env::args().nth(1).as_deref() == Some("snapshot-to-json") {
+        let paths: Vec<String> = std::env::args().skip(2).collect();
+        let (input, output) = match paths.as_slice() {
+            [input] => (input, None),
+            [input, output] => (input, Some(output)),
+            _ => {
+                eprintln!("{USAGE}");
+                return ExitCode::FAILURE;
+            }
+        };
+        return match snapshot_to_json(Path::new(input), output.map(Path::new)) {
+            Ok(()) => ExitCode::SUCCESS,
+            Err(e) => {
+                eprintln!("Error: {e}");
+                ExitCode::FAILURE
+            }
+        };
+    }
+
+    let mut spec_path = None;
+    let mut quiet = false;
+    for arg in std::env::args().skip(1) {
+        match arg.as_str() {
+            "--quiet" | "-q" => quiet = true,
+            "--help" | "-h" => {
+                println!("{USAGE}");
+                return ExitCode::SUCCESS;
+            }
+            _ if spec_path.is_none() && !arg.starts_with('-') => spec_path = Some(arg),
+            _ => {
+                eprintln!("Unexpected argument '{arg}'\n{USAGE}");
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+    let Some(spec_path) = spec_path else {
+        eprintln!("{USAGE}");
+        return ExitCode::FAILURE;
+    };
+
+    match run(Path::new(&spec_path), quiet) {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::from(TARGET_MISSED),
+        Err(e) => {
+            eprintln!("Error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Runs the spec at `spec_path` and writes its outputs. Returns whether the run succeeded,
+/// i.e. reached its target score if it has one.
+fn run(spec_path: &Path, quiet: bool) -> Result<bool, String> {
+    let spec = RunSpec::load(spec_path)?;
+    let objective = Objective::from_spec(&spec.function)?;
+    if !quiet {
+        println!(
+            "Optimising over {} dimensions with population {} and {} target regions",
+            objective.bounds.len(),
+            spec.world.population_size,
+            spec.world.target_regions
+        );
+    }
+
+    let interval = spec.output.progress_interval;
+    let (outcome, world) = runner::run(&spec, objective, |record| {
+        if !quiet && interval > 0 && record.epoch % interval == 0 {
+            println!(
+                "epoch {:>8}  best {}  ({:.1}s)",
+                record.epoch,
+                format_score(record.best_score),
+                record.elapsed_seconds
+            );
+        }
+    });
+
+    if let Some(path) = &spec.output.history {
+        output::write_history(&outcome.history, Path::new(path))
+            .map_err(|e| format!("Cannot write history to {path}: {e}"))?;
+    }
+    if let Some(path) = &spec.output.best_params {
+        output::write_best_params(&outcome, Path::new(path))
+            .map_err(|e| format!("Cannot write best parameters to {path}: {e}"))?;
+    }
+    if let Some(path) = &spec.output.snapshot {
+        output::write_snapshot(&world, Path::new(path))
+            .map_err(|e| format!("Cannot write snapshot to {path}: {e}"))?;
+    }
+
+    println!(
+        "Stopped after {} epochs ({:?}) in {:.1}s; best score {}",
+        outcome.epochs,
+        outcome.stop_reason,
+        outcome.elapsed_seconds,
+        format_score(outcome.best_score)
+    );
+    if !quiet {
+        println!("Best parameters: {:?}", outcome.best_params);
+    }
+    if outcome.target_reached == Some(false) {
+        eprintln!(
+            "Target score {} not reached",
+            spec.stop.target_score.unwrap_or_default()
+        );
+    }
+    Ok(outcome.target_reached != Some(false))
+}
+
+/// Converts the binary snapshot at `input` to JSON in `output`, or on standard output.
+fn snapshot_to_json(input: &Path, output: Option<&Path>) -> Result<(), String> {
+    let reader = BufReader::new(
+        File::open(input).map_err(|e| format!("Cannot read {}: {e}", input.display()))?,
+    );
+    let converted = match output {
+        Some(path) => {
+            let file = File::create(path)
+                .map_err(|e| format!("Cannot write JSON to {}: {e}", path.display()))?;
+            let mut writer = BufWriter::new(file);
+            output::snapshot_to_json(reader, &mut writer)
+                .and_then(|()| writer.flush().map_err(Into::into))
+        }
+        None => {
+            let mut writer = BufWriter::new(io::stdout().lock());
+            output::snapshot_to_json(reader, &mut writer)
+                .and_then(|()| writer.flush().map_err(Into::into))
+        }
+    };
+    converted.map_err(|e| format!("Cannot convert {}: {e}", input.display()))
+}
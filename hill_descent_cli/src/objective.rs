@@ -0,0 +1,147 @@
+use std::ops::RangeInclusive;
+
+use hill_descent_lib::{
+    SingleValuedFunction, WorldFunction, benchmarks,
+    expression::{Expression, ExpressionFunction},
+};
+
+use crate::run_spec::FunctionSpec;
+
+/// The function a run minimises, with its search bounds and floor.
+#[derive(Debug)]
+pub struct Objective {
+    pub function: Box<dyn WorldFunction>,
+    pub bounds: Vec<RangeInclusive<f64>>,
+    pub floor: f64,
+}
+
+impl Objective {
+    /// Builds the objective described by `spec`, which has already been validated.
+    pub fn from_spec(spec: &FunctionSpec) -> Result<Self, String> {
+        if let Some(source) = &spec.expression {
+            return Self::from_expression(source, spec);
+        }
+
+        let name = spec.name.as_deref().unwrap_or_default();
+        let dimensions = spec.dimensions.unwrap_or(2);
+        let function = benchmarks::by_name(name, dimensions).ok_or_else(|| {
+            let names: Vec<_> = benchmarks::catalogue(2).iter().map(|f| f.name()).collect();
+            format!(
+                "function: no {dimensions}-dimensional function named '{name}' (available: {})",
+                names.join(", ")
+            )
+        })?;
+
+        let bounds = match spec.bounds_as_ranges() {
+            Some(bounds) if bounds.len() != dimensions => {
+                return Err(format!(
+                    "function: {name} has {dimensions} dimensions but {} bounds were given",
+                    bounds.len()
+                ));
+            }
+            Some(bounds) => bounds,
+            None => function.bounds(),
+        };
+        let floor = SingleValuedFunction::function_floor(&function);
+        Ok(Self {
+            function: Box::new(function),
+            bounds,
+            floor,
+        })
+    }
+
+    fn from_expression(source: &str, spec: &FunctionSpec) -> Result<Self, String> {
+        let expression =
+            Expression::parse(source).map_err(|e| format!("function: invalid expression: {e}"))?;
+        let bounds = spec.bounds_as_ranges().unwrap_or_default();
+        let needed = expression.variables().max(1);
+        if bounds.len() < needed {
+            return Err(format!(
+                "function: bounds must give at least {needed} [min, max] pairs for this expression, got {}",
+                bounds.len()
+            ));
+        }
+
+        let floor = spec.floor.unwrap_or(0.0);
+        Ok(Self {
+            function: Box::new(ExpressionFunction::new(expression, floor)),
+            bounds,
+            floor,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn spec(name: Option<&str>, expression: Option<&str>) -> FunctionSpec {
+        FunctionSpec {
+            name: name.map(str::to_string),
+            dimensions: None,
+            expression: expression.map(str::to_string),
+            bounds: None,
+            floor: None,
+        }
+    }
+
+    #[test]
+    fn given_catalogue_name_when_built_then_uses_its_bounds_and_floor() {
+        let mut spec = spec(Some("styblinski_tang"), None);
+        spec.dimensions = Some(3);
+
+        let objective = Objective::from_spec(&spec).unwrap();
+
+        assert_eq!(objective.bounds, vec![-5.0..=5.0; 3]);
+        assert!(objective.floor < 0.0);
+        assert_eq!(objective.function.function_floor(), objective.floor);
+    }
+
+    #[test]
+    fn given_unknown_name_when_built_then_error_lists_available_functions() {
+        let error = Objective::from_spec(&spec(Some("nope"), None)).unwrap_err();
+
+        assert!(error.contains("'nope'"), "{error}");
+        assert!(error.contains("rastrigin"), "{error}");
+    }
+
+    #[test]
+    fn given_wrong_number_of_bounds_when_built_then_error() {
+        let mut spec = spec(Some("sphere"), None);
+        spec.bounds = Some(vec![[-1.0, 1.0]]);
+
+        assert!(Objective::from_spec(&spec).is_err());
+    }
+
+    #[test]
+    fn given_expression_when_built_then_evaluates_it() {
+        let mut spec = spec(None, Some("x0 * x1"));
+        spec.bounds = Some(vec![[-1.0, 1.0], [-1.0, 1.0]]);
+        spec.floor = Some(-1.0);
+
+        let objective = Objective::from_spec(&spec).unwrap();
+
+        assert_eq!(objective.function.run(&[0.5, 0.5], &[]), vec![0.25]);
+        assert_eq!(objective.floor, -1.0);
+    }
+
+    #[test]
+    fn given_expression_with_too_few_bounds_when_built_then_error() {
+        let mut spec = spec(None, Some("x0 + x2"));
+        spec.bounds = Some(vec![[-1.0, 1.0], [-1.0, 1.0]]);
+
+        let error = Objective::from_spec(&spec).unwrap_err();
+
+        assert!(error.contains("at least 3"), "{error}");
+    }
+
+    #[test]
+    fn given_invalid_expression_when_built_then_error_has_position() {
+        let mut spec = spec(None, Some("x0 +"));
+        spec.bounds = Some(vec![[-1.0, 1.0]]);
+
+        let error = Objective::from_spec(&spec).unwrap_err();
+
+        assert!(error.contains("position 4"), "{error}");
+    }
+}
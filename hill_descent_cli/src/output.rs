@@ -0,0 +1,127 @@
+use std::{
+    fs::File,
+    io::{self, BufWriter, Read, Write},
+    path::Path,
+};
+
+use hill_descent_lib::World;
+use hill_descent_lib::world::snapshot::{SnapshotError, SnapshotOptions, SnapshotReader};
+
+use crate::runner::{EpochRecord, RunOutcome};
+
+/// Writes the outcome of a run, including the best parameters, as pretty-printed JSON.
+pub fn write_best_params(outcome: &RunOutcome, path: &Path) -> io::Result<()> {
+    let mut file = BufWriter::new(File::create(path)?);
+    serde_json::to_writer_pretty(&mut file, outcome)?;
+    writeln!(file)?;
+    file.flush()
+}
+
+/// Writes one CSV row per epoch.
+pub fn write_history(history: &[EpochRecord], path: &Path) -> io::Result<()> {
+    let mut file = BufWriter::new(File::create(path)?);
+    write_history_to(history, &mut file)?;
+    file.flush()
+}
+
+/// Writes the world, including every organism's gametes, as a binary snapshot.
+pub fn write_snapshot(world: &World, path: &Path) -> io::Result<()> {
+    let mut file = BufWriter::new(File::create(path)?);
+    world.write_snapshot(&mut file, &SnapshotOptions::full())?;
+    file.flush()
+}
+
+/// Converts the binary snapshot read from `input` to JSON, one organism at a time.
+pub fn snapshot_to_json(input: impl Read, output: &mut impl Write) -> Result<(), SnapshotError> {
+    SnapshotReader::new(input)?.write_json(&mut *output)?;
+    writeln!(output)?;
+    Ok(())
+}
+
+fn write_history_to(history: &[EpochRecord], out: &mut impl Write) -> io::Result<()> {
+    writeln!(out, "epoch,best_score,at_resolution_limit,elapsed_seconds")?;
+    for record in history {
+        writeln!(
+            out,
+            "{},{},{},{:.6}",
+            record.epoch, record.best_score, record.at_resolution_limit, record.elapsed_seconds
+        )?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use hill_descent_lib::world::snapshot::WorldSnapshot;
+    use hill_descent_lib::{GlobalConstants, SingleValuedFunction, TrainingData, setup_world};
+
+    use super::*;
+
+    #[test]
+    fn given_history_when_written_then_header_and_one_row_per_epoch() {
+        let history = vec![
+            EpochRecord {
+                epoch: 1,
+                best_score: 2.5,
+                at_resolution_limit: false,
+                elapsed_seconds: 0.25,
+            },
+            EpochRecord {
+                epoch: 2,
+                best_score: 0.125,
+                at_resolution_limit: true,
+                elapsed_seconds: 0.5,
+            },
+        ];
+        let mut out = Vec::new();
+
+        write_history_to(&history, &mut out).unwrap();
+
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "epoch,best_score,at_resolution_limit,elapsed_seconds\n\
+             1,2.5,false,0.250000\n\
+             2,0.125,true,0.500000\n"
+        );
+    }
+
+    #[derive(Debug)]
+    struct Sphere;
+
+    impl SingleValuedFunction for Sphere {
+        fn single_run(&self, params: &[f64]) -> f64 {
+            params.iter().map(|x| x * x).sum()
+        }
+    }
+
+    #[test]
+    fn given_binary_snapshot_when_converted_then_json_snapshot_of_same_world() {
+        let mut world = setup_world(
+            &[-1.0..=1.0, -1.0..=1.0],
+            GlobalConstants::new_with_seed(30, 4, 3),
+            Box::new(Sphere),
+        );
+        world.training_run(TrainingData::None { floor_value: 0.0 });
+        let mut binary = Vec::new();
+        world
+            .write_snapshot(&mut binary, &SnapshotOptions::full())
+            .unwrap();
+        let mut json = Vec::new();
+
+        snapshot_to_json(binary.as_slice(), &mut json).unwrap();
+
+        let json = String::from_utf8(json).unwrap();
+        assert!(json.ends_with('\n'));
+        assert_eq!(
+            WorldSnapshot::from_json(&json).unwrap(),
+            world.snapshot(&SnapshotOptions::full())
+        );
+    }
+
+    #[test]
+    fn given_non_snapshot_when_converted_then_error() {
+        let result = snapshot_to_json(&b"epoch,best_score"[..], &mut Vec::new());
+
+        assert!(matches!(result, Err(SnapshotError::NotBinary)));
+    }
+}
@@ -0,0 +1,329 @@
+//! The run spec file that describes an optimisation.
+//!
+//! Specs are TOML, or JSON when the file name ends in `.json`:
+//!
+//! ```toml
+//! [function]
+//! name = "rastrigin"        # from the benchmark catalogue, or
+//! # expression = "x0^2 + abs(x1)" with `bounds` and an optional `floor`
+//! dimensions = 10
+//!
+//! [world]
+//! population_size = 500
+//! target_regions = 20
+//! seed = 42
+//!
+//! [stop]
+//! max_epochs = 2000
+//! target_score = 1e-6
+//!
+//! [output]
+//! best_params = "best.json"
+//! history = "history.csv"
+//! snapshot = "final.snap"    # binary; `hill_descent snapshot-to-json` converts it
+//! ```
+
+use std::{fs, ops::RangeInclusive, path::Path};
+
+use serde::Deserialize;
+
+/// A complete optimisation run.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RunSpec {
+    pub function: FunctionSpec,
+    pub world: WorldSpec,
+    pub stop: StopSpec,
+    #[serde(default)]
+    pub output: OutputSpec,
+}
+
+/// The function to minimise: a catalogue entry by `name` or an `expression`.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct FunctionSpec {
+    /// Name of a function in `hill_descent_lib::benchmarks`, e.g. `"rastrigin"`.
+    pub name: Option<String>,
+    /// Number of dimensions of a catalogue function; defaults to 2.
+    pub dimensions: Option<usize>,
+    /// An objective over `x0`, `x1`, ... in the library's expression language.
+    pub expression: Option<String>,
+    /// Search range per parameter as `[min, max]`. Optional for catalogue functions, which
+    /// default to their conventional domain; required for expressions.
+    pub bounds: Option<Vec<[f64; 2]>>,
+    /// Lowest value an expression can take; defaults to 0.
+    pub floor: Option<f64>,
+}
+
+/// World construction parameters, passed to `GlobalConstants`.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct WorldSpec {
+    pub population_size: usize,
+    pub target_regions: usize,
+    pub seed: Option<u64>,
+}
+
+/// When to stop. The run ends at the first condition met; at least one of `max_epochs` and
+/// `max_seconds` is required so that every run ends.
+#[derive(Debug, Clone, PartialEq, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct StopSpec {
+    pub max_epochs: Option<u64>,
+    pub max_seconds: Option<f64>,
+    /// Stop once the best score is at or below this value. A run that ends without reaching
+    /// it counts as failed.
+    pub target_score: Option<f64>,
+    /// Stop the first time an epoch reports the resolution limit.
+    #[serde(default)]
+    pub stop_at_resolution_limit: bool,
+}
+
+/// Where to write results and how often to report progress.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct OutputSpec {
+    /// JSON file receiving the best parameters and score.
+    pub best_params: Option<String>,
+    /// CSV file receiving one row per epoch.
+    pub history: Option<String>,
+    /// File receiving a binary snapshot of the world when the run stops.
+    pub snapshot: Option<String>,
+    /// Print progress every this many epochs; 0 disables progress.
+    pub progress_interval: u64,
+}
+
+impl Default for OutputSpec {
+    fn default() -> Self {
+        Self {
+            best_params: None,
+            history: None,
+            snapshot: None,
+            progress_interval: 100,
+        }
+    }
+}
+
+impl RunSpec {
+    /// Reads and validates the spec at `path`.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let text =
+            fs::read_to_string(path).map_err(|e| format!("Cannot read {}: {e}", path.display()))?;
+        let is_json = path
+            .extension()
+            .is_some_and(|extension| extension.eq_ignore_ascii_case("json"));
+        let spec = if is_json {
+            Self::from_json(&text)
+        } else {
+            Self::from_toml(&text)
+        }
+        .map_err(|e| format!("Invalid run spec {}: {e}", path.display()))?;
+        Ok(spec)
+    }
+
+    /// Parses and validates a TOML spec.
+    pub fn from_toml(text: &str) -> Result<Self, String> {
+        let spec: Self = toml::from_str(text).map_err(|e| e.to_string())?;
+        spec.validate()?;
+        Ok(spec)
+    }
+
+    /// Parses and validates a JSON spec.
+    pub fn from_json(text: &str) -> Result<Self, String> {
+        let spec: Self = serde_json::from_str(text).map_err(|e| e.to_string())?;
+        spec.validate()?;
+        Ok(spec)
+    }
+
+    /// Checks the settings the library would otherwise reject with a panic.
+    fn validate(&self) -> Result<(), String> {
+        let function = &self.function;
+        match (&function.name, &function.expression) {
+            (Some(_), Some(_)) => {
+                return Err("function: give either name or expression, not both".to_string());
+            }
+            (None, None) => return Err("function: name or expression is required".to_string()),
+            (Some(_), None) if function.floor.is_some() => {
+                return Err("function: floor only applies to expressions".to_string());
+            }
+            (None, Some(_)) if function.dimensions.is_some() => {
+                return Err("function: the bounds of an expression set its dimensions".to_string());
+            }
+            _ => {}
+        }
+        if let Some(bounds) = &function.bounds
+            && let Some(i) = bounds
+                .iter()
+                .position(|[min, max]| !(min.is_finite() && max.is_finite() && min < max))
+        {
+            return Err(format!(
+                "function: bounds[{i}] must be finite with min < max"
+            ));
+        }
+        if function.floor.is_some_and(|floor| !floor.is_finite()) {
+            return Err("function: floor must be finite".to_string());
+        }
+
+        let world = &self.world;
+        if world.population_size == 0 || world.target_regions == 0 {
+            return Err("world: population_size and target_regions must be positive".to_string());
+        }
+        if world.target_regions > world.population_size {
+            return Err("world: target_regions cannot exceed population_size".to_string());
+        }
+
+        let stop = &self.stop;
+        if stop.max_epochs.is_none() && stop.max_seconds.is_none() {
+            return Err("stop: max_epochs or max_seconds is required".to_string());
+        }
+        if stop.max_epochs == Some(0) {
+            return Err("stop: max_epochs must be positive".to_string());
+        }
+        if stop
+            .max_seconds
+            .is_some_and(|seconds| !(seconds.is_finite() && seconds > 0.0))
+        {
+            return Err("stop: max_seconds must be positive".to_string());
+        }
+        if stop.target_score.is_some_and(f64::is_nan) {
+            return Err("stop: target_score must be a number".to_string());
+        }
+        Ok(())
+    }
+}
+
+impl FunctionSpec {
+    /// Returns the configured bounds as ranges, if any were given.
+    pub fn bounds_as_ranges(&self) -> Option<Vec<RangeInclusive<f64>>> {
+        self.bounds
+            .as_ref()
+            .map(|bounds| bounds.iter().map(|&[min, max]| min..=max).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MINIMAL: &str = r#"
+        [function]
+        name = "sphere"
+
+        [world]
+        population_size = 50
+        target_regions = 5
+
+        [stop]
+        max_epochs = 10
+    "#;
+
+    #[test]
+    fn given_minimal_toml_when_parsed_then_defaults_applied() {
+        let spec = RunSpec::from_toml(MINIMAL).unwrap();
+
+        assert_eq!(spec.function.name.as_deref(), Some("sphere"));
+        assert_eq!(spec.function.dimensions, None);
+        assert_eq!(spec.world.seed, None);
+        assert_eq!(spec.stop.max_epochs, Some(10));
+        assert!(!spec.stop.stop_at_resolution_limit);
+        assert_eq!(spec.output, OutputSpec::default());
+    }
+
+    #[test]
+    fn given_json_spec_when_parsed_then_matches_equivalent_toml() {
+        let json = r#"{
+            "function": { "name": "sphere" },
+            "world": { "population_size": 50, "target_regions": 5 },
+            "stop": { "max_epochs": 10 }
+        }"#;
+
+        assert_eq!(
+            RunSpec::from_json(json).unwrap(),
+            RunSpec::from_toml(MINIMAL).unwrap()
+        );
+    }
+
+    #[test]
+    fn given_expression_with_bounds_when_parsed_then_ranges_available() {
+        let spec = RunSpec::from_toml(
+            r#"
+            [function]
+            expression = "x0^2 + x1^2"
+            bounds = [[-1.0, 1.0], [-2.0, 2.0]]
+            floor = 0.0
+
+            [world]
+            population_size = 50
+            target_regions = 5
+
+            [stop]
+            max_seconds = 1.5
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            spec.function.bounds_as_ranges().unwrap(),
+            vec![-1.0..=1.0, -2.0..=2.0]
+        );
+    }
+
+    #[test]
+    fn given_name_and_expression_when_parsed_then_error() {
+        let text = MINIMAL.replace(
+            "name = \"sphere\"",
+            "name = \"sphere\"\nexpression = \"x0\"",
+        );
+
+        let error = RunSpec::from_toml(&text).unwrap_err();
+
+        assert!(error.contains("not both"), "{error}");
+    }
+
+    #[test]
+    fn given_no_epoch_or_time_limit_when_parsed_then_error() {
+        let text = MINIMAL.replace("max_epochs = 10", "target_score = 0.1");
+
+        let error = RunSpec::from_toml(&text).unwrap_err();
+
+        assert!(error.contains("max_epochs or max_seconds"), "{error}");
+    }
+
+    #[test]
+    fn given_zero_max_epochs_when_parsed_then_error() {
+        let text = MINIMAL.replace("max_epochs = 10", "max_epochs = 0\nmax_seconds = 5.0");
+
+        let error = RunSpec::from_toml(&text).unwrap_err();
+
+        assert!(
+            error.contains("stop: max_epochs must be positive"),
+            "{error}"
+        );
+    }
+
+    #[test]
+    fn given_more_regions_than_population_when_parsed_then_error() {
+        let text = MINIMAL.replace("target_regions = 5", "target_regions = 500");
+
+        assert!(RunSpec::from_toml(&text).is_err());
+    }
+
+    #[test]
+    fn given_inverted_bounds_when_parsed_then_error_names_index() {
+        let text = MINIMAL.replace(
+            "name = \"sphere\"",
+            "name = \"sphere\"\nbounds = [[0.0, 1.0], [1.0, -1.0]]",
+        );
+
+        let error = RunSpec::from_toml(&text).unwrap_err();
+
+        assert!(error.contains("bounds[1]"), "{error}");
+    }
+
+    #[test]
+    fn given_unknown_field_when_parsed_then_error() {
+        let text = MINIMAL.replace("max_epochs = 10", "max_epochs = 10\nmax_epoch = 5");
+
+        assert!(RunSpec::from_toml(&text).is_err());
+    }
+}
@@ -0,0 +1,181 @@
+use std::time::Instant;
+
+use hill_descent_lib::{GlobalConstants, TrainingData, World, setup_world};
+use serde::Serialize;
+
+use crate::{objective::Objective, run_spec::RunSpec};
+
+/// Why a run ended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StopReason {
+    TargetReached,
+    ResolutionLimit,
+    MaxEpochs,
+    MaxSeconds,
+}
+
+/// The state of a run after one epoch, written as a row of the history CSV.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EpochRecord {
+    pub epoch: u64,
+    pub best_score: f64,
+    pub at_resolution_limit: bool,
+    pub elapsed_seconds: f64,
+}
+
+/// The result of a completed run, written as the best-parameters JSON.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct RunOutcome {
+    pub stop_reason: StopReason,
+    pub epochs: u64,
+    pub elapsed_seconds: f64,
+    pub best_score: f64,
+    pub best_params: Vec<f64>,
+    /// `None` when the spec sets no target score.
+    pub target_reached: Option<bool>,
+    #[serde(skip)]
+    pub history: Vec<EpochRecord>,
+}
+
+/// Runs the world until a stop condition in `spec` is met, calling `on_epoch` after every
+/// epoch. Returns the outcome and the world as it was when the run stopped.
+pub fn run(
+    spec: &RunSpec,
+    objective: Objective,
+    mut on_epoch: impl FnMut(&EpochRecord),
+) -> (RunOutcome, World) {
+    let constants = match spec.world.seed {
+        Some(seed) => GlobalConstants::new_with_seed(
+            spec.world.population_size,
+            spec.world.target_regions,
+            seed,
+        ),
+        None => GlobalConstants::new(spec.world.population_size, spec.world.target_regions),
+    };
+    let floor = objective.floor;
+    let mut world = setup_world(&objective.bounds, constants, objective.function);
+
+    let stop = &spec.stop;
+    let start = Instant::now();
+    let mut history = Vec::new();
+    let stop_reason = loop {
+        let at_resolution_limit = world.training_run(TrainingData::None { floor_value: floor });
+        let record = EpochRecord {
+            epoch: world.epoch(),
+            best_score: world.get_best_score(),
+            at_resolution_limit,
+            elapsed_seconds: start.elapsed().as_secs_f64(),
+        };
+        on_epoch(&record);
+        history.push(record);
+        let record = history.last().expect("a record was just pushed");
+
+        if stop
+            .target_score
+            .is_some_and(|target| record.best_score <= target)
+        {
+            break StopReason::TargetReached;
+        }
+        if stop.stop_at_resolution_limit && at_resolution_limit {
+            break StopReason::ResolutionLimit;
+        }
+        if stop.max_epochs.is_some_and(|max| record.epoch >= max) {
+            break StopReason::MaxEpochs;
+        }
+        if stop
+            .max_seconds
+            .is_some_and(|max| record.elapsed_seconds >= max)
+        {
+            break StopReason::MaxSeconds;
+        }
+    };
+
+    let best_score = world.get_best_score();
+    let outcome = RunOutcome {
+        stop_reason,
+        epochs: world.epoch(),
+        elapsed_seconds: start.elapsed().as_secs_f64(),
+        best_score,
+        best_params: world.get_best_params(),
+        target_reached: stop.target_score.map(|target| best_score <= target),
+        history,
+    };
+    (outcome, world)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn spec(stop: &str) -> RunSpec {
+        RunSpec::from_toml(&format!(
+            r#"
+            [function]
+            name = "sphere"
+            dimensions = 2
+
+            [world]
+            population_size = 60
+            target_regions = 6
+            seed = 7
+
+            [stop]
+            {stop}
+            "#
+        ))
+        .unwrap()
+    }
+
+    fn run_spec(spec: &RunSpec) -> RunOutcome {
+        let objective = Objective::from_spec(&spec.function).unwrap();
+        run(spec, objective, |_| {}).0
+    }
+
+    #[test]
+    fn given_max_epochs_when_run_then_stops_after_that_many_epochs() {
+        let mut seen = 0;
+        let spec = spec("max_epochs = 5");
+
+        let (outcome, world) = run(&spec, Objective::from_spec(&spec.function).unwrap(), |_| {
+            seen += 1
+        });
+
+        assert_eq!(outcome.stop_reason, StopReason::MaxEpochs);
+        assert_eq!(outcome.epochs, 5);
+        assert_eq!(seen, 5);
+        assert_eq!(outcome.history.len(), 5);
+        assert_eq!(outcome.best_params.len(), 2);
+        assert_eq!(outcome.target_reached, None);
+        assert_eq!(world.epoch(), outcome.epochs);
+    }
+
+    #[test]
+    fn given_easy_target_when_run_then_stops_when_reached() {
+        let outcome = run_spec(&spec("max_epochs = 100\ntarget_score = 1e6"));
+
+        assert_eq!(outcome.stop_reason, StopReason::TargetReached);
+        assert_eq!(outcome.epochs, 1);
+        assert_eq!(outcome.target_reached, Some(true));
+    }
+
+    #[test]
+    fn given_unreachable_target_when_run_then_reported_as_missed() {
+        let outcome = run_spec(&spec("max_epochs = 3\ntarget_score = -1.0"));
+
+        assert_eq!(outcome.stop_reason, StopReason::MaxEpochs);
+        assert_eq!(outcome.target_reached, Some(false));
+    }
+
+    #[test]
+    fn given_same_seed_when_run_twice_then_histories_match() {
+        let spec = spec("max_epochs = 4");
+
+        let first = run_spec(&spec);
+        let second = run_spec(&spec);
+
+        let scores = |o: &RunOutcome| o.history.iter().map(|r| r.best_score).collect::<Vec<_>>();
+        assert_eq!(scores(&first), scores(&second));
+        assert_eq!(first.best_params, second.best_params);
+    }
+}
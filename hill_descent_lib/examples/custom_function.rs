@@ -40,6 +40,7 @@ impl SingleValuedFunction for Rosenbrock {
 /// - (-2.805118, 3.131312)
 /// - (-3.779310, -3.283186)
 /// - (3.584428, -1.848126)
+///
 /// All with value 0.
 #[derive(Debug)]
 struct Himmelblau;
@@ -0,0 +1,70 @@
+use std::ops::RangeInclusive;
+
+use super::{BenchmarkFunction, Optimum, check_dimensions};
+use crate::SingleValuedFunction;
+
+/// The Ackley function, a nearly flat outer region around a deep central funnel.
+///
+/// f(x) = -20 exp(-0.2 √(Σxᵢ²/n)) - exp(Σ cos(2πxᵢ)/n) + 20 + e, with its global minimum
+/// of 0 at the origin. Conventionally searched on [-32.768, 32.768] in each dimension.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Ackley {
+    dimensions: usize,
+}
+
+impl Ackley {
+    /// Creates the function in `dimensions` dimensions.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `dimensions` is zero.
+    pub fn new(dimensions: usize) -> Self {
+        assert!(dimensions >= 1, "Ackley needs at least 1 dimension");
+        Self { dimensions }
+    }
+}
+
+impl SingleValuedFunction for Ackley {
+    fn single_run(&self, params: &[f64]) -> f64 {
+        check_dimensions(self.name(), self.dimensions, params);
+        let n = params.len() as f64;
+        let two_pi = 2.0 * std::f64::consts::PI;
+        let mean_square = params.iter().map(|x| x * x).sum::<f64>() / n;
+        let mean_cos = params.iter().map(|x| (two_pi * x).cos()).sum::<f64>() / n;
+        let value =
+            -20.0 * (-0.2 * mean_square.sqrt()).exp() - mean_cos.exp() + 20.0 + std::f64::consts::E;
+        // The constants cancel at the optimum, so rounding can dip just below zero
+        value.max(0.0)
+    }
+}
+
+impl BenchmarkFunction for Ackley {
+    fn name(&self) -> &'static str {
+        "ackley"
+    }
+
+    fn dimensions(&self) -> usize {
+        self.dimensions
+    }
+
+    fn bounds(&self) -> Vec<RangeInclusive<f64>> {
+        vec![-32.768..=32.768; self.dimensions]
+    }
+
+    fn optimum(&self) -> Option<Optimum> {
+        Some(Optimum::new(vec![0.0; self.dimensions], 0.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::benchmarks::tests::assert_consistent;
+
+    #[test]
+    fn given_several_dimensions_when_checked_then_optimum_and_floor_consistent() {
+        for dimensions in [1, 2, 10] {
+            assert_consistent(&Ackley::new(dimensions));
+        }
+    }
+}
@@ -0,0 +1,48 @@
+use std::ops::RangeInclusive;
+
+use super::{BenchmarkFunction, Optimum, check_dimensions};
+use crate::SingleValuedFunction;
+
+/// The Bukin N.6 function, a narrow curved valley that is very hard to follow.
+///
+/// f(x, y) = 100 √|y - 0.01x²| + 0.01 |x + 10|, with its global minimum of 0 at (-10, 1).
+/// Conventionally searched on [-15, -5] × [-3, 3].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct BukinN6;
+
+impl SingleValuedFunction for BukinN6 {
+    fn single_run(&self, params: &[f64]) -> f64 {
+        check_dimensions(self.name(), 2, params);
+        let (x, y) = (params[0], params[1]);
+        100.0 * (y - 0.01 * x * x).abs().sqrt() + 0.01 * (x + 10.0).abs()
+    }
+}
+
+impl BenchmarkFunction for BukinN6 {
+    fn name(&self) -> &'static str {
+        "bukin_n6"
+    }
+
+    fn dimensions(&self) -> usize {
+        2
+    }
+
+    fn bounds(&self) -> Vec<RangeInclusive<f64>> {
+        vec![-15.0..=-5.0, -3.0..=3.0]
+    }
+
+    fn optimum(&self) -> Option<Optimum> {
+        Some(Optimum::new(vec![-10.0, 1.0], 0.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::benchmarks::tests::assert_consistent;
+
+    #[test]
+    fn given_bukin_n6_when_checked_then_optimum_and_floor_consistent() {
+        assert_consistent(&BukinN6);
+    }
+}
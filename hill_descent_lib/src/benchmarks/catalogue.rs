@@ -0,0 +1,101 @@
+use super::{
+    Ackley, BenchmarkFunction, BukinN6, Griewank, Himmelblau, LeviN13, Levy, Michalewicz,
+    Rastrigin, Rosenbrock, SchafferN2, Schwefel, Sphere, StyblinskiTang, Zakharov,
+};
+
+/// Names of the functions defined for any number of dimensions.
+const SCALABLE: [&str; 10] = [
+    "sphere",
+    "rosenbrock",
+    "rastrigin",
+    "ackley",
+    "griewank",
+    "schwefel",
+    "levy",
+    "zakharov",
+    "michalewicz",
+    "styblinski_tang",
+];
+
+/// Names of the functions defined only in two dimensions.
+const TWO_DIMENSIONAL: [&str; 4] = ["himmelblau", "bukin_n6", "levi_n13", "schaffer_n2"];
+
+/// Returns the function called `name` (as reported by [`BenchmarkFunction::name`]) in
+/// `dimensions` dimensions, or `None` if there is no such function or it is not defined
+/// for that many dimensions.
+///
+/// # Example
+///
+/// ```
+/// use hill_descent_lib::benchmarks::by_name;
+///
+/// let griewank = by_name("griewank", 10).unwrap();
+/// assert_eq!(griewank.bounds().len(), 10);
+/// assert!(by_name("himmelblau", 3).is_none());
+/// ```
+pub fn by_name(name: &str, dimensions: usize) -> Option<Box<dyn BenchmarkFunction>> {
+    let scalable_minimum = if name == "rosenbrock" { 2 } else { 1 };
+    if SCALABLE.contains(&name) && dimensions < scalable_minimum
+        || TWO_DIMENSIONAL.contains(&name) && dimensions != 2
+    {
+        return None;
+    }
+
+    Some(match name {
+        "sphere" => Box::new(Sphere::new(dimensions)),
+        "rosenbrock" => Box::new(Rosenbrock::new(dimensions)),
+        "rastrigin" => Box::new(Rastrigin::new(dimensions)),
+        "ackley" => Box::new(Ackley::new(dimensions)),
+        "griewank" => Box::new(Griewank::new(dimensions)),
+        "schwefel" => Box::new(Schwefel::new(dimensions)),
+        "levy" => Box::new(Levy::new(dimensions)),
+        "zakharov" => Box::new(Zakharov::new(dimensions)),
+        "michalewicz" => Box::new(Michalewicz::new(dimensions)),
+        "styblinski_tang" => Box::new(StyblinskiTang::new(dimensions)),
+        "himmelblau" => Box::new(Himmelblau),
+        "bukin_n6" => Box::new(BukinN6),
+        "levi_n13" => Box::new(LeviN13),
+        "schaffer_n2" => Box::new(SchafferN2),
+        _ => return None,
+    })
+}
+
+/// Returns every function defined for `dimensions` dimensions.
+pub fn catalogue(dimensions: usize) -> Vec<Box<dyn BenchmarkFunction>> {
+    SCALABLE
+        .iter()
+        .chain(TWO_DIMENSIONAL.iter())
+        .filter_map(|name| by_name(name, dimensions))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn given_two_dimensions_when_catalogue_then_every_function_included() {
+        let names: Vec<_> = catalogue(2).iter().map(|f| f.name()).collect();
+        assert_eq!(names.len(), SCALABLE.len() + TWO_DIMENSIONAL.len());
+        for name in SCALABLE.iter().chain(TWO_DIMENSIONAL.iter()) {
+            assert!(names.contains(name));
+        }
+    }
+
+    #[test]
+    fn given_other_dimensions_when_catalogue_then_only_scalable_functions_included() {
+        assert_eq!(catalogue(5).len(), SCALABLE.len());
+        assert_eq!(catalogue(1).len(), SCALABLE.len() - 1);
+        assert!(catalogue(0).is_empty());
+    }
+
+    #[test]
+    fn given_name_when_by_name_then_function_reports_same_name_and_dimensions() {
+        for function in catalogue(3) {
+            let found = by_name(function.name(), 3).unwrap();
+            assert_eq!(found.name(), function.name());
+            assert_eq!(found.dimensions(), 3);
+        }
+        assert!(by_name("unknown", 2).is_none());
+    }
+}
@@ -0,0 +1,69 @@
+use std::ops::RangeInclusive;
+
+use super::{BenchmarkFunction, Optimum, check_dimensions};
+use crate::SingleValuedFunction;
+
+/// The Griewank function, a bowl overlaid with many shallow local minima.
+///
+/// f(x) = 1 + Σ xᵢ²/4000 - Π cos(xᵢ/√i), with its global minimum of 0 at the origin.
+/// Conventionally searched on [-600, 600] in each dimension.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Griewank {
+    dimensions: usize,
+}
+
+impl Griewank {
+    /// Creates the function in `dimensions` dimensions.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `dimensions` is zero.
+    pub fn new(dimensions: usize) -> Self {
+        assert!(dimensions >= 1, "Griewank needs at least 1 dimension");
+        Self { dimensions }
+    }
+}
+
+impl SingleValuedFunction for Griewank {
+    fn single_run(&self, params: &[f64]) -> f64 {
+        check_dimensions(self.name(), self.dimensions, params);
+        let sum: f64 = params.iter().map(|x| x * x / 4000.0).sum();
+        let product: f64 = params
+            .iter()
+            .enumerate()
+            .map(|(i, x)| (x / ((i + 1) as f64).sqrt()).cos())
+            .product();
+        (1.0 + sum - product).max(0.0)
+    }
+}
+
+impl BenchmarkFunction for Griewank {
+    fn name(&self) -> &'static str {
+        "griewank"
+    }
+
+    fn dimensions(&self) -> usize {
+        self.dimensions
+    }
+
+    fn bounds(&self) -> Vec<RangeInclusive<f64>> {
+        vec![-600.0..=600.0; self.dimensions]
+    }
+
+    fn optimum(&self) -> Option<Optimum> {
+        Some(Optimum::new(vec![0.0; self.dimensions], 0.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::benchmarks::tests::assert_consistent;
+
+    #[test]
+    fn given_several_dimensions_when_checked_then_optimum_and_floor_consistent() {
+        for dimensions in [1, 2, 10] {
+            assert_consistent(&Griewank::new(dimensions));
+        }
+    }
+}
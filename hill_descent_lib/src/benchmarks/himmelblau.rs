@@ -0,0 +1,59 @@
+use std::ops::RangeInclusive;
+
+use super::{BenchmarkFunction, Optimum, check_dimensions};
+use crate::SingleValuedFunction;
+
+/// Himmelblau's function, with four global minima of 0.
+///
+/// f(x, y) = (x² + y - 11)² + (x + y² - 7)². The minima are at (3, 2), (-2.805, 3.131),
+/// (-3.779, -3.283) and (3.584, -1.848). Conventionally searched on [-5, 5]².
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Himmelblau;
+
+impl SingleValuedFunction for Himmelblau {
+    fn single_run(&self, params: &[f64]) -> f64 {
+        check_dimensions(self.name(), 2, params);
+        let (x, y) = (params[0], params[1]);
+        (x.powi(2) + y - 11.0).powi(2) + (x + y.powi(2) - 7.0).powi(2)
+    }
+}
+
+impl BenchmarkFunction for Himmelblau {
+    fn name(&self) -> &'static str {
+        "himmelblau"
+    }
+
+    fn dimensions(&self) -> usize {
+        2
+    }
+
+    fn bounds(&self) -> Vec<RangeInclusive<f64>> {
+        vec![-5.0..=5.0, -5.0..=5.0]
+    }
+
+    fn optimum(&self) -> Option<Optimum> {
+        Some(Optimum::new(vec![3.0, 2.0], 0.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::benchmarks::tests::assert_consistent;
+
+    #[test]
+    fn given_himmelblau_when_checked_then_optimum_and_floor_consistent() {
+        assert_consistent(&Himmelblau);
+    }
+
+    #[test]
+    fn given_each_known_minimum_when_single_run_then_near_zero() {
+        for point in [
+            [-2.805_118, 3.131_312],
+            [-3.779_310, -3.283_186],
+            [3.584_428, -1.848_126],
+        ] {
+            assert!(Himmelblau.single_run(&point) < 1e-9);
+        }
+    }
+}
@@ -0,0 +1,51 @@
+use std::ops::RangeInclusive;
+
+use super::{BenchmarkFunction, Optimum, check_dimensions};
+use crate::SingleValuedFunction;
+
+/// The Lévi N.13 function, multimodal with its global minimum of 0 at (1, 1).
+///
+/// f(x, y) = sin²(3πx) + (x - 1)²(1 + sin²(3πy)) + (y - 1)²(1 + sin²(2πy)).
+/// Conventionally searched on [-10, 10]².
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct LeviN13;
+
+impl SingleValuedFunction for LeviN13 {
+    fn single_run(&self, params: &[f64]) -> f64 {
+        check_dimensions(self.name(), 2, params);
+        let (x, y) = (params[0], params[1]);
+        let pi = std::f64::consts::PI;
+        (3.0 * pi * x).sin().powi(2)
+            + (x - 1.0).powi(2) * (1.0 + (3.0 * pi * y).sin().powi(2))
+            + (y - 1.0).powi(2) * (1.0 + (2.0 * pi * y).sin().powi(2))
+    }
+}
+
+impl BenchmarkFunction for LeviN13 {
+    fn name(&self) -> &'static str {
+        "levi_n13"
+    }
+
+    fn dimensions(&self) -> usize {
+        2
+    }
+
+    fn bounds(&self) -> Vec<RangeInclusive<f64>> {
+        vec![-10.0..=10.0, -10.0..=10.0]
+    }
+
+    fn optimum(&self) -> Option<Optimum> {
+        Some(Optimum::new(vec![1.0, 1.0], 0.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::benchmarks::tests::assert_consistent;
+
+    #[test]
+    fn given_levi_n13_when_checked_then_optimum_and_floor_consistent() {
+        assert_consistent(&LeviN13);
+    }
+}
@@ -0,0 +1,73 @@
+use std::ops::RangeInclusive;
+
+use super::{BenchmarkFunction, Optimum, check_dimensions};
+use crate::SingleValuedFunction;
+
+/// The Levy function, multimodal with its global minimum at (1, ..., 1).
+///
+/// With wᵢ = 1 + (xᵢ - 1)/4,
+/// f(x) = sin²(πw₁) + Σᵢ₌₁ⁿ⁻¹ (wᵢ - 1)²(1 + 10 sin²(πwᵢ + 1)) + (wₙ - 1)²(1 + sin²(2πwₙ)),
+/// with a minimum of 0. Conventionally searched on [-10, 10] in each dimension.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Levy {
+    dimensions: usize,
+}
+
+impl Levy {
+    /// Creates the function in `dimensions` dimensions.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `dimensions` is zero.
+    pub fn new(dimensions: usize) -> Self {
+        assert!(dimensions >= 1, "Levy needs at least 1 dimension");
+        Self { dimensions }
+    }
+}
+
+impl SingleValuedFunction for Levy {
+    fn single_run(&self, params: &[f64]) -> f64 {
+        check_dimensions(self.name(), self.dimensions, params);
+        let pi = std::f64::consts::PI;
+        let w: Vec<f64> = params.iter().map(|x| 1.0 + (x - 1.0) / 4.0).collect();
+        let last = w[w.len() - 1];
+        let middle: f64 = w[..w.len() - 1]
+            .iter()
+            .map(|wi| (wi - 1.0).powi(2) * (1.0 + 10.0 * (pi * wi + 1.0).sin().powi(2)))
+            .sum();
+        (pi * w[0]).sin().powi(2)
+            + middle
+            + (last - 1.0).powi(2) * (1.0 + (2.0 * pi * last).sin().powi(2))
+    }
+}
+
+impl BenchmarkFunction for Levy {
+    fn name(&self) -> &'static str {
+        "levy"
+    }
+
+    fn dimensions(&self) -> usize {
+        self.dimensions
+    }
+
+    fn bounds(&self) -> Vec<RangeInclusive<f64>> {
+        vec![-10.0..=10.0; self.dimensions]
+    }
+
+    fn optimum(&self) -> Option<Optimum> {
+        Some(Optimum::new(vec![1.0; self.dimensions], 0.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::benchmarks::tests::assert_consistent;
+
+    #[test]
+    fn given_several_dimensions_when_checked_then_optimum_and_floor_consistent() {
+        for dimensions in [1, 2, 10] {
+            assert_consistent(&Levy::new(dimensions));
+        }
+    }
+}
@@ -0,0 +1,149 @@
+use std::ops::RangeInclusive;
+
+use super::{BenchmarkFunction, Optimum, check_dimensions};
+use crate::SingleValuedFunction;
+
+/// The Michalewicz function, with steep narrow valleys (steepness m = 10).
+///
+/// f(x) = -Σ sin(xᵢ) sin²ᵐ(i xᵢ²/π). Every term is at least -1, so the floor is -n. The
+/// function is separable, so the minimum is known up to ten dimensions, where the
+/// published values end: about -0.8013 in 1-D, -1.8013 at (2.20, π/2) in 2-D, -4.687658
+/// in 5-D and -9.66015 in 10-D. Conventionally searched on [0, π] in each dimension.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Michalewicz {
+    dimensions: usize,
+}
+
+impl Michalewicz {
+    /// The steepness parameter m.
+    const STEEPNESS: i32 = 10;
+
+    /// The minimising value of each parameter, in parameter order.
+    const OPTIMUM_LOCATION: [f64; 10] = [
+        2.202_905_522_262_705,
+        std::f64::consts::FRAC_PI_2,
+        1.284_991_570_884_997,
+        1.923_058_468_815_935,
+        1.720_469_772_639_552,
+        std::f64::consts::FRAC_PI_2,
+        1.454_413_970_783_711,
+        1.756_086_520_708_521,
+        1.655_717_416_936_38,
+        std::f64::consts::FRAC_PI_2,
+    ];
+
+    /// The global minimum in 1 to 10 dimensions.
+    const OPTIMUM_VALUES: [f64; 10] = [
+        -0.801_303_410_098_553,
+        -1.801_303_410_098_553,
+        -2.760_394_679_994_558,
+        -3.698_857_098_466_642,
+        -4.687_658_179_088_148,
+        -5.687_658_179_088_148,
+        -6.680_885_314_444_026,
+        -7.663_757_350_716_238,
+        -8.660_151_715_641_34,
+        -9.660_151_715_641_34,
+    ];
+
+    /// Creates the function in `dimensions` dimensions.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `dimensions` is zero.
+    pub fn new(dimensions: usize) -> Self {
+        assert!(dimensions >= 1, "Michalewicz needs at least 1 dimension");
+        Self { dimensions }
+    }
+}
+
+impl SingleValuedFunction for Michalewicz {
+    fn single_run(&self, params: &[f64]) -> f64 {
+        check_dimensions(self.name(), self.dimensions, params);
+        let value = -params
+            .iter()
+            .enumerate()
+            .map(|(i, x)| {
+                x.sin()
+                    * ((i + 1) as f64 * x * x / std::f64::consts::PI)
+                        .sin()
+                        .powi(2 * Self::STEEPNESS)
+            })
+            .sum::<f64>();
+        value.max(self.function_floor())
+    }
+
+    fn function_floor(&self) -> f64 {
+        -(self.dimensions as f64)
+    }
+}
+
+impl BenchmarkFunction for Michalewicz {
+    fn name(&self) -> &'static str {
+        "michalewicz"
+    }
+
+    fn dimensions(&self) -> usize {
+        self.dimensions
+    }
+
+    fn bounds(&self) -> Vec<RangeInclusive<f64>> {
+        vec![0.0..=std::f64::consts::PI; self.dimensions]
+    }
+
+    fn optimum(&self) -> Option<Optimum> {
+        let value = *Self::OPTIMUM_VALUES.get(self.dimensions - 1)?;
+        Some(Optimum::new(
+            Self::OPTIMUM_LOCATION[..self.dimensions].to_vec(),
+            value,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::benchmarks::tests::assert_consistent;
+
+    #[test]
+    fn given_several_dimensions_when_checked_then_optimum_and_floor_consistent() {
+        for dimensions in [1, 2, 5, 10, 11] {
+            assert_consistent(&Michalewicz::new(dimensions));
+        }
+    }
+
+    #[test]
+    fn given_dimensions_with_published_minimum_when_optimum_then_matches_it() {
+        for (dimensions, published) in [(1, -0.8013), (2, -1.8013), (5, -4.687658), (10, -9.66015)]
+        {
+            let optimum = Michalewicz::new(dimensions).optimum().unwrap();
+
+            assert_eq!(optimum.location().len(), dimensions);
+            assert!(
+                (optimum.value() - published).abs() < 1e-4,
+                "{dimensions}-D minimum {} differs from {published}",
+                optimum.value()
+            );
+        }
+        assert!((Michalewicz::new(1).optimum().unwrap().location()[0] - 2.2029).abs() < 1e-4);
+    }
+
+    #[test]
+    fn given_optimum_when_each_parameter_nudged_then_value_rises() {
+        let function = Michalewicz::new(10);
+        let optimum = function.optimum().unwrap();
+        for i in 0..10 {
+            for delta in [-1e-3, 1e-3] {
+                let mut nudged = optimum.location().to_vec();
+                nudged[i] += delta;
+
+                assert!(function.single_run(&nudged) > optimum.value());
+            }
+        }
+    }
+
+    #[test]
+    fn given_more_than_ten_dimensions_when_optimum_then_none() {
+        assert!(Michalewicz::new(11).optimum().is_none());
+    }
+}
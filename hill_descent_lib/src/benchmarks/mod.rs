@@ -0,0 +1,155 @@
+//! Standard test functions for exercising optimisers (feature: `benchmarks`).
+//!
+//! Every function implements [`SingleValuedFunction`] with a correct
+//! [`function_floor`](SingleValuedFunction::function_floor), and [`BenchmarkFunction`] for
+//! its usual search bounds and known optimum. Most are defined for any number of dimensions;
+//! [`Himmelblau`], [`BukinN6`], [`LeviN13`] and [`SchafferN2`] are two-dimensional only.
+//!
+//! # Example
+//!
+//! ```
+//! use hill_descent_lib::benchmarks::{BenchmarkFunction, Rastrigin};
+//! use hill_descent_lib::{GlobalConstants, SingleValuedFunction, TrainingData, setup_world};
+//!
+//! let rastrigin = Rastrigin::new(5);
+//! let floor = rastrigin.function_floor();
+//! let bounds = rastrigin.bounds();
+//! let mut world = setup_world(&bounds, GlobalConstants::new(200, 10), Box::new(rastrigin));
+//! world.training_run(TrainingData::None { floor_value: floor });
+//! assert!(world.get_best_score() >= 0.0);
+//! ```
+
+use std::ops::RangeInclusive;
+
+use crate::SingleValuedFunction;
+
+mod ackley;
+mod bukin_n6;
+mod catalogue;
+mod griewank;
+mod himmelblau;
+mod levi_n13;
+mod levy;
+mod michalewicz;
+mod optimum;
+mod rastrigin;
+mod rosenbrock;
+mod schaffer_n2;
+mod schwefel;
+mod sphere;
+mod styblinski_tang;
+mod zakharov;
+
+pub use ackley::Ackley;
+pub use bukin_n6::BukinN6;
+pub use catalogue::{by_name, catalogue};
+pub use griewank::Griewank;
+pub use himmelblau::Himmelblau;
+pub use levi_n13::LeviN13;
+pub use levy::Levy;
+pub use michalewicz::Michalewicz;
+pub use optimum::Optimum;
+pub use rastrigin::Rastrigin;
+pub use rosenbrock::Rosenbrock;
+pub use schaffer_n2::SchafferN2;
+pub use schwefel::Schwefel;
+pub use sphere::Sphere;
+pub use styblinski_tang::StyblinskiTang;
+pub use zakharov::Zakharov;
+
+/// A test function with a conventional search domain and known optimum.
+pub trait BenchmarkFunction: SingleValuedFunction {
+    /// Returns a short lower-case identifier, e.g. `"rastrigin"`.
+    fn name(&self) -> &'static str;
+
+    /// Returns the number of parameters the function takes.
+    fn dimensions(&self) -> usize;
+
+    /// Returns the conventional search domain, one range per dimension.
+    fn bounds(&self) -> Vec<RangeInclusive<f64>>;
+
+    /// Returns a global minimum, if one is known for this number of dimensions.
+    ///
+    /// Functions with several global minima report one of them.
+    fn optimum(&self) -> Option<Optimum>;
+}
+
+/// Lets catalogue entries be passed straight to [`setup_world`](crate::setup_world).
+impl SingleValuedFunction for Box<dyn BenchmarkFunction> {
+    fn single_run(&self, phenotype_expressed_values: &[f64]) -> f64 {
+        self.as_ref().single_run(phenotype_expressed_values)
+    }
+
+    fn function_floor(&self) -> f64 {
+        self.as_ref().function_floor()
+    }
+}
+
+/// Panics unless `params` has one value per dimension.
+fn check_dimensions(name: &str, dimensions: usize, params: &[f64]) {
+    assert_eq!(
+        dimensions,
+        params.len(),
+        "{name} expects {dimensions} parameters, got {}",
+        params.len()
+    );
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    /// Checks the function's optimum is attained, respects the floor and that a grid of
+    /// points across the bounds never evaluates below the floor.
+    pub(crate) fn assert_consistent(function: &dyn BenchmarkFunction) {
+        let bounds = function.bounds();
+        assert_eq!(bounds.len(), function.dimensions());
+        let floor = function.function_floor();
+
+        if let Some(optimum) = function.optimum() {
+            let value = function.single_run(optimum.location());
+            assert!(
+                (value - optimum.value()).abs() <= 1e-6 * (1.0 + optimum.value().abs()),
+                "{}: expected {} at optimum, got {value}",
+                function.name(),
+                optimum.value()
+            );
+            assert!(optimum.value() >= floor);
+        }
+
+        for step in 0..=20 {
+            let t = step as f64 / 20.0;
+            let point: Vec<f64> = bounds
+                .iter()
+                .enumerate()
+                .map(|(i, r)| {
+                    // Stagger dimensions so the points are not all on the diagonal
+                    let u = (t + i as f64 * 0.37).fract();
+                    r.start() + u * (r.end() - r.start())
+                })
+                .collect();
+            let value = function.single_run(&point);
+            assert!(
+                value.is_finite() && value >= floor,
+                "{}: value {value} at {point:?} is below floor {floor}",
+                function.name()
+            );
+        }
+    }
+
+    #[test]
+    fn given_boxed_benchmark_when_used_as_function_then_forwards_to_inner() {
+        let boxed: Box<dyn BenchmarkFunction> = Box::new(Sphere::new(2));
+        assert_eq!(boxed.single_run(&[3.0, 4.0]), 25.0);
+        assert_eq!(
+            SingleValuedFunction::function_floor(&boxed),
+            Sphere::new(2).function_floor()
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "sphere expects 3 parameters, got 2")]
+    fn given_wrong_parameter_count_when_single_run_then_panics() {
+        Sphere::new(3).single_run(&[1.0, 2.0]);
+    }
+}
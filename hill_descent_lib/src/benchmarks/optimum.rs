@@ -0,0 +1,22 @@
+/// The location and value of a function's global minimum.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Optimum {
+    location: Vec<f64>,
+    value: f64,
+}
+
+impl Optimum {
+    pub(crate) fn new(location: Vec<f64>, value: f64) -> Self {
+        Self { location, value }
+    }
+
+    /// Returns the parameters at which the minimum is reached.
+    pub fn location(&self) -> &[f64] {
+        &self.location
+    }
+
+    /// Returns the function's value at the minimum.
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+}
@@ -0,0 +1,68 @@
+use std::ops::RangeInclusive;
+
+use super::{BenchmarkFunction, Optimum, check_dimensions};
+use crate::SingleValuedFunction;
+
+/// The Rastrigin function, highly multimodal with a regular grid of local minima.
+///
+/// f(x) = 10n + Σ (xᵢ² - 10 cos(2πxᵢ)), with its global minimum of 0 at the origin.
+/// Conventionally searched on [-5.12, 5.12] in each dimension.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rastrigin {
+    dimensions: usize,
+}
+
+impl Rastrigin {
+    /// Creates the function in `dimensions` dimensions.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `dimensions` is zero.
+    pub fn new(dimensions: usize) -> Self {
+        assert!(dimensions >= 1, "Rastrigin needs at least 1 dimension");
+        Self { dimensions }
+    }
+}
+
+impl SingleValuedFunction for Rastrigin {
+    fn single_run(&self, params: &[f64]) -> f64 {
+        check_dimensions(self.name(), self.dimensions, params);
+        let two_pi = 2.0 * std::f64::consts::PI;
+        10.0 * params.len() as f64
+            + params
+                .iter()
+                .map(|x| x * x - 10.0 * (two_pi * x).cos())
+                .sum::<f64>()
+    }
+}
+
+impl BenchmarkFunction for Rastrigin {
+    fn name(&self) -> &'static str {
+        "rastrigin"
+    }
+
+    fn dimensions(&self) -> usize {
+        self.dimensions
+    }
+
+    fn bounds(&self) -> Vec<RangeInclusive<f64>> {
+        vec![-5.12..=5.12; self.dimensions]
+    }
+
+    fn optimum(&self) -> Option<Optimum> {
+        Some(Optimum::new(vec![0.0; self.dimensions], 0.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::benchmarks::tests::assert_consistent;
+
+    #[test]
+    fn given_several_dimensions_when_checked_then_optimum_and_floor_consistent() {
+        for dimensions in [1, 2, 10] {
+            assert_consistent(&Rastrigin::new(dimensions));
+        }
+    }
+}
@@ -0,0 +1,72 @@
+use std::ops::RangeInclusive;
+
+use super::{BenchmarkFunction, Optimum, check_dimensions};
+use crate::SingleValuedFunction;
+
+/// The Rosenbrock function, a narrow curved valley.
+///
+/// f(x) = Σᵢ 100(xᵢ₊₁ - xᵢ²)² + (1 - xᵢ)², with its global minimum of 0 at (1, ..., 1).
+/// Conventionally searched on [-5, 10] in each dimension.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rosenbrock {
+    dimensions: usize,
+}
+
+impl Rosenbrock {
+    /// Creates the function in `dimensions` dimensions.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `dimensions` is less than 2.
+    pub fn new(dimensions: usize) -> Self {
+        assert!(dimensions >= 2, "Rosenbrock needs at least 2 dimensions");
+        Self { dimensions }
+    }
+}
+
+impl SingleValuedFunction for Rosenbrock {
+    fn single_run(&self, params: &[f64]) -> f64 {
+        check_dimensions(self.name(), self.dimensions, params);
+        params
+            .windows(2)
+            .map(|w| 100.0 * (w[1] - w[0] * w[0]).powi(2) + (1.0 - w[0]).powi(2))
+            .sum()
+    }
+}
+
+impl BenchmarkFunction for Rosenbrock {
+    fn name(&self) -> &'static str {
+        "rosenbrock"
+    }
+
+    fn dimensions(&self) -> usize {
+        self.dimensions
+    }
+
+    fn bounds(&self) -> Vec<RangeInclusive<f64>> {
+        vec![-5.0..=10.0; self.dimensions]
+    }
+
+    fn optimum(&self) -> Option<Optimum> {
+        Some(Optimum::new(vec![1.0; self.dimensions], 0.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::benchmarks::tests::assert_consistent;
+
+    #[test]
+    fn given_several_dimensions_when_checked_then_optimum_and_floor_consistent() {
+        for dimensions in [2, 3, 10] {
+            assert_consistent(&Rosenbrock::new(dimensions));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Rosenbrock needs at least 2 dimensions")]
+    fn given_one_dimension_when_new_then_panics() {
+        Rosenbrock::new(1);
+    }
+}
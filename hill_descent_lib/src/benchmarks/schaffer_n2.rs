@@ -0,0 +1,50 @@
+use std::ops::RangeInclusive;
+
+use super::{BenchmarkFunction, Optimum, check_dimensions};
+use crate::SingleValuedFunction;
+
+/// The Schaffer N.2 function, smooth areas broken by concentric ripples.
+///
+/// f(x, y) = 0.5 + (sin²(x² - y²) - 0.5) / (1 + 0.001(x² + y²))², with its global minimum
+/// of 0 at the origin. Conventionally searched on [-100, 100]².
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SchafferN2;
+
+impl SingleValuedFunction for SchafferN2 {
+    fn single_run(&self, params: &[f64]) -> f64 {
+        check_dimensions(self.name(), 2, params);
+        let (x, y) = (params[0], params[1]);
+        let numerator = (x * x - y * y).sin().powi(2) - 0.5;
+        let denominator = (1.0 + 0.001 * (x * x + y * y)).powi(2);
+        0.5 + numerator / denominator
+    }
+}
+
+impl BenchmarkFunction for SchafferN2 {
+    fn name(&self) -> &'static str {
+        "schaffer_n2"
+    }
+
+    fn dimensions(&self) -> usize {
+        2
+    }
+
+    fn bounds(&self) -> Vec<RangeInclusive<f64>> {
+        vec![-100.0..=100.0, -100.0..=100.0]
+    }
+
+    fn optimum(&self) -> Option<Optimum> {
+        Some(Optimum::new(vec![0.0, 0.0], 0.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::benchmarks::tests::assert_consistent;
+
+    #[test]
+    fn given_schaffer_n2_when_checked_then_optimum_and_floor_consistent() {
+        assert_consistent(&SchafferN2);
+    }
+}
@@ -0,0 +1,70 @@
+use std::ops::RangeInclusive;
+
+use super::{BenchmarkFunction, Optimum, check_dimensions};
+use crate::SingleValuedFunction;
+
+/// The Schwefel function, whose best local minima lie far from the global one.
+///
+/// f(x) = 418.9829n - Σ xᵢ sin(√|xᵢ|), with its global minimum of 0 at (420.9687, ...).
+/// Conventionally searched on [-500, 500] in each dimension.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Schwefel {
+    dimensions: usize,
+}
+
+impl Schwefel {
+    /// The largest value of xᵢ sin(√|xᵢ|) on the domain, which the constant term cancels.
+    const PEAK: f64 = 418.982_887_272_433_7;
+    /// The coordinate of the global minimum in every dimension.
+    const ARGMIN: f64 = 420.968_746_359_982;
+
+    /// Creates the function in `dimensions` dimensions.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `dimensions` is zero.
+    pub fn new(dimensions: usize) -> Self {
+        assert!(dimensions >= 1, "Schwefel needs at least 1 dimension");
+        Self { dimensions }
+    }
+}
+
+impl SingleValuedFunction for Schwefel {
+    fn single_run(&self, params: &[f64]) -> f64 {
+        check_dimensions(self.name(), self.dimensions, params);
+        let value = Self::PEAK * params.len() as f64
+            - params.iter().map(|x| x * x.abs().sqrt().sin()).sum::<f64>();
+        value.max(0.0)
+    }
+}
+
+impl BenchmarkFunction for Schwefel {
+    fn name(&self) -> &'static str {
+        "schwefel"
+    }
+
+    fn dimensions(&self) -> usize {
+        self.dimensions
+    }
+
+    fn bounds(&self) -> Vec<RangeInclusive<f64>> {
+        vec![-500.0..=500.0; self.dimensions]
+    }
+
+    fn optimum(&self) -> Option<Optimum> {
+        Some(Optimum::new(vec![Self::ARGMIN; self.dimensions], 0.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::benchmarks::tests::assert_consistent;
+
+    #[test]
+    fn given_several_dimensions_when_checked_then_optimum_and_floor_consistent() {
+        for dimensions in [1, 2, 10] {
+            assert_consistent(&Schwefel::new(dimensions));
+        }
+    }
+}
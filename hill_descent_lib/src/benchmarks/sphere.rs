@@ -0,0 +1,63 @@
+use std::ops::RangeInclusive;
+
+use super::{BenchmarkFunction, Optimum, check_dimensions};
+use crate::SingleValuedFunction;
+
+/// The sphere function, a sum of squares.
+///
+/// f(x) = Σ xᵢ², with its global minimum of 0 at the origin. Conventionally searched on
+/// [-5.12, 5.12] in each dimension.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Sphere {
+    dimensions: usize,
+}
+
+impl Sphere {
+    /// Creates the function in `dimensions` dimensions.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `dimensions` is zero.
+    pub fn new(dimensions: usize) -> Self {
+        assert!(dimensions >= 1, "Sphere needs at least 1 dimension");
+        Self { dimensions }
+    }
+}
+
+impl SingleValuedFunction for Sphere {
+    fn single_run(&self, params: &[f64]) -> f64 {
+        check_dimensions(self.name(), self.dimensions, params);
+        params.iter().map(|x| x * x).sum()
+    }
+}
+
+impl BenchmarkFunction for Sphere {
+    fn name(&self) -> &'static str {
+        "sphere"
+    }
+
+    fn dimensions(&self) -> usize {
+        self.dimensions
+    }
+
+    fn bounds(&self) -> Vec<RangeInclusive<f64>> {
+        vec![-5.12..=5.12; self.dimensions]
+    }
+
+    fn optimum(&self) -> Option<Optimum> {
+        Some(Optimum::new(vec![0.0; self.dimensions], 0.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::benchmarks::tests::assert_consistent;
+
+    #[test]
+    fn given_several_dimensions_when_checked_then_optimum_and_floor_consistent() {
+        for dimensions in [1, 2, 10] {
+            assert_consistent(&Sphere::new(dimensions));
+        }
+    }
+}
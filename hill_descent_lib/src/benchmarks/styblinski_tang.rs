@@ -0,0 +1,80 @@
+use std::ops::RangeInclusive;
+
+use super::{BenchmarkFunction, Optimum, check_dimensions};
+use crate::SingleValuedFunction;
+
+/// The Styblinski–Tang function, multimodal with many local minima.
+///
+/// f(x) = Σ (xᵢ⁴ - 16xᵢ² + 5xᵢ)/2, with its global minimum of about -39.166n at
+/// (-2.903534, ...), which is also its floor. Conventionally searched on [-5, 5] in each
+/// dimension.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StyblinskiTang {
+    dimensions: usize,
+}
+
+impl StyblinskiTang {
+    /// The minimum of a single term.
+    const MINIMUM_PER_DIMENSION: f64 = -39.166_165_703_771_41;
+    /// The coordinate of the global minimum in every dimension.
+    const ARGMIN: f64 = -2.903_534_027_771_177;
+
+    /// Creates the function in `dimensions` dimensions.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `dimensions` is zero.
+    pub fn new(dimensions: usize) -> Self {
+        assert!(dimensions >= 1, "StyblinskiTang needs at least 1 dimension");
+        Self { dimensions }
+    }
+}
+
+impl SingleValuedFunction for StyblinskiTang {
+    fn single_run(&self, params: &[f64]) -> f64 {
+        check_dimensions(self.name(), self.dimensions, params);
+        let value = params
+            .iter()
+            .map(|x| (x.powi(4) - 16.0 * x.powi(2) + 5.0 * x) / 2.0)
+            .sum::<f64>();
+        value.max(self.function_floor())
+    }
+
+    fn function_floor(&self) -> f64 {
+        Self::MINIMUM_PER_DIMENSION * self.dimensions as f64
+    }
+}
+
+impl BenchmarkFunction for StyblinskiTang {
+    fn name(&self) -> &'static str {
+        "styblinski_tang"
+    }
+
+    fn dimensions(&self) -> usize {
+        self.dimensions
+    }
+
+    fn bounds(&self) -> Vec<RangeInclusive<f64>> {
+        vec![-5.0..=5.0; self.dimensions]
+    }
+
+    fn optimum(&self) -> Option<Optimum> {
+        Some(Optimum::new(
+            vec![Self::ARGMIN; self.dimensions],
+            self.function_floor(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::benchmarks::tests::assert_consistent;
+
+    #[test]
+    fn given_several_dimensions_when_checked_then_optimum_and_floor_consistent() {
+        for dimensions in [1, 2, 10] {
+            assert_consistent(&StyblinskiTang::new(dimensions));
+        }
+    }
+}
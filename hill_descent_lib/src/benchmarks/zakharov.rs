@@ -0,0 +1,69 @@
+use std::ops::RangeInclusive;
+
+use super::{BenchmarkFunction, Optimum, check_dimensions};
+use crate::SingleValuedFunction;
+
+/// The Zakharov function, a plate-shaped unimodal function.
+///
+/// f(x) = Σ xᵢ² + (Σ 0.5 i xᵢ)² + (Σ 0.5 i xᵢ)⁴, with its global minimum of 0 at the
+/// origin. Conventionally searched on [-5, 10] in each dimension.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Zakharov {
+    dimensions: usize,
+}
+
+impl Zakharov {
+    /// Creates the function in `dimensions` dimensions.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `dimensions` is zero.
+    pub fn new(dimensions: usize) -> Self {
+        assert!(dimensions >= 1, "Zakharov needs at least 1 dimension");
+        Self { dimensions }
+    }
+}
+
+impl SingleValuedFunction for Zakharov {
+    fn single_run(&self, params: &[f64]) -> f64 {
+        check_dimensions(self.name(), self.dimensions, params);
+        let squares: f64 = params.iter().map(|x| x * x).sum();
+        let weighted: f64 = params
+            .iter()
+            .enumerate()
+            .map(|(i, x)| 0.5 * (i + 1) as f64 * x)
+            .sum();
+        squares + weighted.powi(2) + weighted.powi(4)
+    }
+}
+
+impl BenchmarkFunction for Zakharov {
+    fn name(&self) -> &'static str {
+        "zakharov"
+    }
+
+    fn dimensions(&self) -> usize {
+        self.dimensions
+    }
+
+    fn bounds(&self) -> Vec<RangeInclusive<f64>> {
+        vec![-5.0..=10.0; self.dimensions]
+    }
+
+    fn optimum(&self) -> Option<Optimum> {
+        Some(Optimum::new(vec![0.0; self.dimensions], 0.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::benchmarks::tests::assert_consistent;
+
+    #[test]
+    fn given_several_dimensions_when_checked_then_optimum_and_floor_consistent() {
+        for dimensions in [1, 2, 10] {
+            assert_consistent(&Zakharov::new(dimensions));
+        }
+    }
+}
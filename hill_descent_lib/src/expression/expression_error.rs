@@ -0,0 +1,35 @@
+use std::fmt;
+
+use serde::Serialize;
+
+/// Why an expression could not be parsed, returned to clients as a structured error.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ExpressionError {
+    position: usize,
+    message: String,
+}
+
+impl ExpressionError {
+    pub(crate) fn new(position: usize, message: impl Into<String>) -> Self {
+        Self {
+            position,
+            message: message.into(),
+        }
+    }
+
+    /// Returns the byte offset of the offending input.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Returns a description of the problem.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl fmt::Display for ExpressionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at position {}", self.message, self.position)
+    }
+}
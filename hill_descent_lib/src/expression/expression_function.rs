@@ -0,0 +1,91 @@
+use super::Expression;
+use crate::SingleValuedFunction;
+
+/// Adapts an [`Expression`] to [`SingleValuedFunction`].
+///
+/// The world panics on outputs that are not finite, lie below the function floor or are so
+/// far above it that the score overflows, all of which arbitrary expressions readily
+/// produce (e.g. `ln(x0)` for negative `x0`). Outputs are therefore clamped to lie between
+/// the floor and [`MAX_ABOVE_FLOOR`](Self::MAX_ABOVE_FLOOR) above it, with NaN treated as
+/// the worst output.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExpressionFunction {
+    expression: Expression,
+    floor: f64,
+}
+
+impl ExpressionFunction {
+    /// Largest distance above the floor an output may have; squaring it must not overflow.
+    pub const MAX_ABOVE_FLOOR: f64 = 1e150;
+
+    pub fn new(expression: Expression, floor: f64) -> Self {
+        Self { expression, floor }
+    }
+
+    /// Returns the expression being optimised.
+    pub fn expression(&self) -> &Expression {
+        &self.expression
+    }
+}
+
+impl SingleValuedFunction for ExpressionFunction {
+    fn single_run(&self, phenotype_expressed_values: &[f64]) -> f64 {
+        let worst = self.floor + Self::MAX_ABOVE_FLOOR;
+        let value = self.expression.evaluate(phenotype_expressed_values);
+        if value.is_nan() {
+            worst
+        } else {
+            value.clamp(self.floor, worst)
+        }
+    }
+
+    fn function_floor(&self) -> f64 {
+        self.floor
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn function(source: &str, floor: f64) -> ExpressionFunction {
+        ExpressionFunction::new(Expression::parse(source).unwrap(), floor)
+    }
+
+    #[test]
+    fn given_output_within_range_when_run_then_unchanged() {
+        assert_eq!(function("x0 * x1", 0.0).single_run(&[2.0, 3.0]), 6.0);
+    }
+
+    #[test]
+    fn given_output_below_floor_when_run_then_clamped_to_floor() {
+        assert_eq!(function("x0", -1.0).single_run(&[-5.0]), -1.0);
+    }
+
+    #[test]
+    fn given_nan_or_infinite_output_when_run_then_worst_output() {
+        let worst = ExpressionFunction::MAX_ABOVE_FLOOR;
+        assert_eq!(function("ln(x0)", 0.0).single_run(&[-1.0]), worst);
+        assert_eq!(function("1 / x0", 0.0).single_run(&[0.0]), worst);
+    }
+
+    #[test]
+    fn given_unbounded_output_when_run_then_clamped_relative_to_floor() {
+        let floor = -2.0;
+        let worst = floor + ExpressionFunction::MAX_ABOVE_FLOOR;
+        let function = function("x0", floor);
+
+        assert_eq!(function.single_run(&[f64::INFINITY]), worst);
+        assert_eq!(function.single_run(&[f64::NEG_INFINITY]), floor);
+        assert_eq!(function.single_run(&[f64::NAN]), worst);
+        assert_eq!(function.single_run(&[1e200]), worst);
+        assert_eq!(function.single_run(&[-1e200]), floor);
+        // The largest output still leaves the score finite once squared
+        assert!((worst - floor).powi(2).is_finite());
+    }
+
+    #[test]
+    fn given_floor_when_function_floor_then_returned() {
+        assert_eq!(function("x0", -3.5).function_floor(), -3.5);
+    }
+}
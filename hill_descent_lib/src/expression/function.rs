@@ -0,0 +1,84 @@
+/// A built-in function that may be called from an expression.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum Function {
+    Sin,
+    Cos,
+    Tan,
+    Asin,
+    Acos,
+    Atan,
+    Sinh,
+    Cosh,
+    Tanh,
+    Exp,
+    Ln,
+    Log10,
+    Sqrt,
+    Abs,
+    Floor,
+    Ceil,
+    Min,
+    Max,
+    Pow,
+    Atan2,
+}
+
+impl Function {
+    pub(crate) fn from_name(name: &str) -> Option<Self> {
+        Some(match name {
+            "sin" => Self::Sin,
+            "cos" => Self::Cos,
+            "tan" => Self::Tan,
+            "asin" => Self::Asin,
+            "acos" => Self::Acos,
+            "atan" => Self::Atan,
+            "sinh" => Self::Sinh,
+            "cosh" => Self::Cosh,
+            "tanh" => Self::Tanh,
+            "exp" => Self::Exp,
+            "ln" | "log" => Self::Ln,
+            "log10" => Self::Log10,
+            "sqrt" => Self::Sqrt,
+            "abs" => Self::Abs,
+            "floor" => Self::Floor,
+            "ceil" => Self::Ceil,
+            "min" => Self::Min,
+            "max" => Self::Max,
+            "pow" => Self::Pow,
+            "atan2" => Self::Atan2,
+            _ => return None,
+        })
+    }
+
+    pub(crate) fn arity(self) -> usize {
+        match self {
+            Self::Min | Self::Max | Self::Pow | Self::Atan2 => 2,
+            _ => 1,
+        }
+    }
+
+    pub(crate) fn apply(self, args: &[f64]) -> f64 {
+        match self {
+            Self::Sin => args[0].sin(),
+            Self::Cos => args[0].cos(),
+            Self::Tan => args[0].tan(),
+            Self::Asin => args[0].asin(),
+            Self::Acos => args[0].acos(),
+            Self::Atan => args[0].atan(),
+            Self::Sinh => args[0].sinh(),
+            Self::Cosh => args[0].cosh(),
+            Self::Tanh => args[0].tanh(),
+            Self::Exp => args[0].exp(),
+            Self::Ln => args[0].ln(),
+            Self::Log10 => args[0].log10(),
+            Self::Sqrt => args[0].sqrt(),
+            Self::Abs => args[0].abs(),
+            Self::Floor => args[0].floor(),
+            Self::Ceil => args[0].ceil(),
+            Self::Min => args[0].min(args[1]),
+            Self::Max => args[0].max(args[1]),
+            Self::Pow => args[0].powf(args[1]),
+            Self::Atan2 => args[0].atan2(args[1]),
+        }
+    }
+}
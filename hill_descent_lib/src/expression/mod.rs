@@ -0,0 +1,242 @@
+//! A small expression language for user-defined objective functions (feature: `expression`).
+//!
+//! Expressions are parsed once into a syntax tree and evaluated for every organism, so no
+//! user input is ever executed. The grammar, loosest binding first:
+//!
+//! ```text
+//! expr    := term (('+' | '-') term)*
+//! term    := unary (('*' | '/') unary)*
+//! unary   := '-' unary | power
+//! power   := primary ('^' unary)?
+//! primary := number | variable | constant | function '(' expr (',' expr)* ')' | '(' expr ')'
+//! ```
+//!
+//! Variables are `x0`, `x1`, ... and index the problem parameters. `^` is right associative
+//! and binds tighter than unary minus, so `-x0^2` is `-(x0^2)`.
+
+mod expression_error;
+mod expression_function;
+mod function;
+mod node;
+mod parser;
+mod token;
+
+pub use expression_error::ExpressionError;
+pub use expression_function::ExpressionFunction;
+
+use node::Node;
+use parser::Parser;
+use token::{Token, tokenize};
+
+/// Longest expression accepted, in bytes.
+pub const MAX_EXPRESSION_LENGTH: usize = 1_000;
+
+/// Deepest nesting of parentheses and operators accepted, to bound evaluation recursion.
+pub(crate) const MAX_DEPTH: usize = 64;
+
+/// Highest variable index accepted (`x0` to `x99`).
+pub(crate) const MAX_VARIABLES: usize = 100;
+
+/// A parsed expression over the variables `x0`, `x1`, ...
+#[derive(Debug, Clone, PartialEq)]
+pub struct Expression {
+    source: String,
+    root: Node,
+    variables: usize,
+}
+
+impl Expression {
+    /// Parses `source`, reporting the first syntax error found.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use hill_descent_lib::expression::Expression;
+    ///
+    /// let expression = Expression::parse("x0^2 + 3 * sin(x1)").unwrap();
+    /// assert_eq!(expression.variables(), 2);
+    /// assert_eq!(expression.evaluate(&[2.0, 0.0]), 4.0);
+    ///
+    /// let error = Expression::parse("x0 + * x1").unwrap_err();
+    /// assert_eq!(error.position(), 5);
+    /// ```
+    pub fn parse(source: &str) -> Result<Self, ExpressionError> {
+        if source.len() > MAX_EXPRESSION_LENGTH {
+            return Err(ExpressionError::new(
+                MAX_EXPRESSION_LENGTH,
+                format!("Expression is longer than {MAX_EXPRESSION_LENGTH} characters"),
+            ));
+        }
+
+        let mut parser = Parser::new(tokenize(source)?);
+        let root = parser.expression()?;
+        let (position, token) = parser.advance();
+        if token != Token::End {
+            return Err(ExpressionError::new(
+                position,
+                format!("Unexpected {token} after the end of the expression"),
+            ));
+        }
+
+        let variables = root.highest_variable().map_or(0, |index| index + 1);
+        Ok(Self {
+            source: source.to_string(),
+            root,
+            variables,
+        })
+    }
+
+    /// Returns the expression as written.
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    /// Returns the number of parameters the expression needs (one more than its highest
+    /// variable index).
+    pub fn variables(&self) -> usize {
+        self.variables
+    }
+
+    /// Evaluates the expression; `params` must hold at least [`variables`](Self::variables)
+    /// values.
+    pub fn evaluate(&self, params: &[f64]) -> f64 {
+        self.root.evaluate(params)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn eval(source: &str, params: &[f64]) -> f64 {
+        Expression::parse(source).unwrap().evaluate(params)
+    }
+
+    #[test]
+    fn given_mixed_operators_when_evaluated_then_precedence_respected() {
+        assert_eq!(eval("1 + 2 * 3", &[]), 7.0);
+        assert_eq!(eval("(1 + 2) * 3", &[]), 9.0);
+        assert_eq!(eval("8 / 4 / 2", &[]), 1.0);
+        assert_eq!(eval("10 - 4 - 3", &[]), 3.0);
+    }
+
+    #[test]
+    fn given_power_when_evaluated_then_right_associative_and_tighter_than_negation() {
+        assert_eq!(eval("2^3^2", &[]), 512.0);
+        assert_eq!(eval("-x0^2", &[3.0]), -9.0);
+        assert_eq!(eval("2^-1", &[]), 0.5);
+    }
+
+    #[test]
+    fn given_functions_and_constants_when_evaluated_then_applied() {
+        assert_eq!(eval("max(x0, x1) + min(x0, x1)", &[1.0, 5.0]), 6.0);
+        assert_eq!(eval("abs(-2) * sqrt(16)", &[]), 8.0);
+        assert!((eval("cos(pi)", &[]) + 1.0).abs() < 1e-12);
+        assert!((eval("ln(e)", &[]) - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn given_variables_when_parsed_then_count_is_one_past_highest_index() {
+        assert_eq!(Expression::parse("x3 + x0").unwrap().variables(), 4);
+        assert_eq!(Expression::parse("42").unwrap().variables(), 0);
+    }
+
+    #[test]
+    fn given_trailing_tokens_when_parsed_then_error_at_first_extra_token() {
+        let error = Expression::parse("x0 x1").unwrap_err();
+        assert_eq!(error.position(), 3);
+    }
+
+    #[test]
+    fn given_unknown_name_when_parsed_then_error_names_it() {
+        let error = Expression::parse("2 * y").unwrap_err();
+        assert_eq!(error.position(), 4);
+        assert!(error.message().contains("'y'"));
+    }
+
+    #[test]
+    fn given_wrong_argument_count_when_parsed_then_error() {
+        let error = Expression::parse("min(x0)").unwrap_err();
+        assert!(error.message().contains("takes 2 argument(s)"));
+    }
+
+    #[test]
+    fn given_variable_beyond_limit_when_parsed_then_error() {
+        assert!(Expression::parse("x99").is_ok());
+        assert!(Expression::parse("x100").is_err());
+    }
+
+    #[test]
+    fn given_overlong_or_deeply_nested_source_when_parsed_then_error() {
+        let long = "1+".repeat(MAX_EXPRESSION_LENGTH / 2) + "1";
+        assert!(Expression::parse(&long).is_err());
+
+        let nested = "(".repeat(MAX_DEPTH + 1) + "1" + &")".repeat(MAX_DEPTH + 1);
+        assert!(Expression::parse(&nested).is_err());
+    }
+
+    #[test]
+    fn given_source_at_length_limit_when_parsed_then_accepted_and_one_more_rejected() {
+        let at_limit = "1+".repeat(MAX_EXPRESSION_LENGTH / 2 - 1) + "11";
+        assert_eq!(at_limit.len(), MAX_EXPRESSION_LENGTH);
+        assert!(Expression::parse(&at_limit).is_ok());
+
+        let error = Expression::parse(&(at_limit + "1")).unwrap_err();
+        assert_eq!(error.position(), MAX_EXPRESSION_LENGTH);
+        assert!(error.message().contains("longer than 1000 characters"));
+    }
+
+    #[test]
+    fn given_nesting_at_depth_limit_when_parsed_then_accepted_and_one_more_rejected() {
+        // The top-level expression is itself one level deep
+        let parenthesised = |levels: usize| "(".repeat(levels) + "1" + &")".repeat(levels);
+        let negated = |levels: usize| "-".repeat(levels) + "1";
+        let powers = |levels: usize| "2^".repeat(levels) + "1";
+
+        for nest in [parenthesised, negated, powers] {
+            assert!(Expression::parse(&nest(MAX_DEPTH - 1)).is_ok());
+            let error = Expression::parse(&nest(MAX_DEPTH)).unwrap_err();
+            assert!(error.message().contains("nested more than 64 levels deep"));
+        }
+    }
+
+    #[test]
+    fn given_malformed_source_when_parsed_then_error_at_offending_token() {
+        for (source, position) in [
+            ("", 0),
+            ("1 +", 3),
+            ("* 2", 0),
+            ("()", 1),
+            ("x0)", 2),
+            ("1, 2", 1),
+            ("max(x0,)", 7),
+            ("sin x0", 4),
+            ("2 $ 3", 2),
+        ] {
+            let error = Expression::parse(source).unwrap_err();
+            assert_eq!(
+                error.position(),
+                position,
+                "position of error in {source:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn given_names_that_are_not_variables_when_parsed_then_unknown_name_error() {
+        for source in ["x", "X0", "xa", "x0y", "foo(x0)", "pi2"] {
+            let error = Expression::parse(source).unwrap_err();
+            assert!(
+                error.message().starts_with("Unknown name"),
+                "{source:?} gave {}",
+                error.message()
+            );
+        }
+    }
+
+    #[test]
+    fn given_unclosed_parenthesis_when_parsed_then_error_at_end() {
+        let error = Expression::parse("(x0 + 1").unwrap_err();
+        assert_eq!(error.position(), 7);
+    }
+}
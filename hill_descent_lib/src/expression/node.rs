@@ -0,0 +1,57 @@
+use super::function::Function;
+
+/// An infix operator.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum BinaryOp {
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+    Power,
+}
+
+/// A node of a parsed expression's syntax tree.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Node {
+    Number(f64),
+    Variable(usize),
+    Negate(Box<Node>),
+    Binary(BinaryOp, Box<Node>, Box<Node>),
+    Call(Function, Vec<Node>),
+}
+
+impl Node {
+    /// Evaluates the subtree with the variables bound to `params`.
+    pub(crate) fn evaluate(&self, params: &[f64]) -> f64 {
+        match self {
+            Node::Number(value) => *value,
+            Node::Variable(index) => params[*index],
+            Node::Negate(operand) => -operand.evaluate(params),
+            Node::Binary(op, left, right) => {
+                let (left, right) = (left.evaluate(params), right.evaluate(params));
+                match op {
+                    BinaryOp::Add => left + right,
+                    BinaryOp::Subtract => left - right,
+                    BinaryOp::Multiply => left * right,
+                    BinaryOp::Divide => left / right,
+                    BinaryOp::Power => left.powf(right),
+                }
+            }
+            Node::Call(function, args) => {
+                let values: Vec<f64> = args.iter().map(|a| a.evaluate(params)).collect();
+                function.apply(&values)
+            }
+        }
+    }
+
+    /// Returns the highest variable index used in the subtree, if any.
+    pub(crate) fn highest_variable(&self) -> Option<usize> {
+        match self {
+            Node::Number(_) => None,
+            Node::Variable(index) => Some(*index),
+            Node::Negate(operand) => operand.highest_variable(),
+            Node::Binary(_, left, right) => left.highest_variable().max(right.highest_variable()),
+            Node::Call(_, args) => args.iter().filter_map(Node::highest_variable).max(),
+        }
+    }
+}
@@ -0,0 +1,181 @@
+use super::function::Function;
+use super::node::{BinaryOp, Node};
+use super::token::Token;
+use super::{ExpressionError, MAX_DEPTH, MAX_VARIABLES};
+
+/// Recursive descent parser over the token list.
+pub(crate) struct Parser {
+    tokens: Vec<(usize, Token)>,
+    next: usize,
+    depth: usize,
+}
+
+impl Parser {
+    pub(crate) fn new(tokens: Vec<(usize, Token)>) -> Self {
+        Self {
+            tokens,
+            next: 0,
+            depth: 0,
+        }
+    }
+
+    fn peek(&self) -> &Token {
+        &self.tokens[self.next].1
+    }
+
+    fn position(&self) -> usize {
+        self.tokens[self.next].0
+    }
+
+    pub(crate) fn advance(&mut self) -> (usize, Token) {
+        let token = self.tokens[self.next].clone();
+        if token.1 != Token::End {
+            self.next += 1;
+        }
+        token
+    }
+
+    fn expect(&mut self, expected: Token) -> Result<(), ExpressionError> {
+        let (position, token) = self.advance();
+        if token == expected {
+            Ok(())
+        } else {
+            Err(ExpressionError::new(
+                position,
+                format!("Expected {expected} but found {token}"),
+            ))
+        }
+    }
+
+    fn descend(&mut self) -> Result<(), ExpressionError> {
+        self.depth += 1;
+        if self.depth > MAX_DEPTH {
+            return Err(ExpressionError::new(
+                self.position(),
+                format!("Expression is nested more than {MAX_DEPTH} levels deep"),
+            ));
+        }
+        Ok(())
+    }
+
+    pub(crate) fn expression(&mut self) -> Result<Node, ExpressionError> {
+        self.descend()?;
+        let mut node = self.term()?;
+        loop {
+            let op = match self.peek() {
+                Token::Plus => BinaryOp::Add,
+                Token::Minus => BinaryOp::Subtract,
+                _ => break,
+            };
+            self.advance();
+            node = Node::Binary(op, Box::new(node), Box::new(self.term()?));
+        }
+        self.depth -= 1;
+        Ok(node)
+    }
+
+    fn term(&mut self) -> Result<Node, ExpressionError> {
+        let mut node = self.unary()?;
+        loop {
+            let op = match self.peek() {
+                Token::Star => BinaryOp::Multiply,
+                Token::Slash => BinaryOp::Divide,
+                _ => break,
+            };
+            self.advance();
+            node = Node::Binary(op, Box::new(node), Box::new(self.unary()?));
+        }
+        Ok(node)
+    }
+
+    fn unary(&mut self) -> Result<Node, ExpressionError> {
+        if *self.peek() == Token::Minus {
+            self.advance();
+            self.descend()?;
+            let operand = self.unary()?;
+            self.depth -= 1;
+            return Ok(Node::Negate(Box::new(operand)));
+        }
+        self.power()
+    }
+
+    fn power(&mut self) -> Result<Node, ExpressionError> {
+        let base = self.primary()?;
+        if *self.peek() != Token::Caret {
+            return Ok(base);
+        }
+        self.advance();
+        self.descend()?;
+        let exponent = self.unary()?;
+        self.depth -= 1;
+        Ok(Node::Binary(
+            BinaryOp::Power,
+            Box::new(base),
+            Box::new(exponent),
+        ))
+    }
+
+    fn primary(&mut self) -> Result<Node, ExpressionError> {
+        let (position, token) = self.advance();
+        match token {
+            Token::Number(value) => Ok(Node::Number(value)),
+            Token::LeftParen => {
+                let node = self.expression()?;
+                self.expect(Token::RightParen)?;
+                Ok(node)
+            }
+            Token::Identifier(name) => self.identifier(position, &name),
+            token => Err(ExpressionError::new(
+                position,
+                format!("Expected a number, variable or function but found {token}"),
+            )),
+        }
+    }
+
+    fn identifier(&mut self, position: usize, name: &str) -> Result<Node, ExpressionError> {
+        if let Some(function) = Function::from_name(name) {
+            self.expect(Token::LeftParen)?;
+            let mut args = vec![self.expression()?];
+            while *self.peek() == Token::Comma {
+                self.advance();
+                args.push(self.expression()?);
+            }
+            self.expect(Token::RightParen)?;
+            if args.len() != function.arity() {
+                return Err(ExpressionError::new(
+                    position,
+                    format!(
+                        "{name} takes {} argument(s) but was given {}",
+                        function.arity(),
+                        args.len()
+                    ),
+                ));
+            }
+            return Ok(Node::Call(function, args));
+        }
+
+        match name {
+            "pi" => return Ok(Node::Number(std::f64::consts::PI)),
+            "e" => return Ok(Node::Number(std::f64::consts::E)),
+            _ => {}
+        }
+
+        let index = name
+            .strip_prefix('x')
+            .filter(|digits| !digits.is_empty() && digits.chars().all(|c| c.is_ascii_digit()))
+            .and_then(|digits| digits.parse::<usize>().ok())
+            .ok_or_else(|| {
+                ExpressionError::new(
+                    position,
+                    format!("Unknown name '{name}'; variables are x0, x1, ..."),
+                )
+            })?;
+        if index >= MAX_VARIABLES {
+            return Err(ExpressionError::new(
+                position,
+                format!("Variable '{name}' exceeds the limit of {MAX_VARIABLES} variables"),
+            ));
+        }
+        Ok(Node::Variable(index))
+    }
+}
@@ -0,0 +1,144 @@
+use std::{fmt, iter::Peekable, str::CharIndices};
+
+use super::ExpressionError;
+
+/// A lexical token of an expression.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Token {
+    Number(f64),
+    Identifier(String),
+    Plus,
+    Minus,
+    Star,
+    Slash,
+    Caret,
+    LeftParen,
+    RightParen,
+    Comma,
+    End,
+}
+
+impl fmt::Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Token::Number(value) => write!(f, "number {value}"),
+            Token::Identifier(name) => write!(f, "'{name}'"),
+            Token::Plus => write!(f, "'+'"),
+            Token::Minus => write!(f, "'-'"),
+            Token::Star => write!(f, "'*'"),
+            Token::Slash => write!(f, "'/'"),
+            Token::Caret => write!(f, "'^'"),
+            Token::LeftParen => write!(f, "'('"),
+            Token::RightParen => write!(f, "')'"),
+            Token::Comma => write!(f, "','"),
+            Token::End => write!(f, "end of expression"),
+        }
+    }
+}
+
+/// Splits `source` into tokens paired with their byte offsets.
+pub(crate) fn tokenize(source: &str) -> Result<Vec<(usize, Token)>, ExpressionError> {
+    let mut tokens = Vec::new();
+    let mut chars: Peekable<CharIndices> = source.char_indices().peekable();
+
+    while let Some(&(start, c)) = chars.peek() {
+        let token = match c {
+            c if c.is_whitespace() => {
+                chars.next();
+                continue;
+            }
+            '0'..='9' | '.' => {
+                let mut end = start;
+                while let Some(&(i, c)) = chars.peek() {
+                    let exponent_sign = matches!(c, '+' | '-')
+                        && matches!(source[..i].chars().last(), Some('e' | 'E'));
+                    if c.is_ascii_digit() || matches!(c, '.' | 'e' | 'E') || exponent_sign {
+                        end = i + c.len_utf8();
+                        chars.next();
+                    } else {
+                        break;
+                    }
+                }
+                let text = &source[start..end];
+                let value = text
+                    .parse::<f64>()
+                    .map_err(|_| ExpressionError::new(start, format!("Invalid number '{text}'")))?;
+                Token::Number(value)
+            }
+            c if c.is_ascii_alphabetic() || c == '_' => {
+                let mut end = start;
+                while let Some(&(i, c)) = chars.peek() {
+                    if c.is_ascii_alphanumeric() || c == '_' {
+                        end = i + c.len_utf8();
+                        chars.next();
+                    } else {
+                        break;
+                    }
+                }
+                Token::Identifier(source[start..end].to_string())
+            }
+            _ => {
+                chars.next();
+                match c {
+                    '+' => Token::Plus,
+                    '-' => Token::Minus,
+                    '*' => Token::Star,
+                    '/' => Token::Slash,
+                    '^' => Token::Caret,
+                    '(' => Token::LeftParen,
+                    ')' => Token::RightParen,
+                    ',' => Token::Comma,
+                    _ => {
+                        return Err(ExpressionError::new(
+                            start,
+                            format!("Unexpected character '{c}'"),
+                        ));
+                    }
+                }
+            }
+        };
+        tokens.push((start, token));
+    }
+
+    tokens.push((source.len(), Token::End));
+    Ok(tokens)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn given_numbers_with_exponents_when_tokenized_then_single_number_tokens() {
+        let tokens = tokenize("1.5e-3+2E2").unwrap();
+        assert_eq!(
+            tokens,
+            vec![
+                (0, Token::Number(1.5e-3)),
+                (6, Token::Plus),
+                (7, Token::Number(200.0)),
+                (10, Token::End),
+            ]
+        );
+    }
+
+    #[test]
+    fn given_identifiers_and_punctuation_when_tokenized_then_positions_recorded() {
+        let tokens = tokenize(" max(x0, x_1)").unwrap();
+        assert_eq!(tokens[0], (1, Token::Identifier("max".to_string())));
+        assert_eq!(tokens[1], (4, Token::LeftParen));
+        assert_eq!(tokens[3], (7, Token::Comma));
+        assert_eq!(tokens[4], (9, Token::Identifier("x_1".to_string())));
+    }
+
+    #[test]
+    fn given_unexpected_character_when_tokenized_then_error_at_character() {
+        let error = tokenize("x0 # 2").unwrap_err();
+        assert_eq!(error.position(), 3);
+    }
+
+    #[test]
+    fn given_malformed_number_when_tokenized_then_error() {
+        assert!(tokenize("1.2.3").is_err());
+    }
+}
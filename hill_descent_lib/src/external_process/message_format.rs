@@ -0,0 +1,112 @@
+use serde::Serialize;
+
+/// How requests and replies are encoded on an external process's stdin and stdout.
+///
+/// Every request is one line and every reply must be one line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MessageFormat {
+    /// Requests are JSON objects, `{"params":[1.5,-2.0],"inputs":[]}`, and replies are a
+    /// JSON array of outputs, `[0.25]`, or a single JSON number.
+    #[default]
+    Json,
+    /// Requests are the parameters separated by spaces, followed by `;` and the inputs
+    /// when there are any (`1.5 -2 ; 0.1 0.2`). Replies are the outputs separated by spaces
+    /// or commas.
+    Lines,
+}
+
+#[derive(Serialize)]
+struct JsonRequest<'a> {
+    params: &'a [f64],
+    inputs: &'a [f64],
+}
+
+impl MessageFormat {
+    /// Encodes one request, without the trailing newline.
+    pub(crate) fn encode(self, params: &[f64], inputs: &[f64]) -> String {
+        match self {
+            MessageFormat::Json => serde_json::to_string(&JsonRequest { params, inputs })
+                .expect("finite parameters always serialise"),
+            MessageFormat::Lines => {
+                let join = |values: &[f64]| {
+                    values
+                        .iter()
+                        .map(f64::to_string)
+                        .collect::<Vec<_>>()
+                        .join(" ")
+                };
+                if inputs.is_empty() {
+                    join(params)
+                } else {
+                    format!("{} ; {}", join(params), join(inputs))
+                }
+            }
+        }
+    }
+
+    /// Decodes one reply, returning `None` if it is malformed or holds no outputs.
+    pub(crate) fn decode(self, reply: &str) -> Option<Vec<f64>> {
+        let outputs = match self {
+            MessageFormat::Json => match serde_json::from_str::<serde_json::Value>(reply).ok()? {
+                serde_json::Value::Number(n) => vec![n.as_f64()?],
+                serde_json::Value::Array(values) => values
+                    .iter()
+                    .map(serde_json::Value::as_f64)
+                    .collect::<Option<Vec<_>>>()?,
+                _ => return None,
+            },
+            MessageFormat::Lines => reply
+                .split(|c: char| c.is_whitespace() || c == ',')
+                .filter(|s| !s.is_empty())
+                .map(|s| s.parse::<f64>().ok())
+                .collect::<Option<Vec<_>>>()?,
+        };
+        (!outputs.is_empty()).then_some(outputs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn given_json_format_when_encode_then_object_with_params_and_inputs() {
+        assert_eq!(
+            MessageFormat::Json.encode(&[1.5, -2.0], &[3.0]),
+            r#"{"params":[1.5,-2.0],"inputs":[3.0]}"#
+        );
+    }
+
+    #[test]
+    fn given_lines_format_when_encode_then_space_separated_with_inputs_after_semicolon() {
+        assert_eq!(MessageFormat::Lines.encode(&[1.5, -2.0], &[]), "1.5 -2");
+        assert_eq!(
+            MessageFormat::Lines.encode(&[1.5], &[0.25, 4.0]),
+            "1.5 ; 0.25 4"
+        );
+    }
+
+    #[test]
+    fn given_json_reply_when_decode_then_array_or_number_accepted() {
+        assert_eq!(MessageFormat::Json.decode("[0.5, 2]"), Some(vec![0.5, 2.0]));
+        assert_eq!(MessageFormat::Json.decode(" 3.25 "), Some(vec![3.25]));
+    }
+
+    #[test]
+    fn given_malformed_json_reply_when_decode_then_none() {
+        assert_eq!(MessageFormat::Json.decode("[1, \"x\"]"), None);
+        assert_eq!(MessageFormat::Json.decode("{\"a\": 1}"), None);
+        assert_eq!(MessageFormat::Json.decode("[]"), None);
+        assert_eq!(MessageFormat::Json.decode("oops"), None);
+    }
+
+    #[test]
+    fn given_lines_reply_when_decode_then_spaces_and_commas_separate_outputs() {
+        assert_eq!(
+            MessageFormat::Lines.decode("1.5, 2\t-3e2"),
+            Some(vec![1.5, 2.0, -300.0])
+        );
+        assert_eq!(MessageFormat::Lines.decode("1.5 abc"), None);
+        assert_eq!(MessageFormat::Lines.decode("   "), None);
+    }
+}
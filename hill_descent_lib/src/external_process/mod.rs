@@ -0,0 +1,352 @@
+//! Objective functions evaluated by an external program (feature: `external-process`).
+//!
+//! [`ExternalProcessFunction`] lets a simulator written in any language act as a
+//! [`WorldFunction`]. The program is started once per worker and then answers one request
+//! per line on stdin with one reply per line on stdout, in a [`MessageFormat`], until its
+//! stdin is closed.
+
+mod message_format;
+mod worker;
+mod worker_pool;
+
+use std::{
+    ffi::OsString,
+    io,
+    sync::atomic::{AtomicUsize, Ordering},
+    time::Duration,
+};
+
+pub use message_format::MessageFormat;
+
+use crate::WorldFunction;
+use worker::WorkerFailure;
+use worker_pool::WorkerPool;
+
+/// A [`WorldFunction`] that sends each phenotype to a pool of external processes.
+///
+/// Regions are evaluated in parallel, so by default the pool may grow to one process per
+/// Rayon thread. Each process handles one request at a time.
+///
+/// A request that times out, a process that crashes, and a reply that is malformed, holds
+/// a non-finite value or lies below the [function floor](Self::with_floor) all produce the
+/// [failure output](Self::with_failure_output) instead, so a misbehaving simulator makes the
+/// organism uncompetitive rather than stopping the run. Timed-out and crashed processes are
+/// killed and restarted on a later request.
+///
+/// # Example
+///
+/// A Python simulator that reads `{"params": [...], "inputs": [...]}` lines and prints one
+/// JSON array of outputs per line:
+///
+/// ```no_run
+/// use std::time::Duration;
+///
+/// use hill_descent_lib::external_process::ExternalProcessFunction;
+/// use hill_descent_lib::{GlobalConstants, TrainingData, setup_world};
+///
+/// let function = ExternalProcessFunction::new("python3", ["simulator.py"])
+///     .expect("simulator should start")
+///     .with_timeout(Duration::from_secs(5))
+///     .with_workers(8);
+///
+/// let bounds = vec![-10.0..=10.0; 4];
+/// let mut world = setup_world(&bounds, GlobalConstants::new(200, 10), Box::new(function));
+/// world.training_run(TrainingData::None { floor_value: 0.0 });
+/// ```
+#[derive(Debug)]
+pub struct ExternalProcessFunction {
+    pool: WorkerPool,
+    format: MessageFormat,
+    timeout: Duration,
+    failure_output: f64,
+    outputs: usize,
+    floor: f64,
+    failures: AtomicUsize,
+}
+
+impl ExternalProcessFunction {
+    /// How long a reply may take unless changed with [`with_timeout`](Self::with_timeout).
+    pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(30);
+
+    /// Output reported for failed evaluations unless changed with
+    /// [`with_failure_output`](Self::with_failure_output). Squaring it must not overflow.
+    pub const DEFAULT_FAILURE_OUTPUT: f64 = 1e150;
+
+    /// Starts `program` with `args` and returns a function backed by it.
+    ///
+    /// One process is started immediately so that a missing or unrunnable program is
+    /// reported here; further processes start as parallel evaluations need them.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error from starting the first process.
+    pub fn new<I, S>(program: impl Into<OsString>, args: I) -> io::Result<Self>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<OsString>,
+    {
+        let args = args.into_iter().map(Into::into).collect();
+        Ok(Self {
+            pool: WorkerPool::new(program.into(), args, rayon::current_num_threads())?,
+            format: MessageFormat::default(),
+            timeout: Self::DEFAULT_TIMEOUT,
+            failure_output: Self::DEFAULT_FAILURE_OUTPUT,
+            outputs: 1,
+            floor: 0.0,
+            failures: AtomicUsize::new(0),
+        })
+    }
+
+    /// Sets how requests and replies are encoded.
+    pub fn with_format(mut self, format: MessageFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Sets how long to wait for each reply before killing the process.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `timeout` is zero.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        assert!(!timeout.is_zero(), "timeout must be greater than zero");
+        self.timeout = timeout;
+        self
+    }
+
+    /// Sets the output value reported for every output of a failed evaluation. Values
+    /// below the function floor are raised to it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `failure_output` is not finite.
+    pub fn with_failure_output(mut self, failure_output: f64) -> Self {
+        assert!(
+            failure_output.is_finite(),
+            "failure_output must be finite, got {failure_output}"
+        );
+        self.failure_output = failure_output;
+        self
+    }
+
+    /// Sets how many outputs the program returns, which is 1 for objective functions.
+    /// Replies with a different number count as failures, and failed evaluations report
+    /// this many failure outputs.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `outputs` is zero.
+    pub fn with_outputs(mut self, outputs: usize) -> Self {
+        assert!(outputs > 0, "outputs must be greater than zero");
+        self.outputs = outputs;
+        self
+    }
+
+    /// Sets the lowest value the program can return, reported as the
+    /// [`function_floor`](WorldFunction::function_floor). Defaults to 0.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `floor` is not finite.
+    pub fn with_floor(mut self, floor: f64) -> Self {
+        assert!(floor.is_finite(), "floor must be finite, got {floor}");
+        self.floor = floor;
+        self
+    }
+
+    /// Sets the maximum number of processes run at once. Defaults to the number of Rayon
+    /// threads.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `workers` is zero.
+    pub fn with_workers(mut self, workers: usize) -> Self {
+        assert!(workers > 0, "workers must be greater than zero");
+        self.pool.set_size(workers);
+        self
+    }
+
+    /// Returns how requests and replies are encoded.
+    pub fn format(&self) -> MessageFormat {
+        self.format
+    }
+
+    /// Returns how long each reply may take.
+    pub fn timeout(&self) -> Duration {
+        self.timeout
+    }
+
+    /// Returns the maximum number of processes run at once.
+    pub fn workers(&self) -> usize {
+        self.pool.size()
+    }
+
+    /// Returns the number of evaluations that failed and reported the failure output.
+    pub fn failure_count(&self) -> usize {
+        self.failures.load(Ordering::Relaxed)
+    }
+
+    fn evaluate(&self, params: &[f64], inputs: &[f64]) -> Result<Vec<f64>, String> {
+        let request = self.format.encode(params, inputs);
+        let reply = self
+            .pool
+            .with_worker(|worker| worker.exchange(&request, self.timeout))
+            .map_err(|failure: WorkerFailure| failure.to_string())?;
+        let outputs = self
+            .format
+            .decode(&reply)
+            .ok_or_else(|| format!("malformed reply {reply:?}"))?;
+        if outputs.len() != self.outputs {
+            return Err(format!(
+                "expected {} outputs, got {}",
+                self.outputs,
+                outputs.len()
+            ));
+        }
+        if let Some(bad) = outputs
+            .iter()
+            .find(|&&output| !output.is_finite() || output < self.floor)
+        {
+            return Err(format!(
+                "output {bad} is not finite or is below the floor {}",
+                self.floor
+            ));
+        }
+        Ok(outputs)
+    }
+}
+
+impl WorldFunction for ExternalProcessFunction {
+    fn run(&self, phenotype_expressed_values: &[f64], inputs: &[f64]) -> Vec<f64> {
+        match self.evaluate(phenotype_expressed_values, inputs) {
+            Ok(outputs) => outputs,
+            Err(_reason) => {
+                crate::warn!("external process evaluation failed: {}", _reason);
+                self.failures.fetch_add(1, Ordering::Relaxed);
+                vec![self.failure_output.max(self.floor); self.outputs]
+            }
+        }
+    }
+
+    fn function_floor(&self) -> f64 {
+        self.floor
+    }
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use crate::{GlobalConstants, TrainingData, setup_world};
+
+    fn sh(script: &str) -> ExternalProcessFunction {
+        ExternalProcessFunction::new("sh", ["-c", script]).unwrap()
+    }
+
+    #[test]
+    fn given_missing_program_when_new_then_error() {
+        assert!(ExternalProcessFunction::new("no-such-program-hill-descent", [""; 0]).is_err());
+    }
+
+    #[test]
+    fn given_json_replies_when_run_then_outputs_returned() {
+        let function = sh(r#"while read -r line; do echo "[2.5]"; done"#);
+
+        assert_eq!(function.run(&[1.0, 2.0], &[]), vec![2.5]);
+        assert_eq!(function.failure_count(), 0);
+    }
+
+    #[test]
+    fn given_lines_format_when_run_then_params_round_trip() {
+        let function = sh("cat").with_format(MessageFormat::Lines).with_outputs(2);
+
+        assert_eq!(function.run(&[1.5, 0.25], &[]), vec![1.5, 0.25]);
+    }
+
+    #[test]
+    fn given_slow_process_when_run_then_failure_output_after_timeout() {
+        let function = sh("sleep 10")
+            .with_timeout(Duration::from_millis(100))
+            .with_failure_output(1e6);
+
+        assert_eq!(function.run(&[1.0], &[]), vec![1e6]);
+        assert_eq!(function.failure_count(), 1);
+    }
+
+    #[test]
+    fn given_crashing_process_when_run_then_failure_output_and_process_restarted() {
+        // Each process answers once and then exits
+        let function = sh(r#"read -r line; echo "[1]"; exit 1"#).with_workers(1);
+
+        assert_eq!(function.run(&[0.0], &[]), vec![1.0]);
+        assert_eq!(
+            function.run(&[0.0], &[]),
+            vec![ExternalProcessFunction::DEFAULT_FAILURE_OUTPUT]
+        );
+        assert_eq!(function.run(&[0.0], &[]), vec![1.0]);
+        assert_eq!(function.failure_count(), 1);
+    }
+
+    #[test]
+    fn given_invalid_replies_when_run_then_failure_output_with_configured_length() {
+        let function = sh(r#"while read -r line; do echo "[-1, 2]"; done"#).with_outputs(2);
+
+        assert_eq!(
+            function.run(&[0.0], &[]),
+            vec![ExternalProcessFunction::DEFAULT_FAILURE_OUTPUT; 2]
+        );
+    }
+
+    #[test]
+    fn given_empty_reply_when_run_then_failure_output() {
+        let function = sh(r#"while read -r line; do echo "[]"; done"#);
+
+        assert_eq!(
+            function.run(&[0.0], &[]),
+            vec![ExternalProcessFunction::DEFAULT_FAILURE_OUTPUT; 1]
+        );
+        assert_eq!(function.failure_count(), 1);
+    }
+
+    #[test]
+    fn given_more_outputs_than_configured_when_run_then_failure_output() {
+        let function = sh(r#"while read -r line; do echo "[1, 2]"; done"#).with_outputs(1);
+
+        assert_eq!(
+            function.run(&[0.0], &[]),
+            vec![ExternalProcessFunction::DEFAULT_FAILURE_OUTPUT; 1]
+        );
+        assert_eq!(function.failure_count(), 1);
+        assert_eq!(
+            function.evaluate(&[0.0], &[]).unwrap_err(),
+            "expected 1 outputs, got 2"
+        );
+    }
+
+    #[test]
+    fn given_floor_when_set_then_reported_and_lower_outputs_allowed() {
+        let function = sh(r#"while read -r line; do echo "-1.5"; done"#).with_floor(-2.0);
+
+        assert_eq!(function.function_floor(), -2.0);
+        assert_eq!(function.run(&[0.0], &[]), vec![-1.5]);
+    }
+
+    #[test]
+    #[should_panic(expected = "workers must be greater than zero")]
+    fn given_zero_workers_when_set_then_panics() {
+        sh("cat").with_workers(0);
+    }
+
+    #[test]
+    fn given_world_when_trained_with_external_function_then_runs_in_parallel() {
+        let function = sh(r#"while read -r line; do echo "[1]"; done"#).with_workers(4);
+        let mut world = setup_world(
+            &[-1.0..=1.0, -1.0..=1.0],
+            GlobalConstants::new(40, 4),
+            Box::new(function),
+        );
+
+        world.training_run(TrainingData::None { floor_value: 0.0 });
+
+        assert_eq!(world.get_best_score(), 1.0);
+    }
+}
@@ -0,0 +1,142 @@
+use std::{
+    ffi::OsString,
+    fmt,
+    io::{self, BufRead, BufReader, Write},
+    process::{Child, ChildStdin, Command, Stdio},
+    sync::mpsc::{self, Receiver, RecvTimeoutError},
+    thread,
+    time::Duration,
+};
+
+/// Why a worker could not answer a request. The worker is unusable afterwards.
+#[derive(Debug)]
+pub(crate) enum WorkerFailure {
+    /// The process could not be started.
+    Spawn(io::Error),
+    /// Writing the request failed, usually because the process has exited.
+    Write(io::Error),
+    /// The process closed its stdout without replying.
+    Exited,
+    /// No reply arrived in time.
+    TimedOut(Duration),
+}
+
+impl fmt::Display for WorkerFailure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WorkerFailure::Spawn(e) => write!(f, "could not start process: {e}"),
+            WorkerFailure::Write(e) => write!(f, "could not send request: {e}"),
+            WorkerFailure::Exited => write!(f, "process exited without replying"),
+            WorkerFailure::TimedOut(timeout) => write!(f, "no reply within {timeout:?}"),
+        }
+    }
+}
+
+/// One running instance of the external command.
+///
+/// A reader thread forwards the process's stdout line by line so replies can be awaited
+/// with a timeout. Dropping the worker kills the process.
+#[derive(Debug)]
+pub(crate) struct Worker {
+    child: Child,
+    stdin: ChildStdin,
+    replies: Receiver<String>,
+}
+
+impl Worker {
+    /// Starts `program` with `args`, its stdin and stdout piped and its stderr inherited.
+    pub(crate) fn spawn(program: &OsString, args: &[OsString]) -> io::Result<Self> {
+        let mut child = Command::new(program)
+            .args(args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::inherit())
+            .spawn()?;
+        let stdin = child.stdin.take().expect("stdin is piped");
+        let stdout = child.stdout.take().expect("stdout is piped");
+
+        let (sender, replies) = mpsc::channel();
+        thread::spawn(move || {
+            for line in BufReader::new(stdout).lines() {
+                let Ok(line) = line else { break };
+                if sender.send(line).is_err() {
+                    break;
+                }
+            }
+        });
+
+        Ok(Self {
+            child,
+            stdin,
+            replies,
+        })
+    }
+
+    /// Sends `request` as one line and waits up to `timeout` for the reply line.
+    pub(crate) fn exchange(
+        &mut self,
+        request: &str,
+        timeout: Duration,
+    ) -> Result<String, WorkerFailure> {
+        writeln!(self.stdin, "{request}")
+            .and_then(|()| self.stdin.flush())
+            .map_err(WorkerFailure::Write)?;
+        match self.replies.recv_timeout(timeout) {
+            Ok(reply) => Ok(reply),
+            Err(RecvTimeoutError::Timeout) => Err(WorkerFailure::TimedOut(timeout)),
+            Err(RecvTimeoutError::Disconnected) => Err(WorkerFailure::Exited),
+        }
+    }
+}
+
+impl Drop for Worker {
+    fn drop(&mut self) {
+        // Killing an already exited process fails harmlessly
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+
+    fn sh(script: &str) -> Worker {
+        Worker::spawn(&"sh".into(), &["-c".into(), script.into()]).unwrap()
+    }
+
+    #[test]
+    fn given_echoing_process_when_exchange_then_reply_is_returned() {
+        let mut worker = sh("cat");
+
+        let reply = worker.exchange("1 2 3", Duration::from_secs(5)).unwrap();
+
+        assert_eq!(reply, "1 2 3");
+        assert_eq!(worker.exchange("4", Duration::from_secs(5)).unwrap(), "4");
+    }
+
+    #[test]
+    fn given_silent_process_when_exchange_then_times_out() {
+        let mut worker = sh("sleep 10");
+
+        let failure = worker
+            .exchange("1", Duration::from_millis(100))
+            .unwrap_err();
+
+        assert!(matches!(failure, WorkerFailure::TimedOut(_)));
+    }
+
+    #[test]
+    fn given_process_that_exits_when_exchange_then_reports_exit() {
+        let mut worker = sh("read line; exit 3");
+
+        let failure = worker.exchange("1", Duration::from_secs(5)).unwrap_err();
+
+        assert!(matches!(failure, WorkerFailure::Exited));
+    }
+
+    #[test]
+    fn given_missing_program_when_spawn_then_error() {
+        assert!(Worker::spawn(&"no-such-program-hill-descent".into(), &[]).is_err());
+    }
+}
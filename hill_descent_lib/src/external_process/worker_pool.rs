@@ -0,0 +1,176 @@
+use std::{
+    ffi::OsString,
+    io,
+    sync::{Condvar, Mutex},
+};
+
+use super::worker::{Worker, WorkerFailure};
+
+#[derive(Debug)]
+struct PoolState {
+    idle: Vec<Worker>,
+    /// Workers either idle or lent out.
+    live: usize,
+    size: usize,
+}
+
+/// Up to `size` instances of the external command, shared between evaluating threads.
+///
+/// Workers are started on demand, reused between requests and replaced after a failure.
+#[derive(Debug)]
+pub(crate) struct WorkerPool {
+    program: OsString,
+    args: Vec<OsString>,
+    state: Mutex<PoolState>,
+    returned: Condvar,
+}
+
+impl WorkerPool {
+    /// Creates a pool of `size` workers, starting the first straight away so that a
+    /// misconfigured command is reported immediately.
+    pub(crate) fn new(program: OsString, args: Vec<OsString>, size: usize) -> io::Result<Self> {
+        let first = Worker::spawn(&program, &args)?;
+        Ok(Self {
+            program,
+            args,
+            state: Mutex::new(PoolState {
+                idle: vec![first],
+                live: 1,
+                size,
+            }),
+            returned: Condvar::new(),
+        })
+    }
+
+    /// Changes the maximum number of workers. Surplus idle workers are stopped.
+    pub(crate) fn set_size(&mut self, size: usize) {
+        let state = self.state.get_mut().expect("worker pool lock poisoned");
+        state.size = size;
+        while state.live > size && state.idle.pop().is_some() {
+            state.live -= 1;
+        }
+    }
+
+    /// Returns the maximum number of workers.
+    pub(crate) fn size(&self) -> usize {
+        self.state.lock().expect("worker pool lock poisoned").size
+    }
+
+    /// Runs `request` on an idle worker, waiting for one if all are busy. A worker whose
+    /// request fails is stopped and replaced on a later request.
+    pub(crate) fn with_worker<T>(
+        &self,
+        request: impl FnOnce(&mut Worker) -> Result<T, WorkerFailure>,
+    ) -> Result<T, WorkerFailure> {
+        let mut worker = self.acquire()?;
+        let result = request(&mut worker);
+
+        let mut state = self.state.lock().expect("worker pool lock poisoned");
+        let surplus = state.live > state.size;
+        let discarded = if result.is_ok() && !surplus {
+            state.idle.push(worker);
+            None
+        } else {
+            state.live -= 1;
+            Some(worker)
+        };
+        drop(state);
+        self.returned.notify_one();
+        // Stopping the process happens outside the lock
+        drop(discarded);
+        result
+    }
+
+    fn acquire(&self) -> Result<Worker, WorkerFailure> {
+        let mut state = self.state.lock().expect("worker pool lock poisoned");
+        loop {
+            if let Some(worker) = state.idle.pop() {
+                return Ok(worker);
+            }
+            if state.live < state.size {
+                state.live += 1;
+                drop(state);
+                return Worker::spawn(&self.program, &self.args).map_err(|e| {
+                    self.state.lock().expect("worker pool lock poisoned").live -= 1;
+                    self.returned.notify_one();
+                    WorkerFailure::Spawn(e)
+                });
+            }
+            state = self
+                .returned
+                .wait(state)
+                .expect("worker pool lock poisoned");
+        }
+    }
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    fn cat_pool(size: usize) -> WorkerPool {
+        WorkerPool::new("cat".into(), Vec::new(), size).unwrap()
+    }
+
+    fn live(pool: &WorkerPool) -> usize {
+        pool.state.lock().unwrap().live
+    }
+
+    #[test]
+    fn given_new_pool_when_created_then_one_worker_started() {
+        let pool = cat_pool(4);
+
+        assert_eq!(live(&pool), 1);
+        assert_eq!(pool.size(), 4);
+    }
+
+    #[test]
+    fn given_concurrent_requests_when_run_then_workers_never_exceed_size() {
+        let pool = cat_pool(3);
+
+        std::thread::scope(|scope| {
+            for i in 0..12 {
+                let pool = &pool;
+                scope.spawn(move || {
+                    let request = i.to_string();
+                    let reply = pool
+                        .with_worker(|w| w.exchange(&request, Duration::from_secs(5)))
+                        .unwrap();
+                    assert_eq!(reply, request);
+                    assert!(live(pool) <= 3);
+                });
+            }
+        });
+
+        assert!(live(&pool) <= 3);
+    }
+
+    #[test]
+    fn given_failed_request_when_returned_then_worker_replaced_on_next_request() {
+        let pool = cat_pool(1);
+
+        let failed: Result<(), _> = pool.with_worker(|_| Err(WorkerFailure::Exited));
+        assert!(failed.is_err());
+        assert_eq!(live(&pool), 0);
+
+        let reply = pool
+            .with_worker(|w| w.exchange("7", Duration::from_secs(5)))
+            .unwrap();
+        assert_eq!(reply, "7");
+        assert_eq!(live(&pool), 1);
+    }
+
+    #[test]
+    fn given_smaller_size_when_set_then_idle_workers_stopped() {
+        let mut pool = cat_pool(2);
+        // A nested request needs a second worker while the first is lent out
+        pool.with_worker(|_| pool.with_worker(|_| Ok(()))).unwrap();
+        assert_eq!(live(&pool), 2);
+
+        pool.set_size(1);
+
+        assert_eq!(live(&pool), 1);
+    }
+}
@@ -1,4 +1,5 @@
 pub mod new_random_gamete;
+pub mod recombine_values;
 pub mod reproduce;
 
 use crate::locus::Locus;
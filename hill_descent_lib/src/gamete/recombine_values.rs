@@ -0,0 +1,109 @@
+use super::Gamete;
+use crate::NUM_SYSTEM_PARAMETERS;
+use crate::world::crossover_operator::CrossoverOperator;
+use rand::Rng;
+
+impl Gamete {
+    /// Recombines the problem-parameter locus values of two gametes in place.
+    ///
+    /// For each locus after the system parameters, the two values are replaced by the children
+    /// produced by `operator`. Locus adjustments and apply flags are left untouched, as are the
+    /// system-parameter loci. Values are set without clamping, matching unbounded mutation of
+    /// problem parameters.
+    ///
+    /// Panics if the gametes differ in length.
+    pub fn recombine_values<R: Rng>(
+        gamete1: &mut Gamete,
+        gamete2: &mut Gamete,
+        operator: &CrossoverOperator,
+        rng: &mut R,
+    ) {
+        assert_eq!(
+            gamete1.len(),
+            gamete2.len(),
+            "Gametes must have same number of loci"
+        );
+        let problem_loci = gamete1
+            .loci
+            .iter_mut()
+            .zip(gamete2.loci.iter_mut())
+            .skip(NUM_SYSTEM_PARAMETERS);
+        for (l1, l2) in problem_loci {
+            let (c1, c2) = operator.recombine(l1.value.get(), l2.value.get(), rng);
+            l1.value.set_unbound(c1);
+            l2.value.set_unbound(c2);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::NUM_SYSTEM_PARAMETERS;
+    use crate::gamete::Gamete;
+    use crate::phenotype::tests::create_test_gamete;
+    use crate::world::crossover_operator::CrossoverOperator;
+    use rand::SeedableRng;
+    use rand::rngs::SmallRng;
+
+    fn values(g: &Gamete) -> Vec<f64> {
+        g.loci().iter().map(|l| l.value().get()).collect()
+    }
+
+    #[test]
+    fn given_arithmetic_when_recombine_values_then_system_loci_unchanged() {
+        let mut g1 = create_test_gamete(&[0.1; NUM_SYSTEM_PARAMETERS + 2]);
+        let mut g2 = create_test_gamete(&[0.9; NUM_SYSTEM_PARAMETERS + 2]);
+        let mut rng = SmallRng::seed_from_u64(0);
+
+        Gamete::recombine_values(&mut g1, &mut g2, &CrossoverOperator::Arithmetic, &mut rng);
+
+        assert_eq!(
+            values(&g1)[..NUM_SYSTEM_PARAMETERS],
+            [0.1; NUM_SYSTEM_PARAMETERS]
+        );
+        assert_eq!(
+            values(&g2)[..NUM_SYSTEM_PARAMETERS],
+            [0.9; NUM_SYSTEM_PARAMETERS]
+        );
+    }
+
+    #[test]
+    fn given_arithmetic_when_recombine_values_then_problem_loci_between_parents() {
+        let mut v1 = vec![0.5; NUM_SYSTEM_PARAMETERS];
+        v1.extend([-10.0, 0.0]);
+        let mut v2 = vec![0.5; NUM_SYSTEM_PARAMETERS];
+        v2.extend([10.0, 4.0]);
+        let mut g1 = create_test_gamete(&v1);
+        let mut g2 = create_test_gamete(&v2);
+        let mut rng = SmallRng::seed_from_u64(4);
+
+        Gamete::recombine_values(&mut g1, &mut g2, &CrossoverOperator::Arithmetic, &mut rng);
+
+        let (r1, r2) = (values(&g1), values(&g2));
+        let i = NUM_SYSTEM_PARAMETERS;
+        assert!((-10.0..=10.0).contains(&r1[i]) && (-10.0..=10.0).contains(&r2[i]));
+        assert!((0.0..=4.0).contains(&r1[i + 1]) && (0.0..=4.0).contains(&r2[i + 1]));
+        assert!((r1[i] + r2[i]).abs() < 1e-9);
+    }
+
+    #[test]
+    fn given_k_point_when_recombine_values_then_gametes_unchanged() {
+        let mut g1 = create_test_gamete(&[1.0; NUM_SYSTEM_PARAMETERS + 1]);
+        let mut g2 = create_test_gamete(&[2.0; NUM_SYSTEM_PARAMETERS + 1]);
+        let (o1, o2) = (g1.clone(), g2.clone());
+        let mut rng = SmallRng::seed_from_u64(0);
+
+        Gamete::recombine_values(&mut g1, &mut g2, &CrossoverOperator::KPoint, &mut rng);
+
+        assert_eq!((g1, g2), (o1, o2));
+    }
+
+    #[test]
+    #[should_panic(expected = "Gametes must have same number of loci")]
+    fn given_mismatched_lengths_when_recombine_values_then_panics() {
+        let mut g1 = create_test_gamete(&[1.0; NUM_SYSTEM_PARAMETERS]);
+        let mut g2 = create_test_gamete(&[1.0; NUM_SYSTEM_PARAMETERS + 1]);
+        let mut rng = SmallRng::seed_from_u64(0);
+        Gamete::recombine_values(&mut g1, &mut g2, &CrossoverOperator::Arithmetic, &mut rng);
+    }
+}
@@ -1,4 +1,7 @@
-use crate::{NUM_SYSTEM_PARAMETERS, parameters::system_parameters::SystemParameters};
+use crate::{
+    NUM_SYSTEM_PARAMETERS, parameters::system_parameters::SystemParameters,
+    world::mutation_operator::MutationOperator,
+};
 
 use super::Gamete;
 use rand::Rng;
@@ -6,6 +9,9 @@ use rand::Rng;
 impl Gamete {
     /// Performs multi-point crossover with `crossovers` points and returns two offspring gametes.
     ///
+    /// Each copied locus is mutated; problem-parameter loci use `mutation_operator` while
+    /// system-parameter loci always use the bounded PDD rules.
+    ///
     /// Panics if gametes differ in length or if `len <= 2 * crossovers`.
     pub fn reproduce<R: Rng>(
         parent1: &Gamete,
@@ -13,6 +19,7 @@ impl Gamete {
         crossovers: usize,
         rng: &mut R,
         sys: &SystemParameters,
+        mutation_operator: &MutationOperator,
     ) -> (Gamete, Gamete) {
         let len = parent1.len();
         assert_eq!(len, parent2.len(), "Gametes must have same number of loci");
@@ -58,8 +65,16 @@ impl Gamete {
                     offspring2.push(parent2.loci()[i].mutate(rng, &dists));
                 } else {
                     // Problem parameters: use unbounded mutation
-                    offspring1.push(parent1.loci()[i].mutate_unbound(rng, &dists));
-                    offspring2.push(parent2.loci()[i].mutate_unbound(rng, &dists));
+                    offspring1.push(parent1.loci()[i].mutate_with_operator(
+                        mutation_operator,
+                        rng,
+                        &dists,
+                    ));
+                    offspring2.push(parent2.loci()[i].mutate_with_operator(
+                        mutation_operator,
+                        rng,
+                        &dists,
+                    ));
                 }
             } else if i < NUM_SYSTEM_PARAMETERS {
                 // System parameters: use bounded mutation
@@ -67,8 +82,16 @@ impl Gamete {
                 offspring2.push(parent1.loci()[i].mutate(rng, &dists));
             } else {
                 // Problem parameters: use unbounded mutation
-                offspring1.push(parent2.loci()[i].mutate_unbound(rng, &dists));
-                offspring2.push(parent1.loci()[i].mutate_unbound(rng, &dists));
+                offspring1.push(parent2.loci()[i].mutate_with_operator(
+                    mutation_operator,
+                    rng,
+                    &dists,
+                ));
+                offspring2.push(parent1.loci()[i].mutate_with_operator(
+                    mutation_operator,
+                    rng,
+                    &dists,
+                ));
             }
         }
         (Gamete::new(offspring1), Gamete::new(offspring2))
@@ -82,6 +105,7 @@ mod tests {
     use crate::locus::locus_adjustment::{DirectionOfTravel, LocusAdjustment};
     use crate::parameters::parameter::Parameter;
     use crate::parameters::system_parameters::SystemParameters;
+    use crate::world::mutation_operator::MutationOperator;
     use rand::SeedableRng;
     use rand::rngs::SmallRng;
 
@@ -102,7 +126,7 @@ mod tests {
         let g2 = create_test_gamete(&[4.0, 5.0, 6.0]);
         let mut rng = SmallRng::seed_from_u64(0);
         let sys = SystemParameters::default();
-        let (o1, o2) = Gamete::reproduce(&g1, &g2, 0, &mut rng, &sys);
+        let (o1, o2) = Gamete::reproduce(&g1, &g2, 0, &mut rng, &sys, &MutationOperator::default());
         assert_eq!(o1, g1);
         assert_eq!(o2, g2);
     }
@@ -114,7 +138,7 @@ mod tests {
         let g2 = create_test_gamete(&[1.0, 2.0]);
         let mut rng = SmallRng::seed_from_u64(0);
         let sys = SystemParameters::default();
-        let _ = Gamete::reproduce(&g1, &g2, 0, &mut rng, &sys);
+        let _ = Gamete::reproduce(&g1, &g2, 0, &mut rng, &sys, &MutationOperator::default());
     }
 
     #[test]
@@ -124,7 +148,7 @@ mod tests {
         let g2 = create_test_gamete(&[4.0, 5.0, 6.0]);
         let mut rng = SmallRng::seed_from_u64(0);
         let sys = SystemParameters::default();
-        let _ = Gamete::reproduce(&g1, &g2, 2, &mut rng, &sys);
+        let _ = Gamete::reproduce(&g1, &g2, 2, &mut rng, &sys, &MutationOperator::default());
     }
 
     #[test]
@@ -164,7 +188,8 @@ mod tests {
 
         let mut rng = SmallRng::seed_from_u64(0);
         let sys = SystemParameters::new(&[0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0]); // No mutations
-        let (offspring1, _) = Gamete::reproduce(&g1, &g2, 0, &mut rng, &sys);
+        let (offspring1, _) =
+            Gamete::reproduce(&g1, &g2, 0, &mut rng, &sys, &MutationOperator::default());
 
         // System parameters (0-6) should be clamped to 2.0
         for i in 0..NUM_SYSTEM_PARAMETERS {
@@ -220,7 +245,8 @@ mod tests {
 
         let mut rng = SmallRng::seed_from_u64(0);
         let sys = SystemParameters::new(&[0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0]); // No mutations
-        let (offspring1, _) = Gamete::reproduce(&g1, &g2, 0, &mut rng, &sys);
+        let (offspring1, _) =
+            Gamete::reproduce(&g1, &g2, 0, &mut rng, &sys, &MutationOperator::default());
 
         // System parameters should remain unchanged (apply_flag = false)
         for i in 0..NUM_SYSTEM_PARAMETERS {
@@ -51,6 +51,10 @@
 //! - Deterministic results via seeded RNG
 //! - Parallel processing with Rayon
 //! - Optional tracing support (feature: `enable-tracing`)
+//! - Optional catalogue of standard test functions (feature: `benchmarks`)
+//! - Optional expression language for objectives given as text (feature: `expression`)
+//! - Optional adapter for objectives evaluated by external programs (feature: `external-process`)
+//! - Optional Parquet and Arrow export of populations for analysis (feature: `parquet`)
 //!
 //! ## Algorithm Overview
 //!
@@ -78,6 +82,12 @@ mod locus;
 mod phenotype;
 
 // Public modules containing public types and traits
+#[cfg(feature = "benchmarks")]
+pub mod benchmarks;
+#[cfg(feature = "expression")]
+pub mod expression;
+#[cfg(feature = "external-process")]
+pub mod external_process;
 pub mod parameters;
 pub mod training_data;
 pub mod world;
@@ -1,6 +1,7 @@
 // src/locus/mod.rs
 pub mod locus_adjustment;
 pub mod mutate; // Declare the new mutate module
+pub mod mutate_with_operator;
 pub mod new_random_locus;
 pub mod new_random_locus_adjustment;
 
@@ -0,0 +1,223 @@
+use super::Locus;
+use crate::{
+    locus::locus_adjustment::{DirectionOfTravel, LocusAdjustment},
+    parameters::system_parameters::MutationDistributions,
+    world::mutation_operator::MutationOperator,
+};
+use rand::Rng;
+use rand::distr::Distribution;
+
+impl Locus {
+    /// Applies mutation to a problem-parameter locus using the given operator, without
+    /// clamping the final value to bounds.
+    ///
+    /// The flag mutations and their probabilities are those of [`Locus::mutate_unbound`];
+    /// only the step-size update (`m5`) and the step itself are delegated to `operator`.
+    /// Both are limited to the span of the value's initial bounds, the parameter's range
+    /// when the world was created. [`MutationOperator::DoublingHalving`] is identical to
+    /// `mutate_unbound`.
+    #[cfg_attr(
+        feature = "enable-tracing",
+        tracing::instrument(level = "trace", skip(self, operator, rng, dists))
+    )]
+    pub fn mutate_with_operator<R: Rng>(
+        &self,
+        operator: &MutationOperator,
+        rng: &mut R,
+        dists: &MutationDistributions,
+    ) -> Self {
+        if *operator == MutationOperator::DoublingHalving {
+            return self.mutate_unbound(rng, dists);
+        }
+        let mut new_adj_val = *self.adjustment.adjustment_value();
+        let mut new_direction = self.adjustment.direction_of_travel();
+        let mut new_double_flag = self.adjustment.doubling_or_halving_flag();
+        let mut new_apply_flag = self.apply_adjustment_flag();
+        let bounds = self.value.bounds();
+        let max_step = bounds.end() - bounds.start();
+        // Direction mutation (m4)
+        if dists.m4.sample(rng) {
+            new_direction = match new_direction {
+                DirectionOfTravel::Add => DirectionOfTravel::Subtract,
+                DirectionOfTravel::Subtract => DirectionOfTravel::Add,
+            };
+            new_double_flag = !new_double_flag;
+        }
+        // Doubling flag mutation (m3)
+        if dists.m3.sample(rng) {
+            new_double_flag = !new_double_flag;
+        }
+        // Step size mutation (m5)
+        if dists.m5.sample(rng) {
+            new_adj_val.set(operator.adapt_step_size(
+                new_adj_val.get(),
+                new_double_flag,
+                max_step,
+                rng,
+            ));
+        }
+        // Rebuild adjustment only if any properties changed to avoid redundant hashing
+        let new_adjustment = if new_adj_val != *self.adjustment.adjustment_value()
+            || new_direction != self.adjustment.direction_of_travel()
+            || new_double_flag != self.adjustment.doubling_or_halving_flag()
+        {
+            LocusAdjustment::new(new_adj_val, new_direction, new_double_flag)
+        } else {
+            self.adjustment.clone()
+        };
+        // Apply flag mutation (m1/m2)
+        if new_apply_flag {
+            if dists.m2.sample(rng) {
+                new_apply_flag = false;
+            }
+        } else if dists.m1.sample(rng) {
+            new_apply_flag = true;
+        }
+        // Take a step drawn from the operator if flag is true (without clamping)
+        let mut new_value = self.value;
+        if new_apply_flag {
+            let sign = match new_adjustment.direction_of_travel() {
+                DirectionOfTravel::Add => 1.0,
+                DirectionOfTravel::Subtract => -1.0,
+            };
+            let step =
+                operator.sample_step(new_adjustment.adjustment_value().get(), sign, max_step, rng);
+            new_value.set_unbound(new_value.get() + step);
+        }
+        Locus::new(new_value, new_adjustment, new_apply_flag)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::locus::Locus;
+    use crate::locus::locus_adjustment::{DirectionOfTravel, LocusAdjustment};
+    use crate::parameters::parameter::Parameter;
+    use crate::parameters::system_parameters::SystemParameters;
+    use crate::world::mutation_operator::MutationOperator;
+    use rand::SeedableRng;
+    use rand::rngs::SmallRng;
+
+    fn create_test_locus(value: f64, adj_value: f64, apply_flag: bool) -> Locus {
+        let adj = LocusAdjustment::new(
+            Parameter::with_bounds(adj_value, 0.0, 10.0),
+            DirectionOfTravel::Add,
+            false,
+        );
+        Locus::new(Parameter::new(value), adj, apply_flag)
+    }
+
+    #[test]
+    fn given_doubling_halving_when_mutate_with_operator_then_matches_mutate_unbound() {
+        let locus = create_test_locus(1.0, 0.5, true);
+        let sys = SystemParameters::new(&[0.3, 0.3, 0.3, 0.3, 0.3, 0.0, 0.0]);
+        let dists = sys.mutation_distributions();
+
+        let mut rng1 = SmallRng::seed_from_u64(9);
+        let mut rng2 = SmallRng::seed_from_u64(9);
+        for _ in 0..50 {
+            assert_eq!(
+                locus.mutate_with_operator(&MutationOperator::DoublingHalving, &mut rng1, &dists),
+                locus.mutate_unbound(&mut rng2, &dists)
+            );
+        }
+    }
+
+    #[test]
+    fn given_all_mutation_probs_zero_when_mutate_with_gaussian_then_locus_is_unchanged() {
+        let locus = create_test_locus(1.0, 0.5, false);
+        let sys = SystemParameters::new(&[0.0; 7]);
+        let mut rng = SmallRng::seed_from_u64(0);
+
+        let mutated = locus.mutate_with_operator(
+            &MutationOperator::Gaussian { learning_rate: 0.5 },
+            &mut rng,
+            &sys.mutation_distributions(),
+        );
+
+        assert_eq!(mutated, locus);
+    }
+
+    #[test]
+    fn given_m5_certain_when_mutate_with_gaussian_then_step_size_changes_continuously() {
+        let locus = create_test_locus(1.0, 0.5, false);
+        let sys = SystemParameters::new(&[0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0]);
+        let mut rng = SmallRng::seed_from_u64(1);
+
+        let mutated = locus.mutate_with_operator(
+            &MutationOperator::Gaussian { learning_rate: 0.5 },
+            &mut rng,
+            &sys.mutation_distributions(),
+        );
+
+        let sigma = mutated.adjustment().adjustment_value().get();
+        assert!(sigma > 0.0 && sigma != 0.5 && sigma != 1.0 && sigma != 0.25);
+        assert_eq!(mutated.value().get(), 1.0);
+    }
+
+    #[test]
+    fn given_apply_flag_set_when_mutate_with_polynomial_then_value_moves_within_sigma() {
+        let locus = create_test_locus(1.0, 0.5, true);
+        let sys = SystemParameters::new(&[0.0; 7]);
+        let mut rng = SmallRng::seed_from_u64(2);
+
+        let mutated = locus.mutate_with_operator(
+            &MutationOperator::Polynomial { eta: 5.0 },
+            &mut rng,
+            &sys.mutation_distributions(),
+        );
+
+        assert!(mutated.apply_adjustment_flag());
+        assert!((mutated.value().get() - 1.0).abs() <= 0.5);
+    }
+
+    #[test]
+    fn given_large_cauchy_steps_when_mutate_with_operator_then_value_is_not_clamped() {
+        let adj = LocusAdjustment::new(
+            Parameter::with_bounds(10.0, 0.0, 10.0),
+            DirectionOfTravel::Add,
+            false,
+        );
+        let locus = Locus::new(Parameter::with_bounds(0.0, -1.0, 1.0), adj, true);
+        let sys = SystemParameters::new(&[0.0; 7]);
+        let dists = sys.mutation_distributions();
+        let mut rng = SmallRng::seed_from_u64(3);
+
+        let escaped = (0..100).any(|_| {
+            let mutated = locus.mutate_with_operator(
+                &MutationOperator::Cauchy { learning_rate: 0.1 },
+                &mut rng,
+                &dists,
+            );
+            mutated.value().get().abs() > 1.0
+        });
+
+        assert!(escaped);
+    }
+
+    #[test]
+    fn given_cauchy_steps_when_mutated_repeatedly_then_each_step_within_initial_range() {
+        let adj = LocusAdjustment::new(
+            Parameter::with_bounds(0.2, 0.0, 0.2),
+            DirectionOfTravel::Add,
+            false,
+        );
+        let mut locus = Locus::new(Parameter::with_bounds(0.0, -1.0, 1.0), adj, true);
+        let sys = SystemParameters::new(&[0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0]);
+        let dists = sys.mutation_distributions();
+        let mut rng = SmallRng::seed_from_u64(4);
+
+        for _ in 0..10_000 {
+            let mutated = locus.mutate_with_operator(
+                &MutationOperator::Cauchy { learning_rate: 1.0 },
+                &mut rng,
+                &dists,
+            );
+
+            assert!((mutated.value().get() - locus.value().get()).abs() <= 2.0);
+            assert!(mutated.adjustment().adjustment_value().get() <= 0.2);
+            locus = mutated;
+        }
+        assert!(locus.value().get().is_finite());
+    }
+}
@@ -1,4 +1,5 @@
 use crate::phenotype::Phenotype;
+use crate::world::reproduction_operators::ReproductionOperators;
 use rand::Rng;
 
 impl Phenotype {
@@ -7,10 +8,14 @@ impl Phenotype {
     /// Each parent undergoes a simulated meiosis (`Gamete::reproduce`) using its own two gametes
     /// and its system parameters. The number of crossovers is determined by its `m3` value.
     /// Two offspring are then formed by combining one meiotic product from each parent.
+    /// Problem-parameter loci are mutated with the operators' mutation operator. If the
+    /// crossover operator blends values, the two gametes forming each offspring have their
+    /// problem-parameter loci recombined first.
     ///
     /// # Arguments
     /// * `parent1`: A reference to the first parent phenotype.
     /// * `parent2`: A reference to the second parent phenotype.
+    /// * `operators`: The crossover and mutation operators to apply.
     /// * `rng`: A mutable reference to a random number generator.
     ///
     /// # Returns
@@ -22,9 +27,10 @@ impl Phenotype {
     ///     * Gametes within a single parent have different lengths.
     ///     * Any gamete length is 0.
     ///     * `gamete_len <= 2 * crossovers` (though `calculate_crossovers` tries to prevent this for `gamete_len > 0`).
-    pub fn sexual_reproduction<R: Rng>(
+    pub(crate) fn sexual_reproduction<R: Rng>(
         parent1: &Phenotype,
         parent2: &Phenotype,
+        operators: &ReproductionOperators,
         rng: &mut R,
     ) -> (Phenotype, Phenotype) {
         let p1_g1 = parent1.gamete1();
@@ -45,12 +51,40 @@ impl Phenotype {
         let sys_params2 = parent2.system_parameters();
 
         let crossovers1 = Phenotype::calculate_crossovers(sys_params1.m3(), gamete_len);
-        let (meiotic_g1_p1, meiotic_g2_p1) =
-            crate::gamete::Gamete::reproduce(p1_g1, p1_g2, crossovers1, rng, sys_params1);
+        let (mut meiotic_g1_p1, mut meiotic_g2_p1) = crate::gamete::Gamete::reproduce(
+            p1_g1,
+            p1_g2,
+            crossovers1,
+            rng,
+            sys_params1,
+            operators.mutation_operator(),
+        );
 
         let crossovers2 = Phenotype::calculate_crossovers(sys_params2.m3(), gamete_len);
-        let (meiotic_g1_p2, meiotic_g2_p2) =
-            crate::gamete::Gamete::reproduce(p2_g1, p2_g2, crossovers2, rng, sys_params2);
+        let (mut meiotic_g1_p2, mut meiotic_g2_p2) = crate::gamete::Gamete::reproduce(
+            p2_g1,
+            p2_g2,
+            crossovers2,
+            rng,
+            sys_params2,
+            operators.mutation_operator(),
+        );
+
+        let crossover_operator = operators.crossover_operator();
+        if crossover_operator.blends_values() {
+            crate::gamete::Gamete::recombine_values(
+                &mut meiotic_g1_p1,
+                &mut meiotic_g1_p2,
+                crossover_operator,
+                rng,
+            );
+            crate::gamete::Gamete::recombine_values(
+                &mut meiotic_g2_p1,
+                &mut meiotic_g2_p2,
+                crossover_operator,
+                rng,
+            );
+        }
 
         let offspring1 = Phenotype::new(meiotic_g1_p1, meiotic_g1_p2, rng);
         let offspring2 = Phenotype::new(meiotic_g2_p1, meiotic_g2_p2, rng);
@@ -67,6 +101,7 @@ mod tests {
     use crate::parameters::parameter::Parameter;
     use crate::parameters::system_parameters::SystemParameters;
     use crate::phenotype::Phenotype; // For Phenotype::new in helpers
+    use crate::world::reproduction_operators::ReproductionOperators;
     use rand::Rng;
     use rand::SeedableRng;
     use rand::rngs::SmallRng;
@@ -118,7 +153,12 @@ mod tests {
             "P2 sys params mismatch"
         );
 
-        let (offspring1, offspring2) = Phenotype::sexual_reproduction(&parent1, &parent2, &mut rng);
+        let (offspring1, offspring2) = Phenotype::sexual_reproduction(
+            &parent1,
+            &parent2,
+            &ReproductionOperators::default(),
+            &mut rng,
+        );
 
         // With 0 crossovers and 0 mutation rates, meiotic gametes = original parental gametes.
         // O1 gets (P1.G1, P2.G1), O2 gets (P1.G2, P2.G2)
@@ -153,7 +193,7 @@ mod tests {
         let p2_loci_vals = &[0.0; 8]; // 8 loci
         let p1 = helper_create_test_phenotype(p1_loci_vals, p1_loci_vals, &mut rng);
         let p2 = helper_create_test_phenotype(p2_loci_vals, p2_loci_vals, &mut rng);
-        Phenotype::sexual_reproduction(&p1, &p2, &mut rng);
+        Phenotype::sexual_reproduction(&p1, &p2, &ReproductionOperators::default(), &mut rng);
     }
 
     #[test]
@@ -171,6 +211,11 @@ mod tests {
         // However, sexual_reproduction itself would not be reached if parent creation fails.
         let parent2 = Phenotype::new(empty_gamete.clone(), empty_gamete.clone(), &mut rng);
         // The call to sexual_reproduction will not be reached due to panic in Phenotype::new above.
-        Phenotype::sexual_reproduction(&parent1, &parent2, &mut rng);
+        Phenotype::sexual_reproduction(
+            &parent1,
+            &parent2,
+            &ReproductionOperators::default(),
+            &mut rng,
+        );
     }
 }
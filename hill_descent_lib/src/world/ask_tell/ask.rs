@@ -0,0 +1,103 @@
+use super::Candidate;
+use crate::World;
+
+impl World {
+    /// Returns the organisms awaiting evaluation, for scoring outside the world.
+    ///
+    /// These are the living organisms that have not been scored: the whole initial
+    /// population, and after that each epoch's offspring. Report their outputs with
+    /// [`tell`](World::tell). Asking again before telling returns the candidates still
+    /// outstanding.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use hill_descent_lib::{GlobalConstants, SingleValuedFunction, setup_world};
+    /// use hill_descent_lib::world::ask_tell::TellOutcome;
+    ///
+    /// // The world function is not called in ask/tell mode; only its floor is used.
+    /// #[derive(Debug)]
+    /// struct Experiment;
+    ///
+    /// impl SingleValuedFunction for Experiment {
+    ///     fn single_run(&self, _params: &[f64]) -> f64 {
+    ///         unreachable!("evaluated outside the world")
+    ///     }
+    /// }
+    ///
+    /// let bounds = vec![-5.0..=5.0; 2];
+    /// let mut world = setup_world(&bounds, GlobalConstants::new(50, 5), Box::new(Experiment));
+    ///
+    /// for _ in 0..3 {
+    ///     let results: Vec<_> = world
+    ///         .ask()
+    ///         .iter()
+    ///         .map(|c| (c.id(), vec![c.params().iter().map(|x| x * x).sum()]))
+    ///         .collect();
+    ///     let outcome = world.tell(&results).unwrap();
+    ///     assert!(matches!(outcome, TellOutcome::EpochCompleted { .. }));
+    /// }
+    /// assert_eq!(world.epoch(), 3);
+    /// ```
+    pub fn ask(&self) -> Vec<Candidate> {
+        self.organisms
+            .iter()
+            .filter(|o| !o.is_dead() && o.score().is_none())
+            .map(|o| Candidate::new(o.id(), o.phenotype().expression_problem_values().to_vec()))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::TrainingData;
+    use crate::parameters::global_constants::GlobalConstants;
+    use crate::world::World;
+    use crate::world::world_function::WorldFunction;
+
+    #[derive(Debug)]
+    struct SumFn;
+    impl WorldFunction for SumFn {
+        fn run(&self, p: &[f64], _v: &[f64]) -> Vec<f64> {
+            vec![p.iter().map(|x| x.abs()).sum::<f64>()]
+        }
+    }
+
+    #[test]
+    fn given_new_world_when_ask_then_whole_population_with_problem_values() {
+        let world = World::new(
+            &[-1.0..=1.0, 2.0..=3.0],
+            GlobalConstants::new(40, 4),
+            Box::new(SumFn),
+        );
+
+        let candidates = world.ask();
+
+        assert_eq!(candidates.len(), world.organisms.len());
+        for candidate in &candidates {
+            assert_eq!(candidate.params().len(), 2);
+            assert!((-1.0..=1.0).contains(&candidate.params()[0]));
+            assert!((2.0..=3.0).contains(&candidate.params()[1]));
+        }
+    }
+
+    #[test]
+    fn given_trained_world_when_ask_then_only_unscored_organisms() {
+        let mut world = World::new(
+            &[-1.0..=1.0, -1.0..=1.0],
+            GlobalConstants::new(60, 4),
+            Box::new(SumFn),
+        );
+        world.training_run(TrainingData::None { floor_value: 0.0 });
+
+        let candidates = world.ask();
+
+        let unscored = world
+            .organisms
+            .iter()
+            .filter(|o| !o.is_dead() && o.score().is_none())
+            .count();
+        assert_eq!(candidates.len(), unscored);
+        assert!(candidates.len() < world.organisms.len());
+    }
+}
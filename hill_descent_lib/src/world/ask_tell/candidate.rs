@@ -0,0 +1,25 @@
+use serde::Serialize;
+
+/// An organism awaiting evaluation, as returned by [`World::ask`](crate::World::ask).
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Candidate {
+    id: usize,
+    params: Vec<f64>,
+}
+
+impl Candidate {
+    pub(crate) fn new(id: usize, params: Vec<f64>) -> Self {
+        Self { id, params }
+    }
+
+    /// Returns the organism's ID, which identifies its result in
+    /// [`World::tell`](crate::World::tell).
+    pub fn id(&self) -> usize {
+        self.id
+    }
+
+    /// Returns the problem parameters to evaluate.
+    pub fn params(&self) -> &[f64] {
+        &self.params
+    }
+}
@@ -0,0 +1,24 @@
+//! Externally driven evaluation, as an alternative to [`World::training_run`].
+//!
+//! [`World::ask`] hands out the organisms awaiting evaluation as [`Candidate`]s, and
+//! [`World::tell`] accepts their outputs, which may have come from a person, a lab
+//! experiment or a job scheduler. Once every candidate has been told the world advances
+//! one epoch exactly as `training_run` would, but without calling its [`WorldFunction`].
+//!
+//! Unlike `training_run`, organisms that survive an epoch keep their score rather than
+//! being evaluated again, so each organism is asked about once.
+//!
+//! [`World::training_run`]: crate::World::training_run
+//! [`World::ask`]: crate::World::ask
+//! [`World::tell`]: crate::World::tell
+//! [`WorldFunction`]: crate::WorldFunction
+
+mod ask;
+mod candidate;
+mod tell;
+mod tell_error;
+mod tell_outcome;
+
+pub use candidate::Candidate;
+pub use tell_error::TellError;
+pub use tell_outcome::TellOutcome;
@@ -0,0 +1,219 @@
+use std::collections::{HashMap, HashSet};
+
+use super::{TellError, TellOutcome};
+use crate::World;
+
+impl World {
+    /// Records the outputs of candidates returned by [`ask`](World::ask).
+    ///
+    /// Each result pairs a candidate's ID with its outputs, which must be a single value
+    /// scored against the world function's [floor](crate::WorldFunction::function_floor),
+    /// as in `training_run(TrainingData::None { floor_value })`. Results may arrive in any
+    /// number of batches; the one that completes the set runs the rest of the epoch
+    /// (selection, reproduction and region updates). Telling an empty batch when nothing is
+    /// outstanding also runs the epoch.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`TellError`] without recording anything if any result names an unknown
+    /// or already told candidate, or holds an invalid output.
+    pub fn tell(&mut self, results: &[(usize, Vec<f64>)]) -> Result<TellOutcome, TellError> {
+        let floor = self.world_function.function_floor();
+        let pending: HashMap<usize, _> = self
+            .organisms
+            .iter()
+            .filter(|o| !o.is_dead() && o.score().is_none())
+            .map(|o| (o.id(), o))
+            .collect();
+
+        let mut told = HashSet::with_capacity(results.len());
+        for (id, outputs) in results {
+            let id = *id;
+            if !pending.contains_key(&id) {
+                let alive = self.organisms.iter().any(|o| o.id() == id && !o.is_dead());
+                return Err(if alive {
+                    TellError::AlreadyTold { id }
+                } else {
+                    TellError::UnknownCandidate { id }
+                });
+            }
+            if !told.insert(id) {
+                return Err(TellError::AlreadyTold { id });
+            }
+            let [output] = outputs[..] else {
+                return Err(TellError::WrongOutputCount {
+                    id,
+                    count: outputs.len(),
+                });
+            };
+            // The score squares the distance from the floor, which must not overflow
+            if !(output >= floor && (output - floor).powi(2).is_finite()) {
+                return Err(TellError::InvalidOutput { id, output, floor });
+            }
+        }
+
+        for (id, outputs) in results {
+            pending[id].score_outputs(outputs, &[floor]);
+        }
+        if let Some(history) = &mut self.run_history {
+            history.count_evaluations(results.len());
+        }
+
+        let remaining = pending.len() - told.len();
+        if remaining > 0 {
+            return Ok(TellOutcome::Pending { remaining });
+        }
+        Ok(TellOutcome::EpochCompleted {
+            at_resolution_limit: self.run_epoch(false, &[], &[]),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::TrainingData;
+    use crate::parameters::global_constants::GlobalConstants;
+    use crate::world::World;
+    use crate::world::ask_tell::{TellError, TellOutcome};
+    use crate::world::world_function::WorldFunction;
+
+    #[derive(Debug)]
+    struct SumFn;
+    impl WorldFunction for SumFn {
+        fn run(&self, p: &[f64], _v: &[f64]) -> Vec<f64> {
+            vec![p.iter().map(|x| x.abs()).sum::<f64>()]
+        }
+    }
+
+    fn world(seed: u64) -> World {
+        World::new(
+            &[-1.0..=1.0, -1.0..=1.0],
+            GlobalConstants::new_with_seed(60, 4, seed),
+            Box::new(SumFn),
+        )
+    }
+
+    fn evaluate(world: &World) -> Vec<(usize, Vec<f64>)> {
+        world
+            .ask()
+            .iter()
+            .map(|c| (c.id(), SumFn.run(c.params(), &[])))
+            .collect()
+    }
+
+    #[test]
+    fn given_all_results_when_tell_then_epoch_completes() {
+        let mut world = world(1);
+
+        let outcome = world.tell(&evaluate(&world)).unwrap();
+
+        assert!(matches!(outcome, TellOutcome::EpochCompleted { .. }));
+        assert_eq!(world.epoch(), 1);
+        assert!(world.get_best_score().is_finite());
+    }
+
+    #[test]
+    fn given_partial_results_when_tell_then_pending_until_rest_told() {
+        let mut world = world(2);
+        let results = evaluate(&world);
+        let (first, rest) = results.split_at(10);
+
+        assert_eq!(
+            world.tell(first).unwrap(),
+            TellOutcome::Pending {
+                remaining: rest.len()
+            }
+        );
+        assert_eq!(world.epoch(), 0);
+        assert_eq!(world.ask().len(), rest.len());
+
+        assert!(matches!(
+            world.tell(rest).unwrap(),
+            TellOutcome::EpochCompleted { .. }
+        ));
+        assert_eq!(world.epoch(), 1);
+    }
+
+    #[test]
+    fn given_first_epoch_when_ask_tell_then_matches_training_run() {
+        let mut trained = world(3);
+        trained.training_run(TrainingData::None { floor_value: 0.0 });
+        let mut told = world(3);
+
+        told.tell(&evaluate(&told)).unwrap();
+
+        assert_eq!(told.get_best_score(), trained.get_best_score());
+        assert_eq!(told.get_best_params(), trained.get_best_params());
+    }
+
+    #[test]
+    fn given_unknown_id_when_tell_then_error_and_nothing_recorded() {
+        let mut world = world(4);
+        let mut results = evaluate(&world);
+        results.push((usize::MAX, vec![1.0]));
+
+        let error = world.tell(&results).unwrap_err();
+
+        assert_eq!(error, TellError::UnknownCandidate { id: usize::MAX });
+        assert_eq!(world.ask().len(), results.len() - 1);
+    }
+
+    #[test]
+    fn given_duplicate_or_repeated_id_when_tell_then_already_told() {
+        let mut world = world(5);
+        let results = evaluate(&world);
+        let id = results[0].0;
+
+        let duplicate = [results[0].clone(), results[0].clone()];
+        assert_eq!(
+            world.tell(&duplicate).unwrap_err(),
+            TellError::AlreadyTold { id }
+        );
+
+        world.tell(&results[..1]).unwrap();
+        assert_eq!(
+            world.tell(&results[..1]).unwrap_err(),
+            TellError::AlreadyTold { id }
+        );
+    }
+
+    #[test]
+    fn given_invalid_outputs_when_tell_then_rejected() {
+        let mut world = world(6);
+        let id = world.ask()[0].id();
+
+        assert_eq!(
+            world.tell(&[(id, vec![1.0, 2.0])]).unwrap_err(),
+            TellError::WrongOutputCount { id, count: 2 }
+        );
+        assert!(matches!(
+            world.tell(&[(id, vec![-1.0])]).unwrap_err(),
+            TellError::InvalidOutput { .. }
+        ));
+        assert!(matches!(
+            world.tell(&[(id, vec![f64::NAN])]).unwrap_err(),
+            TellError::InvalidOutput { .. }
+        ));
+        assert!(matches!(
+            world.tell(&[(id, vec![1e200])]).unwrap_err(),
+            TellError::InvalidOutput { .. }
+        ));
+    }
+
+    #[test]
+    fn given_several_epochs_when_ask_tell_then_survivors_not_asked_again() {
+        let mut world = world(7);
+        world.tell(&evaluate(&world)).unwrap();
+
+        let asked = world.ask();
+
+        let survivors_scored = world
+            .organisms
+            .iter()
+            .filter(|o| o.score().is_some())
+            .all(|o| asked.iter().all(|c| c.id() != o.id()));
+        assert!(survivors_scored);
+        world.tell(&evaluate(&world)).unwrap();
+        assert_eq!(world.epoch(), 2);
+    }
+}
@@ -0,0 +1,35 @@
+use std::fmt;
+
+/// Why [`World::tell`](crate::World::tell) rejected a batch of results.
+///
+/// A rejected batch is discarded as a whole, so it can be corrected and told again.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TellError {
+    /// No living organism has this ID.
+    UnknownCandidate { id: usize },
+    /// The candidate has already been told, earlier or in the same batch.
+    AlreadyTold { id: usize },
+    /// The result does not hold exactly one output.
+    WrongOutputCount { id: usize, count: usize },
+    /// The output is not finite, lies below the world function's floor, or is so far above
+    /// it that the score would overflow.
+    InvalidOutput { id: usize, output: f64, floor: f64 },
+}
+
+impl fmt::Display for TellError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TellError::UnknownCandidate { id } => write!(f, "no candidate has id {id}"),
+            TellError::AlreadyTold { id } => write!(f, "candidate {id} has already been told"),
+            TellError::WrongOutputCount { id, count } => {
+                write!(f, "candidate {id} needs exactly 1 output, got {count}")
+            }
+            TellError::InvalidOutput { id, output, floor } => write!(
+                f,
+                "output {output} for candidate {id} is not a finite value at or above the floor {floor}"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for TellError {}
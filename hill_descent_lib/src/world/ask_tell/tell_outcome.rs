@@ -0,0 +1,15 @@
+/// What [`World::tell`](crate::World::tell) did with a batch of results.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TellOutcome {
+    /// Some candidates are still awaiting results.
+    Pending {
+        /// The number of candidates not yet told.
+        remaining: usize,
+    },
+    /// Every candidate has been told and the world advanced one epoch.
+    EpochCompleted {
+        /// `true` if the resolution limit has been reached, as returned by
+        /// [`World::training_run`](crate::World::training_run).
+        at_resolution_limit: bool,
+    },
+}
@@ -0,0 +1,198 @@
+use rand::Rng;
+
+/// Recombination operator applied to problem-parameter loci during sexual reproduction.
+///
+/// Every operator keeps the existing meiosis (k-point crossover with mutation) within each
+/// parent. The non-default operators then recombine the *values* of the two gametes that
+/// form an offspring, one from each parent, locus by locus. This lets offspring take values
+/// between (or slightly beyond) their parents' alleles, which suits continuous problems.
+///
+/// System-parameter loci (mutation rates, max age, crossover points) are never blended and
+/// always follow the k-point behaviour described in PDD §5.2.3.2.
+///
+/// # Examples
+///
+/// ```
+/// use hill_descent_lib::{GlobalConstants, SingleValuedFunction, setup_world};
+/// use hill_descent_lib::world::crossover_operator::CrossoverOperator;
+///
+/// #[derive(Debug)]
+/// struct Sphere;
+///
+/// impl SingleValuedFunction for Sphere {
+///     fn single_run(&self, params: &[f64]) -> f64 {
+///         params.iter().map(|x| x * x).sum()
+///     }
+/// }
+///
+/// let bounds = vec![-5.0..=5.0; 2];
+/// let mut world = setup_world(&bounds, GlobalConstants::new(100, 10), Box::new(Sphere));
+/// world.set_crossover_operator(CrossoverOperator::SimulatedBinary { eta: 15.0 });
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum CrossoverOperator {
+    /// Multi-point crossover over whole loci only (the original behaviour).
+    ///
+    /// Each expressed value is copied from one of the parents' alleles.
+    #[default]
+    KPoint,
+    /// Blend crossover (BLX-α).
+    ///
+    /// Each child value is drawn uniformly from `[min - α·d, max + α·d]` where `d` is the
+    /// distance between the two parent values. `alpha` must be finite and non-negative;
+    /// 0.5 is a common choice.
+    BlendAlpha { alpha: f64 },
+    /// Simulated binary crossover (SBX).
+    ///
+    /// Children are spread symmetrically around the parents' mean with a spread factor
+    /// controlled by the distribution index `eta`. Larger values keep children closer to
+    /// their parents. `eta` must be finite and non-negative; 2-20 is typical.
+    SimulatedBinary { eta: f64 },
+    /// Whole arithmetic crossover.
+    ///
+    /// For a weight `λ` drawn uniformly from `[0, 1]` per locus the children are
+    /// `λ·x1 + (1-λ)·x2` and `(1-λ)·x1 + λ·x2`.
+    Arithmetic,
+}
+
+impl CrossoverOperator {
+    /// Returns `true` if this operator recombines locus values rather than copying them.
+    pub fn blends_values(&self) -> bool {
+        !matches!(self, CrossoverOperator::KPoint)
+    }
+
+    /// Panics if the operator's parameters are out of range.
+    pub(crate) fn validate(&self) {
+        match *self {
+            CrossoverOperator::BlendAlpha { alpha } => assert!(
+                alpha.is_finite() && alpha >= 0.0,
+                "BlendAlpha alpha must be finite and non-negative"
+            ),
+            CrossoverOperator::SimulatedBinary { eta } => assert!(
+                eta.is_finite() && eta >= 0.0,
+                "SimulatedBinary eta must be finite and non-negative"
+            ),
+            CrossoverOperator::KPoint | CrossoverOperator::Arithmetic => {}
+        }
+    }
+
+    /// Recombines two parent values into two child values.
+    ///
+    /// `KPoint` returns the inputs unchanged.
+    pub(crate) fn recombine<R: Rng>(&self, x1: f64, x2: f64, rng: &mut R) -> (f64, f64) {
+        match *self {
+            CrossoverOperator::KPoint => (x1, x2),
+            CrossoverOperator::BlendAlpha { alpha } => {
+                let (lo, hi) = (x1.min(x2), x1.max(x2));
+                let d = hi - lo;
+                if d == 0.0 {
+                    return (x1, x2);
+                }
+                let (lo, hi) = (lo - alpha * d, hi + alpha * d);
+                (rng.random_range(lo..=hi), rng.random_range(lo..=hi))
+            }
+            CrossoverOperator::SimulatedBinary { eta } => {
+                let u: f64 = rng.random_range(0.0..1.0);
+                let beta = if u <= 0.5 {
+                    (2.0 * u).powf(1.0 / (eta + 1.0))
+                } else {
+                    (1.0 / (2.0 * (1.0 - u))).powf(1.0 / (eta + 1.0))
+                };
+                (
+                    0.5 * ((1.0 + beta) * x1 + (1.0 - beta) * x2),
+                    0.5 * ((1.0 - beta) * x1 + (1.0 + beta) * x2),
+                )
+            }
+            CrossoverOperator::Arithmetic => {
+                let lambda: f64 = rng.random_range(0.0..=1.0);
+                (
+                    lambda * x1 + (1.0 - lambda) * x2,
+                    (1.0 - lambda) * x1 + lambda * x2,
+                )
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand::rngs::SmallRng;
+
+    #[test]
+    fn given_default_when_created_then_is_k_point() {
+        assert_eq!(CrossoverOperator::default(), CrossoverOperator::KPoint);
+        assert!(!CrossoverOperator::KPoint.blends_values());
+    }
+
+    #[test]
+    fn given_k_point_when_recombine_then_values_unchanged() {
+        let mut rng = SmallRng::seed_from_u64(0);
+        assert_eq!(
+            CrossoverOperator::KPoint.recombine(1.0, 3.0, &mut rng),
+            (1.0, 3.0)
+        );
+    }
+
+    #[test]
+    fn given_blend_alpha_when_recombine_then_children_within_extended_interval() {
+        let op = CrossoverOperator::BlendAlpha { alpha: 0.5 };
+        let mut rng = SmallRng::seed_from_u64(1);
+        for _ in 0..200 {
+            let (c1, c2) = op.recombine(1.0, 3.0, &mut rng);
+            // d = 2, interval = [0, 4]
+            assert!((0.0..=4.0).contains(&c1));
+            assert!((0.0..=4.0).contains(&c2));
+        }
+    }
+
+    #[test]
+    fn given_blend_alpha_with_equal_parents_when_recombine_then_children_equal_parents() {
+        let op = CrossoverOperator::BlendAlpha { alpha: 0.5 };
+        let mut rng = SmallRng::seed_from_u64(1);
+        assert_eq!(op.recombine(2.0, 2.0, &mut rng), (2.0, 2.0));
+    }
+
+    #[test]
+    fn given_sbx_when_recombine_then_children_preserve_parent_mean() {
+        let op = CrossoverOperator::SimulatedBinary { eta: 10.0 };
+        let mut rng = SmallRng::seed_from_u64(2);
+        for _ in 0..200 {
+            let (c1, c2) = op.recombine(-1.0, 5.0, &mut rng);
+            assert!(((c1 + c2) / 2.0 - 2.0).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn given_arithmetic_when_recombine_then_children_between_parents_and_mean_preserved() {
+        let op = CrossoverOperator::Arithmetic;
+        let mut rng = SmallRng::seed_from_u64(3);
+        for _ in 0..200 {
+            let (c1, c2) = op.recombine(-2.0, 4.0, &mut rng);
+            assert!((-2.0..=4.0).contains(&c1));
+            assert!((-2.0..=4.0).contains(&c2));
+            assert!(((c1 + c2) - 2.0).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "BlendAlpha alpha must be finite and non-negative")]
+    fn given_negative_alpha_when_validate_then_panics() {
+        CrossoverOperator::BlendAlpha { alpha: -0.1 }.validate();
+    }
+
+    #[test]
+    #[should_panic(expected = "SimulatedBinary eta must be finite and non-negative")]
+    fn given_nan_eta_when_validate_then_panics() {
+        CrossoverOperator::SimulatedBinary { eta: f64::NAN }.validate();
+    }
+
+    #[test]
+    fn given_valid_parameters_when_validate_then_does_not_panic() {
+        CrossoverOperator::KPoint.validate();
+        CrossoverOperator::Arithmetic.validate();
+        CrossoverOperator::BlendAlpha { alpha: 0.0 }.validate();
+        CrossoverOperator::SimulatedBinary { eta: 0.0 }.validate();
+    }
+}
@@ -0,0 +1,63 @@
+use rayon::prelude::*;
+
+/// Returns the population standard deviation of each dimension of `points`, or nothing if
+/// there are no points. Every point must have the same number of dimensions.
+///
+/// The mean is taken first and then the squared deviations from it. Unlike the one-pass
+/// `E[x²] − mean²`, this stays accurate for a tight cluster far from zero, which is how a
+/// converged population looks. Each dimension is one Rayon task, summed in point order.
+pub(crate) fn dimension_std_devs(points: &[&[f64]]) -> Vec<f64> {
+    let Some(dimensions) = points.first().map(|point| point.len()) else {
+        return Vec::new();
+    };
+    let count = points.len() as f64;
+    (0..dimensions)
+        .into_par_iter()
+        .map(|d| {
+            let mean = points.iter().map(|point| point[d]).sum::<f64>() / count;
+            let squares: f64 = points
+                .iter()
+                .map(|point| (point[d] - mean) * (point[d] - mean))
+                .sum();
+            (squares / count).sqrt()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn given_points_when_std_devs_taken_then_population_std_dev_per_dimension() {
+        let points: [&[f64]; 4] = [&[0.0, 1.0], &[2.0, 1.0], &[4.0, 1.0], &[6.0, 1.0]];
+
+        let std_devs = dimension_std_devs(&points);
+
+        assert!((std_devs[0] - 5.0f64.sqrt()).abs() < 1e-12);
+        assert_eq!(std_devs[1], 0.0);
+    }
+
+    #[test]
+    fn given_tight_cluster_at_large_offset_when_std_devs_taken_then_accurate() {
+        // A collapsed population inside bounds such as -25e6..-5e6
+        let offset = -1.5e7;
+        let values: Vec<[f64; 1]> = (0..4).map(|i| [offset + i as f64 * 1e-4]).collect();
+        let points: Vec<&[f64]> = values.iter().map(|v| v.as_slice()).collect();
+
+        let std_devs = dimension_std_devs(&points);
+
+        // The offsets themselves are only stored to about 1e-9
+        let expected = 1.25e-8f64.sqrt();
+        assert!(
+            (std_devs[0] - expected).abs() < 1e-3 * expected,
+            "got {}, expected {expected}",
+            std_devs[0]
+        );
+    }
+
+    #[test]
+    fn given_no_points_when_std_devs_taken_then_empty() {
+        assert!(dimension_std_devs(&[]).is_empty());
+    }
+}
@@ -0,0 +1,140 @@
+use rustc_hash::FxHashSet;
+
+use super::organisms::{Organism, Organisms};
+
+/// Which organisms are exempt from truncation and death by old age.
+///
+/// Without elitism every organism eventually dies when its age exceeds its `max_age` system
+/// parameter, including the best one found so far, so the best score in the population can
+/// get worse between epochs. Elites still age, and still reproduce, but are never removed
+/// by the region lifecycle.
+///
+/// # Examples
+///
+/// ```
+/// use hill_descent_lib::{GlobalConstants, SingleValuedFunction, TrainingData, setup_world};
+/// use hill_descent_lib::world::elitism::Elitism;
+///
+/// #[derive(Debug)]
+/// struct Sphere;
+///
+/// impl SingleValuedFunction for Sphere {
+///     fn single_run(&self, params: &[f64]) -> f64 {
+///         params.iter().map(|x| x * x).sum()
+///     }
+/// }
+///
+/// let bounds = vec![-5.0..=5.0; 2];
+/// let mut world = setup_world(&bounds, GlobalConstants::new(100, 10), Box::new(Sphere));
+/// world.set_elitism(Elitism::Global { count: 1 });
+///
+/// let mut previous = f64::MAX;
+/// for _ in 0..20 {
+///     world.training_run(TrainingData::None { floor_value: 0.0 });
+///     let best = world.get_best_score();
+///     assert!(best <= previous);
+///     previous = best;
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Elitism {
+    /// No organism is protected (the PDD behaviour).
+    #[default]
+    None,
+    /// The `count` best organisms in the whole world are protected.
+    ///
+    /// Elites are chosen at the start of each epoch from the scores of the previous epoch,
+    /// so with a stationary fitness function the best score never gets worse.
+    Global { count: usize },
+    /// The `count` best organisms of every region are protected.
+    ///
+    /// Elites are chosen after each region is evaluated and ranked.
+    PerRegion { count: usize },
+}
+
+/// The elitism rules resolved for a single epoch, shared by all regions.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct ElitePolicy {
+    per_region: usize,
+    global_ids: FxHashSet<usize>,
+    leaders_per_region: usize,
+}
+
+impl ElitePolicy {
+    /// Resolves `elitism` against the current population.
+    ///
+    /// `leaders_per_region` is the number of top-ranked organisms each region reports back
+    /// after evaluation, for the hall of fame.
+    pub(crate) fn new(elitism: Elitism, organisms: &Organisms, leaders_per_region: usize) -> Self {
+        let (per_region, global_ids) = match elitism {
+            Elitism::None => (0, FxHashSet::default()),
+            Elitism::PerRegion { count } => (count, FxHashSet::default()),
+            Elitism::Global { count } => {
+                let mut scored: Vec<(f64, usize)> = organisms
+                    .iter()
+                    .filter_map(|o| o.score().map(|s| (s, o.id())))
+                    .collect();
+                scored.sort_by(|a, b| a.0.total_cmp(&b.0).then(a.1.cmp(&b.1)));
+                let ids = scored.into_iter().take(count).map(|(_, id)| id).collect();
+                (0, ids)
+            }
+        };
+        Self {
+            per_region,
+            global_ids,
+            leaders_per_region,
+        }
+    }
+
+    /// Returns `true` if the organism at `rank` within its (sorted) region is protected.
+    pub(crate) fn is_protected(&self, rank: usize, organism: &Organism) -> bool {
+        rank < self.per_region || self.global_ids.contains(&organism.id())
+    }
+
+    /// Returns the number of top-ranked organisms each region reports after evaluation.
+    pub(crate) fn leaders_per_region(&self) -> usize {
+        self.leaders_per_region
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::world::selection_strategy::tests::make_org;
+    use std::sync::Arc;
+
+    fn organisms(scores: &[f64]) -> (Organisms, Vec<Arc<Organism>>) {
+        let orgs: Vec<_> = scores
+            .iter()
+            .enumerate()
+            .map(|(i, s)| make_org(*s, 0, i))
+            .collect();
+        (Organisms::new_from_arc_vec(orgs.clone()), orgs)
+    }
+
+    #[test]
+    fn given_no_elitism_when_is_protected_then_nothing_is_protected() {
+        let (population, orgs) = organisms(&[1.0, 2.0]);
+        let policy = ElitePolicy::new(Elitism::None, &population, 0);
+        assert!(!policy.is_protected(0, &orgs[0]));
+    }
+
+    #[test]
+    fn given_per_region_elitism_when_is_protected_then_only_top_ranks_protected() {
+        let (population, orgs) = organisms(&[1.0, 2.0, 3.0]);
+        let policy = ElitePolicy::new(Elitism::PerRegion { count: 2 }, &population, 0);
+        assert!(policy.is_protected(0, &orgs[2]));
+        assert!(policy.is_protected(1, &orgs[2]));
+        assert!(!policy.is_protected(2, &orgs[2]));
+    }
+
+    #[test]
+    fn given_global_elitism_when_is_protected_then_best_scored_organisms_protected_at_any_rank() {
+        let (population, orgs) = organisms(&[3.0, 1.0, 2.0]);
+        orgs[2].set_score(None);
+        let policy = ElitePolicy::new(Elitism::Global { count: 2 }, &population, 0);
+        assert!(policy.is_protected(7, &orgs[1]));
+        assert!(policy.is_protected(7, &orgs[0]));
+        assert!(!policy.is_protected(0, &orgs[2]));
+    }
+}
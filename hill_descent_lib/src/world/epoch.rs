@@ -0,0 +1,43 @@
+use super::World;
+
+impl World {
+    /// Returns the number of completed calls to [`training_run`](World::training_run).
+    ///
+    /// The epoch is mixed into every region's random seed, so regions draw a fresh random
+    /// stream each epoch while a run with a fixed world seed stays reproducible.
+    pub fn epoch(&self) -> u64 {
+        self.epoch
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::TrainingData;
+    use crate::parameters::global_constants::GlobalConstants;
+    use crate::world::World;
+    use crate::world::world_function::WorldFunction;
+
+    #[derive(Debug)]
+    struct SumFn;
+    impl WorldFunction for SumFn {
+        fn run(&self, p: &[f64], _v: &[f64]) -> Vec<f64> {
+            vec![p.iter().map(|x| x.abs()).sum::<f64>() + 1.0]
+        }
+    }
+
+    #[test]
+    fn given_training_runs_when_epoch_then_counts_completed_runs() {
+        let mut world = World::new(
+            &[-1.0..=1.0, -1.0..=1.0],
+            GlobalConstants::new(40, 4),
+            Box::new(SumFn),
+        );
+        assert_eq!(world.epoch(), 0);
+
+        for _ in 0..3 {
+            world.training_run(TrainingData::None { floor_value: 0.0 });
+        }
+
+        assert_eq!(world.epoch(), 3);
+    }
+}
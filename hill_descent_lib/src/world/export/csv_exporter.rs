@@ -0,0 +1,227 @@
+use std::fs::{File, OpenOptions};
+use std::io::{BufRead, BufReader, BufWriter, Write};
+use std::path::Path;
+
+use super::{ExportError, check_parameters, column_names, system_parameter_values};
+use crate::world::World;
+
+/// Writes the population as CSV, one row per organism per epoch, with the columns of
+/// [`column_names`](super::column_names).
+///
+/// Missing values are left empty and the region key is written as its indices joined by
+/// `;`, e.g. `3;0;1`.
+#[derive(Debug)]
+pub struct CsvExporter<W: Write> {
+    writer: W,
+    problem_parameters: usize,
+}
+
+impl CsvExporter<BufWriter<File>> {
+    /// Creates or truncates the file at `path` and writes the header for `world`'s columns.
+    pub fn create(path: impl AsRef<Path>, world: &World) -> Result<Self, ExportError> {
+        Self::new(BufWriter::new(File::create(path)?), world)
+    }
+
+    /// Opens the file at `path` to add rows after those already in it, creating it if
+    /// needed, so one table can be built up over several runs or processes.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ExportError::HeaderMismatch`] if the file already holds a table whose
+    /// columns differ from `world`'s.
+    pub fn append(path: impl AsRef<Path>, world: &World) -> Result<Self, ExportError> {
+        let mut file = OpenOptions::new()
+            .read(true)
+            .append(true)
+            .create(true)
+            .open(path)?;
+        let mut existing = String::new();
+        BufReader::new(&mut file).read_line(&mut existing)?;
+        let problem_parameters = world.dimensions.num_dimensions();
+        let writer = BufWriter::new(file);
+        if existing.is_empty() {
+            return Self::new(writer, world);
+        }
+
+        let expected = header(problem_parameters);
+        let found = existing.trim_end();
+        if found != expected {
+            return Err(ExportError::HeaderMismatch {
+                expected,
+                found: found.to_string(),
+            });
+        }
+        Ok(Self {
+            writer,
+            problem_parameters,
+        })
+    }
+}
+
+impl<W: Write> CsvExporter<W> {
+    /// Writes the header for `world`'s columns to `writer`.
+    pub fn new(mut writer: W, world: &World) -> Result<Self, ExportError> {
+        let problem_parameters = world.dimensions.num_dimensions();
+        writeln!(writer, "{}", header(problem_parameters))?;
+        Ok(Self {
+            writer,
+            problem_parameters,
+        })
+    }
+
+    /// Appends a row for each organism in `world`, tagged with its current epoch.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ExportError::ParameterCountMismatch`] if `world` has a different number
+    /// of problem parameters from the table.
+    pub fn write_epoch(&mut self, world: &World) -> Result<(), ExportError> {
+        check_parameters(self.problem_parameters, world)?;
+        let epoch = world.epoch();
+        let writer = &mut self.writer;
+        for organism in world.organisms.iter() {
+            let (parent1, parent2) = organism.parent_ids();
+            write!(
+                writer,
+                "{epoch},{},{},{},{},{},",
+                organism.id(),
+                optional(parent1),
+                optional(parent2),
+                organism.age(),
+                optional(organism.score()),
+            )?;
+            if let Some(key) = organism.region_key() {
+                let indices: Vec<String> = key.values().iter().map(usize::to_string).collect();
+                write!(writer, "{}", indices.join(";"))?;
+            }
+            let problem_values = organism.phenotype().expression_problem_values();
+            for value in problem_values
+                .iter()
+                .chain(&system_parameter_values(organism))
+            {
+                write!(writer, ",{value}")?;
+            }
+            writeln!(writer)?;
+        }
+        Ok(())
+    }
+
+    /// Flushes the output and returns the writer.
+    pub fn finish(mut self) -> Result<W, ExportError> {
+        self.writer.flush()?;
+        Ok(self.writer)
+    }
+}
+
+fn header(problem_parameters: usize) -> String {
+    column_names(problem_parameters).join(",")
+}
+
+/// Formats a missing value as an empty field.
+fn optional<T: ToString>(value: Option<T>) -> String {
+    value.map(|value| value.to_string()).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TrainingData;
+    use crate::parameters::global_constants::GlobalConstants;
+    use crate::world::world_function::WorldFunction;
+
+    #[derive(Debug)]
+    struct SumFn;
+    impl WorldFunction for SumFn {
+        fn run(&self, p: &[f64], _v: &[f64]) -> Vec<f64> {
+            vec![p.iter().map(|x| x.abs()).sum::<f64>()]
+        }
+    }
+
+    fn world(dimensions: usize) -> World {
+        World::new(
+            &vec![-1.0..=1.0; dimensions],
+            GlobalConstants::new_with_seed(30, 4, 9),
+            Box::new(SumFn),
+        )
+    }
+
+    fn temp_path(name: &str) -> std::path::PathBuf {
+        std::env::temp_dir().join(format!("hill_descent_{}_{name}.csv", std::process::id()))
+    }
+
+    #[test]
+    fn given_epochs_when_written_then_header_once_and_row_per_organism() {
+        let mut world = world(2);
+        let mut exporter = CsvExporter::new(Vec::new(), &world).unwrap();
+
+        exporter.write_epoch(&world).unwrap();
+        world.training_run(TrainingData::None { floor_value: 0.0 });
+        exporter.write_epoch(&world).unwrap();
+
+        let csv = String::from_utf8(exporter.finish().unwrap()).unwrap();
+        let mut lines = csv.lines();
+        assert_eq!(
+            lines.next().unwrap(),
+            "epoch,id,parent1,parent2,age,score,region_key,x0,x1,\
+             m1,m2,m3,m4,m5,max_age,crossover_points"
+        );
+        let rows: Vec<Vec<&str>> = lines.map(|line| line.split(',').collect()).collect();
+        assert_eq!(rows.len(), 30 + world.organisms.len());
+        assert!(rows.iter().all(|row| row.len() == 16));
+        // Founders have no parents or score before the first epoch
+        assert_eq!(rows[0][0], "0");
+        assert_eq!(&rows[0][2..4], &["", ""]);
+        assert_eq!(rows[0][5], "");
+        let last = rows.last().unwrap();
+        assert_eq!(last[0], "1");
+        assert!(
+            last[6]
+                .split(';')
+                .all(|index| index.parse::<usize>().is_ok())
+        );
+        assert!(last[7].parse::<f64>().is_ok());
+    }
+
+    #[test]
+    fn given_existing_table_when_appended_then_rows_added_without_header() {
+        let path = temp_path("append");
+        let _ = std::fs::remove_file(&path);
+        let world = world(1);
+
+        let mut exporter = CsvExporter::append(&path, &world).unwrap();
+        exporter.write_epoch(&world).unwrap();
+        exporter.finish().unwrap();
+        let mut exporter = CsvExporter::append(&path, &world).unwrap();
+        exporter.write_epoch(&world).unwrap();
+        exporter.finish().unwrap();
+
+        let csv = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(csv.matches("epoch,").count(), 1);
+        assert_eq!(csv.lines().count(), 1 + 2 * 30);
+    }
+
+    #[test]
+    fn given_different_columns_when_appended_or_written_then_mismatch() {
+        let path = temp_path("mismatch");
+        CsvExporter::create(&path, &world(2))
+            .unwrap()
+            .finish()
+            .unwrap();
+
+        let appended = CsvExporter::append(&path, &world(3));
+        std::fs::remove_file(&path).unwrap();
+        let written = CsvExporter::new(Vec::new(), &world(2))
+            .unwrap()
+            .write_epoch(&world(3));
+
+        assert!(matches!(appended, Err(ExportError::HeaderMismatch { .. })));
+        assert!(matches!(
+            written,
+            Err(ExportError::ParameterCountMismatch {
+                expected: 2,
+                found: 3
+            })
+        ));
+    }
+}
@@ -0,0 +1,71 @@
+use std::{fmt, io};
+
+/// Why a table of organisms could not be exported.
+#[derive(Debug)]
+pub enum ExportError {
+    /// The output could not be read or written.
+    Io(io::Error),
+    /// The world has a different number of problem parameters from the table.
+    ParameterCountMismatch { expected: usize, found: usize },
+    /// A file opened for appending holds a table with different columns.
+    HeaderMismatch { expected: String, found: String },
+    /// An Arrow record batch could not be built.
+    #[cfg(feature = "parquet")]
+    Arrow(arrow_schema::ArrowError),
+    /// The Parquet writer failed.
+    #[cfg(feature = "parquet")]
+    Parquet(parquet::errors::ParquetError),
+}
+
+impl fmt::Display for ExportError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ExportError::Io(error) => write!(f, "export failed: {error}"),
+            ExportError::ParameterCountMismatch { expected, found } => write!(
+                f,
+                "the table has {expected} problem parameters but the world has {found}"
+            ),
+            ExportError::HeaderMismatch { expected, found } => write!(
+                f,
+                "cannot append to a table with header '{found}'; expected '{expected}'"
+            ),
+            #[cfg(feature = "parquet")]
+            ExportError::Arrow(error) => write!(f, "cannot build record batch: {error}"),
+            #[cfg(feature = "parquet")]
+            ExportError::Parquet(error) => write!(f, "cannot write Parquet: {error}"),
+        }
+    }
+}
+
+impl std::error::Error for ExportError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ExportError::Io(error) => Some(error),
+            ExportError::ParameterCountMismatch { .. } | ExportError::HeaderMismatch { .. } => None,
+            #[cfg(feature = "parquet")]
+            ExportError::Arrow(error) => Some(error),
+            #[cfg(feature = "parquet")]
+            ExportError::Parquet(error) => Some(error),
+        }
+    }
+}
+
+impl From<io::Error> for ExportError {
+    fn from(error: io::Error) -> Self {
+        ExportError::Io(error)
+    }
+}
+
+#[cfg(feature = "parquet")]
+impl From<arrow_schema::ArrowError> for ExportError {
+    fn from(error: arrow_schema::ArrowError) -> Self {
+        ExportError::Arrow(error)
+    }
+}
+
+#[cfg(feature = "parquet")]
+impl From<parquet::errors::ParquetError> for ExportError {
+    fn from(error: parquet::errors::ParquetError) -> Self {
+        ExportError::Parquet(error)
+    }
+}
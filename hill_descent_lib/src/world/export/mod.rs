@@ -0,0 +1,113 @@
+//! Tables of organisms for analysis outside Rust, for example in pandas or polars.
+//!
+//! Each exporter writes the population of a [`World`] as one row per organism every time
+//! [`write_epoch`](CsvExporter::write_epoch) is called, so a file grows epoch by epoch over
+//! a run. The columns are the same in every format:
+//!
+//! | column | type | |
+//! |---|---|---|
+//! | `epoch` | integer | [`World::epoch`] when the rows were written |
+//! | `id` | integer | |
+//! | `parent1`, `parent2` | integer, nullable | empty for founders |
+//! | `age` | integer | in epochs |
+//! | `score` | float, nullable | empty until evaluated |
+//! | `region_key` | list of integers, nullable | interval index in each dimension |
+//! | `x0`, `x1`, ... | float | the problem parameters |
+//! | `m1` ... `crossover_points` | float | the [`SYSTEM_PARAMETER_COLUMNS`] |
+//!
+//! [`CsvExporter`] is always available; in CSV the region key is written as the indices
+//! joined by `;`. With the `parquet` feature, `ParquetExporter` writes Apache Parquet with
+//! one row group per epoch, and `record_batch` returns an epoch as an Arrow `RecordBatch`
+//! for use with other Arrow tooling.
+//!
+//! ```
+//! use hill_descent_lib::world::export::CsvExporter;
+//! use hill_descent_lib::{GlobalConstants, SingleValuedFunction, TrainingData, setup_world};
+//!
+//! #[derive(Debug)]
+//! struct Sphere;
+//!
+//! impl SingleValuedFunction for Sphere {
+//!     fn single_run(&self, params: &[f64]) -> f64 {
+//!         params.iter().map(|x| x * x).sum()
+//!     }
+//! }
+//!
+//! let bounds = vec![-5.0..=5.0; 2];
+//! let mut world = setup_world(&bounds, GlobalConstants::new(50, 5), Box::new(Sphere));
+//! let mut exporter = CsvExporter::new(Vec::new(), &world).unwrap();
+//! for _ in 0..3 {
+//!     world.training_run(TrainingData::None { floor_value: 0.0 });
+//!     exporter.write_epoch(&world).unwrap();
+//! }
+//!
+//! let csv = String::from_utf8(exporter.finish().unwrap()).unwrap();
+//! assert!(csv.starts_with("epoch,id,parent1,parent2,age,score,region_key,x0,x1,m1,"));
+//! assert!(csv.lines().skip(1).any(|row| row.starts_with("3,")));
+//! ```
+//!
+//! [`World`]: crate::World
+//! [`World::epoch`]: crate::World::epoch
+
+mod csv_exporter;
+mod export_error;
+#[cfg(feature = "parquet")]
+mod parquet_exporter;
+#[cfg(feature = "parquet")]
+mod record_batch;
+
+pub use csv_exporter::CsvExporter;
+pub use export_error::ExportError;
+#[cfg(feature = "parquet")]
+pub use parquet_exporter::ParquetExporter;
+#[cfg(feature = "parquet")]
+pub use record_batch::{record_batch, schema};
+
+use super::organisms::Organism;
+
+/// The names of the system parameter columns, which follow the problem parameters.
+pub const SYSTEM_PARAMETER_COLUMNS: [&str; crate::NUM_SYSTEM_PARAMETERS] =
+    ["m1", "m2", "m3", "m4", "m5", "max_age", "crossover_points"];
+
+/// Returns the column names of a table of organisms with `problem_parameters` parameters.
+pub fn column_names(problem_parameters: usize) -> Vec<String> {
+    [
+        "epoch",
+        "id",
+        "parent1",
+        "parent2",
+        "age",
+        "score",
+        "region_key",
+    ]
+    .into_iter()
+    .map(str::to_string)
+    .chain((0..problem_parameters).map(|i| format!("x{i}")))
+    .chain(SYSTEM_PARAMETER_COLUMNS.into_iter().map(str::to_string))
+    .collect()
+}
+
+/// Returns the organism's expressed system parameters in [`SYSTEM_PARAMETER_COLUMNS`]
+/// order.
+fn system_parameter_values(organism: &Organism) -> [f64; crate::NUM_SYSTEM_PARAMETERS] {
+    let parameters = organism.phenotype().system_parameters();
+    [
+        parameters.m1(),
+        parameters.m2(),
+        parameters.m3(),
+        parameters.m4(),
+        parameters.m5(),
+        parameters.max_age(),
+        parameters.crossover_points(),
+    ]
+}
+
+/// Fails if the world's parameter count differs from the one the exporter was created for.
+fn check_parameters(expected: usize, world: &crate::World) -> Result<(), ExportError> {
+    let found = world.dimensions.num_dimensions();
+    if found == expected {
+        Ok(())
+    } else {
+        Err(ExportError::ParameterCountMismatch { expected, found })
+    }
+}
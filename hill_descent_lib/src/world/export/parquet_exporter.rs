@@ -0,0 +1,136 @@
+use std::fs::File;
+use std::io::Write;
+use std::path::Path;
+
+use parquet::arrow::ArrowWriter;
+use parquet::basic::Compression;
+use parquet::file::properties::WriterProperties;
+
+use super::{ExportError, check_parameters, record_batch, schema};
+use crate::world::World;
+
+/// Writes the population as Apache Parquet, Snappy-compressed, with one row group per
+/// epoch and the columns of [`schema`](super::schema).
+///
+/// Each [`write_epoch`](Self::write_epoch) appends a row group and flushes it, so memory
+/// holds at most one epoch. The file is only readable once [`finish`](Self::finish) has
+/// written its footer. A finished Parquet file cannot be appended to; to continue a table
+/// in a later run, write another file beside it and read the directory as one dataset.
+pub struct ParquetExporter<W: Write + Send> {
+    writer: ArrowWriter<W>,
+    problem_parameters: usize,
+}
+
+impl ParquetExporter<File> {
+    /// Creates or truncates the file at `path` for a table of `world`'s columns.
+    pub fn create(path: impl AsRef<Path>, world: &World) -> Result<Self, ExportError> {
+        Self::new(File::create(path)?, world)
+    }
+}
+
+impl<W: Write + Send> ParquetExporter<W> {
+    /// Starts a Parquet table of `world`'s columns in `writer`.
+    pub fn new(writer: W, world: &World) -> Result<Self, ExportError> {
+        let problem_parameters = world.dimensions.num_dimensions();
+        let properties = WriterProperties::builder()
+            .set_compression(Compression::SNAPPY)
+            .build();
+        Ok(Self {
+            writer: ArrowWriter::try_new(writer, schema(problem_parameters), Some(properties))?,
+            problem_parameters,
+        })
+    }
+
+    /// Appends a row group holding a row for each organism in `world`, tagged with its
+    /// current epoch.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ExportError::ParameterCountMismatch`] if `world` has a different number
+    /// of problem parameters from the table.
+    pub fn write_epoch(&mut self, world: &World) -> Result<(), ExportError> {
+        check_parameters(self.problem_parameters, world)?;
+        self.writer.write(&record_batch(world)?)?;
+        self.writer.flush()?;
+        Ok(())
+    }
+
+    /// Writes the Parquet footer and returns the writer.
+    pub fn finish(self) -> Result<W, ExportError> {
+        Ok(self.writer.into_inner()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::UInt64Type;
+    use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+
+    use super::*;
+    use crate::TrainingData;
+    use crate::parameters::global_constants::GlobalConstants;
+    use crate::world::world_function::WorldFunction;
+
+    #[derive(Debug)]
+    struct SumFn;
+    impl WorldFunction for SumFn {
+        fn run(&self, p: &[f64], _v: &[f64]) -> Vec<f64> {
+            vec![p.iter().map(|x| x.abs()).sum::<f64>()]
+        }
+    }
+
+    #[test]
+    fn given_epochs_when_written_then_read_back_as_one_row_group_each() {
+        let path = std::env::temp_dir().join(format!(
+            "hill_descent_{}_export.parquet",
+            std::process::id()
+        ));
+        let mut world = World::new(
+            &[-1.0..=1.0, -2.0..=2.0],
+            GlobalConstants::new_with_seed(40, 4, 5),
+            Box::new(SumFn),
+        );
+        let mut exporter = ParquetExporter::create(&path, &world).unwrap();
+        let mut rows = 0;
+        for _ in 0..3 {
+            world.training_run(TrainingData::None { floor_value: 0.0 });
+            exporter.write_epoch(&world).unwrap();
+            rows += world.organisms.len();
+        }
+        exporter.finish().unwrap();
+
+        let builder = ParquetRecordBatchReaderBuilder::try_new(File::open(&path).unwrap()).unwrap();
+        assert_eq!(builder.metadata().num_row_groups(), 3);
+        assert_eq!(builder.schema(), &schema(2));
+        let batches: Vec<_> = builder.build().unwrap().collect::<Result<_, _>>().unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(batches.iter().map(|b| b.num_rows()).sum::<usize>(), rows);
+        let last = batches.last().unwrap();
+        let epochs = last.column(0).as_primitive::<UInt64Type>();
+        assert_eq!(epochs.value(last.num_rows() - 1), 3);
+    }
+
+    #[test]
+    fn given_world_with_other_dimensions_when_written_then_mismatch() {
+        let world = |dimensions| {
+            World::new(
+                &vec![-1.0..=1.0; dimensions],
+                GlobalConstants::new_with_seed(20, 4, 5),
+                Box::new(SumFn),
+            )
+        };
+        let mut exporter = ParquetExporter::new(Vec::new(), &world(2)).unwrap();
+
+        let result = exporter.write_epoch(&world(4));
+
+        assert!(matches!(
+            result,
+            Err(ExportError::ParameterCountMismatch {
+                expected: 2,
+                found: 4
+            })
+        ));
+    }
+}
@@ -0,0 +1,134 @@
+use std::sync::Arc;
+
+use arrow_array::builder::{ListBuilder, UInt64Builder};
+use arrow_array::{ArrayRef, Float64Array, RecordBatch, UInt64Array};
+use arrow_schema::{DataType, Field, Schema, SchemaRef};
+
+use super::{ExportError, SYSTEM_PARAMETER_COLUMNS, column_names, system_parameter_values};
+use crate::world::World;
+
+/// Returns the Arrow schema of a table of organisms with `problem_parameters` parameters.
+pub fn schema(problem_parameters: usize) -> SchemaRef {
+    let names = column_names(problem_parameters);
+    let region_key = DataType::List(Arc::new(Field::new("item", DataType::UInt64, true)));
+    let fields: Vec<Field> = names
+        .iter()
+        .enumerate()
+        .map(|(i, name)| match i {
+            0 | 1 | 4 => Field::new(name, DataType::UInt64, false),
+            2 | 3 => Field::new(name, DataType::UInt64, true),
+            5 => Field::new(name, DataType::Float64, true),
+            6 => Field::new(name, region_key.clone(), true),
+            _ => Field::new(name, DataType::Float64, false),
+        })
+        .collect();
+    Arc::new(Schema::new(fields))
+}
+
+/// Returns `world`'s population as an Arrow record batch with one row per organism and the
+/// columns of [`schema`].
+pub fn record_batch(world: &World) -> Result<RecordBatch, ExportError> {
+    let problem_parameters = world.dimensions.num_dimensions();
+    let count = world.organisms.len();
+    let epoch = world.epoch();
+
+    let mut ids = Vec::with_capacity(count);
+    let mut parents1 = Vec::with_capacity(count);
+    let mut parents2 = Vec::with_capacity(count);
+    let mut ages = Vec::with_capacity(count);
+    let mut scores = Vec::with_capacity(count);
+    let mut region_keys = ListBuilder::new(UInt64Builder::new());
+    let mut parameters =
+        vec![Vec::with_capacity(count); problem_parameters + SYSTEM_PARAMETER_COLUMNS.len()];
+    for organism in world.organisms.iter() {
+        let (parent1, parent2) = organism.parent_ids();
+        ids.push(organism.id() as u64);
+        parents1.push(parent1.map(|id| id as u64));
+        parents2.push(parent2.map(|id| id as u64));
+        ages.push(organism.age() as u64);
+        scores.push(organism.score());
+        match organism.region_key() {
+            Some(key) => {
+                for &index in key.values() {
+                    region_keys.values().append_value(index as u64);
+                }
+                region_keys.append(true);
+            }
+            None => region_keys.append(false),
+        }
+        let problem_values = organism.phenotype().expression_problem_values();
+        let system_values = system_parameter_values(organism);
+        let values = problem_values.iter().chain(&system_values);
+        for (column, &value) in parameters.iter_mut().zip(values) {
+            column.push(value);
+        }
+    }
+
+    let mut columns: Vec<ArrayRef> = vec![
+        Arc::new(UInt64Array::from(vec![epoch; count])),
+        Arc::new(UInt64Array::from(ids)),
+        Arc::new(UInt64Array::from(parents1)),
+        Arc::new(UInt64Array::from(parents2)),
+        Arc::new(UInt64Array::from(ages)),
+        Arc::new(Float64Array::from(scores)),
+        Arc::new(region_keys.finish()),
+    ];
+    columns.extend(
+        parameters
+            .into_iter()
+            .map(|column| Arc::new(Float64Array::from(column)) as ArrayRef),
+    );
+    Ok(RecordBatch::try_new(schema(problem_parameters), columns)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow_array::Array;
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::{Float64Type, UInt64Type};
+
+    use super::*;
+    use crate::TrainingData;
+    use crate::parameters::global_constants::GlobalConstants;
+    use crate::world::world_function::WorldFunction;
+
+    #[derive(Debug)]
+    struct SumFn;
+    impl WorldFunction for SumFn {
+        fn run(&self, p: &[f64], _v: &[f64]) -> Vec<f64> {
+            vec![p.iter().map(|x| x.abs()).sum::<f64>()]
+        }
+    }
+
+    #[test]
+    fn given_world_when_converted_then_one_row_per_organism_with_typed_columns() {
+        let mut world = World::new(
+            &[-1.0..=1.0, -2.0..=2.0, 0.0..=1.0],
+            GlobalConstants::new_with_seed(40, 4, 2),
+            Box::new(SumFn),
+        );
+        world.training_run(TrainingData::None { floor_value: 0.0 });
+
+        let batch = record_batch(&world).unwrap();
+
+        assert_eq!(batch.num_rows(), world.organisms.len());
+        assert_eq!(batch.num_columns(), 7 + 3 + SYSTEM_PARAMETER_COLUMNS.len());
+        assert_eq!(batch.schema(), schema(3));
+        let first = world.organisms.iter().next().unwrap();
+        let ids = batch.column(1).as_primitive::<UInt64Type>();
+        assert_eq!(ids.value(0), first.id() as u64);
+        let epochs = batch.column(0).as_primitive::<UInt64Type>();
+        assert!(epochs.iter().all(|epoch| epoch == Some(1)));
+        let x2 = batch
+            .column_by_name("x2")
+            .unwrap()
+            .as_primitive::<Float64Type>();
+        assert_eq!(
+            x2.value(0),
+            first.phenotype().expression_problem_values()[2]
+        );
+        let region_keys = batch.column(6).as_list::<i32>();
+        let placed = world.organisms.iter().filter(|o| o.region_key().is_some());
+        assert_eq!(region_keys.len() - region_keys.null_count(), placed.count());
+    }
+}
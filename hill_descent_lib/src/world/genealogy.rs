@@ -0,0 +1,303 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::io::{self, Write};
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+
+use super::format_score::format_score;
+use super::organisms::Organism;
+
+/// The birth of one organism, recorded in a [`Genealogy`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BirthRecord {
+    id: usize,
+    parent_ids: (Option<usize>, Option<usize>),
+    epoch: u64,
+    region_key: Option<Vec<usize>>,
+    score: Option<f64>,
+}
+
+impl BirthRecord {
+    fn new(organism: &Organism, epoch: u64) -> Self {
+        Self {
+            id: organism.id(),
+            parent_ids: organism.parent_ids(),
+            epoch,
+            region_key: organism.region_key().map(|key| key.values().to_vec()),
+            score: organism.score(),
+        }
+    }
+
+    /// Returns the ID of the organism.
+    pub fn id(&self) -> usize {
+        self.id
+    }
+
+    /// Returns the parent IDs, as [`Organism::parent_ids`] does.
+    pub fn parent_ids(&self) -> (Option<usize>, Option<usize>) {
+        self.parent_ids
+    }
+
+    /// Returns the parent IDs that are present, without repeating a self-fertilising parent.
+    pub fn parents(&self) -> impl Iterator<Item = usize> + '_ {
+        let (first, second) = self.parent_ids;
+        first
+            .into_iter()
+            .chain(second.filter(|&second| Some(second) != first))
+    }
+
+    /// Returns the number of epochs completed when the organism was first seen: the epoch
+    /// it was born in, or the epoch recording started for the founders.
+    pub fn epoch(&self) -> u64 {
+        self.epoch
+    }
+
+    /// Returns the region the organism was placed in when it was first seen.
+    pub fn region_key(&self) -> Option<&[usize]> {
+        self.region_key.as_deref()
+    }
+
+    /// Returns the organism's latest score, or `None` if it was never evaluated.
+    pub fn score(&self) -> Option<f64> {
+        self.score
+    }
+}
+
+/// The family tree of a run: a directed acyclic graph of births.
+///
+/// Recording is off by default; turn it on with
+/// [`World::enable_genealogy`](crate::World::enable_genealogy). The organisms alive at that
+/// point are the founders, and every organism born afterwards is added at the end of the
+/// epoch it was born in, so its ancestry survives the death of its parents. Each record
+/// holds only IDs, the epoch, the region key and the score, not the organism's genes.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Genealogy {
+    records: HashMap<usize, BirthRecord>,
+    founders: Vec<usize>,
+}
+
+impl Genealogy {
+    pub(crate) fn new<'a>(
+        epoch: u64,
+        population: impl IntoIterator<Item = &'a Arc<Organism>>,
+    ) -> Self {
+        let mut genealogy = Self::default();
+        for organism in population {
+            genealogy.founders.push(organism.id());
+            genealogy
+                .records
+                .insert(organism.id(), BirthRecord::new(organism, epoch));
+        }
+        genealogy.founders.sort_unstable();
+        genealogy
+    }
+
+    /// Returns the record of organism `id`, if it was recorded.
+    pub fn get(&self, id: usize) -> Option<&BirthRecord> {
+        self.records.get(&id)
+    }
+
+    /// Returns every record, in no particular order.
+    pub fn records(&self) -> impl Iterator<Item = &BirthRecord> {
+        self.records.values()
+    }
+
+    /// Returns the number of recorded organisms.
+    pub fn len(&self) -> usize {
+        self.records.len()
+    }
+
+    /// Returns `true` if no organism has been recorded.
+    pub fn is_empty(&self) -> bool {
+        self.records.is_empty()
+    }
+
+    /// Returns the IDs of the founders, in ascending order.
+    pub fn founders(&self) -> &[usize] {
+        &self.founders
+    }
+
+    /// Returns organism `id` followed by its recorded ancestors, nearest generation first.
+    ///
+    /// Each ancestor appears once, however many paths lead to it. The chain ends at the
+    /// founders. It is empty if `id` was not recorded.
+    pub fn lineage(&self, id: usize) -> Vec<&BirthRecord> {
+        self.ancestry([id]).collect()
+    }
+
+    /// Returns the founders with at least one descendant, or themselves, among `living`,
+    /// in ascending order.
+    pub fn surviving_founders(&self, living: impl IntoIterator<Item = usize>) -> Vec<usize> {
+        let mut survivors: Vec<usize> = self
+            .ancestry(living)
+            .filter(|record| self.founders.binary_search(&record.id).is_ok())
+            .map(|record| record.id)
+            .collect();
+        survivors.sort_unstable();
+        survivors
+    }
+
+    /// Writes the whole genealogy as a Graphviz DOT digraph, with an edge from each parent
+    /// to its child.
+    pub fn write_dot(&self, writer: impl Write) -> io::Result<()> {
+        write_dot(writer, self.records.values())
+    }
+
+    /// Returns the whole genealogy as DOT; see [`write_dot`](Self::write_dot).
+    pub fn to_dot(&self) -> String {
+        dot_string(self.records.values())
+    }
+
+    /// Returns the [lineage](Self::lineage) of organism `id` as DOT.
+    pub fn lineage_to_dot(&self, id: usize) -> String {
+        dot_string(self.lineage(id))
+    }
+
+    /// Adds the organisms born during `epoch` and updates the scores of those evaluated.
+    pub(crate) fn record_epoch<'a>(
+        &mut self,
+        epoch: u64,
+        evaluated: &[Arc<Organism>],
+        population: impl IntoIterator<Item = &'a Arc<Organism>>,
+    ) {
+        for organism in evaluated {
+            if let Some(record) = self.records.get_mut(&organism.id()) {
+                record.score = organism.score().or(record.score);
+            }
+        }
+        for organism in population {
+            self.records
+                .entry(organism.id())
+                .or_insert_with(|| BirthRecord::new(organism, epoch));
+        }
+    }
+
+    /// Walks the recorded ancestors of `ids` breadth first, visiting each once.
+    fn ancestry(&self, ids: impl IntoIterator<Item = usize>) -> impl Iterator<Item = &BirthRecord> {
+        let mut queue: VecDeque<usize> = ids.into_iter().collect();
+        let mut seen: HashSet<usize> = queue.iter().copied().collect();
+        std::iter::from_fn(move || {
+            while let Some(id) = queue.pop_front() {
+                if let Some(record) = self.records.get(&id) {
+                    queue.extend(record.parents().filter(|&parent| seen.insert(parent)));
+                    return Some(record);
+                }
+            }
+            None
+        })
+    }
+}
+
+/// Writes `records` as a DOT digraph, sorted by ID so output is stable. Edges are drawn
+/// only between records in the set.
+fn write_dot<'a>(
+    mut writer: impl Write,
+    records: impl IntoIterator<Item = &'a BirthRecord>,
+) -> io::Result<()> {
+    let mut records: Vec<&BirthRecord> = records.into_iter().collect();
+    records.sort_unstable_by_key(|record| record.id);
+    let included: HashSet<usize> = records.iter().map(|record| record.id).collect();
+
+    writeln!(writer, "digraph genealogy {{")?;
+    writeln!(writer, "    rankdir=TB;")?;
+    writeln!(writer, "    node [shape=box];")?;
+    for record in &records {
+        let score = record.score.map_or_else(|| "-".to_string(), format_score);
+        writeln!(
+            writer,
+            "    n{} [label=\"#{}\\nepoch {}\\nscore {}\"];",
+            record.id, record.id, record.epoch, score
+        )?;
+    }
+    for record in &records {
+        for parent in record.parents().filter(|parent| included.contains(parent)) {
+            writeln!(writer, "    n{parent} -> n{};", record.id)?;
+        }
+    }
+    writeln!(writer, "}}")
+}
+
+fn dot_string<'a>(records: impl IntoIterator<Item = &'a BirthRecord>) -> String {
+    let mut dot = Vec::new();
+    write_dot(&mut dot, records).expect("writing to a Vec cannot fail");
+    String::from_utf8(dot).expect("DOT is valid UTF-8")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::phenotype::Phenotype;
+
+    fn organism(parent_ids: (Option<usize>, Option<usize>), score: Option<f64>) -> Arc<Organism> {
+        let expressed = vec![0.1, 0.5, 0.001, 0.001, 0.001, 100.0, 2.0, 1.0];
+        let phenotype = Arc::new(Phenotype::new_for_test(expressed));
+        let organism = Arc::new(Organism::new(phenotype, 0, parent_ids));
+        organism.set_score(score);
+        organism
+    }
+
+    /// Founders a and b; c = a × b in epoch 1; d = c × c in epoch 2
+    fn family() -> (Genealogy, [Arc<Organism>; 4]) {
+        let a = organism((None, None), Some(3.0));
+        let b = organism((None, None), None);
+        let mut genealogy = Genealogy::new(0, [&a, &b]);
+        let c = organism((Some(a.id()), Some(b.id())), None);
+        genealogy.record_epoch(1, &[a.clone(), b.clone()], [&c]);
+        let d = organism((Some(c.id()), Some(c.id())), None);
+        c.set_score(Some(1.0));
+        genealogy.record_epoch(2, std::slice::from_ref(&c), [&c, &d]);
+        (genealogy, [a, b, c, d])
+    }
+
+    #[test]
+    fn given_births_when_recorded_then_records_hold_parents_epoch_and_score() {
+        let (genealogy, [a, b, c, d]) = family();
+
+        assert_eq!(genealogy.len(), 4);
+        assert_eq!(genealogy.founders().len(), 2);
+        let record = genealogy.get(c.id()).unwrap();
+        assert_eq!(record.parent_ids(), (Some(a.id()), Some(b.id())));
+        assert_eq!(record.epoch(), 1);
+        assert_eq!(record.score(), Some(1.0));
+        assert_eq!(genealogy.get(d.id()).unwrap().epoch(), 2);
+        assert_eq!(genealogy.get(d.id()).unwrap().parents().count(), 1);
+        assert_eq!(genealogy.get(a.id()).unwrap().score(), Some(3.0));
+    }
+
+    #[test]
+    fn given_descendant_when_lineage_queried_then_ancestors_nearest_first() {
+        let (genealogy, [a, b, c, d]) = family();
+
+        let lineage: Vec<usize> = genealogy.lineage(d.id()).iter().map(|r| r.id()).collect();
+
+        assert_eq!(lineage, vec![d.id(), c.id(), a.id(), b.id()]);
+        assert!(genealogy.lineage(usize::MAX).is_empty());
+    }
+
+    #[test]
+    fn given_living_descendants_when_founders_counted_then_only_ancestors_survive() {
+        let (genealogy, [a, b, _, d]) = family();
+        let e = organism((Some(a.id()), None), None);
+        let mut genealogy = genealogy;
+        genealogy.record_epoch(3, &[], [&e]);
+
+        assert_eq!(genealogy.surviving_founders([e.id()]), vec![a.id()]);
+        assert_eq!(genealogy.surviving_founders([d.id()]), vec![a.id(), b.id()]);
+        assert!(genealogy.surviving_founders([]).is_empty());
+    }
+
+    #[test]
+    fn given_genealogy_when_exported_to_dot_then_nodes_and_edges_written() {
+        let (genealogy, [a, b, c, d]) = family();
+
+        let dot = genealogy.to_dot();
+        let lineage = genealogy.lineage_to_dot(c.id());
+
+        assert!(dot.starts_with("digraph genealogy {"));
+        assert!(dot.contains(&format!("n{} -> n{};", a.id(), c.id())));
+        assert!(dot.contains(&format!("n{} -> n{};", c.id(), d.id())));
+        assert_eq!(dot.matches(&format!("-> n{};", d.id())).count(), 1);
+        assert!(lineage.contains(&format!("n{} -> n{};", b.id(), c.id())));
+        assert!(!lineage.contains(&format!("n{} ", d.id())));
+    }
+}
@@ -0,0 +1,155 @@
+use super::World;
+use super::population_diversity::PopulationDiversity;
+use super::regions::region::region_key::RegionKey;
+use super::regions::{SeedStream, derive_region_seed};
+use super::run_history::RunHistory;
+
+impl World {
+    /// Measures how spread out the current population is.
+    ///
+    /// Pairwise distances are sampled for populations larger than
+    /// [`MAX_EXACT_PAIRWISE_POPULATION`](super::population_diversity::MAX_EXACT_PAIRWISE_POPULATION),
+    /// seeded from the world seed and epoch on a random stream of their own, so repeated calls
+    /// agree and reproduction draws are unaffected.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use hill_descent_lib::{GlobalConstants, SingleValuedFunction, TrainingData, setup_world};
+    ///
+    /// #[derive(Debug)]
+    /// struct Sphere;
+    ///
+    /// impl SingleValuedFunction for Sphere {
+    ///     fn single_run(&self, params: &[f64]) -> f64 {
+    ///         params.iter().map(|x| x * x).sum()
+    ///     }
+    /// }
+    ///
+    /// let bounds = vec![-5.0..=5.0; 2];
+    /// let mut world = setup_world(&bounds, GlobalConstants::new(100, 10), Box::new(Sphere));
+    /// let before = world.diversity();
+    /// for _ in 0..50 {
+    ///     world.training_run(TrainingData::None { floor_value: 0.0 });
+    /// }
+    ///
+    /// let after = world.diversity();
+    /// assert_eq!(after.dimension_std_devs().len(), 2);
+    /// assert!(after.mean_pairwise_distance() < before.mean_pairwise_distance());
+    /// ```
+    pub fn diversity(&self) -> PopulationDiversity {
+        let population: Vec<_> = self.organisms.iter().cloned().collect();
+        PopulationDiversity::measure(&population, self.diversity_seed())
+    }
+
+    /// Starts recording per-epoch statistics, as
+    /// [`enable_run_history`](World::enable_run_history) does, with the
+    /// [`diversity`](World::diversity) of the surviving population added to each epoch.
+    pub fn enable_diversity_history(&mut self) {
+        self.run_history
+            .get_or_insert_with(RunHistory::new)
+            .record_diversity();
+    }
+
+    pub(super) fn diversity_seed(&self) -> u64 {
+        derive_region_seed(
+            self.global_constants.world_seed(),
+            self.epoch,
+            SeedStream::DIVERSITY,
+            &RegionKey::new(Vec::new()),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::TrainingData;
+    use crate::parameters::global_constants::GlobalConstants;
+    use crate::world::World;
+    use crate::world::regions::region::region_key::RegionKey;
+    use crate::world::regions::{SeedStream, derive_region_seed};
+    use crate::world::world_function::WorldFunction;
+
+    #[derive(Debug)]
+    struct SumFn;
+    impl WorldFunction for SumFn {
+        fn run(&self, p: &[f64], _v: &[f64]) -> Vec<f64> {
+            vec![p.iter().map(|x| x.abs()).sum::<f64>()]
+        }
+    }
+
+    fn world() -> World {
+        World::new(
+            &[-1.0..=1.0, -1.0..=1.0],
+            GlobalConstants::new_with_seed(60, 4, 11),
+            Box::new(SumFn),
+        )
+    }
+
+    #[test]
+    fn given_new_world_when_diversity_measured_then_covers_population() {
+        let world = world();
+
+        let diversity = world.diversity();
+
+        assert_eq!(diversity.organisms(), 60);
+        assert_eq!(diversity.dimension_std_devs().len(), 2);
+        assert!(diversity.mean_pairwise_distance() > 0.0);
+        assert!(diversity.distinct_expressed_hashes() > 1);
+        assert!(diversity.populated_regions() >= 1);
+        assert!(diversity.region_entropy() <= (diversity.populated_regions() as f64).ln() + 1e-12);
+        assert_eq!(diversity, world.diversity());
+    }
+
+    #[test]
+    fn given_world_when_diversity_seeded_then_independent_of_reproduction_stream() {
+        let world = world();
+        let empty = RegionKey::new(Vec::new());
+        let seed = world.global_constants.world_seed();
+
+        assert_eq!(
+            world.diversity_seed(),
+            derive_region_seed(seed, world.epoch, SeedStream::DIVERSITY, &empty)
+        );
+        assert_ne!(
+            world.diversity_seed(),
+            derive_region_seed(seed, world.epoch, SeedStream::REPRODUCTION, &empty)
+        );
+    }
+
+    #[test]
+    fn given_run_history_when_training_then_diversity_not_recorded() {
+        let mut world = world();
+        world.enable_run_history();
+
+        world.training_run(TrainingData::None { floor_value: 0.0 });
+
+        let stats = world.run_history().unwrap().last().unwrap();
+        assert!(stats.survivor_diversity().is_none());
+    }
+
+    #[test]
+    fn given_diversity_history_when_training_then_diversity_recorded_each_epoch() {
+        let mut world = world();
+        world.enable_diversity_history();
+
+        for _ in 0..3 {
+            world.training_run(TrainingData::None { floor_value: 0.0 });
+        }
+
+        let history = world.run_history().unwrap();
+        assert_eq!(history.len(), 3);
+        assert!(
+            history
+                .epochs()
+                .iter()
+                .all(|s| s.survivor_diversity().is_some())
+        );
+        assert_eq!(
+            history.last().unwrap().survivor_diversity(),
+            Some(&world.diversity())
+        );
+        let csv = history.to_csv();
+        assert!(!csv.lines().nth(1).unwrap().ends_with(",,,"));
+    }
+}
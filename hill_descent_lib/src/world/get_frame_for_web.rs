@@ -0,0 +1,167 @@
+use super::World;
+use super::web_frame::{WebFrame, WebFrameBounds, WebFrameOrganism, WebFrameRegion};
+
+impl World {
+    /// Returns a compact [`WebFrame`] of the world for animating a run epoch by epoch.
+    ///
+    /// The frame holds the best score and parameters, the regions and the living
+    /// organisms' positions, but none of the genetic detail included by
+    /// [`get_state_for_web`](World::get_state_for_web). Like that method it projects worlds
+    /// with more than 2 dimensions onto their first two.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use hill_descent_lib::{GlobalConstants, SingleValuedFunction, TrainingData, setup_world};
+    ///
+    /// #[derive(Debug)]
+    /// struct Sphere;
+    ///
+    /// impl SingleValuedFunction for Sphere {
+    ///     fn single_run(&self, params: &[f64]) -> f64 {
+    ///         params.iter().map(|x| x * x).sum()
+    ///     }
+    /// }
+    ///
+    /// let bounds = vec![-5.0..=5.0; 2];
+    /// let mut world = setup_world(&bounds, GlobalConstants::new(100, 10), Box::new(Sphere));
+    /// world.training_run(TrainingData::None { floor_value: 0.0 });
+    ///
+    /// let frame = world.get_frame_for_web();
+    /// assert_eq!(frame.epoch(), 1);
+    /// assert_eq!(frame.best_params().len(), 2);
+    /// assert!(!frame.organisms().is_empty());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the world has fewer than 2 dimensions.
+    pub fn get_frame_for_web(&self) -> WebFrame {
+        let dims = self.dimensions.get_dimensions();
+        assert!(
+            dims.len() >= 2,
+            "get_frame_for_web needs at least 2 dimensions"
+        );
+
+        let world_bounds = WebFrameBounds::new(
+            (*dims[0].range().start(), *dims[0].range().end()),
+            (*dims[1].range().start(), *dims[1].range().end()),
+        );
+
+        let regions = self
+            .regions
+            .iter_regions()
+            .map(|(key, region)| {
+                // Only the first two dimensions are drawn
+                let interval = |i: usize| {
+                    dims[i]
+                        .interval_bounds(key.values()[i])
+                        .expect("Region key contained an out-of-range interval index")
+                };
+                WebFrameRegion::new(
+                    key.values().to_vec(),
+                    WebFrameBounds::new(interval(0), interval(1)),
+                    region.carrying_capacity().unwrap_or(0),
+                    region.min_score(),
+                )
+            })
+            .collect();
+
+        let organisms = self
+            .organisms
+            .iter()
+            .filter(|o| !o.is_dead())
+            .map(|o| {
+                let expressed_values = o.phenotype().expressed_values();
+                WebFrameOrganism::new(
+                    o.id(),
+                    (
+                        expressed_values[crate::NUM_SYSTEM_PARAMETERS],
+                        expressed_values[crate::NUM_SYSTEM_PARAMETERS + 1],
+                    ),
+                    o.score(),
+                    o.age(),
+                    o.phenotype().system_parameters().max_age().round() as usize,
+                )
+            })
+            .collect();
+
+        WebFrame::new(
+            self.epoch,
+            self.get_best_score(),
+            self.get_best_params(),
+            world_bounds,
+            regions,
+            organisms,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::TrainingData;
+    use crate::parameters::global_constants::GlobalConstants;
+    use crate::world::World;
+    use crate::world::world_function::WorldFunction;
+
+    #[derive(Debug)]
+    struct SumFn;
+    impl WorldFunction for SumFn {
+        fn run(&self, p: &[f64], _v: &[f64]) -> Vec<f64> {
+            vec![p.iter().map(|x| x.abs()).sum::<f64>() + 1.0]
+        }
+    }
+
+    #[test]
+    fn given_trained_2d_world_when_get_frame_for_web_then_organisms_lie_in_their_regions() {
+        let mut world = World::new(
+            &[-1.0..=1.0, -1.0..=1.0],
+            GlobalConstants::new(60, 4),
+            Box::new(SumFn),
+        );
+        for _ in 0..3 {
+            world.training_run(TrainingData::None { floor_value: 0.0 });
+        }
+
+        let frame = world.get_frame_for_web();
+
+        assert_eq!(frame.epoch(), 3);
+        assert_eq!(frame.best_score(), world.get_best_score());
+        assert_eq!(
+            frame.organisms().len(),
+            world.organisms.iter().filter(|o| !o.is_dead()).count()
+        );
+        for organism in frame.organisms() {
+            assert!(frame.regions().iter().any(|r| {
+                let (x, y) = (r.bounds().x(), r.bounds().y());
+                (x.0..=x.1).contains(&organism.x()) && (y.0..=y.1).contains(&organism.y())
+            }));
+        }
+    }
+
+    #[test]
+    fn given_3d_world_when_get_frame_for_web_then_regions_projected_onto_first_two_dimensions() {
+        let mut world = World::new(
+            &[-1.0..=1.0, -2.0..=2.0, -3.0..=3.0],
+            GlobalConstants::new(60, 4),
+            Box::new(SumFn),
+        );
+        world.training_run(TrainingData::None { floor_value: 0.0 });
+
+        let frame = world.get_frame_for_web();
+
+        let (x, y) = (frame.world_bounds().x(), frame.world_bounds().y());
+        for region in frame.regions() {
+            assert_eq!(region.key().len(), 3);
+            assert!(region.bounds().x().0 >= x.0 && region.bounds().x().1 <= x.1);
+            assert!(region.bounds().y().0 >= y.0 && region.bounds().y().1 <= y.1);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "get_frame_for_web needs at least 2 dimensions")]
+    fn given_1d_world_when_get_frame_for_web_then_panics() {
+        let world = World::new(&[-1.0..=1.0], GlobalConstants::new(60, 4), Box::new(SumFn));
+        world.get_frame_for_web();
+    }
+}
@@ -0,0 +1,139 @@
+use super::World;
+use super::genealogy::{BirthRecord, Genealogy};
+
+impl World {
+    /// Starts recording a [`Genealogy`] of births, with the current population as founders.
+    ///
+    /// Calling it again while recording keeps the existing genealogy.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use hill_descent_lib::{GlobalConstants, SingleValuedFunction, TrainingData, setup_world};
+    ///
+    /// #[derive(Debug)]
+    /// struct Sphere;
+    ///
+    /// impl SingleValuedFunction for Sphere {
+    ///     fn single_run(&self, params: &[f64]) -> f64 {
+    ///         params.iter().map(|x| x * x).sum()
+    ///     }
+    /// }
+    ///
+    /// let bounds = vec![-5.0..=5.0; 2];
+    /// let mut world = setup_world(&bounds, GlobalConstants::new(100, 10), Box::new(Sphere));
+    /// world.enable_genealogy();
+    /// for _ in 0..10 {
+    ///     world.training_run(TrainingData::None { floor_value: 0.0 });
+    /// }
+    ///
+    /// let best = world.get_best_organism(TrainingData::None { floor_value: 0.0 });
+    /// let lineage = world.lineage(best.id());
+    /// assert_eq!(lineage[0].id(), best.id());
+    /// println!("{} founders survive", world.surviving_founders().len());
+    /// let dot = world.genealogy().unwrap().lineage_to_dot(best.id());
+    /// assert!(dot.starts_with("digraph"));
+    /// ```
+    pub fn enable_genealogy(&mut self) {
+        if self.genealogy.is_none() {
+            self.genealogy = Some(Genealogy::new(self.epoch, self.organisms.iter()));
+        }
+    }
+
+    /// Returns the recorded genealogy, or `None` if recording is off.
+    pub fn genealogy(&self) -> Option<&Genealogy> {
+        self.genealogy.as_ref()
+    }
+
+    /// Stops recording and returns the genealogy recorded so far.
+    pub fn take_genealogy(&mut self) -> Option<Genealogy> {
+        self.genealogy.take()
+    }
+
+    /// Returns organism `id` followed by its ancestors, nearest generation first; see
+    /// [`Genealogy::lineage`]. It is empty if recording is off or `id` was not recorded.
+    pub fn lineage(&self, id: usize) -> Vec<&BirthRecord> {
+        self.genealogy
+            .as_ref()
+            .map(|genealogy| genealogy.lineage(id))
+            .unwrap_or_default()
+    }
+
+    /// Returns the founders with a descendant in the current population; see
+    /// [`Genealogy::surviving_founders`]. It is empty if recording is off.
+    pub fn surviving_founders(&self) -> Vec<usize> {
+        self.genealogy
+            .as_ref()
+            .map(|genealogy| genealogy.surviving_founders(self.organisms.iter().map(|o| o.id())))
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::TrainingData;
+    use crate::parameters::global_constants::GlobalConstants;
+    use crate::world::World;
+    use crate::world::world_function::WorldFunction;
+
+    #[derive(Debug)]
+    struct SumFn;
+    impl WorldFunction for SumFn {
+        fn run(&self, p: &[f64], _v: &[f64]) -> Vec<f64> {
+            vec![p.iter().map(|x| x.abs()).sum::<f64>()]
+        }
+    }
+
+    fn world() -> World {
+        World::new(
+            &[-1.0..=1.0, -1.0..=1.0],
+            GlobalConstants::new_with_seed(60, 4, 11),
+            Box::new(SumFn),
+        )
+    }
+
+    #[test]
+    fn given_new_world_when_genealogy_queried_then_none() {
+        let world = world();
+
+        assert!(world.genealogy().is_none());
+        assert!(world.lineage(1).is_empty());
+        assert!(world.surviving_founders().is_empty());
+    }
+
+    #[test]
+    fn given_genealogy_enabled_when_training_then_every_living_organism_traces_to_founders() {
+        let mut world = world();
+        world.enable_genealogy();
+        let founders = world.genealogy().unwrap().founders().to_vec();
+
+        for _ in 0..5 {
+            world.training_run(TrainingData::None { floor_value: 0.0 });
+        }
+
+        let genealogy = world.genealogy().unwrap();
+        assert_eq!(founders.len(), 60);
+        assert!(genealogy.len() > founders.len());
+        for organism in world.organisms.iter() {
+            let lineage = world.lineage(organism.id());
+            assert_eq!(lineage[0].id(), organism.id());
+            let last = lineage.last().unwrap();
+            assert!(founders.binary_search(&last.id()).is_ok());
+        }
+        let surviving = world.surviving_founders();
+        assert!(!surviving.is_empty() && surviving.len() <= founders.len());
+    }
+
+    #[test]
+    fn given_genealogy_when_taken_then_recording_stops() {
+        let mut world = world();
+        world.enable_genealogy();
+        world.training_run(TrainingData::None { floor_value: 0.0 });
+
+        let genealogy = world.take_genealogy().unwrap();
+        world.training_run(TrainingData::None { floor_value: 0.0 });
+
+        assert!(genealogy.len() >= 60);
+        assert!(world.genealogy().is_none());
+    }
+}
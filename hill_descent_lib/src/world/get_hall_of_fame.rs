@@ -0,0 +1,95 @@
+use super::World;
+use super::hall_of_fame::HallOfFame;
+
+impl World {
+    /// Returns the archive of the best distinct solutions seen since the world was created.
+    ///
+    /// The archive is updated after every [`training_run`](World::training_run) from each
+    /// region's best organisms, before any of them can die, so it retains good solutions
+    /// even when elitism is disabled.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use hill_descent_lib::{GlobalConstants, SingleValuedFunction, TrainingData, setup_world};
+    ///
+    /// #[derive(Debug)]
+    /// struct Sphere;
+    ///
+    /// impl SingleValuedFunction for Sphere {
+    ///     fn single_run(&self, params: &[f64]) -> f64 {
+    ///         params.iter().map(|x| x * x).sum()
+    ///     }
+    /// }
+    ///
+    /// let bounds = vec![-5.0..=5.0; 2];
+    /// let mut world = setup_world(&bounds, GlobalConstants::new(100, 10), Box::new(Sphere));
+    /// for _ in 0..10 {
+    ///     world.training_run(TrainingData::None { floor_value: 0.0 });
+    /// }
+    ///
+    /// let best = world.hall_of_fame().best().unwrap();
+    /// assert_eq!(best.params().len(), 2);
+    /// ```
+    pub fn hall_of_fame(&self) -> &HallOfFame {
+        &self.hall_of_fame
+    }
+
+    /// Sets how many solutions the hall of fame keeps, dropping the worst if it shrinks.
+    ///
+    /// The default is [`HallOfFame::DEFAULT_CAPACITY`]. A capacity of zero disables it.
+    pub fn set_hall_of_fame_capacity(&mut self, capacity: usize) {
+        self.hall_of_fame.set_capacity(capacity);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::TrainingData;
+    use crate::parameters::global_constants::GlobalConstants;
+    use crate::world::World;
+    use crate::world::world_function::WorldFunction;
+
+    #[derive(Debug)]
+    struct SumFn;
+    impl WorldFunction for SumFn {
+        fn run(&self, p: &[f64], _v: &[f64]) -> Vec<f64> {
+            vec![p.iter().map(|x| x.abs()).sum::<f64>() + 1.0]
+        }
+    }
+
+    fn world() -> World {
+        World::new(
+            &[-1.0..=1.0, -1.0..=1.0],
+            GlobalConstants::new_with_seed(60, 4, 11),
+            Box::new(SumFn),
+        )
+    }
+
+    #[test]
+    fn given_new_world_when_hall_of_fame_then_empty() {
+        assert!(world().hall_of_fame().is_empty());
+    }
+
+    #[test]
+    fn given_training_when_hall_of_fame_then_best_entry_never_worse_than_population() {
+        let mut world = world();
+        for _ in 0..10 {
+            world.training_run(TrainingData::None { floor_value: 0.0 });
+            let hall_best = world.hall_of_fame().best().unwrap().score();
+            assert!(hall_best <= world.get_best_score());
+        }
+
+        let entries = world.hall_of_fame().entries();
+        assert!(entries.windows(2).all(|w| w[0].score() <= w[1].score()));
+        assert!(entries.len() <= world.hall_of_fame().capacity());
+    }
+
+    #[test]
+    fn given_zero_capacity_when_training_then_hall_of_fame_stays_empty() {
+        let mut world = world();
+        world.set_hall_of_fame_capacity(0);
+        world.training_run(TrainingData::None { floor_value: 0.0 });
+        assert!(world.hall_of_fame().is_empty());
+    }
+}
@@ -0,0 +1,129 @@
+use super::World;
+use super::run_history::RunHistory;
+
+impl World {
+    /// Starts recording per-epoch statistics in a [`RunHistory`].
+    ///
+    /// Elapsed times and evaluation counts are measured from this call. Calling it again
+    /// while recording keeps the existing history.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use hill_descent_lib::{GlobalConstants, SingleValuedFunction, TrainingData, setup_world};
+    ///
+    /// #[derive(Debug)]
+    /// struct Sphere;
+    ///
+    /// impl SingleValuedFunction for Sphere {
+    ///     fn single_run(&self, params: &[f64]) -> f64 {
+    ///         params.iter().map(|x| x * x).sum()
+    ///     }
+    /// }
+    ///
+    /// let bounds = vec![-5.0..=5.0; 2];
+    /// let mut world = setup_world(&bounds, GlobalConstants::new(100, 10), Box::new(Sphere));
+    /// world.enable_run_history();
+    /// for _ in 0..10 {
+    ///     world.training_run(TrainingData::None { floor_value: 0.0 });
+    /// }
+    ///
+    /// let history = world.run_history().unwrap();
+    /// assert_eq!(history.len(), 10);
+    /// let csv = history.to_csv(); // or history.to_json()
+    /// assert_eq!(csv.lines().count(), 11);
+    /// ```
+    pub fn enable_run_history(&mut self) {
+        self.run_history.get_or_insert_with(RunHistory::new);
+    }
+
+    /// Returns the recorded history, or `None` if recording is off.
+    pub fn run_history(&self) -> Option<&RunHistory> {
+        self.run_history.as_ref()
+    }
+
+    /// Stops recording and returns the history recorded so far.
+    pub fn take_run_history(&mut self) -> Option<RunHistory> {
+        self.run_history.take()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::TrainingData;
+    use crate::parameters::global_constants::GlobalConstants;
+    use crate::world::World;
+    use crate::world::world_function::WorldFunction;
+
+    #[derive(Debug)]
+    struct SumFn;
+    impl WorldFunction for SumFn {
+        fn run(&self, p: &[f64], _v: &[f64]) -> Vec<f64> {
+            vec![p.iter().map(|x| x.abs()).sum::<f64>()]
+        }
+    }
+
+    fn world() -> World {
+        World::new(
+            &[-1.0..=1.0, -1.0..=1.0],
+            GlobalConstants::new_with_seed(60, 4, 11),
+            Box::new(SumFn),
+        )
+    }
+
+    #[test]
+    fn given_new_world_when_history_queried_then_none() {
+        assert!(world().run_history().is_none());
+    }
+
+    #[test]
+    fn given_history_enabled_when_training_then_one_record_per_epoch() {
+        let mut world = world();
+        world.enable_run_history();
+
+        for _ in 0..3 {
+            world.training_run(TrainingData::None { floor_value: 0.0 });
+        }
+
+        let history = world.run_history().unwrap();
+        assert_eq!(history.len(), 3);
+        let epochs: Vec<_> = history.epochs().iter().map(|s| s.epoch()).collect();
+        assert_eq!(epochs, vec![1, 2, 3]);
+        let last = history.last().unwrap();
+        assert_eq!(last.regions(), world.regions.len());
+        assert!(last.best_score().unwrap() <= last.median_score().unwrap());
+        assert!(last.median_score().unwrap() <= last.worst_score().unwrap());
+        assert!(last.best_score().unwrap() <= world.get_best_score());
+        assert!(history.epochs()[0].evaluations() < last.evaluations());
+    }
+
+    #[test]
+    fn given_history_when_taken_then_recording_stops() {
+        let mut world = world();
+        world.enable_run_history();
+        world.training_run(TrainingData::None { floor_value: 0.0 });
+
+        let history = world.take_run_history().unwrap();
+        world.training_run(TrainingData::None { floor_value: 0.0 });
+
+        assert_eq!(history.len(), 1);
+        assert!(world.run_history().is_none());
+    }
+
+    #[test]
+    fn given_history_enabled_when_ask_tell_then_told_results_counted() {
+        let mut world = world();
+        world.enable_run_history();
+        let results: Vec<_> = world
+            .ask()
+            .iter()
+            .map(|c| (c.id(), SumFn.run(c.params(), &[])))
+            .collect();
+
+        world.tell(&results).unwrap();
+
+        let stats = world.run_history().unwrap().last().unwrap();
+        assert_eq!(stats.epoch(), 1);
+        assert_eq!(stats.evaluations(), results.len() as u64);
+    }
+}
@@ -0,0 +1,36 @@
+use super::World;
+use super::snapshot::{SnapshotOptions, WorldSnapshot};
+
+impl World {
+    /// Captures the world's state as a typed [`WorldSnapshot`], with the sections selected by
+    /// `options`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use hill_descent_lib::world::snapshot::{SnapshotOptions, WorldSnapshot};
+    /// use hill_descent_lib::{GlobalConstants, SingleValuedFunction, TrainingData, setup_world};
+    ///
+    /// #[derive(Debug)]
+    /// struct Sphere;
+    ///
+    /// impl SingleValuedFunction for Sphere {
+    ///     fn single_run(&self, params: &[f64]) -> f64 {
+    ///         params.iter().map(|x| x * x).sum()
+    ///     }
+    /// }
+    ///
+    /// let bounds = vec![-5.0..=5.0; 2];
+    /// let mut world = setup_world(&bounds, GlobalConstants::new(100, 10), Box::new(Sphere));
+    /// world.training_run(TrainingData::None { floor_value: 0.0 });
+    ///
+    /// let snapshot = world.snapshot(&SnapshotOptions::new().with_regions(false));
+    /// assert!(snapshot.regions().is_none());
+    /// let json = snapshot.to_json();
+    /// let restored = WorldSnapshot::from_json(&json).unwrap();
+    /// assert_eq!(restored.epoch(), 1);
+    /// ```
+    pub fn snapshot(&self, options: &SnapshotOptions) -> WorldSnapshot {
+        WorldSnapshot::capture(self, options)
+    }
+}
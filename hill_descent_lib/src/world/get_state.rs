@@ -1,86 +1,4 @@
-use serde::Serialize;
-use serde_json;
-
-use super::dimensions::dimension::Dimension;
-use super::organisms::organism::Organism;
-use super::regions::region::{Region, region_key::RegionKey};
-
-// Helper structs purely for serialisation of the World state -----------------
-#[derive(Serialize)]
-struct DimensionState {
-    range: (f64, f64),
-    number_of_doublings: usize,
-}
-
-#[derive(Serialize)]
-struct PhenotypeState {
-    expressed_values: Vec<f64>,
-}
-
-#[derive(Serialize)]
-struct OrganismState {
-    region_key: Option<Vec<usize>>,
-    age: usize,
-    score: Option<f64>,
-    is_dead: bool,
-    phenotype: PhenotypeState,
-}
-
-#[derive(Serialize)]
-struct RegionState {
-    key: Vec<usize>,
-    min_score: Option<f64>,
-    carrying_capacity: Option<usize>,
-    organism_count: usize,
-}
-
-#[derive(Serialize)]
-struct WorldState {
-    dimensions: Vec<DimensionState>,
-    organisms: Vec<OrganismState>,
-    regions: Vec<RegionState>,
-}
-
-impl DimensionState {
-    fn from_dimension(d: &Dimension) -> Self {
-        let range = (*d.range().start(), *d.range().end());
-        Self {
-            range,
-            number_of_doublings: d.number_of_doublings(),
-        }
-    }
-}
-
-impl PhenotypeState {
-    fn from_phenotype(p: &crate::phenotype::Phenotype) -> Self {
-        Self {
-            expressed_values: p.expressed_values().to_vec(),
-        }
-    }
-}
-
-impl OrganismState {
-    fn from_organism(o: &Organism) -> Self {
-        Self {
-            region_key: o.region_key().map(Vec::<usize>::from),
-            age: o.age(),
-            score: o.score(),
-            is_dead: o.is_dead(),
-            phenotype: PhenotypeState::from_phenotype(o.phenotype()),
-        }
-    }
-}
-
-impl RegionState {
-    fn from_region(key: &RegionKey, r: &Region) -> Self {
-        Self {
-            key: Vec::<usize>::from(key),
-            min_score: r.min_score(),
-            carrying_capacity: r.carrying_capacity(),
-            organism_count: r.organism_count(),
-        }
-    }
-}
+use super::snapshot::SnapshotOptions;
 
 impl super::World {
     /// Returns a JSON representation of the complete world state.
@@ -93,35 +11,50 @@ impl super::World {
     /// - **Checkpointing**: Save state for later resumption (though not directly supported)
     /// - **Analysis**: Detailed post-processing of optimization behavior
     ///
+    /// It is the JSON of [`snapshot`](super::World::snapshot) with the default
+    /// [`SnapshotOptions`], so it can be read back with
+    /// [`WorldSnapshot::from_json`](super::snapshot::WorldSnapshot::from_json) rather than
+    /// parsed by hand.
+    ///
     /// # Returns
     ///
     /// A JSON string containing:
     ///
+    /// - **schema_version** and **epoch**: The snapshot schema and completed epochs
     /// - **dimensions**: Parameter ranges and subdivision levels
     /// - **organisms**: Complete population with parameters, scores, ages, regions
-    /// - **regions**: Spatial partitions with capacity and statistics
+    /// - **regions**: Spatial partitions with bounds, capacity and statistics
     ///
     /// # JSON Structure
     ///
     /// ```json
     /// {
+    ///   "schema_version": 1,
+    ///   "epoch": 12,
     ///   "dimensions": [
     ///     {"range": [-10.0, 10.0], "number_of_doublings": 3},
     ///     ...
     ///   ],
     ///   "organisms": [
     ///     {
+    ///       "id": 42,
+    ///       "parent_ids": [20, 35],
     ///       "region_key": [0, 1],
     ///       "age": 5,
     ///       "score": 0.0123,
     ///       "is_dead": false,
-    ///       "phenotype": {"expressed_values": [1.23, 4.56]}
+    ///       "phenotype": {
+    ///         "expressed_values": [0.1, 0.5, 0.001, 0.001, 0.001, 12.0, 2.0, 1.23, 4.56],
+    ///         "system_parameters": {"m1": 0.1, "m2": 0.5, ...},
+    ///         "expressed_hash": 1234567890
+    ///       }
     ///     },
     ///     ...
     ///   ],
     ///   "regions": [
     ///     {
     ///       "key": [0, 0],
+    ///       "bounds": [[-10.0, -7.5], [-10.0, -7.5]],
     ///       "min_score": 0.01,
     ///       "carrying_capacity": 10,
     ///       "organism_count": 8
@@ -245,39 +178,14 @@ impl super::World {
     /// - [`get_best_score`](super::World::get_best_score) - Quick fitness check without full state
     /// - [`get_best_organism`](super::World::get_best_organism) - Extract just the best solution
     pub fn get_state(&self) -> String {
-        let dimensions: Vec<DimensionState> = self
-            .dimensions
-            .get_dimensions()
-            .iter()
-            .map(DimensionState::from_dimension)
-            .collect();
-
-        let organisms: Vec<OrganismState> = self
-            .organisms
-            .iter()
-            .map(|rc| OrganismState::from_organism(rc.as_ref()))
-            .collect();
-
-        let regions: Vec<RegionState> = self
-            .regions
-            .iter_regions()
-            .map(|(k, r)| RegionState::from_region(k, r))
-            .collect();
-
-        let state = WorldState {
-            dimensions,
-            organisms,
-            regions,
-        };
-
-        serde_json::to_string(&state).expect("Serialization of World state failed")
+        self.snapshot(&SnapshotOptions::new()).to_json()
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::*;
     use crate::parameters::global_constants::GlobalConstants;
+    use crate::world::snapshot::WorldSnapshot;
     use crate::world::world_function::WorldFunction;
     use std::ops::RangeInclusive;
 
@@ -447,4 +355,16 @@ mod tests {
 
         assert_eq!(dead_count, 1);
     }
+
+    #[test]
+    fn given_world_when_get_state_then_json_reads_back_as_snapshot() {
+        let bounds: Vec<RangeInclusive<f64>> = vec![0.0..=1.0, 10.0..=11.0];
+        let gc = GlobalConstants::new(10, 4);
+        let world = super::super::World::new(&bounds, gc, Box::new(DummyFn));
+
+        let snapshot = WorldSnapshot::from_json(&world.get_state()).unwrap();
+
+        assert_eq!(snapshot.organisms().unwrap().len(), world.organisms.len());
+        assert_eq!(snapshot.dimensions().len(), 2);
+    }
 }
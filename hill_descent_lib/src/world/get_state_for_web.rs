@@ -1,5 +1,4 @@
-use crate::locus::locus_adjustment::DirectionOfTravel;
-use crate::world::regions::region::region_key::RegionKey;
+use super::snapshot::{PhenotypeSnapshot, SnapshotOptions};
 use serde::Serialize;
 use serde_json;
 
@@ -35,47 +34,6 @@ struct OrganismParamsState {
     y: f64,
 }
 
-// Full detail structures for complete organism information
-#[derive(Serialize, Debug)]
-struct LocusAdjustmentState {
-    adjustment_value: f64,
-    direction_of_travel: String, // "Add" or "Subtract"
-    doubling_or_halving_flag: bool,
-    checksum: u64,
-}
-
-#[derive(Serialize, Debug)]
-struct LocusState {
-    value: f64,
-    adjustment: LocusAdjustmentState,
-    apply_adjustment_flag: bool,
-}
-
-#[derive(Serialize, Debug)]
-struct GameteState {
-    loci: Vec<LocusState>,
-}
-
-#[derive(Serialize, Debug)]
-struct SystemParametersState {
-    m1: f64,
-    m2: f64,
-    m3: f64,
-    m4: f64,
-    m5: f64,
-    max_age: f64,
-    crossover_points: f64,
-}
-
-#[derive(Serialize, Debug)]
-struct PhenotypeState {
-    gamete1: GameteState,
-    gamete2: GameteState,
-    expressed_values: Vec<f64>,
-    system_parameters: SystemParametersState,
-    expressed_hash: u64,
-}
-
 #[derive(Serialize, Debug)]
 struct OrganismState {
     id: usize,
@@ -87,7 +45,7 @@ struct OrganismState {
     is_dead: bool,
     parent_id_1: Option<usize>,
     parent_id_2: Option<usize>,
-    phenotype: PhenotypeState,
+    phenotype: PhenotypeSnapshot,
 }
 
 #[derive(Serialize, Debug)]
@@ -98,69 +56,6 @@ struct WorldState {
     organisms: Vec<OrganismState>,
 }
 
-// Helper functions for converting internal structures to state structures
-impl LocusAdjustmentState {
-    fn from_locus_adjustment(adj: &crate::locus::locus_adjustment::LocusAdjustment) -> Self {
-        Self {
-            adjustment_value: adj.adjustment_value().get(),
-            direction_of_travel: match adj.direction_of_travel() {
-                DirectionOfTravel::Add => "Add".to_string(),
-                DirectionOfTravel::Subtract => "Subtract".to_string(),
-            },
-            doubling_or_halving_flag: adj.doubling_or_halving_flag(),
-            checksum: adj.checksum(),
-        }
-    }
-}
-
-impl LocusState {
-    fn from_locus(locus: &crate::locus::Locus) -> Self {
-        Self {
-            value: locus.value().get(),
-            adjustment: LocusAdjustmentState::from_locus_adjustment(locus.adjustment()),
-            apply_adjustment_flag: locus.apply_adjustment_flag(),
-        }
-    }
-}
-
-impl GameteState {
-    fn from_gamete(gamete: &crate::gamete::Gamete) -> Self {
-        Self {
-            loci: gamete.loci().iter().map(LocusState::from_locus).collect(),
-        }
-    }
-}
-
-impl SystemParametersState {
-    fn from_system_parameters(
-        sys_params: &crate::parameters::system_parameters::SystemParameters,
-    ) -> Self {
-        Self {
-            m1: sys_params.m1(),
-            m2: sys_params.m2(),
-            m3: sys_params.m3(),
-            m4: sys_params.m4(),
-            m5: sys_params.m5(),
-            max_age: sys_params.max_age(),
-            crossover_points: sys_params.crossover_points(),
-        }
-    }
-}
-
-impl PhenotypeState {
-    fn from_phenotype(phenotype: &crate::phenotype::Phenotype) -> Self {
-        Self {
-            gamete1: GameteState::from_gamete(phenotype.gamete1()),
-            gamete2: GameteState::from_gamete(phenotype.gamete2()),
-            expressed_values: phenotype.expressed_values().to_vec(),
-            system_parameters: SystemParametersState::from_system_parameters(
-                phenotype.system_parameters(),
-            ),
-            expressed_hash: phenotype.expressed_hash(),
-        }
-    }
-}
-
 impl super::World {
     /// Returns a JSON representation optimized for 2D web visualization.
     ///
@@ -168,12 +63,14 @@ impl super::World {
     /// the `hill_descent_server` web interface. It formats data specifically for
     /// 2D visualizations and includes additional metadata for rendering.
     ///
-    /// **Note:** This method is specifically designed for 2D worlds and produces a format
-    /// optimized for web-based visualization. For general-purpose state serialization that
-    /// works with any number of dimensions, use [`get_state()`](Self::get_state) instead.
+    /// **Note:** This method is specifically designed for 2D visualization and produces a
+    /// format optimized for web-based rendering. For general-purpose state serialization
+    /// use [`get_state()`](Self::get_state) instead. Both are built from a
+    /// [`snapshot`](Self::snapshot); this one includes the gametes.
     ///
-    /// **Important**: This method only works with 2-dimensional optimization problems
-    /// and will panic if called on worlds with more or fewer than 2 dimensions.
+    /// **Important**: Worlds with more than 2 dimensions are projected onto their first two
+    /// dimensions, so regions that differ only in later dimensions share a bounding box.
+    /// The method panics if called on worlds with fewer than 2 dimensions.
     ///
     /// # Returns
     ///
@@ -278,7 +175,7 @@ impl super::World {
     ///
     /// # Panics
     ///
-    /// Panics if the world is not 2-dimensional:
+    /// Panics if the world has fewer than 2 dimensions:
     ///
     /// ```should_panic
     /// use hill_descent_lib::{setup_world, GlobalConstants, SingleValuedFunction};
@@ -290,13 +187,13 @@ impl super::World {
     /// }
     ///
     /// use hill_descent_lib::TrainingData;
-    /// // 3D world - will panic!
-    /// let param_range = vec![-1.0..=1.0; 3];
+    /// // 1D world - will panic!
+    /// let param_range = vec![-1.0..=1.0; 1];
     /// let constants = GlobalConstants::new(10, 2);
     /// let mut world = setup_world(&param_range, constants, Box::new(F));
     ///
     /// world.training_run(TrainingData::None { floor_value: 0.0 });
-    /// world.get_state_for_web(); // PANICS: not 2D
+    /// world.get_state_for_web(); // PANICS: fewer than 2 dimensions
     /// ```
     ///
     /// # See Also
@@ -306,44 +203,45 @@ impl super::World {
     /// - `web_pdd.md` - Documentation of the web visualization contract
     pub fn get_state_for_web(&self) -> String {
         let dims = self.dimensions.get_dimensions();
-        // This function is specifically for a 2D visualization.
-        // It will panic if the world is not 2D.
-        assert_eq!(dims.len(), 2, "get_state_for_web is only for 2D worlds");
+        // This function is specifically for a 2D visualization of the first two dimensions.
+        // It will panic if the world has fewer than 2 dimensions.
+        assert!(
+            dims.len() >= 2,
+            "get_state_for_web needs at least 2 dimensions"
+        );
 
         let world_bounds = WorldBoundsState {
             x: (*dims[0].range().start(), *dims[0].range().end()),
             y: (*dims[1].range().start(), *dims[1].range().end()),
         };
 
-        let organisms: Vec<OrganismState> = self
-            .organisms
+        let snapshot = self.snapshot(&SnapshotOptions::full());
+
+        let organisms: Vec<OrganismState> = snapshot
+            .organisms()
+            .unwrap_or_default()
             .iter()
-            .filter_map(|o| {
-                if o.is_dead() {
-                    return None; // Filter out dead organisms
-                }
-                let expressed_values = o.phenotype().expressed_values();
+            .filter(|o| !o.is_dead()) // Filter out dead organisms
+            .map(|o| {
+                let phenotype = o.phenotype().clone();
                 // The first two non-system parameters are x and y
                 let params = OrganismParamsState {
-                    x: expressed_values[crate::NUM_SYSTEM_PARAMETERS],
-                    y: expressed_values[crate::NUM_SYSTEM_PARAMETERS + 1],
+                    x: phenotype.problem_values()[0],
+                    y: phenotype.problem_values()[1],
                 };
-                let raw_max_age = o.phenotype().system_parameters().max_age();
-                let rounded_max_age = raw_max_age.round() as usize;
-
                 let (parent_id_1, parent_id_2) = o.parent_ids();
-                Some(OrganismState {
+                OrganismState {
                     id: o.id(),
                     params,
                     age: o.age(),
-                    max_age: rounded_max_age,
+                    max_age: phenotype.system_parameters().max_age().round() as usize,
                     score: o.score(),
-                    region_key: o.region_key().map(Vec::<usize>::from),
+                    region_key: o.region_key().map(<[usize]>::to_vec),
                     is_dead: o.is_dead(),
                     parent_id_1,
                     parent_id_2,
-                    phenotype: PhenotypeState::from_phenotype(o.phenotype()),
-                })
+                    phenotype,
+                }
             })
             .collect();
 
@@ -351,39 +249,27 @@ impl super::World {
         let mut max_score_global = f64::MIN;
 
         // Also capture the region keys so we can validate organism membership precisely.
-        let mut region_keys: Vec<RegionKey> = Vec::new();
+        let mut region_keys: Vec<&[usize]> = Vec::new();
 
-        let regions: Vec<RegionState> = self
-            .regions
-            .iter_regions()
-            .map(|(key, region)| {
+        let regions: Vec<RegionState> = snapshot
+            .regions()
+            .unwrap_or_default()
+            .iter()
+            .map(|region| {
                 if let Some(score) = region.min_score() {
                     min_score_global = min_score_global.min(score);
                     max_score_global = max_score_global.max(score);
                 }
 
-                // Calculate region bounds from key
-                let mut bounds_x = (0.0, 0.0);
-                let mut bounds_y = (0.0, 0.0);
-
-                for (i, &dim_idx) in key.values().iter().enumerate() {
-                    let (start, end) = dims[i]
-                        .interval_bounds(dim_idx)
-                        .expect("Region key contained an out-of-range interval index");
-                    if i == 0 {
-                        bounds_x = (start, end);
-                    } else {
-                        bounds_y = (start, end);
-                    }
-                }
-
                 // Keep the key for later membership validation
-                region_keys.push(key.clone());
+                region_keys.push(region.key());
 
+                // Only the first two dimensions are visualised
+                let bounds = region.bounds();
                 RegionState {
                     bounds: RegionBoundsState {
-                        x: bounds_x,
-                        y: bounds_y,
+                        x: (*bounds[0].start(), *bounds[0].end()),
+                        y: (*bounds[1].start(), *bounds[1].end()),
                     },
                     min_score: region.min_score(),
                     carrying_capacity: region.carrying_capacity().unwrap_or(0),
@@ -406,10 +292,7 @@ impl super::World {
                 .get_interval(org.params.y)
                 .expect("Organism y not in any interval despite dimensions");
 
-            let in_region_key = region_keys.iter().any(|k| {
-                let values = k.values();
-                values.len() == 2 && values[0] == xi && values[1] == yi
-            });
+            let in_region_key = region_keys.iter().any(|k| k[0] == xi && k[1] == yi);
             if !in_region_key {
                 eprintln!("Organism outside any region: {org:?}");
                 eprintln!("Regions: {regions:?}");
@@ -0,0 +1,130 @@
+use super::World;
+use super::views::{DimensionView, OrganismView, RegionView};
+
+impl World {
+    /// Returns a read-only view of each region, in the world's internal order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use hill_descent_lib::{GlobalConstants, SingleValuedFunction, TrainingData, setup_world};
+    ///
+    /// #[derive(Debug)]
+    /// struct Sphere;
+    ///
+    /// impl SingleValuedFunction for Sphere {
+    ///     fn single_run(&self, params: &[f64]) -> f64 {
+    ///         params.iter().map(|x| x * x).sum()
+    ///     }
+    /// }
+    ///
+    /// let bounds = vec![-5.0..=5.0; 2];
+    /// let mut world = setup_world(&bounds, GlobalConstants::new(100, 10), Box::new(Sphere));
+    /// world.training_run(TrainingData::None { floor_value: 0.0 });
+    ///
+    /// for region in world.regions() {
+    ///     let extent: Vec<_> = region.bounds();
+    ///     println!("{:?}: {} organisms in {extent:?}", region.key(), region.organism_count());
+    /// }
+    /// for dimension in world.dimensions() {
+    ///     println!("{:?} halved {} times", dimension.range(), dimension.doublings());
+    /// }
+    /// let placed: usize = world.regions().map(|r| r.organism_count()).sum();
+    /// assert_eq!(placed, world.organisms().len());
+    /// ```
+    pub fn regions(&self) -> impl ExactSizeIterator<Item = RegionView<'_>> {
+        self.regions
+            .iter_regions()
+            .map(|(key, region)| RegionView::new(key, region, &self.dimensions))
+    }
+
+    /// Returns a read-only view of each problem parameter's dimension, in parameter order.
+    pub fn dimensions(&self) -> impl ExactSizeIterator<Item = DimensionView<'_>> {
+        self.dimensions
+            .get_dimensions()
+            .iter()
+            .map(DimensionView::new)
+    }
+
+    /// Returns a read-only view of each organism in the current population.
+    pub fn organisms(&self) -> impl ExactSizeIterator<Item = OrganismView<'_>> {
+        self.organisms
+            .iter()
+            .map(|organism| OrganismView::new(organism))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::TrainingData;
+    use crate::parameters::global_constants::GlobalConstants;
+    use crate::world::World;
+    use crate::world::world_function::WorldFunction;
+
+    #[derive(Debug)]
+    struct SumFn;
+    impl WorldFunction for SumFn {
+        fn run(&self, p: &[f64], _v: &[f64]) -> Vec<f64> {
+            vec![p.iter().map(|x| x.abs()).sum::<f64>()]
+        }
+    }
+
+    fn trained_world() -> World {
+        let mut world = World::new(
+            &[-1.0..=1.0, -2.0..=2.0],
+            GlobalConstants::new_with_seed(60, 4, 11),
+            Box::new(SumFn),
+        );
+        for _ in 0..3 {
+            world.training_run(TrainingData::None { floor_value: 0.0 });
+        }
+        world
+    }
+
+    #[test]
+    fn given_world_when_dimensions_viewed_then_one_per_parameter() {
+        let world = trained_world();
+
+        let dimensions: Vec<_> = world.dimensions().collect();
+
+        assert_eq!(dimensions.len(), 2);
+        for dimension in &dimensions {
+            assert_eq!(dimension.intervals(), 1 << dimension.doublings());
+            let first = dimension.interval_bounds(0).unwrap();
+            assert_eq!(first.start(), dimension.range().start());
+            assert!(dimension.interval_bounds(dimension.intervals()).is_none());
+        }
+    }
+
+    #[test]
+    fn given_world_when_regions_viewed_then_organisms_lie_within_bounds() {
+        let world = trained_world();
+
+        assert_eq!(world.regions().len(), world.regions.len());
+        let mut placed = 0;
+        for region in world.regions() {
+            let bounds = region.bounds();
+            assert_eq!(bounds.len(), 2);
+            assert_eq!(region.organisms().len(), region.organism_count());
+            for organism in region.organisms() {
+                assert_eq!(organism.region_key().as_deref(), Some(region.key()));
+                for (value, range) in organism.params().iter().zip(&bounds) {
+                    assert!(range.contains(value), "{value} outside {range:?}");
+                }
+            }
+            placed += region.organism_count();
+        }
+        assert_eq!(placed, world.organisms().len());
+    }
+
+    #[test]
+    fn given_world_when_organisms_viewed_then_match_population() {
+        let world = trained_world();
+
+        let ids: Vec<_> = world.organisms().map(|o| o.id()).collect();
+        let expected: Vec<_> = world.organisms.iter().map(|o| o.id()).collect();
+
+        assert_eq!(ids, expected);
+        assert!(world.organisms().all(|o| o.params().len() == 2));
+    }
+}
@@ -0,0 +1,176 @@
+use super::organisms::Organism;
+
+/// A solution recorded in the [`HallOfFame`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct HallOfFameEntry {
+    score: f64,
+    params: Vec<f64>,
+    organism_id: usize,
+}
+
+impl HallOfFameEntry {
+    /// Returns the score the solution achieved when it was recorded.
+    pub fn score(&self) -> f64 {
+        self.score
+    }
+
+    /// Returns the problem parameters of the solution.
+    pub fn params(&self) -> &[f64] {
+        &self.params
+    }
+
+    /// Returns the ID of the organism that expressed the solution.
+    pub fn organism_id(&self) -> usize {
+        self.organism_id
+    }
+}
+
+/// An archive of the best distinct solutions seen over the lifetime of a world.
+///
+/// Entries are kept sorted best first. Two solutions are distinct if their problem
+/// parameters differ; when the same parameters are seen again only the better score is
+/// kept. Unlike the population, the archive is never affected by ageing or truncation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HallOfFame {
+    capacity: usize,
+    entries: Vec<HallOfFameEntry>,
+}
+
+impl HallOfFame {
+    /// The number of entries kept by a new world.
+    pub const DEFAULT_CAPACITY: usize = 10;
+
+    /// Creates an empty archive holding at most `capacity` entries.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            entries: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// Returns the maximum number of entries.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the entries, best first.
+    pub fn entries(&self) -> &[HallOfFameEntry] {
+        &self.entries
+    }
+
+    /// Returns the best entry, if any.
+    pub fn best(&self) -> Option<&HallOfFameEntry> {
+        self.entries.first()
+    }
+
+    /// Returns the number of entries.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if nothing has been recorded.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Changes the capacity, dropping the worst entries if it shrinks.
+    pub(crate) fn set_capacity(&mut self, capacity: usize) {
+        self.capacity = capacity;
+        self.entries.truncate(capacity);
+    }
+
+    /// Records the organism's solution if it has a finite score that earns it a place.
+    pub(crate) fn consider(&mut self, organism: &Organism) {
+        let Some(score) = organism.score().filter(|s| s.is_finite()) else {
+            return;
+        };
+        if self.capacity == 0
+            || (self.entries.len() == self.capacity
+                && self
+                    .entries
+                    .last()
+                    .is_some_and(|worst| score >= worst.score))
+        {
+            return;
+        }
+
+        let params = organism.phenotype().expression_problem_values();
+        if let Some(existing) = self.entries.iter().position(|e| e.params == params) {
+            if score >= self.entries[existing].score {
+                return;
+            }
+            self.entries.remove(existing);
+        }
+
+        let position = self.entries.partition_point(|e| e.score <= score);
+        self.entries.insert(
+            position,
+            HallOfFameEntry {
+                score,
+                params: params.to_vec(),
+                organism_id: organism.id(),
+            },
+        );
+        self.entries.truncate(self.capacity);
+    }
+}
+
+impl Default for HallOfFame {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_CAPACITY)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::world::selection_strategy::tests::make_org;
+
+    #[test]
+    fn given_organisms_when_consider_then_entries_sorted_best_first_and_capped() {
+        let mut hall = HallOfFame::new(2);
+        for (i, score) in [3.0, 1.0, 2.0].into_iter().enumerate() {
+            hall.consider(&make_org(score, 0, i));
+        }
+
+        let scores: Vec<f64> = hall.entries().iter().map(|e| e.score()).collect();
+        assert_eq!(scores, vec![1.0, 2.0]);
+        assert_eq!(hall.best().unwrap().params(), &[1.0]);
+    }
+
+    #[test]
+    fn given_same_params_seen_twice_when_consider_then_kept_once_with_best_score() {
+        let mut hall = HallOfFame::new(5);
+        hall.consider(&make_org(4.0, 0, 7));
+        hall.consider(&make_org(2.0, 0, 7));
+        hall.consider(&make_org(3.0, 0, 7));
+
+        assert_eq!(hall.len(), 1);
+        assert_eq!(hall.best().unwrap().score(), 2.0);
+    }
+
+    #[test]
+    fn given_unscored_or_non_finite_organism_when_consider_then_ignored() {
+        let mut hall = HallOfFame::default();
+        let unscored = make_org(1.0, 0, 0);
+        unscored.set_score(None);
+        hall.consider(&unscored);
+        hall.consider(&make_org(f64::NAN, 0, 1));
+
+        assert!(hall.is_empty());
+    }
+
+    #[test]
+    fn given_smaller_capacity_when_set_capacity_then_worst_entries_dropped() {
+        let mut hall = HallOfFame::new(3);
+        for (i, score) in [1.0, 2.0, 3.0].into_iter().enumerate() {
+            hall.consider(&make_org(score, 0, i));
+        }
+
+        hall.set_capacity(1);
+
+        assert_eq!(hall.len(), 1);
+        assert_eq!(hall.capacity(), 1);
+        assert_eq!(hall.best().unwrap().score(), 1.0);
+    }
+}
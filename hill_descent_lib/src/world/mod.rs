@@ -37,27 +37,63 @@
 
 use crate::parameters::global_constants::GlobalConstants;
 use crate::world::dimensions::Dimensions;
+use crossover_operator::CrossoverOperator;
+use elitism::Elitism;
+use genealogy::Genealogy;
+use hall_of_fame::HallOfFame;
+use mutation_operator::MutationOperator;
 use organisms::Organisms;
 use rand::SeedableRng;
 use rand::rngs::StdRng;
 use regions::Regions; // Required for StdRng::from_seed
+use run_history::RunHistory;
+use selection_strategy::{ExtremePairing, SelectionStrategy};
 use std::ops::RangeInclusive;
 
 use world_function::WorldFunction;
 
+pub mod ask_tell;
+pub mod crossover_operator;
+mod dimension_std_devs;
 mod dimensions;
+pub mod elitism;
+mod epoch;
+pub mod export;
 mod format_score;
+pub mod genealogy;
 mod get_best_organism;
 mod get_best_params;
 mod get_best_score;
+mod get_diversity;
+mod get_frame_for_web;
+mod get_genealogy;
+mod get_hall_of_fame;
+mod get_run_history;
+mod get_snapshot;
 mod get_state;
 mod get_state_for_web;
+mod get_views;
+pub mod hall_of_fame;
+pub mod mutation_operator;
 pub mod organisms;
+pub mod population_diversity;
 mod regions;
 mod remove_dead;
+pub(crate) mod reproduction_operators;
+mod run_epoch;
+pub mod run_history;
+pub mod selection_strategy;
+mod set_crossover_operator;
+mod set_elitism;
+mod set_mutation_operator;
+mod set_selection_strategy;
 pub mod single_valued_function;
+pub mod snapshot;
 mod training_run;
+pub mod views;
+pub mod web_frame;
 pub mod world_function;
+mod write_snapshot;
 
 pub use format_score::format_score;
 
@@ -70,6 +106,17 @@ pub use format_score::format_score;
 /// - **Spatial regions**: Adaptive partitioning of the parameter space
 /// - **Evaluation function**: Your fitness function implementing [`single_valued_function::SingleValuedFunction`] or [`world_function::WorldFunction`]
 /// - **Configuration**: Global constants controlling population size, regions, etc.
+/// - **Selection strategy**: How parents are paired within a region (see [`selection_strategy`])
+/// - **Crossover operator**: How parent values are recombined (see [`crossover_operator`])
+/// - **Mutation operator**: How offspring values are perturbed (see [`mutation_operator`])
+/// - **Elitism**: Which organisms are exempt from death (see [`elitism`])
+/// - **Hall of fame**: The best distinct solutions ever seen (see [`hall_of_fame`])
+/// - **Run history**: Optional per-epoch statistics (see [`run_history`])
+/// - **Diversity**: How spread out the population is (see [`population_diversity`])
+/// - **Genealogy**: Optional record of births for lineage queries (see [`genealogy`])
+///
+/// Regions, dimensions and organisms can be inspected through the read-only [`views`], and
+/// the whole state captured as a typed [`snapshot`].
 ///
 /// # Core Workflow
 ///
@@ -162,6 +209,14 @@ pub struct World {
     #[allow(dead_code)] // Kept for backward compatibility; replaced by per-region RNG
     rng: StdRng,
     world_function: Box<dyn WorldFunction>,
+    selection_strategy: Box<dyn SelectionStrategy>,
+    crossover_operator: CrossoverOperator,
+    mutation_operator: MutationOperator,
+    elitism: Elitism,
+    hall_of_fame: HallOfFame,
+    run_history: Option<RunHistory>,
+    genealogy: Option<Genealogy>,
+    epoch: u64,
     global_constants: GlobalConstants,
 }
 
@@ -229,6 +284,14 @@ impl World {
             regions,
             rng,
             world_function: function,
+            selection_strategy: Box::new(ExtremePairing),
+            crossover_operator: CrossoverOperator::default(),
+            mutation_operator: MutationOperator::default(),
+            elitism: Elitism::default(),
+            hall_of_fame: HallOfFame::default(),
+            run_history: None,
+            genealogy: None,
+            epoch: 0,
             global_constants,
         }
     }
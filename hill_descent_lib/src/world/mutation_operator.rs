@@ -0,0 +1,275 @@
+use rand::Rng;
+use rand::distr::{Open01, OpenClosed01};
+
+/// Mutation operator applied to problem-parameter loci when gametes are copied.
+///
+/// Every operator keeps the PDD mutation probabilities: `m3`/`m4` still flip the doubling
+/// flag and direction of travel, `m5` decides whether the step size changes and `m1`/`m2`
+/// toggle whether the step is applied at all. The operators differ in how the step size
+/// (the locus adjustment value) evolves and in how a step is drawn from it.
+///
+/// Neither the step size nor a single step may exceed the span of the parameter's initial
+/// bounds, so heavy-tailed draws and self-adaptation cannot throw a value arbitrarily far.
+///
+/// System-parameter loci (mutation rates, max age, crossover points) always follow the
+/// doubling/halving rules of PDD §5.2.4.
+///
+/// # Examples
+///
+/// ```
+/// use hill_descent_lib::{GlobalConstants, SingleValuedFunction, setup_world};
+/// use hill_descent_lib::world::mutation_operator::MutationOperator;
+///
+/// #[derive(Debug)]
+/// struct Sphere;
+///
+/// impl SingleValuedFunction for Sphere {
+///     fn single_run(&self, params: &[f64]) -> f64 {
+///         params.iter().map(|x| x * x).sum()
+///     }
+/// }
+///
+/// let bounds = vec![-5.0..=5.0; 2];
+/// let mut world = setup_world(&bounds, GlobalConstants::new(100, 10), Box::new(Sphere));
+/// world.set_mutation_operator(MutationOperator::Gaussian { learning_rate: 0.3 });
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum MutationOperator {
+    /// The PDD rule (the original behaviour).
+    ///
+    /// The step size doubles or halves when `m5` fires and the value moves by exactly the
+    /// step size in the locus's direction of travel.
+    #[default]
+    DoublingHalving,
+    /// Gaussian mutation with self-adaptive sigma, as in evolution strategies.
+    ///
+    /// When `m5` fires the step size is multiplied by `exp(learning_rate · N(0, 1))`. A
+    /// step is `sigma · N(0, 1)`. `learning_rate` must be finite and non-negative; values
+    /// around `1/sqrt(2n)` for `n` problem parameters are customary.
+    Gaussian { learning_rate: f64 },
+    /// Cauchy mutation with self-adaptive sigma.
+    ///
+    /// Adapts the step size as [`MutationOperator::Gaussian`] does, but draws steps from a
+    /// Cauchy distribution whose heavy tails give occasional long jumps.
+    Cauchy { learning_rate: f64 },
+    /// Polynomial mutation.
+    ///
+    /// The step size keeps the doubling/halving rule and bounds a step of
+    /// `sigma · delta`, where `delta` in `[-1, 1]` follows Deb's polynomial distribution
+    /// with distribution index `eta`. Larger values keep steps small. `eta` must be finite
+    /// and non-negative; 20 is typical.
+    Polynomial { eta: f64 },
+}
+
+impl MutationOperator {
+    /// Panics if the operator's parameters are out of range.
+    pub(crate) fn validate(&self) {
+        match *self {
+            MutationOperator::Gaussian { learning_rate }
+            | MutationOperator::Cauchy { learning_rate } => assert!(
+                learning_rate.is_finite() && learning_rate >= 0.0,
+                "learning_rate must be finite and non-negative"
+            ),
+            MutationOperator::Polynomial { eta } => assert!(
+                eta.is_finite() && eta >= 0.0,
+                "Polynomial eta must be finite and non-negative"
+            ),
+            MutationOperator::DoublingHalving => {}
+        }
+    }
+
+    /// Returns the mutated step size for a locus whose `m5` mutation fired, at most
+    /// `max_step`.
+    pub(crate) fn adapt_step_size<R: Rng>(
+        &self,
+        sigma: f64,
+        doubling: bool,
+        max_step: f64,
+        rng: &mut R,
+    ) -> f64 {
+        let adapted = match *self {
+            MutationOperator::Gaussian { learning_rate }
+            | MutationOperator::Cauchy { learning_rate } => {
+                sigma * (learning_rate * standard_normal(rng)).exp()
+            }
+            MutationOperator::DoublingHalving | MutationOperator::Polynomial { .. } => {
+                if doubling {
+                    sigma * 2.0
+                } else {
+                    sigma / 2.0
+                }
+            }
+        };
+        adapted.min(max_step)
+    }
+
+    /// Draws a signed step from the step size `sigma`, at most `max_step` either way.
+    ///
+    /// `sign` is the direction of travel (+1 or -1) and is only used by `DoublingHalving`.
+    pub(crate) fn sample_step<R: Rng>(
+        &self,
+        sigma: f64,
+        sign: f64,
+        max_step: f64,
+        rng: &mut R,
+    ) -> f64 {
+        let step = match *self {
+            MutationOperator::DoublingHalving => sign * sigma,
+            MutationOperator::Gaussian { .. } => sigma * standard_normal(rng),
+            MutationOperator::Cauchy { .. } => {
+                // u must avoid both ends, where the tangent is unbounded
+                let u: f64 = rng.sample(Open01);
+                sigma * (std::f64::consts::PI * (u - 0.5)).tan()
+            }
+            MutationOperator::Polynomial { eta } => {
+                let u: f64 = rng.random_range(0.0..1.0);
+                let delta = if u < 0.5 {
+                    (2.0 * u).powf(1.0 / (eta + 1.0)) - 1.0
+                } else {
+                    1.0 - (2.0 * (1.0 - u)).powf(1.0 / (eta + 1.0))
+                };
+                sigma * delta
+            }
+        };
+        step.clamp(-max_step, max_step)
+    }
+}
+
+/// Draws from `N(0, 1)` using the Box-Muller transform.
+fn standard_normal<R: Rng>(rng: &mut R) -> f64 {
+    let u1: f64 = rng.sample(OpenClosed01);
+    let u2: f64 = rng.random_range(0.0..1.0);
+    (-2.0 * u1.ln()).sqrt() * (2.0 * std::f64::consts::PI * u2).cos()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand::rngs::SmallRng;
+
+    const UNLIMITED: f64 = f64::INFINITY;
+
+    #[test]
+    fn given_default_when_created_then_is_doubling_halving() {
+        assert_eq!(
+            MutationOperator::default(),
+            MutationOperator::DoublingHalving
+        );
+    }
+
+    #[test]
+    fn given_doubling_halving_when_adapt_step_size_then_doubles_or_halves() {
+        let op = MutationOperator::DoublingHalving;
+        let mut rng = SmallRng::seed_from_u64(0);
+        assert_eq!(op.adapt_step_size(0.5, true, UNLIMITED, &mut rng), 1.0);
+        assert_eq!(op.adapt_step_size(0.5, false, UNLIMITED, &mut rng), 0.25);
+    }
+
+    #[test]
+    fn given_doubling_halving_when_sample_step_then_moves_by_sigma_in_direction() {
+        let op = MutationOperator::DoublingHalving;
+        let mut rng = SmallRng::seed_from_u64(0);
+        assert_eq!(op.sample_step(0.5, -1.0, UNLIMITED, &mut rng), -0.5);
+    }
+
+    #[test]
+    fn given_gaussian_with_zero_learning_rate_when_adapt_step_size_then_unchanged() {
+        let op = MutationOperator::Gaussian { learning_rate: 0.0 };
+        let mut rng = SmallRng::seed_from_u64(1);
+        assert_eq!(op.adapt_step_size(0.7, true, UNLIMITED, &mut rng), 0.7);
+    }
+
+    #[test]
+    fn given_gaussian_when_adapt_step_size_then_stays_positive_and_varies() {
+        let op = MutationOperator::Gaussian { learning_rate: 0.5 };
+        let mut rng = SmallRng::seed_from_u64(2);
+        let sizes: Vec<f64> = (0..100)
+            .map(|_| op.adapt_step_size(1.0, false, UNLIMITED, &mut rng))
+            .collect();
+        assert!(sizes.iter().all(|s| *s > 0.0));
+        assert!(sizes.iter().any(|s| *s > 1.0));
+        assert!(sizes.iter().any(|s| *s < 1.0));
+    }
+
+    #[test]
+    fn given_gaussian_when_sample_step_many_times_then_mean_near_zero_and_spread_near_sigma() {
+        let op = MutationOperator::Gaussian { learning_rate: 0.1 };
+        let mut rng = SmallRng::seed_from_u64(3);
+        let n = 20_000;
+        let steps: Vec<f64> = (0..n)
+            .map(|_| op.sample_step(2.0, 1.0, UNLIMITED, &mut rng))
+            .collect();
+        let mean = steps.iter().sum::<f64>() / n as f64;
+        let var = steps.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / n as f64;
+        assert!(mean.abs() < 0.1, "mean {mean}");
+        assert!((var.sqrt() - 2.0).abs() < 0.1, "std dev {}", var.sqrt());
+    }
+
+    #[test]
+    fn given_cauchy_when_sample_step_then_steps_are_finite_and_both_signs() {
+        let op = MutationOperator::Cauchy { learning_rate: 0.1 };
+        let mut rng = SmallRng::seed_from_u64(4);
+        let steps: Vec<f64> = (0..1000)
+            .map(|_| op.sample_step(1.0, 1.0, UNLIMITED, &mut rng))
+            .collect();
+        assert!(steps.iter().all(|s| s.is_finite()));
+        assert!(steps.iter().any(|s| *s > 0.0));
+        assert!(steps.iter().any(|s| *s < 0.0));
+    }
+
+    #[test]
+    fn given_polynomial_when_sample_step_then_bounded_by_sigma() {
+        let op = MutationOperator::Polynomial { eta: 20.0 };
+        let mut rng = SmallRng::seed_from_u64(5);
+        for _ in 0..1000 {
+            let step = op.sample_step(0.3, 1.0, UNLIMITED, &mut rng);
+            assert!(step.abs() <= 0.3);
+        }
+    }
+
+    #[test]
+    fn given_max_step_when_cauchy_sampled_then_heavy_tail_is_capped() {
+        let op = MutationOperator::Cauchy { learning_rate: 0.1 };
+        let mut rng = SmallRng::seed_from_u64(6);
+
+        let steps: Vec<f64> = (0..10_000)
+            .map(|_| op.sample_step(1.0, 1.0, 5.0, &mut rng))
+            .collect();
+
+        assert!(steps.iter().all(|s| s.abs() <= 5.0));
+        // The tails still reach the cap on both sides
+        assert!(steps.contains(&5.0) && steps.contains(&-5.0));
+    }
+
+    #[test]
+    fn given_max_step_when_step_size_self_adapts_then_never_exceeds_it() {
+        let mut rng = SmallRng::seed_from_u64(7);
+        for op in [
+            MutationOperator::Gaussian { learning_rate: 5.0 },
+            MutationOperator::Cauchy { learning_rate: 5.0 },
+            MutationOperator::Polynomial { eta: 20.0 },
+        ] {
+            let mut sigma = 1.0;
+            for _ in 0..1000 {
+                sigma = op.adapt_step_size(sigma, true, 4.0, &mut rng);
+                assert!(sigma > 0.0 && sigma <= 4.0, "{op:?} gave {sigma}");
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "learning_rate must be finite and non-negative")]
+    fn given_negative_learning_rate_when_validate_then_panics() {
+        MutationOperator::Cauchy {
+            learning_rate: -1.0,
+        }
+        .validate();
+    }
+
+    #[test]
+    #[should_panic(expected = "Polynomial eta must be finite and non-negative")]
+    fn given_infinite_eta_when_validate_then_panics() {
+        MutationOperator::Polynomial { eta: f64::INFINITY }.validate();
+    }
+}
@@ -23,6 +23,13 @@ impl Organism {
             self.mark_dead();
         }
     }
+
+    /// Atomically increments the organism's age by 1 without applying the `max_age` limit.
+    ///
+    /// Used for elites, which are exempt from death by old age.
+    pub fn increment_age_as_elite(&self) {
+        self.age.fetch_add(1, Ordering::Relaxed);
+    }
 }
 
 #[cfg(test)]
@@ -54,4 +61,13 @@ mod tests {
         assert_eq!(organism.age(), 1);
         assert!(organism.is_dead());
     }
+
+    #[test]
+    fn given_organism_when_increment_age_as_elite_past_max_then_stays_alive() {
+        let phenotype = phenotype_with_max_age(0.0);
+        let organism = super::Organism::new(Arc::clone(&phenotype), 0, (None, None));
+        organism.increment_age_as_elite();
+        assert_eq!(organism.age(), 1);
+        assert!(!organism.is_dead());
+    }
 }
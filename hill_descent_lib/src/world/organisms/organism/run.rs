@@ -38,6 +38,19 @@ impl Organism {
         let phenotype_expressed_values = phenotype.expression_problem_values();
         let outputs = function.run(phenotype_expressed_values, inputs);
 
+        self.score_outputs(&outputs, known_outputs);
+    }
+
+    /// Sets the organism's score from outputs evaluated elsewhere, e.g. reported through
+    /// [`World::tell`](crate::World::tell).
+    ///
+    /// The score is computed exactly as in [`run`](Self::run).
+    ///
+    /// # Panics
+    ///
+    /// Panics under the same conditions as [`run`](Self::run), apart from those about
+    /// `known_outputs`.
+    pub fn score_outputs(&self, outputs: &[f64], known_outputs: &[f64]) {
         // Validate output count matches known_outputs
         if outputs.len() != known_outputs.len() {
             panic!(
@@ -0,0 +1,262 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::dimension_std_devs::dimension_std_devs;
+use super::organisms::Organism;
+
+/// Largest population whose pairwise distances are all measured; larger populations are
+/// sampled.
+pub const MAX_EXACT_PAIRWISE_POPULATION: usize = 1_000;
+
+/// Pairs measured when the population is too large to measure every pair.
+pub const SAMPLED_PAIRS: usize = 100_000;
+
+/// How spread out a population is, returned by [`World::diversity`](crate::World::diversity).
+///
+/// A run has collapsed when the pairwise distance and standard deviations approach zero,
+/// few distinct solutions remain and the organisms crowd into a handful of regions.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PopulationDiversity {
+    organisms: usize,
+    mean_pairwise_distance: f64,
+    pairs_measured: usize,
+    dimension_std_devs: Vec<f64>,
+    distinct_expressed_hashes: usize,
+    populated_regions: usize,
+    region_entropy: f64,
+}
+
+impl PopulationDiversity {
+    /// Measures `population`, sampling pairs with `seed` when it has more than
+    /// [`MAX_EXACT_PAIRWISE_POPULATION`] organisms.
+    pub(crate) fn measure(population: &[Arc<Organism>], seed: u64) -> Self {
+        let values: Vec<&[f64]> = population
+            .iter()
+            .map(|o| o.phenotype().expression_problem_values())
+            .collect();
+        let (mean_pairwise_distance, pairs_measured) = mean_pairwise_distance(&values, seed);
+
+        let distinct_expressed_hashes = population
+            .iter()
+            .map(|o| o.phenotype().expressed_hash())
+            .collect::<HashSet<_>>()
+            .len();
+
+        let mut occupancy = HashMap::new();
+        for key in population.iter().filter_map(|o| o.region_key()) {
+            *occupancy.entry(key).or_insert(0usize) += 1;
+        }
+        // Summed in a fixed order so the entropy does not depend on the map's iteration order
+        let mut counts: Vec<usize> = occupancy.values().copied().collect();
+        counts.sort_unstable();
+        let placed: usize = counts.iter().sum();
+        let region_entropy = counts
+            .iter()
+            .map(|&count| {
+                let share = count as f64 / placed as f64;
+                -share * share.ln()
+            })
+            .sum::<f64>()
+            .max(0.0);
+
+        Self {
+            organisms: population.len(),
+            mean_pairwise_distance,
+            pairs_measured,
+            dimension_std_devs: dimension_std_devs(&values),
+            distinct_expressed_hashes,
+            populated_regions: occupancy.len(),
+            region_entropy,
+        }
+    }
+
+    /// Returns the number of organisms measured.
+    pub fn organisms(&self) -> usize {
+        self.organisms
+    }
+
+    /// Returns the mean Euclidean distance between the problem values of two organisms,
+    /// or 0 for fewer than two organisms.
+    pub fn mean_pairwise_distance(&self) -> f64 {
+        self.mean_pairwise_distance
+    }
+
+    /// Returns the number of pairs behind [`mean_pairwise_distance`](Self::mean_pairwise_distance):
+    /// every pair, or [`SAMPLED_PAIRS`] random pairs for large populations.
+    pub fn pairs_measured(&self) -> usize {
+        self.pairs_measured
+    }
+
+    /// Returns `true` if the pairwise distance was estimated from a sample of pairs.
+    pub fn is_sampled(&self) -> bool {
+        self.organisms > MAX_EXACT_PAIRWISE_POPULATION
+    }
+
+    /// Returns the population standard deviation of each problem parameter.
+    pub fn dimension_std_devs(&self) -> &[f64] {
+        &self.dimension_std_devs
+    }
+
+    /// Returns the number of distinct expressed value hashes, a count of distinct solutions.
+    pub fn distinct_expressed_hashes(&self) -> usize {
+        self.distinct_expressed_hashes
+    }
+
+    /// Returns the number of regions holding at least one organism.
+    pub fn populated_regions(&self) -> usize {
+        self.populated_regions
+    }
+
+    /// Returns the Shannon entropy, in nats, of the share of organisms in each region.
+    ///
+    /// It is 0 when every organism is in one region and `ln(populated_regions)` when they
+    /// are spread evenly. Organisms without a region are ignored.
+    pub fn region_entropy(&self) -> f64 {
+        self.region_entropy
+    }
+}
+
+/// Returns the mean distance between pairs of points and the number of pairs measured.
+///
+/// Distances are computed in parallel but summed in a fixed order, so the result does not
+/// depend on how Rayon splits the work.
+fn mean_pairwise_distance(values: &[&[f64]], seed: u64) -> (f64, usize) {
+    let n = values.len();
+    if n < 2 {
+        return (0.0, 0);
+    }
+
+    let distances: Vec<f64> = if n <= MAX_EXACT_PAIRWISE_POPULATION {
+        (0..n)
+            .into_par_iter()
+            .map(|i| {
+                values[i + 1..]
+                    .iter()
+                    .map(|other| distance(values[i], other))
+                    .sum::<f64>()
+            })
+            .collect()
+    } else {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let pairs: Vec<(usize, usize)> = (0..SAMPLED_PAIRS)
+            .map(|_| {
+                let i = rng.random_range(0..n);
+                // Skip i itself by shifting the upper part of the range
+                let j = rng.random_range(0..n - 1);
+                (i, if j >= i { j + 1 } else { j })
+            })
+            .collect();
+        pairs
+            .par_iter()
+            .map(|&(i, j)| distance(values[i], values[j]))
+            .collect()
+    };
+    let pairs = if n <= MAX_EXACT_PAIRWISE_POPULATION {
+        n * (n - 1) / 2
+    } else {
+        SAMPLED_PAIRS
+    };
+    (distances.iter().sum::<f64>() / pairs as f64, pairs)
+}
+
+fn distance(a: &[f64], b: &[f64]) -> f64 {
+    a.iter()
+        .zip(b)
+        .map(|(x, y)| (x - y) * (x - y))
+        .sum::<f64>()
+        .sqrt()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::phenotype::Phenotype;
+    use crate::world::regions::region::region_key::RegionKey;
+
+    fn organism(problem_values: &[f64], region: Option<Vec<usize>>) -> Arc<Organism> {
+        let mut expressed = vec![0.1, 0.5, 0.001, 0.001, 0.001, 100.0, 2.0];
+        expressed.extend_from_slice(problem_values);
+        let phenotype = Arc::new(Phenotype::new_for_test(expressed));
+        let organism = Arc::new(Organism::new(phenotype, 0, (None, None)));
+        organism.set_region_key(region.map(RegionKey::new));
+        organism
+    }
+
+    #[test]
+    fn given_small_population_when_measured_then_exact_metrics() {
+        let population = vec![
+            organism(&[0.0, 0.0], Some(vec![0])),
+            organism(&[3.0, 4.0], Some(vec![1])),
+            organism(&[3.0, 4.0], None),
+        ];
+
+        let diversity = PopulationDiversity::measure(&population, 1);
+
+        assert_eq!(diversity.organisms(), 3);
+        // Distances: 5, 5 and 0
+        assert!((diversity.mean_pairwise_distance() - 10.0 / 3.0).abs() < 1e-12);
+        assert_eq!(diversity.pairs_measured(), 3);
+        assert!(!diversity.is_sampled());
+        assert!((diversity.dimension_std_devs()[0] - 2.0f64.sqrt()).abs() < 1e-12);
+        assert_eq!(diversity.distinct_expressed_hashes(), 2);
+        assert_eq!(diversity.populated_regions(), 2);
+        assert!((diversity.region_entropy() - 2.0f64.ln()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn given_collapsed_population_when_measured_then_no_diversity() {
+        let population: Vec<_> = (0..4)
+            .map(|_| organism(&[1.5, -2.0], Some(vec![3, 3])))
+            .collect();
+
+        let diversity = PopulationDiversity::measure(&population, 1);
+
+        assert_eq!(diversity.mean_pairwise_distance(), 0.0);
+        assert_eq!(diversity.dimension_std_devs(), &[0.0, 0.0]);
+        assert_eq!(diversity.distinct_expressed_hashes(), 1);
+        assert_eq!(diversity.region_entropy(), 0.0);
+    }
+
+    #[test]
+    fn given_converged_population_at_large_offset_when_measured_then_std_devs_accurate() {
+        let population: Vec<_> = (0..4)
+            .map(|i| organism(&[-1.5e7 + i as f64 * 1e-4], None))
+            .collect();
+
+        let diversity = PopulationDiversity::measure(&population, 1);
+
+        let expected = 1.25e-8f64.sqrt();
+        assert!((diversity.dimension_std_devs()[0] - expected).abs() < 1e-3 * expected);
+    }
+
+    #[test]
+    fn given_large_population_when_measured_then_pairs_sampled_reproducibly() {
+        let population: Vec<_> = (0..MAX_EXACT_PAIRWISE_POPULATION + 1)
+            .map(|i| organism(&[(i % 2) as f64], None))
+            .collect();
+
+        let first = PopulationDiversity::measure(&population, 7);
+        let second = PopulationDiversity::measure(&population, 7);
+
+        assert!(first.is_sampled());
+        assert_eq!(first.pairs_measured(), SAMPLED_PAIRS);
+        assert_eq!(first, second);
+        // Half of all pairs differ by 1, so the sampled mean is close to 0.5
+        assert!((first.mean_pairwise_distance() - 0.5).abs() < 0.01);
+        assert_eq!(first.populated_regions(), 0);
+    }
+
+    #[test]
+    fn given_empty_population_when_measured_then_zeroes() {
+        let diversity = PopulationDiversity::measure(&[], 1);
+
+        assert_eq!(diversity.mean_pairwise_distance(), 0.0);
+        assert!(diversity.dimension_std_devs().is_empty());
+        assert_eq!(diversity.region_entropy(), 0.0);
+    }
+}
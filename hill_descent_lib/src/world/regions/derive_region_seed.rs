@@ -2,10 +2,35 @@ use xxhash_rust::xxh3::xxh3_64;
 
 use crate::world::regions::region::region_key::RegionKey;
 
-/// Derives deterministic seed for region from world seed + region key.
-/// Same world seed + region key = same RNG, different regions = independent streams.
-pub fn derive_region_seed(world_seed: u64, region_key: &RegionKey) -> u64 {
-    let mut hasher_input = world_seed.to_le_bytes().to_vec();
+/// Identifies an independent random stream drawn by a region, or the whole world, within an
+/// epoch.
+///
+/// Giving each consumer of randomness its own stream keeps them decorrelated and lets new
+/// consumers be added without disturbing the draws of existing ones.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SeedStream(u64);
+
+impl SeedStream {
+    /// The stream used for selection, crossover and mutation during reproduction.
+    pub const REPRODUCTION: SeedStream = SeedStream(0);
+    /// The stream used to sample pairs when measuring population diversity. It belongs to the
+    /// whole world, so it is derived with an empty region key.
+    pub const DIVERSITY: SeedStream = SeedStream(1);
+}
+
+/// Derives deterministic seed for region from world seed + epoch + stream + region key.
+/// Same inputs = same RNG; a different epoch, stream or region = an independent stream, so a
+/// region that keeps its key does not replay the same draws every epoch.
+pub fn derive_region_seed(
+    world_seed: u64,
+    epoch: u64,
+    stream: SeedStream,
+    region_key: &RegionKey,
+) -> u64 {
+    let mut hasher_input = Vec::with_capacity(8 * (3 + region_key.values().len()));
+    hasher_input.extend_from_slice(&world_seed.to_le_bytes());
+    hasher_input.extend_from_slice(&epoch.to_le_bytes());
+    hasher_input.extend_from_slice(&stream.0.to_le_bytes());
     for &idx in region_key.values() {
         hasher_input.extend_from_slice(&(idx as u64).to_le_bytes());
     }
@@ -17,6 +42,8 @@ mod tests {
     use super::*;
     use crate::world::regions::region::region_key::RegionKey;
 
+    const STREAM: SeedStream = SeedStream::REPRODUCTION;
+
     fn rk(values: &[usize]) -> RegionKey {
         RegionKey::from(values)
     }
@@ -24,35 +51,54 @@ mod tests {
     #[test]
     fn given_same_world_seed_and_key_when_derive_then_same_result() {
         assert_eq!(
-            derive_region_seed(12345, &rk(&[0, 1, 2])),
-            derive_region_seed(12345, &rk(&[0, 1, 2]))
+            derive_region_seed(12345, 3, STREAM, &rk(&[0, 1, 2])),
+            derive_region_seed(12345, 3, STREAM, &rk(&[0, 1, 2]))
         );
     }
 
     #[test]
     fn given_different_world_seeds_when_derive_then_different_results() {
         assert_ne!(
-            derive_region_seed(12345, &rk(&[0, 1, 2])),
-            derive_region_seed(67890, &rk(&[0, 1, 2]))
+            derive_region_seed(12345, 0, STREAM, &rk(&[0, 1, 2])),
+            derive_region_seed(67890, 0, STREAM, &rk(&[0, 1, 2]))
         );
     }
 
     #[test]
     fn given_different_region_keys_when_derive_then_different_results() {
         assert_ne!(
-            derive_region_seed(12345, &rk(&[0, 1, 2])),
-            derive_region_seed(12345, &rk(&[0, 1, 3]))
+            derive_region_seed(12345, 0, STREAM, &rk(&[0, 1, 2])),
+            derive_region_seed(12345, 0, STREAM, &rk(&[0, 1, 3]))
+        );
+    }
+
+    #[test]
+    fn given_different_epochs_when_derive_then_different_results() {
+        assert_ne!(
+            derive_region_seed(12345, 0, STREAM, &rk(&[0, 1, 2])),
+            derive_region_seed(12345, 1, STREAM, &rk(&[0, 1, 2]))
+        );
+    }
+
+    #[test]
+    fn given_different_streams_when_derive_then_different_results() {
+        assert_ne!(
+            derive_region_seed(12345, 0, SeedStream(0), &rk(&[0, 1, 2])),
+            derive_region_seed(12345, 0, SeedStream(1), &rk(&[0, 1, 2]))
         );
     }
 
     #[test]
     fn given_empty_region_key_when_derive_then_returns_valid_seed() {
-        assert_ne!(derive_region_seed(12345, &rk(&[])), 0);
+        assert_ne!(derive_region_seed(12345, 0, STREAM, &rk(&[])), 0);
     }
 
     #[test]
     fn given_large_region_key_when_derive_then_returns_valid_seed() {
         let large_key: Vec<usize> = (0..100).collect();
-        assert_ne!(derive_region_seed(12345, &RegionKey::from(large_key)), 0);
+        assert_ne!(
+            derive_region_seed(12345, 0, STREAM, &RegionKey::from(large_key)),
+            0
+        );
     }
 }
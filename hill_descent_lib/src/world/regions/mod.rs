@@ -19,7 +19,7 @@ pub mod get_most_diverse_dimension;
 mod refill;
 
 mod derive_region_seed;
-pub use derive_region_seed::derive_region_seed;
+pub use derive_region_seed::{SeedStream, derive_region_seed};
 
 mod parallel_process;
 
@@ -83,7 +83,7 @@ impl Regions {
     }
 
     /// Returns an iterator over (key, region) pairs.
-    pub fn iter_regions(&self) -> impl Iterator<Item = (&RegionKey, &Region)> {
+    pub fn iter_regions(&self) -> impl ExactSizeIterator<Item = (&RegionKey, &Region)> {
         self.regions.iter()
     }
 
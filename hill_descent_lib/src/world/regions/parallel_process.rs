@@ -1,6 +1,8 @@
 use super::Regions;
+use crate::world::elitism::ElitePolicy;
 use crate::world::organisms::{Organisms, organism::Organism};
-use crate::world::regions::derive_region_seed;
+use crate::world::regions::{SeedStream, derive_region_seed};
+use crate::world::reproduction_operators::ReproductionOperators;
 use crate::world::world_function::WorldFunction;
 use rayon::prelude::*;
 use std::sync::Arc;
@@ -10,22 +12,37 @@ impl Regions {
     /// Each region gets dedicated thread with deterministic RNG.
     /// Regions are sorted by organism count (descending) to minimize parallel execution time
     /// by starting the largest workloads first.
+    ///
+    /// Returns the new population together with the leaders reported by every region.
+    /// Pass no `world_function` when every organism has already been scored.
+    #[allow(clippy::too_many_arguments)]
     pub fn parallel_process_regions(
         &mut self,
-        world_function: &dyn WorldFunction,
+        world_function: Option<&dyn WorldFunction>,
+        operators: &ReproductionOperators,
+        elites: &ElitePolicy,
         inputs: &[f64],
         known_outputs: &[f64],
         world_seed: u64,
-    ) -> Organisms {
+        epoch: u64,
+    ) -> (Organisms, Vec<Arc<Organism>>) {
         // Sort regions by organism count (largest first) to optimize parallel scheduling
         let mut region_entries: Vec<_> = self.regions.iter_mut().collect();
-        region_entries.sort_by(|a, b| b.1.organisms().len().cmp(&a.1.organisms().len()));
+        region_entries.sort_by_key(|entry| std::cmp::Reverse(entry.1.organisms().len()));
 
-        let all_offspring: Vec<Vec<Arc<Organism>>> = region_entries
+        let outcomes: Vec<_> = region_entries
             .par_iter_mut()
             .map(|(region_key, region)| {
-                let region_seed = derive_region_seed(world_seed, region_key);
-                region.process_region_lifecycle(world_function, inputs, known_outputs, region_seed)
+                let region_seed =
+                    derive_region_seed(world_seed, epoch, SeedStream::REPRODUCTION, region_key);
+                region.process_region_lifecycle(
+                    world_function,
+                    operators,
+                    elites,
+                    inputs,
+                    known_outputs,
+                    region_seed,
+                )
             })
             .collect();
 
@@ -39,10 +56,13 @@ impl Regions {
             all_organisms.extend(region.take_organisms());
         }
 
-        // Add offspring directly via iterator (no intermediate Vec allocation)
-        all_organisms.extend(all_offspring.into_iter().flatten());
+        let mut leaders = Vec::new();
+        for outcome in outcomes {
+            all_organisms.extend(outcome.offspring);
+            leaders.extend(outcome.leaders);
+        }
 
-        Organisms::new_from_arc_vec(all_organisms)
+        (Organisms::new_from_arc_vec(all_organisms), leaders)
     }
 }
 
@@ -84,7 +104,15 @@ mod tests {
             regions.insert_region(rk(&[i]), region);
         }
 
-        let all_organisms = regions.parallel_process_regions(&MockFunction, &[], &[1.0], 12345);
+        let (all_organisms, _) = regions.parallel_process_regions(
+            Some(&MockFunction),
+            &ReproductionOperators::default(),
+            &ElitePolicy::default(),
+            &[],
+            &[1.0],
+            12345,
+            0,
+        );
         // 3 regions * (5 survivors + 5 offspring) = 30 total
         assert_eq!(all_organisms.len(), 30);
     }
@@ -111,8 +139,24 @@ mod tests {
             regions2.insert_region(rk(&[i]), r2);
         }
 
-        let all_organisms1 = regions1.parallel_process_regions(&MockFunction, &[], &[1.0], 12345);
-        let all_organisms2 = regions2.parallel_process_regions(&MockFunction, &[], &[1.0], 12345);
+        let (all_organisms1, _) = regions1.parallel_process_regions(
+            Some(&MockFunction),
+            &ReproductionOperators::default(),
+            &ElitePolicy::default(),
+            &[],
+            &[1.0],
+            12345,
+            0,
+        );
+        let (all_organisms2, _) = regions2.parallel_process_regions(
+            Some(&MockFunction),
+            &ReproductionOperators::default(),
+            &ElitePolicy::default(),
+            &[],
+            &[1.0],
+            12345,
+            0,
+        );
         assert_eq!(all_organisms1.len(), all_organisms2.len());
     }
 
@@ -148,7 +192,15 @@ mod tests {
         regions.insert_region(rk(&[2]), region_medium);
 
         // Process regions - should be sorted by size (largest first)
-        let all_organisms = regions.parallel_process_regions(&MockFunction, &[], &[1.0], 12345);
+        let (all_organisms, _) = regions.parallel_process_regions(
+            Some(&MockFunction),
+            &ReproductionOperators::default(),
+            &ElitePolicy::default(),
+            &[],
+            &[1.0],
+            12345,
+            0,
+        );
 
         // Total: (8 + 8 offspring) + (5 + 5 offspring) + (2 + 2 offspring) = 30
         assert_eq!(all_organisms.len(), 30);
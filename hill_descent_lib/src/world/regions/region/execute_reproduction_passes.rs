@@ -1,7 +1,10 @@
 use rand::Rng;
 use std::sync::Arc;
 
-use crate::world::{organisms::organism::Organism, regions::region::Region};
+use crate::world::{
+    organisms::organism::Organism, regions::region::Region,
+    reproduction_operators::ReproductionOperators,
+};
 
 // The function `execute_single_reproduction_pass` from helper modules is imported in mod.rs
 // to make it available on Region. This file depends on that function for reproduction logic.
@@ -9,14 +12,15 @@ use crate::world::{organisms::organism::Organism, regions::region::Region};
 impl Region {
     /// Executes multiple reproduction passes to generate offspring.
     ///
-    /// This helper function handles the core reproduction logic using extreme pairing strategy
-    /// across multiple passes when needed for population growth.
+    /// This helper function handles the core reproduction logic, asking the selection strategy
+    /// for fresh parent pairs on each pass when multiple passes are needed for population growth.
     ///
     /// * `original_organisms` - The ranked parent organisms to use for reproduction
     /// * `parents_required` - Number of parents available for reproduction
     /// * `max_offspring_per_pass` - Maximum offspring that can be produced in a single pass
     /// * `number_to_reproduce` - Total number of offspring requested
     /// * `max_passes` - Maximum number of reproduction passes allowed
+    /// * `operators` - Selection strategy and crossover operator used on each pass
     /// * `rng` - Random number generator for reproduction operations
     pub(super) fn execute_reproduction_passes<R: Rng>(
         original_organisms: &[Arc<Organism>],
@@ -24,6 +28,7 @@ impl Region {
         max_offspring_per_pass: usize,
         number_to_reproduce: usize,
         max_passes: usize,
+        operators: &ReproductionOperators,
         rng: &mut R,
     ) -> Vec<Arc<Organism>> {
         let mut all_offspring: Vec<Arc<Organism>> = Vec::new();
@@ -36,11 +41,15 @@ impl Region {
             }
 
             let offspring_this_pass = remaining_to_reproduce.min(max_offspring_per_pass);
-            let selected_slice = &original_organisms[..parents_required];
 
             // Execute single reproduction pass
-            let offspring =
-                Region::execute_single_reproduction_pass(selected_slice, offspring_this_pass, rng);
+            let offspring = Region::execute_single_reproduction_pass(
+                original_organisms,
+                parents_required,
+                offspring_this_pass,
+                operators,
+                rng,
+            );
 
             remaining_to_reproduce -= offspring.len();
             all_offspring.extend(offspring);
@@ -53,6 +62,7 @@ impl Region {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::world::reproduction_operators::ReproductionOperators;
     use crate::{phenotype::Phenotype, world::organisms::organism::Organism};
     use rand::SeedableRng;
     use rand::rngs::SmallRng;
@@ -73,10 +83,12 @@ mod tests {
         let mut rng = SmallRng::seed_from_u64(0);
 
         let offspring = Region::execute_reproduction_passes(
-            &organisms, 1, // parents_required
+            &organisms,
+            1, // parents_required
             1, // max_offspring_per_pass
             1, // number_to_reproduce
             1, // max_passes
+            &ReproductionOperators::default(),
             &mut rng,
         );
 
@@ -90,10 +102,12 @@ mod tests {
         let mut rng = SmallRng::seed_from_u64(0);
 
         let offspring = Region::execute_reproduction_passes(
-            &organisms, 2, // parents_required
+            &organisms,
+            2, // parents_required
             2, // max_offspring_per_pass
             2, // number_to_reproduce
             1, // max_passes
+            &ReproductionOperators::default(),
             &mut rng,
         );
 
@@ -107,10 +121,12 @@ mod tests {
         let mut rng = SmallRng::seed_from_u64(0);
 
         let offspring = Region::execute_reproduction_passes(
-            &organisms, 1, // parents_required
+            &organisms,
+            1, // parents_required
             1, // max_offspring_per_pass
             3, // number_to_reproduce
             3, // max_passes
+            &ReproductionOperators::default(),
             &mut rng,
         );
 
@@ -124,10 +140,12 @@ mod tests {
         let mut rng = SmallRng::seed_from_u64(0);
 
         let offspring = Region::execute_reproduction_passes(
-            &organisms, 2, // parents_required
+            &organisms,
+            2, // parents_required
             2, // max_offspring_per_pass
             6, // number_to_reproduce
             3, // max_passes
+            &ReproductionOperators::default(),
             &mut rng,
         );
 
@@ -145,10 +163,12 @@ mod tests {
         let mut rng = SmallRng::seed_from_u64(0);
 
         let offspring = Region::execute_reproduction_passes(
-            &organisms, 3, // parents_required
+            &organisms,
+            3, // parents_required
             4, // max_offspring_per_pass (3 organisms with duplication: 2 pairs = 4 offspring)
             9, // number_to_reproduce
             3, // max_passes
+            &ReproductionOperators::default(),
             &mut rng,
         );
 
@@ -163,10 +183,12 @@ mod tests {
         let mut rng = SmallRng::seed_from_u64(0);
 
         let offspring = Region::execute_reproduction_passes(
-            &organisms, 1, // parents_required
+            &organisms,
+            1, // parents_required
             1, // max_offspring_per_pass
             0, // number_to_reproduce
             1, // max_passes
+            &ReproductionOperators::default(),
             &mut rng,
         );
 
@@ -179,10 +201,12 @@ mod tests {
         let mut rng = SmallRng::seed_from_u64(0);
 
         let offspring = Region::execute_reproduction_passes(
-            &organisms, 1,  // parents_required
+            &organisms,
+            1,  // parents_required
             2,  // max_offspring_per_pass (single organism produces 2 via self-fertilization)
             10, // number_to_reproduce (way more than passes allow)
             3,  // max_passes
+            &ReproductionOperators::default(),
             &mut rng,
         );
 
@@ -197,10 +221,12 @@ mod tests {
         let mut rng = SmallRng::seed_from_u64(0);
 
         let offspring = Region::execute_reproduction_passes(
-            &organisms, 2, // parents_required
+            &organisms,
+            2, // parents_required
             1, // max_offspring_per_pass (artificially low)
             3, // number_to_reproduce
             3, // max_passes
+            &ReproductionOperators::default(),
             &mut rng,
         );
 
@@ -217,10 +243,12 @@ mod tests {
         let mut rng = SmallRng::seed_from_u64(0);
 
         let offspring = Region::execute_reproduction_passes(
-            &organisms, 1, // parents_required
+            &organisms,
+            1, // parents_required
             2, // max_offspring_per_pass (single organism now produces 2 via self-fertilization)
             1, // number_to_reproduce
             1, // max_passes
+            &ReproductionOperators::default(),
             &mut rng,
         );
 
@@ -242,10 +270,12 @@ mod tests {
         let mut rng = SmallRng::seed_from_u64(0);
 
         let offspring = Region::execute_reproduction_passes(
-            &organisms, 2, // parents_required
+            &organisms,
+            2, // parents_required
             2, // max_offspring_per_pass
             2, // number_to_reproduce
             1, // max_passes
+            &ReproductionOperators::default(),
             &mut rng,
         );
 
@@ -272,10 +302,12 @@ mod tests {
         let mut rng = SmallRng::seed_from_u64(0);
 
         let offspring = Region::execute_reproduction_passes(
-            &organisms, 3, // parents_required
+            &organisms,
+            3, // parents_required
             4, // max_offspring_per_pass (3 organisms become 4 with duplication: 2 pairs = 4 offspring)
             3, // number_to_reproduce (limit to 3)
             1, // max_passes
+            &ReproductionOperators::default(),
             &mut rng,
         );
 
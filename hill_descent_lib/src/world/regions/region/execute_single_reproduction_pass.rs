@@ -1,32 +1,41 @@
 use rand::Rng;
 use std::sync::Arc;
 
-use crate::world::{organisms::organism::Organism, regions::region::Region};
+use crate::world::{
+    organisms::organism::Organism, regions::region::Region,
+    reproduction_operators::ReproductionOperators,
+};
 
 impl Region {
     /// Executes a single reproduction pass to generate offspring.
     ///
-    /// This function uses extreme pairing strategy where organisms are paired first-with-last,
-    /// second-with-second-to-last, etc. For odd counts, the top performer is duplicated.
-    /// All reproduction is now sexual (no asexual reproduction).
+    /// Parent pairs are chosen by the operators' selection strategy (extreme pairing by
+    /// default). All reproduction is sexual (no asexual reproduction).
     ///
-    /// * `selected_organisms` - The slice of organisms selected for reproduction
+    /// * `ranked_organisms` - The region's organisms, sorted best first
+    /// * `parents_required` - Number of parents the strategy should use this pass
     /// * `max_offspring_this_pass` - Maximum number of offspring to produce in this pass
+    /// * `operators` - Selection strategy and crossover operator to apply
     /// * `rng` - Random number generator for reproduction operations
     ///
     /// Returns a vector of offspring produced in this reproduction pass
     pub(super) fn execute_single_reproduction_pass<R: Rng>(
-        selected_organisms: &[Arc<Organism>],
+        ranked_organisms: &[Arc<Organism>],
+        parents_required: usize,
         max_offspring_this_pass: usize,
+        operators: &ReproductionOperators,
         rng: &mut R,
     ) -> Vec<Arc<Organism>> {
         let mut offspring = Vec::new();
 
-        // Pair organisms for reproduction using extreme pairing strategy
-        let organism_pairs = Self::pair_organisms_for_reproduction(selected_organisms);
+        // Pair organisms for reproduction using the configured selection strategy
+        let organism_pairs =
+            operators
+                .selection_strategy()
+                .select_pairs(ranked_organisms, parents_required, rng);
 
         // Perform sexual reproduction for all pairs (no more asexual reproduction)
-        let sexual_offspring = Self::perform_sexual_reproduction(&organism_pairs, rng);
+        let sexual_offspring = Self::perform_sexual_reproduction(&organism_pairs, operators, rng);
         offspring.extend(sexual_offspring);
 
         // Limit offspring to what was requested for this pass
@@ -39,6 +48,7 @@ impl Region {
 mod tests {
     use super::*;
     use crate::phenotype::Phenotype;
+    use crate::world::reproduction_operators::ReproductionOperators;
     use rand::{SeedableRng, rngs::SmallRng};
     use std::sync::Arc;
 
@@ -58,7 +68,13 @@ mod tests {
         let parent_id = organisms[0].id();
         let mut rng = SmallRng::seed_from_u64(0);
 
-        let offspring = Region::execute_single_reproduction_pass(&organisms, 5, &mut rng);
+        let offspring = Region::execute_single_reproduction_pass(
+            &organisms,
+            organisms.len(),
+            5,
+            &ReproductionOperators::default(),
+            &mut rng,
+        );
 
         // Single organism pairs with itself, producing 2 offspring
         assert_eq!(offspring.len(), 2);
@@ -76,7 +92,13 @@ mod tests {
         let id2 = organisms[1].id();
         let mut rng = SmallRng::seed_from_u64(0);
 
-        let offspring = Region::execute_single_reproduction_pass(&organisms, 5, &mut rng);
+        let offspring = Region::execute_single_reproduction_pass(
+            &organisms,
+            organisms.len(),
+            5,
+            &ReproductionOperators::default(),
+            &mut rng,
+        );
 
         assert_eq!(offspring.len(), 2);
         for child in &offspring {
@@ -99,7 +121,13 @@ mod tests {
         let id3 = organisms[2].id(); // Worst
         let mut rng = SmallRng::seed_from_u64(0);
 
-        let offspring = Region::execute_single_reproduction_pass(&organisms, 5, &mut rng);
+        let offspring = Region::execute_single_reproduction_pass(
+            &organisms,
+            organisms.len(),
+            5,
+            &ReproductionOperators::default(),
+            &mut rng,
+        );
 
         // With 3 organisms, top performer duplicated: [best, best, middle, worst]
         // Pairs: (best, worst), (best, middle) = 4 offspring total
@@ -127,7 +155,13 @@ mod tests {
         let organisms = vec![make_org(1.0, 5, 0), make_org(2.0, 3, 1)];
         let mut rng = SmallRng::seed_from_u64(0);
 
-        let offspring = Region::execute_single_reproduction_pass(&organisms, 1, &mut rng);
+        let offspring = Region::execute_single_reproduction_pass(
+            &organisms,
+            organisms.len(),
+            1,
+            &ReproductionOperators::default(),
+            &mut rng,
+        );
 
         assert_eq!(offspring.len(), 1); // Limited to 1 despite 2 organisms could produce 2
     }
@@ -1,6 +1,5 @@
 mod execute_reproduction_passes;
 mod execute_single_reproduction_pass;
-mod pair_organisms_for_reproduction;
 mod perform_sexual_reproduction;
 mod process_region;
 pub mod region_key;
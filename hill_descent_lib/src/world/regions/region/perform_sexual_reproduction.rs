@@ -3,29 +3,33 @@ use std::sync::Arc;
 
 use crate::{
     phenotype::Phenotype,
-    world::{organisms::organism::Organism, regions::region::Region},
+    world::{
+        organisms::organism::Organism, regions::region::Region,
+        reproduction_operators::ReproductionOperators, selection_strategy::OrganismPairs,
+    },
 };
 
-type OrganismPairs = Vec<(Arc<Organism>, Arc<Organism>)>;
-
 impl Region {
     /// Performs sexual reproduction for all provided organism pairs.
     ///
     /// Each pair produces two offspring through sexual reproduction.
     ///
     /// * `organism_pairs` - Vector of organism pairs for sexual reproduction
+    /// * `operators` - Crossover and mutation operators used to form offspring
     /// * `rng` - Random number generator for reproduction operations
     ///
     /// Returns a vector of offspring organisms produced from sexual reproduction
     pub(super) fn perform_sexual_reproduction<R: Rng>(
         organism_pairs: &OrganismPairs,
+        operators: &ReproductionOperators,
         rng: &mut R,
     ) -> Vec<Arc<Organism>> {
         // Pre-allocate: each pair produces exactly 2 offspring
         let mut offspring = Vec::with_capacity(organism_pairs.len() * 2);
 
         for (p1, p2) in organism_pairs {
-            let (c1, c2) = Phenotype::sexual_reproduction(p1.phenotype(), p2.phenotype(), rng);
+            let (c1, c2) =
+                Phenotype::sexual_reproduction(p1.phenotype(), p2.phenotype(), operators, rng);
             offspring.push(Arc::new(Organism::new(
                 Arc::new(c1),
                 0,
@@ -64,7 +68,11 @@ mod tests {
         let pairs: OrganismPairs = vec![];
         let mut rng = SmallRng::seed_from_u64(0);
 
-        let offspring = Region::perform_sexual_reproduction(&pairs, &mut rng);
+        let offspring = Region::perform_sexual_reproduction(
+            &pairs,
+            &ReproductionOperators::default(),
+            &mut rng,
+        );
 
         assert!(offspring.is_empty());
     }
@@ -78,7 +86,11 @@ mod tests {
         let pairs = vec![(org1, org2)];
         let mut rng = SmallRng::seed_from_u64(0);
 
-        let offspring = Region::perform_sexual_reproduction(&pairs, &mut rng);
+        let offspring = Region::perform_sexual_reproduction(
+            &pairs,
+            &ReproductionOperators::default(),
+            &mut rng,
+        );
 
         assert_eq!(offspring.len(), 2);
         for child in &offspring {
@@ -98,7 +110,11 @@ mod tests {
         let pairs = vec![(org1, org2), (org3, org4)];
         let mut rng = SmallRng::seed_from_u64(0);
 
-        let offspring = Region::perform_sexual_reproduction(&pairs, &mut rng);
+        let offspring = Region::perform_sexual_reproduction(
+            &pairs,
+            &ReproductionOperators::default(),
+            &mut rng,
+        );
 
         assert_eq!(offspring.len(), 4);
         assert!(offspring.iter().all(|o| o.age() == 0));
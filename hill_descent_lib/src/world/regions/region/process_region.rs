@@ -1,24 +1,45 @@
 use super::Region;
+use crate::world::elitism::ElitePolicy;
 use crate::world::organisms::organism::Organism;
+use crate::world::reproduction_operators::ReproductionOperators;
 use crate::world::world_function::WorldFunction;
 use rand::SeedableRng;
 use rand::rngs::StdRng;
 use std::sync::Arc;
 
+/// What a region hands back to the world after one lifecycle.
+#[derive(Debug, Default)]
+pub struct RegionLifecycleOutcome {
+    /// Offspring produced this epoch, not yet evaluated.
+    pub offspring: Vec<Arc<Organism>>,
+    /// The region's best organisms after evaluation, best first, captured before any of
+    /// them could die. At most `ElitePolicy::leaders_per_region` entries.
+    pub leaders: Vec<Arc<Organism>>,
+}
+
 impl Region {
     /// Processes region's complete lifecycle independently (designed for parallel execution).
     /// Operations: Fitness → Sort → Truncate → Cull → Reproduce → Age → Cull
+    ///
+    /// Organisms protected by `elites` are exempt from truncation and from death by old age.
+    /// Without a `world_function` the fitness step is skipped, as the organisms have already
+    /// been scored.
     pub fn process_region_lifecycle(
         &mut self,
-        world_function: &dyn WorldFunction,
+        world_function: Option<&dyn WorldFunction>,
+        operators: &ReproductionOperators,
+        elites: &ElitePolicy,
         inputs: &[f64],
         known_outputs: &[f64],
         region_seed: u64,
-    ) -> Vec<Arc<Organism>> {
+    ) -> RegionLifecycleOutcome {
         // 1. Fitness evaluation (sequential within region since outer loop is already parallelized)
         // Parallelizing here adds significant Rayon overhead for trivial fitness functions.
-        for organism in self.organisms.iter() {
-            organism.run(world_function, inputs, known_outputs);
+        // Without a function the organisms were already scored through World::tell.
+        if let Some(world_function) = world_function {
+            for organism in self.organisms.iter() {
+                organism.run(world_function, inputs, known_outputs);
+            }
         }
 
         // 2. Sort by fitness (best first) then age (older first)
@@ -30,6 +51,12 @@ impl Region {
                 .unwrap_or(std::cmp::Ordering::Equal);
             score_cmp.then_with(|| b.age().cmp(&a.age()))
         });
+        let leaders = self
+            .organisms
+            .iter()
+            .take(elites.leaders_per_region())
+            .cloned()
+            .collect();
 
         // 3. Truncate to capacity
         // Skip truncation if capacity is None or 0 (first iteration or no min_score)
@@ -37,8 +64,10 @@ impl Region {
             && capacity > 0
             && self.organism_count() > capacity
         {
-            for organism in self.organisms.iter().skip(capacity) {
-                organism.mark_dead();
+            for (rank, organism) in self.organisms.iter().enumerate().skip(capacity) {
+                if !elites.is_protected(rank, organism) {
+                    organism.mark_dead();
+                }
             }
         }
 
@@ -50,7 +79,7 @@ impl Region {
             let current = self.organism_count();
             if current < capacity {
                 let mut region_rng = StdRng::seed_from_u64(region_seed);
-                self.reproduce(capacity - current, &mut region_rng)
+                self.reproduce(capacity - current, operators, &mut region_rng)
             } else {
                 Vec::new()
             }
@@ -59,14 +88,18 @@ impl Region {
         };
 
         // 6. Age organisms
-        for organism in self.organisms.iter() {
-            organism.increment_age();
+        for (rank, organism) in self.organisms.iter().enumerate() {
+            if elites.is_protected(rank, organism) {
+                organism.increment_age_as_elite();
+            } else {
+                organism.increment_age();
+            }
         }
 
         // 7. Remove aged-out
         self.organisms.retain(|org| !org.is_dead());
 
-        offspring
+        RegionLifecycleOutcome { offspring, leaders }
     }
 }
 
@@ -98,12 +131,19 @@ mod tests {
             region.add_organism(create_test_organism(i));
         }
 
-        let offspring = region.process_region_lifecycle(&MockFunction, &[], &[1.0], 12345);
+        let outcome = region.process_region_lifecycle(
+            Some(&MockFunction),
+            &ReproductionOperators::default(),
+            &ElitePolicy::default(),
+            &[],
+            &[1.0],
+            12345,
+        );
 
         for org in region.organisms() {
             assert!(org.score().is_some());
         }
-        assert_eq!(offspring.len(), 5);
+        assert_eq!(outcome.offspring.len(), 5);
     }
 
     #[test]
@@ -114,7 +154,14 @@ mod tests {
             region.add_organism(create_test_organism(i));
         }
 
-        region.process_region_lifecycle(&MockFunction, &[], &[1.0], 12345);
+        region.process_region_lifecycle(
+            Some(&MockFunction),
+            &ReproductionOperators::default(),
+            &ElitePolicy::default(),
+            &[],
+            &[1.0],
+            12345,
+        );
         assert_eq!(region.organism_count(), 3);
     }
 
@@ -130,8 +177,66 @@ mod tests {
             region2.add_organism(create_test_organism(i));
         }
 
-        let offspring1 = region1.process_region_lifecycle(&MockFunction, &[], &[1.0], 12345);
-        let offspring2 = region2.process_region_lifecycle(&MockFunction, &[], &[1.0], 12345);
-        assert_eq!(offspring1.len(), offspring2.len());
+        let offspring1 = region1.process_region_lifecycle(
+            Some(&MockFunction),
+            &ReproductionOperators::default(),
+            &ElitePolicy::default(),
+            &[],
+            &[1.0],
+            12345,
+        );
+        let offspring2 = region2.process_region_lifecycle(
+            Some(&MockFunction),
+            &ReproductionOperators::default(),
+            &ElitePolicy::default(),
+            &[],
+            &[1.0],
+            12345,
+        );
+        assert_eq!(offspring1.offspring.len(), offspring2.offspring.len());
+    }
+
+    fn create_scored_test_organism(score: f64, max_age: f64) -> Arc<Organism> {
+        let expressed = vec![0.1, 0.5, 0.001, 0.001, 0.001, max_age, 2.0, score];
+        let phenotype = Arc::new(Phenotype::new_for_test(expressed));
+        Arc::new(Organism::new(phenotype, 0, (None, None)))
+    }
+
+    #[derive(Debug)]
+    struct ParamFunction;
+    impl WorldFunction for ParamFunction {
+        fn run(&self, p: &[f64], _v: &[f64]) -> Vec<f64> {
+            vec![p[0] + 1.0]
+        }
+    }
+
+    #[test]
+    fn given_per_region_elitism_when_best_exceeds_max_age_then_best_survives() {
+        let mut region = Region::new();
+        region.set_carrying_capacity(Some(2));
+        // All organisms die of old age after one epoch (max_age 0)
+        for score in [3.0, 1.0, 2.0] {
+            region.add_organism(create_scored_test_organism(score, 0.0));
+        }
+        let elites = ElitePolicy::new(
+            crate::world::elitism::Elitism::PerRegion { count: 1 },
+            &crate::world::organisms::Organisms::new_empty(),
+            2,
+        );
+
+        let outcome = region.process_region_lifecycle(
+            Some(&ParamFunction),
+            &ReproductionOperators::default(),
+            &elites,
+            &[],
+            &[0.0],
+            12345,
+        );
+
+        assert_eq!(region.organism_count(), 1);
+        assert_eq!(region.organisms()[0].score(), Some(2.0));
+        assert_eq!(outcome.leaders.len(), 2);
+        assert_eq!(outcome.leaders[0].score(), Some(2.0));
+        assert_eq!(outcome.leaders[1].score(), Some(3.0));
     }
 }
@@ -1,7 +1,10 @@
 use rand::Rng;
 use std::sync::Arc;
 
-use crate::world::{organisms::organism::Organism, regions::region::Region};
+use crate::world::{
+    organisms::organism::Organism, regions::region::Region,
+    reproduction_operators::ReproductionOperators,
+};
 
 impl Region {
     /// Reproduces new organisms for this region based on the ranking rules in the PDD (§5.2.3).
@@ -14,9 +17,11 @@ impl Region {
     /// times (up to REPRODUCTION_FACTOR) to accelerate population growth.
     ///
     /// * `number_to_reproduce` – the number of offspring **required** for this region.
+    /// * `operators` – the world's selection strategy and crossover operator.
     /// * `rng` – RNG used for crossover & mutation in the underlying phenotype reproduction.
     ///
-    /// The algorithm follows the PDD exactly:
+    /// With the default [`ExtremePairing`](crate::world::selection_strategy::ExtremePairing)
+    /// strategy the algorithm follows the PDD exactly:
     /// 1. Select the top `number_to_reproduce` parents from the pre-sorted list (or all organisms if fewer).
     /// 2. Organisms are paired using extreme pairing strategy (best with worst, etc.).
     ///    For odd counts, the top performer is duplicated to create an even list.
@@ -28,6 +33,7 @@ impl Region {
     pub fn reproduce<R: Rng>(
         &mut self,
         number_to_reproduce: usize,
+        operators: &ReproductionOperators,
         rng: &mut R,
     ) -> Vec<Arc<Organism>> {
         if number_to_reproduce == 0 || self.organisms.is_empty() {
@@ -46,7 +52,7 @@ impl Region {
         let parents_required = number_to_reproduce.min(slice.len());
 
         // Calculate maximum offspring per pass based on available parents
-        // With extreme pairing:
+        // Strategies return parents_required.div_ceil(2) pairs; with extreme pairing:
         // - Odd count: top performer duplicated, creating (parents_required+1) organisms,
         //   which form (parents_required+1)/2 pairs producing 2*(parents_required+1)/2 = parents_required+1 offspring
         // - Even count: parents_required/2 pairs producing 2*(parents_required/2) = parents_required offspring
@@ -80,6 +86,7 @@ impl Region {
             max_offspring_per_pass,
             number_to_reproduce,
             max_passes,
+            operators,
             rng,
         )
     }
@@ -88,6 +95,7 @@ impl Region {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::world::reproduction_operators::ReproductionOperators;
     use crate::{phenotype::Phenotype, world::organisms::organism::Organism};
     use rand::SeedableRng;
     use rand::rngs::SmallRng;
@@ -110,7 +118,7 @@ mod tests {
             region.add_organism(make_org(i as f64 + 1.0, i, i));
         }
         let mut rng = SmallRng::seed_from_u64(0);
-        let offspring = region.reproduce(4, &mut rng);
+        let offspring = region.reproduce(4, &ReproductionOperators::default(), &mut rng);
         assert_eq!(offspring.len(), 4);
         assert!(offspring.iter().all(|o| o.age() == 0));
     }
@@ -122,7 +130,7 @@ mod tests {
             region.add_organism(make_org(i as f64 + 1.0, i, i));
         }
         let mut rng = SmallRng::seed_from_u64(0);
-        let offspring = region.reproduce(3, &mut rng);
+        let offspring = region.reproduce(3, &ReproductionOperators::default(), &mut rng);
         assert_eq!(offspring.len(), 3);
     }
 
@@ -131,7 +139,7 @@ mod tests {
         let mut region = Region::new();
         region.add_organism(make_org(1.0, 0, 0));
         let mut rng = SmallRng::seed_from_u64(0);
-        let offspring = region.reproduce(0, &mut rng);
+        let offspring = region.reproduce(0, &ReproductionOperators::default(), &mut rng);
         assert!(offspring.is_empty());
     }
 
@@ -139,7 +147,7 @@ mod tests {
     fn given_empty_region_when_reproduce_then_returns_empty_vec() {
         let mut region = Region::new();
         let mut rng = SmallRng::seed_from_u64(0);
-        let offspring = region.reproduce(3, &mut rng);
+        let offspring = region.reproduce(3, &ReproductionOperators::default(), &mut rng);
         assert!(offspring.is_empty());
     }
 
@@ -148,7 +156,7 @@ mod tests {
         let mut region = Region::new();
         region.add_organism(make_org(2.0, 5, 0));
         let mut rng = SmallRng::seed_from_u64(0);
-        let offspring = region.reproduce(1, &mut rng);
+        let offspring = region.reproduce(1, &ReproductionOperators::default(), &mut rng);
         // Single organism produces 2 offspring via self-fertilization, limited to 1 by request
         assert_eq!(offspring.len(), 1);
     }
@@ -160,7 +168,7 @@ mod tests {
         region.add_organism(make_org(2.0, 2, 1));
         let mut rng = SmallRng::seed_from_u64(0);
         // Request more than available (5 > 2)
-        let offspring = region.reproduce(5, &mut rng);
+        let offspring = region.reproduce(5, &ReproductionOperators::default(), &mut rng);
         // Two parents => even => 2 offspring via sexual reproduction
         assert_eq!(offspring.len(), 2);
     }
@@ -172,7 +180,7 @@ mod tests {
         region.add_organism(make_org(1.0, 10, 0)); // older
         region.add_organism(make_org(1.0, 5, 1)); // younger
         let mut rng = SmallRng::seed_from_u64(0);
-        let _ = region.reproduce(1, &mut rng);
+        let _ = region.reproduce(1, &ReproductionOperators::default(), &mut rng);
         // After reproduction, organisms slice is sorted; index 0 should be older
         let first_age = region.organisms()[0].age();
         assert_eq!(first_age, 10);
@@ -185,7 +193,7 @@ mod tests {
         region.add_organism(make_org(1.0, 5, 0));
         let mut rng = SmallRng::seed_from_u64(0);
         // Request more offspring than can be produced in single pass
-        let offspring = region.reproduce(3, &mut rng);
+        let offspring = region.reproduce(3, &ReproductionOperators::default(), &mut rng);
         // Single organism pairs with itself (self-fertilization), producing 2 offspring per pass
         // 3 requested / 2 per pass = 2 passes = 3 offspring (last pass truncated)
         assert_eq!(offspring.len(), 3);
@@ -200,7 +208,7 @@ mod tests {
         region.add_organism(make_org(2.0, 3, 1));
         let mut rng = SmallRng::seed_from_u64(0);
         // Request more offspring than can be produced in single pass (2 parents -> 2 offspring per pass)
-        let offspring = region.reproduce(6, &mut rng);
+        let offspring = region.reproduce(6, &ReproductionOperators::default(), &mut rng);
         // Two organisms can produce 2 offspring per pass, so we get 6 offspring over 3 passes
         assert_eq!(offspring.len(), 6);
         assert!(offspring.iter().all(|o| o.age() == 0));
@@ -213,7 +221,7 @@ mod tests {
         region.add_organism(make_org(1.0, 5, 0));
         let mut rng = SmallRng::seed_from_u64(0);
         // Request way more offspring than REPRODUCTION_FACTOR allows
-        let offspring = region.reproduce(20, &mut rng);
+        let offspring = region.reproduce(20, &ReproductionOperators::default(), &mut rng);
         // Single organism produces 2 offspring per pass via self-fertilization, limited by REPRODUCTION_FACTOR = 10
         // 10 passes * 2 offspring = 20
         assert_eq!(offspring.len(), 20);
@@ -228,7 +236,7 @@ mod tests {
             region.add_organism(make_org(i as f64 + 1.0, i, i));
         }
         let mut rng = SmallRng::seed_from_u64(0);
-        let offspring = region.reproduce(4, &mut rng);
+        let offspring = region.reproduce(4, &ReproductionOperators::default(), &mut rng);
         // Should produce exactly 4 offspring in single pass
         assert_eq!(offspring.len(), 4);
         assert!(offspring.iter().all(|o| o.age() == 0));
@@ -245,7 +253,7 @@ mod tests {
         }
         let mut rng = SmallRng::seed_from_u64(0);
         // Request more than single pass can produce
-        let offspring = region.reproduce(9, &mut rng);
+        let offspring = region.reproduce(9, &ReproductionOperators::default(), &mut rng);
         // 3 organisms with extreme pairing: top performer duplicated creates 4 organisms
         // 2 pairs produce 4 offspring per pass. 9 requested / 4 per pass = 3 passes (rounded up) = 9 offspring
         assert_eq!(offspring.len(), 9);
@@ -259,7 +267,7 @@ mod tests {
         region.add_organism(make_org(1.0, 5, 0));
         let mut rng = SmallRng::seed_from_u64(0);
         // Request more offspring than single pass can produce
-        let offspring = region.reproduce(5, &mut rng);
+        let offspring = region.reproduce(5, &ReproductionOperators::default(), &mut rng);
         // Without carrying capacity, should only get 2 offspring (single pass, self-fertilization)
         assert_eq!(offspring.len(), 2);
         assert!(offspring.iter().all(|o| o.age() == 0));
@@ -275,7 +283,7 @@ mod tests {
         }
         let mut rng = SmallRng::seed_from_u64(0);
         // Request offspring that would exceed carrying capacity (3 + 5 = 8 > 5)
-        let offspring = region.reproduce(5, &mut rng);
+        let offspring = region.reproduce(5, &ReproductionOperators::default(), &mut rng);
         // Should only do single pass since total would exceed capacity
         // 3 organisms with extreme pairing: top performer duplicated = 2 pairs = 4 offspring per pass
         assert_eq!(offspring.len(), 4);
@@ -0,0 +1,73 @@
+use super::crossover_operator::CrossoverOperator;
+use super::mutation_operator::MutationOperator;
+use super::selection_strategy::{ExtremePairing, SelectionStrategy};
+
+/// The world-level operators consulted while regions reproduce.
+///
+/// Built by the world at the start of each epoch and shared, read-only, by all regions as
+/// they are processed in parallel.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct ReproductionOperators<'a> {
+    selection_strategy: &'a dyn SelectionStrategy,
+    crossover_operator: CrossoverOperator,
+    mutation_operator: MutationOperator,
+}
+
+impl<'a> ReproductionOperators<'a> {
+    pub(crate) fn new(
+        selection_strategy: &'a dyn SelectionStrategy,
+        crossover_operator: CrossoverOperator,
+        mutation_operator: MutationOperator,
+    ) -> Self {
+        Self {
+            selection_strategy,
+            crossover_operator,
+            mutation_operator,
+        }
+    }
+
+    /// Returns the strategy used to pair parents.
+    pub(crate) fn selection_strategy(&self) -> &'a dyn SelectionStrategy {
+        self.selection_strategy
+    }
+
+    /// Returns the operator used to recombine problem-parameter loci.
+    pub(crate) fn crossover_operator(&self) -> &CrossoverOperator {
+        &self.crossover_operator
+    }
+
+    /// Returns the operator used to mutate problem-parameter loci.
+    pub(crate) fn mutation_operator(&self) -> &MutationOperator {
+        &self.mutation_operator
+    }
+}
+
+impl Default for ReproductionOperators<'static> {
+    /// The PDD behaviour: extreme pairing, k-point crossover and doubling/halving mutation.
+    fn default() -> Self {
+        Self::new(
+            &ExtremePairing,
+            CrossoverOperator::KPoint,
+            MutationOperator::DoublingHalving,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn given_default_when_created_then_uses_pdd_operators() {
+        let operators = ReproductionOperators::default();
+        assert_eq!(
+            format!("{:?}", operators.selection_strategy()),
+            "ExtremePairing"
+        );
+        assert_eq!(*operators.crossover_operator(), CrossoverOperator::KPoint);
+        assert_eq!(
+            *operators.mutation_operator(),
+            MutationOperator::DoublingHalving
+        );
+    }
+}
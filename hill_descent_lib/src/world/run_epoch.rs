@@ -0,0 +1,85 @@
+use super::World;
+use super::elitism::ElitePolicy;
+use super::population_diversity::PopulationDiversity;
+use super::reproduction_operators::ReproductionOperators;
+
+impl World {
+    /// Runs one epoch: every region's lifecycle in parallel, then the global region update.
+    ///
+    /// When `evaluate` is `false` the world function is not called and the organisms must
+    /// already have been scored, as they are once every candidate from
+    /// [`ask`](World::ask) has been [told](World::tell).
+    ///
+    /// Returns `true` if the resolution limit has been reached.
+    pub(super) fn run_epoch(
+        &mut self,
+        evaluate: bool,
+        inputs: &[f64],
+        known_outputs: &[f64],
+    ) -> bool {
+        let world_seed = self.global_constants.world_seed();
+        // Built from individual fields so `self.regions` can still be borrowed mutably below.
+        let operators = ReproductionOperators::new(
+            self.selection_strategy.as_ref(),
+            self.crossover_operator,
+            self.mutation_operator,
+        );
+        let elites = ElitePolicy::new(self.elitism, &self.organisms, self.hall_of_fame.capacity());
+        let world_function = evaluate.then_some(self.world_function.as_ref());
+        // Held so the history and genealogy can include organisms that die during the epoch
+        let population: Option<Vec<_>> = (self.run_history.is_some() || self.genealogy.is_some())
+            .then(|| self.organisms.iter().cloned().collect());
+
+        let leaders;
+        (self.organisms, leaders) = self.regions.parallel_process_regions(
+            world_function,
+            &operators,
+            &elites,
+            inputs,
+            known_outputs,
+            world_seed,
+            self.epoch,
+        );
+
+        for leader in &leaders {
+            self.hall_of_fame.consider(leader);
+        }
+
+        self.epoch += 1;
+
+        // SYNC PHASE: Global coordination
+        let at_resolution_limit = self
+            .regions
+            .update(&mut self.organisms, &mut self.dimensions);
+
+        if let (Some(genealogy), Some(population)) = (&mut self.genealogy, &population) {
+            genealogy.record_epoch(self.epoch, population, self.organisms.iter());
+        }
+
+        let diversity_seed = self.diversity_seed();
+        if let (Some(history), Some(population)) = (&mut self.run_history, population) {
+            if evaluate {
+                history.count_evaluations(population.len());
+            }
+            let doublings = self
+                .dimensions
+                .get_dimensions()
+                .iter()
+                .map(|d| d.number_of_doublings())
+                .sum();
+            let diversity = history.records_diversity().then(|| {
+                let survivors: Vec<_> = self.organisms.iter().cloned().collect();
+                PopulationDiversity::measure(&survivors, diversity_seed)
+            });
+            history.record(
+                self.epoch,
+                &population,
+                self.regions.len(),
+                doublings,
+                diversity,
+            );
+        }
+
+        at_resolution_limit
+    }
+}
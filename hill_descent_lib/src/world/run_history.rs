@@ -0,0 +1,372 @@
+use std::io::{self, Write};
+use std::sync::Arc;
+use std::time::Instant;
+
+use serde::{Deserialize, Serialize};
+
+use super::dimension_std_devs::dimension_std_devs;
+use super::organisms::Organism;
+use super::population_diversity::PopulationDiversity;
+
+/// Statistics of one epoch, recorded in a [`RunHistory`].
+///
+/// Score statistics and [`evaluated_std_dev`](Self::evaluated_std_dev) cover the whole
+/// population evaluated during the epoch, including the organisms that then died; the
+/// scores are `None` if no organism had a score.
+/// [`survivor_diversity`](Self::survivor_diversity) covers only the organisms left at the end
+/// of the epoch.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EpochStats {
+    epoch: u64,
+    best_score: Option<f64>,
+    mean_score: Option<f64>,
+    median_score: Option<f64>,
+    worst_score: Option<f64>,
+    evaluated_std_dev: f64,
+    regions: usize,
+    dimension_doublings: usize,
+    evaluations: u64,
+    elapsed_seconds: f64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    survivor_diversity: Option<PopulationDiversity>,
+}
+
+impl EpochStats {
+    /// The CSV header written by [`RunHistory::write_csv`].
+    pub const CSV_HEADER: &'static str = "epoch,best_score,mean_score,median_score,worst_score,evaluated_std_dev,regions,dimension_doublings,evaluations,elapsed_seconds,survivor_mean_pairwise_distance,survivor_distinct_expressed_hashes,survivor_region_entropy";
+
+    /// Computes the statistics of `population`, the organisms evaluated during `epoch`.
+    pub(crate) fn from_population(
+        epoch: u64,
+        population: &[Arc<Organism>],
+        regions: usize,
+        dimension_doublings: usize,
+        evaluations: u64,
+        elapsed_seconds: f64,
+        survivor_diversity: Option<PopulationDiversity>,
+    ) -> Self {
+        let mut scores: Vec<f64> = population.iter().filter_map(|o| o.score()).collect();
+        scores.sort_by(f64::total_cmp);
+        let median_score = match scores.len() {
+            0 => None,
+            n if n % 2 == 1 => Some(scores[n / 2]),
+            n => Some((scores[n / 2 - 1] + scores[n / 2]) / 2.0),
+        };
+        let mean_score =
+            (!scores.is_empty()).then(|| scores.iter().sum::<f64>() / scores.len() as f64);
+
+        Self {
+            epoch,
+            best_score: scores.first().copied(),
+            mean_score,
+            median_score,
+            worst_score: scores.last().copied(),
+            evaluated_std_dev: mean_standard_deviation(population),
+            regions,
+            dimension_doublings,
+            evaluations,
+            elapsed_seconds,
+            survivor_diversity,
+        }
+    }
+
+    /// Returns the number of epochs completed when the statistics were taken.
+    pub fn epoch(&self) -> u64 {
+        self.epoch
+    }
+
+    /// Returns the lowest score of the epoch.
+    pub fn best_score(&self) -> Option<f64> {
+        self.best_score
+    }
+
+    /// Returns the mean score of the epoch.
+    pub fn mean_score(&self) -> Option<f64> {
+        self.mean_score
+    }
+
+    /// Returns the median score of the epoch.
+    pub fn median_score(&self) -> Option<f64> {
+        self.median_score
+    }
+
+    /// Returns the highest score of the epoch.
+    pub fn worst_score(&self) -> Option<f64> {
+        self.worst_score
+    }
+
+    /// Returns the standard deviation of each problem parameter across the population
+    /// evaluated during the epoch, averaged over the parameters. It falls towards zero as the
+    /// population converges.
+    pub fn evaluated_std_dev(&self) -> f64 {
+        self.evaluated_std_dev
+    }
+
+    /// Returns the number of regions after the epoch.
+    pub fn regions(&self) -> usize {
+        self.regions
+    }
+
+    /// Returns the number of interval doublings summed over all dimensions after the epoch.
+    pub fn dimension_doublings(&self) -> usize {
+        self.dimension_doublings
+    }
+
+    /// Returns the number of evaluations since the history was enabled.
+    pub fn evaluations(&self) -> u64 {
+        self.evaluations
+    }
+
+    /// Returns the seconds elapsed between enabling the history and the end of the epoch.
+    pub fn elapsed_seconds(&self) -> f64 {
+        self.elapsed_seconds
+    }
+
+    /// Returns the diversity of the organisms left at the end of the epoch, as
+    /// [`World::diversity`](crate::World::diversity) would then report it. It is recorded only
+    /// when the history was started with
+    /// [`World::enable_diversity_history`](crate::World::enable_diversity_history).
+    pub fn survivor_diversity(&self) -> Option<&PopulationDiversity> {
+        self.survivor_diversity.as_ref()
+    }
+
+    fn write_csv_row(&self, mut writer: impl Write) -> io::Result<()> {
+        let optional = |value: Option<f64>| value.map(|v| v.to_string()).unwrap_or_default();
+        let survivors = self.survivor_diversity.as_ref();
+        writeln!(
+            writer,
+            "{},{},{},{},{},{},{},{},{},{},{},{},{}",
+            self.epoch,
+            optional(self.best_score),
+            optional(self.mean_score),
+            optional(self.median_score),
+            optional(self.worst_score),
+            self.evaluated_std_dev,
+            self.regions,
+            self.dimension_doublings,
+            self.evaluations,
+            self.elapsed_seconds,
+            optional(survivors.map(|d| d.mean_pairwise_distance())),
+            survivors
+                .map(|d| d.distinct_expressed_hashes().to_string())
+                .unwrap_or_default(),
+            optional(survivors.map(|d| d.region_entropy()))
+        )
+    }
+}
+
+/// Per-epoch statistics of a run, for plotting convergence curves and comparing
+/// configurations offline.
+///
+/// Recording is off by default; turn it on with
+/// [`World::enable_run_history`](crate::World::enable_run_history). One [`EpochStats`] is then
+/// added at the end of every epoch, whether it was run by
+/// [`training_run`](crate::World::training_run) or completed by
+/// [`tell`](crate::World::tell).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RunHistory {
+    epochs: Vec<EpochStats>,
+    #[serde(skip, default = "Instant::now")]
+    started: Instant,
+    #[serde(skip)]
+    evaluations: u64,
+    #[serde(skip)]
+    record_diversity: bool,
+}
+
+impl RunHistory {
+    pub(crate) fn new() -> Self {
+        Self {
+            epochs: Vec::new(),
+            started: Instant::now(),
+            evaluations: 0,
+            record_diversity: false,
+        }
+    }
+
+    /// Returns the statistics of each recorded epoch, oldest first.
+    pub fn epochs(&self) -> &[EpochStats] {
+        &self.epochs
+    }
+
+    /// Returns the statistics of the latest epoch, if any.
+    pub fn last(&self) -> Option<&EpochStats> {
+        self.epochs.last()
+    }
+
+    /// Returns the number of recorded epochs.
+    pub fn len(&self) -> usize {
+        self.epochs.len()
+    }
+
+    /// Returns `true` if no epoch has been recorded.
+    pub fn is_empty(&self) -> bool {
+        self.epochs.is_empty()
+    }
+
+    /// Writes the history as CSV with a [header](EpochStats::CSV_HEADER) and one row per
+    /// epoch. Missing scores are left empty.
+    pub fn write_csv(&self, mut writer: impl Write) -> io::Result<()> {
+        writeln!(writer, "{}", EpochStats::CSV_HEADER)?;
+        for stats in &self.epochs {
+            stats.write_csv_row(&mut writer)?;
+        }
+        Ok(())
+    }
+
+    /// Returns the history as CSV; see [`write_csv`](Self::write_csv).
+    pub fn to_csv(&self) -> String {
+        let mut csv = Vec::new();
+        self.write_csv(&mut csv)
+            .expect("writing to a Vec cannot fail");
+        String::from_utf8(csv).expect("CSV is valid UTF-8")
+    }
+
+    /// Returns the history as a JSON object with an `epochs` array. Missing scores are
+    /// `null`.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("run history is always serializable")
+    }
+
+    /// Reads a history written by [`to_json`](Self::to_json).
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+
+    pub(crate) fn count_evaluations(&mut self, evaluations: usize) {
+        self.evaluations += evaluations as u64;
+    }
+
+    pub(crate) fn record_diversity(&mut self) {
+        self.record_diversity = true;
+    }
+
+    pub(crate) fn records_diversity(&self) -> bool {
+        self.record_diversity
+    }
+
+    pub(crate) fn record(
+        &mut self,
+        epoch: u64,
+        population: &[Arc<Organism>],
+        regions: usize,
+        dimension_doublings: usize,
+        survivor_diversity: Option<PopulationDiversity>,
+    ) {
+        self.epochs.push(EpochStats::from_population(
+            epoch,
+            population,
+            regions,
+            dimension_doublings,
+            self.evaluations,
+            self.started.elapsed().as_secs_f64(),
+            survivor_diversity,
+        ));
+    }
+}
+
+/// Returns the population standard deviation of each problem parameter, averaged over the
+/// parameters, or 0 for an empty population.
+fn mean_standard_deviation(population: &[Arc<Organism>]) -> f64 {
+    let values: Vec<&[f64]> = population
+        .iter()
+        .map(|o| o.phenotype().expression_problem_values())
+        .collect();
+    let std_devs = dimension_std_devs(&values);
+    if std_devs.is_empty() {
+        return 0.0;
+    }
+    std_devs.iter().sum::<f64>() / std_devs.len() as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::phenotype::Phenotype;
+
+    fn organism(problem_values: &[f64], score: Option<f64>) -> Arc<Organism> {
+        let mut expressed = vec![0.1, 0.5, 0.001, 0.001, 0.001, 100.0, 2.0];
+        expressed.extend_from_slice(problem_values);
+        let phenotype = Arc::new(Phenotype::new_for_test(expressed));
+        let organism = Arc::new(Organism::new(phenotype, 0, (None, None)));
+        if let Some(score) = score {
+            organism.set_score(Some(score));
+        }
+        organism
+    }
+
+    #[test]
+    fn given_scored_population_when_stats_computed_then_summaries_correct() {
+        let population = vec![
+            organism(&[0.0], Some(4.0)),
+            organism(&[2.0], Some(1.0)),
+            organism(&[4.0], Some(3.0)),
+            organism(&[6.0], Some(2.0)),
+        ];
+
+        let stats = EpochStats::from_population(3, &population, 5, 7, 40, 1.5, None);
+
+        assert_eq!(stats.epoch(), 3);
+        assert_eq!(stats.best_score(), Some(1.0));
+        assert_eq!(stats.worst_score(), Some(4.0));
+        assert_eq!(stats.mean_score(), Some(2.5));
+        assert_eq!(stats.median_score(), Some(2.5));
+        assert!((stats.evaluated_std_dev() - 5.0f64.sqrt()).abs() < 1e-12);
+        assert_eq!(stats.regions(), 5);
+        assert_eq!(stats.dimension_doublings(), 7);
+        assert_eq!(stats.evaluations(), 40);
+    }
+
+    #[test]
+    fn given_converged_population_at_large_offset_when_stats_computed_then_evaluated_std_dev_accurate()
+     {
+        let population: Vec<_> = (0..4)
+            .map(|i| organism(&[-1.5e7 + i as f64 * 1e-4, 2e7], Some(1.0)))
+            .collect();
+
+        let stats = EpochStats::from_population(1, &population, 1, 0, 0, 0.0, None);
+
+        // Half the spread of the first parameter, since the second has none
+        let expected = 1.25e-8f64.sqrt() / 2.0;
+        assert!((stats.evaluated_std_dev() - expected).abs() < 1e-3 * expected);
+    }
+
+    #[test]
+    fn given_unscored_population_when_stats_computed_then_scores_missing() {
+        let population = vec![organism(&[1.0, 1.0], None)];
+
+        let stats = EpochStats::from_population(1, &population, 1, 0, 0, 0.0, None);
+
+        assert_eq!(stats.best_score(), None);
+        assert_eq!(stats.median_score(), None);
+        assert_eq!(stats.evaluated_std_dev(), 0.0);
+    }
+
+    #[test]
+    fn given_history_when_exported_to_csv_then_header_and_rows_written() {
+        let mut history = RunHistory::new();
+        history.count_evaluations(2);
+        history.record(1, &[organism(&[0.0], Some(0.5))], 1, 0, None);
+        history.record(2, &[organism(&[0.0], None)], 1, 0, None);
+
+        let csv = history.to_csv();
+        let lines: Vec<_> = csv.lines().collect();
+
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[0], EpochStats::CSV_HEADER);
+        assert!(lines[1].starts_with("1,0.5,0.5,0.5,0.5,0,1,0,2,"));
+        assert!(lines[2].starts_with("2,,,,,0,1,0,2,"));
+    }
+
+    #[test]
+    fn given_history_when_round_tripped_through_json_then_epochs_preserved() {
+        let mut history = RunHistory::new();
+        history.record(1, &[organism(&[1.0], Some(2.0))], 2, 1, None);
+        history.record(2, &[], 2, 1, None);
+
+        let restored = RunHistory::from_json(&history.to_json()).unwrap();
+
+        assert_eq!(restored.epochs(), history.epochs());
+        assert!(history.to_json().contains("\"best_score\":null"));
+    }
+}
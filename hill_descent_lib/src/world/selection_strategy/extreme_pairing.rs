@@ -1,22 +1,27 @@
+use rand::RngCore;
 use std::sync::Arc;
 
-use crate::world::{organisms::organism::Organism, regions::region::Region};
-
-type OrganismPairs = Vec<(Arc<Organism>, Arc<Organism>)>;
-
-impl Region {
-    /// Pairs organisms for reproduction using extreme pairing strategy.
-    ///
-    /// For even counts: Pairs first with last, second with second-to-last, etc.
-    /// For odd counts: Duplicates the top performer, then applies extreme pairing.
-    /// This eliminates asexual reproduction - even single organisms pair with themselves.
-    ///
-    /// * `selected_organisms` - The slice of organisms to pair for reproduction
-    ///
-    /// Returns a vector of organism pairs for sexual reproduction
-    pub(super) fn pair_organisms_for_reproduction(
-        selected_organisms: &[Arc<Organism>],
+use super::{OrganismPairs, SelectionStrategy};
+use crate::world::organisms::Organism;
+
+/// The default selection strategy: truncation to the top parents followed by extreme pairing.
+///
+/// Only the best `parents_required` organisms reproduce. They are paired first with last,
+/// second with second-to-last, etc. For odd counts the top performer is duplicated before
+/// pairing, so even a single organism reproduces sexually by pairing with itself.
+///
+/// This strategy is fully deterministic and never consumes the RNG.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ExtremePairing;
+
+impl SelectionStrategy for ExtremePairing {
+    fn select_pairs(
+        &self,
+        ranked: &[Arc<Organism>],
+        parents_required: usize,
+        _rng: &mut dyn RngCore,
     ) -> OrganismPairs {
+        let selected_organisms = &ranked[..parents_required.min(ranked.len())];
         let mut pairs = Vec::new();
 
         if selected_organisms.is_empty() {
@@ -53,34 +58,30 @@ impl Region {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::phenotype::Phenotype;
-    use std::sync::Arc;
-
-    /// Helper: create an Organism with given score and age.
-    fn make_org(score: f64, age: usize, idx: usize) -> Arc<Organism> {
-        // Expressed values: default 7 system parameters + one dummy problem param
-        let expressed = vec![0.1, 0.5, 0.001, 0.001, 0.001, 100.0, 2.0, idx as f64];
-        let phenotype = Arc::new(Phenotype::new_for_test(expressed));
-        let org = Organism::new(Arc::clone(&phenotype), age, (None, None));
-        org.set_score(Some(score));
-        Arc::new(org)
+    use crate::world::selection_strategy::tests::make_org;
+    use rand::SeedableRng;
+    use rand::rngs::SmallRng;
+
+    fn pair_all(organisms: &[Arc<Organism>]) -> OrganismPairs {
+        let mut rng = SmallRng::seed_from_u64(0);
+        ExtremePairing.select_pairs(organisms, organisms.len(), &mut rng)
     }
 
     #[test]
-    fn given_empty_organisms_when_pair_organisms_then_returns_empty_pairs() {
+    fn given_empty_organisms_when_select_pairs_then_returns_empty_pairs() {
         let organisms: Vec<Arc<Organism>> = vec![];
 
-        let pairs = Region::pair_organisms_for_reproduction(&organisms);
+        let pairs = pair_all(&organisms);
 
         assert!(pairs.is_empty());
     }
 
     #[test]
-    fn given_single_organism_when_pair_organisms_then_pairs_with_itself() {
+    fn given_single_organism_when_select_pairs_then_pairs_with_itself() {
         let organisms = vec![make_org(1.0, 5, 0)];
         let organism_id = organisms[0].id();
 
-        let pairs = Region::pair_organisms_for_reproduction(&organisms);
+        let pairs = pair_all(&organisms);
 
         assert_eq!(pairs.len(), 1);
         assert_eq!(pairs[0].0.id(), organism_id);
@@ -88,12 +89,12 @@ mod tests {
     }
 
     #[test]
-    fn given_two_organisms_when_pair_organisms_then_pairs_first_with_last() {
+    fn given_two_organisms_when_select_pairs_then_pairs_first_with_last() {
         let organisms = vec![make_org(1.0, 5, 0), make_org(2.0, 3, 1)];
         let id1 = organisms[0].id(); // Best performer (lowest score)
         let id2 = organisms[1].id(); // Worst performer
 
-        let pairs = Region::pair_organisms_for_reproduction(&organisms);
+        let pairs = pair_all(&organisms);
 
         assert_eq!(pairs.len(), 1);
         assert_eq!(pairs[0].0.id(), id1); // First (best)
@@ -101,7 +102,7 @@ mod tests {
     }
 
     #[test]
-    fn given_three_organisms_when_pair_organisms_then_duplicates_top_performer() {
+    fn given_three_organisms_when_select_pairs_then_duplicates_top_performer() {
         let organisms = vec![
             make_org(1.0, 5, 0), // Best performer
             make_org(2.0, 3, 1), // Middle
@@ -111,19 +112,19 @@ mod tests {
         let id2 = organisms[1].id(); // Middle
         let id3 = organisms[2].id(); // Worst performer
 
-        let pairs = Region::pair_organisms_for_reproduction(&organisms);
+        let pairs = pair_all(&organisms);
 
         // With 3 organisms, top performer gets duplicated creating 4 total:
         // [best, best, middle, worst] -> pairs: (best, worst), (best, middle)
         assert_eq!(pairs.len(), 2);
         assert_eq!(pairs[0].0.id(), id1); // First copy of best performer
         assert_eq!(pairs[0].1.id(), id3); // Worst performer
-        assert_eq!(pairs[1].0.id(), id1); // Second copy of best performer  
+        assert_eq!(pairs[1].0.id(), id1); // Second copy of best performer
         assert_eq!(pairs[1].1.id(), id2); // Middle performer
     }
 
     #[test]
-    fn given_four_organisms_when_pair_organisms_then_pairs_extremes() {
+    fn given_four_organisms_when_select_pairs_then_pairs_extremes() {
         let organisms = vec![
             make_org(1.0, 5, 0), // Best performer
             make_org(2.0, 3, 1), // Second best
@@ -135,7 +136,7 @@ mod tests {
         let id3 = organisms[2].id(); // Second worst
         let id4 = organisms[3].id(); // Worst
 
-        let pairs = Region::pair_organisms_for_reproduction(&organisms);
+        let pairs = pair_all(&organisms);
 
         // Even count: extreme pairing (first with last, second with second-to-last)
         assert_eq!(pairs.len(), 2);
@@ -146,7 +147,7 @@ mod tests {
     }
 
     #[test]
-    fn given_five_organisms_when_pair_organisms_then_duplicates_top_and_pairs_extremes() {
+    fn given_five_organisms_when_select_pairs_then_duplicates_top_and_pairs_extremes() {
         let organisms = vec![
             make_org(1.0, 5, 0), // Best
             make_org(2.0, 4, 1), // 2nd best
@@ -160,7 +161,7 @@ mod tests {
         let id4 = organisms[3].id(); // 2nd worst
         let id5 = organisms[4].id(); // Worst
 
-        let pairs = Region::pair_organisms_for_reproduction(&organisms);
+        let pairs = pair_all(&organisms);
 
         // With 5 organisms, duplicated list becomes: [best, best, 2nd, middle, 2nd_worst, worst]
         // Extreme pairing: (best, worst), (best, 2nd_worst), (2nd, middle)
@@ -174,7 +175,7 @@ mod tests {
     }
 
     #[test]
-    fn given_six_organisms_when_pair_organisms_then_pairs_all_extremes() {
+    fn given_six_organisms_when_select_pairs_then_pairs_all_extremes() {
         let organisms = vec![
             make_org(1.0, 6, 0), // Best
             make_org(2.0, 5, 1), // 2nd best
@@ -190,7 +191,7 @@ mod tests {
         let id5 = organisms[4].id();
         let id6 = organisms[5].id();
 
-        let pairs = Region::pair_organisms_for_reproduction(&organisms);
+        let pairs = pair_all(&organisms);
 
         // Even count: (best, worst), (2nd_best, 2nd_worst), (3rd_best, 3rd_worst)
         assert_eq!(pairs.len(), 3);
@@ -201,4 +202,23 @@ mod tests {
         assert_eq!(pairs[2].0.id(), id3); // 3rd best
         assert_eq!(pairs[2].1.id(), id4); // 3rd worst
     }
+
+    #[test]
+    fn given_parents_required_less_than_ranked_when_select_pairs_then_only_top_used() {
+        let organisms = vec![
+            make_org(1.0, 4, 0),
+            make_org(2.0, 3, 1),
+            make_org(3.0, 2, 2),
+            make_org(4.0, 1, 3),
+        ];
+        let id1 = organisms[0].id();
+        let id2 = organisms[1].id();
+        let mut rng = SmallRng::seed_from_u64(0);
+
+        let pairs = ExtremePairing.select_pairs(&organisms, 2, &mut rng);
+
+        assert_eq!(pairs.len(), 1);
+        assert_eq!(pairs[0].0.id(), id1);
+        assert_eq!(pairs[0].1.id(), id2);
+    }
 }
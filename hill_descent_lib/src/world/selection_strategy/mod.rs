@@ -0,0 +1,145 @@
+//! Parent selection and pairing strategies used during regional reproduction.
+//!
+//! Every epoch each region ranks its organisms by score (ascending) then age (descending)
+//! and asks the world's [`SelectionStrategy`] which pairs of organisms should mate. The
+//! strategy therefore controls the selection pressure applied within a region, while the
+//! region itself still decides *how many* offspring are required (see PDD §5.2.3).
+//!
+//! # Built-in Strategies
+//!
+//! - [`ExtremePairing`] - The default. Pairs best with worst over the top-ranked parents.
+//! - [`TournamentSelection`] - Each parent is the winner of a k-way tournament.
+//! - [`RouletteWheelSelection`] - Fitness-proportional selection adapted for minimisation.
+//! - [`RankSelection`] - Linear ranking selection with configurable selection pressure.
+//! - [`RandomMating`] - Uniformly random pairs drawn from the top-ranked parents.
+//!
+//! # Examples
+//!
+//! ```
+//! use hill_descent_lib::{GlobalConstants, SingleValuedFunction, TrainingData, setup_world};
+//! use hill_descent_lib::world::selection_strategy::TournamentSelection;
+//!
+//! #[derive(Debug)]
+//! struct Sphere;
+//!
+//! impl SingleValuedFunction for Sphere {
+//!     fn single_run(&self, params: &[f64]) -> f64 {
+//!         params.iter().map(|x| x * x).sum()
+//!     }
+//! }
+//!
+//! let bounds = vec![-5.0..=5.0; 2];
+//! let mut world = setup_world(&bounds, GlobalConstants::new(100, 10), Box::new(Sphere));
+//! world.set_selection_strategy(Box::new(TournamentSelection::new(3)));
+//!
+//! for _ in 0..10 {
+//!     world.training_run(TrainingData::None { floor_value: 0.0 });
+//! }
+//! ```
+
+use rand::RngCore;
+use std::fmt::Debug;
+use std::sync::Arc;
+
+use crate::world::organisms::Organism;
+
+pub mod extreme_pairing;
+pub mod random_mating;
+pub mod rank_selection;
+pub mod roulette_wheel_selection;
+pub mod tournament_selection;
+
+pub use extreme_pairing::ExtremePairing;
+pub use random_mating::RandomMating;
+pub use rank_selection::RankSelection;
+pub use roulette_wheel_selection::RouletteWheelSelection;
+pub use tournament_selection::TournamentSelection;
+
+/// A list of organism pairs, each of which produces two offspring.
+pub type OrganismPairs = Vec<(Arc<Organism>, Arc<Organism>)>;
+
+/// Chooses which organisms of a region mate with each other.
+///
+/// Implementations must be deterministic for a given RNG state so that runs remain
+/// reproducible across thread counts; all randomness must come from the supplied `rng`.
+///
+/// The strategy is shared by all regions, which are processed in parallel, hence the
+/// `Send + Sync` bounds.
+pub trait SelectionStrategy: Debug + Send + Sync {
+    /// Selects the parent pairs for a single reproduction pass.
+    ///
+    /// * `ranked` - All living organisms in the region, sorted best first (score ascending,
+    ///   then age descending). Never empty.
+    /// * `parents_required` - The number of parents the region wants to use this pass,
+    ///   always in `1..=ranked.len()`.
+    /// * `rng` - The region's deterministic random number generator.
+    ///
+    /// Implementations should return exactly `parents_required.div_ceil(2)` pairs, as the
+    /// region sizes its offspring budget on that assumption. An organism may appear in
+    /// several pairs, or be paired with itself.
+    fn select_pairs(
+        &self,
+        ranked: &[Arc<Organism>],
+        parents_required: usize,
+        rng: &mut dyn RngCore,
+    ) -> OrganismPairs;
+}
+
+/// Draws an index in `0..len` from cumulative weights using the supplied RNG.
+///
+/// Falls back to a uniform draw if the weights do not sum to a positive finite value.
+pub(crate) fn sample_weighted_index(cumulative: &[f64], rng: &mut dyn RngCore) -> usize {
+    use rand::Rng;
+
+    let total = cumulative.last().copied().unwrap_or(0.0);
+    if !(total.is_finite() && total > 0.0) {
+        return rng.random_range(0..cumulative.len());
+    }
+    let target = rng.random_range(0.0..total);
+    cumulative
+        .partition_point(|&c| c <= target)
+        .min(cumulative.len() - 1)
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::phenotype::Phenotype;
+    use rand::SeedableRng;
+    use rand::rngs::SmallRng;
+
+    /// Helper: create an Organism with given score and age.
+    pub(crate) fn make_org(score: f64, age: usize, idx: usize) -> Arc<Organism> {
+        // Expressed values: default 7 system parameters + one dummy problem param
+        let expressed = vec![0.1, 0.5, 0.001, 0.001, 0.001, 100.0, 2.0, idx as f64];
+        let phenotype = Arc::new(Phenotype::new_for_test(expressed));
+        let org = Organism::new(Arc::clone(&phenotype), age, (None, None));
+        org.set_score(Some(score));
+        Arc::new(org)
+    }
+
+    #[test]
+    fn given_zero_total_weight_when_sample_weighted_index_then_uniform_index_in_range() {
+        let mut rng = SmallRng::seed_from_u64(0);
+        for _ in 0..100 {
+            let idx = sample_weighted_index(&[0.0, 0.0, 0.0], &mut rng);
+            assert!(idx < 3);
+        }
+    }
+
+    #[test]
+    fn given_single_non_zero_weight_when_sample_weighted_index_then_always_that_index() {
+        let mut rng = SmallRng::seed_from_u64(0);
+        // Weights [0, 1, 0] -> cumulative [0, 1, 1]
+        for _ in 0..100 {
+            assert_eq!(sample_weighted_index(&[0.0, 1.0, 1.0], &mut rng), 1);
+        }
+    }
+
+    #[test]
+    fn given_infinite_total_when_sample_weighted_index_then_falls_back_to_uniform() {
+        let mut rng = SmallRng::seed_from_u64(0);
+        let idx = sample_weighted_index(&[1.0, f64::INFINITY], &mut rng);
+        assert!(idx < 2);
+    }
+}
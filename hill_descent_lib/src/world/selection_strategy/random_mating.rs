@@ -0,0 +1,63 @@
+use rand::{Rng, RngCore};
+use std::sync::Arc;
+
+use super::{OrganismPairs, SelectionStrategy};
+use crate::world::organisms::Organism;
+
+/// Uniformly random mating among the top-ranked parents.
+///
+/// Truncation selection is kept (only the best `parents_required` organisms reproduce), but
+/// mates are paired at random rather than best-with-worst, which removes the structured
+/// pairing bias of [`ExtremePairing`](super::ExtremePairing).
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RandomMating;
+
+impl SelectionStrategy for RandomMating {
+    fn select_pairs(
+        &self,
+        ranked: &[Arc<Organism>],
+        parents_required: usize,
+        rng: &mut dyn RngCore,
+    ) -> OrganismPairs {
+        let pool = &ranked[..parents_required.min(ranked.len())];
+        if pool.is_empty() {
+            return Vec::new();
+        }
+        (0..parents_required.div_ceil(2))
+            .map(|_| {
+                let first = rng.random_range(0..pool.len());
+                let second = rng.random_range(0..pool.len());
+                (Arc::clone(&pool[first]), Arc::clone(&pool[second]))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::world::selection_strategy::tests::make_org;
+    use rand::SeedableRng;
+    use rand::rngs::SmallRng;
+
+    #[test]
+    fn given_parents_required_when_select_pairs_then_only_top_parents_used() {
+        let organisms: Vec<_> = (0..6).map(|i| make_org(i as f64, 0, i)).collect();
+        let top_ids: Vec<_> = organisms[..3].iter().map(|o| o.id()).collect();
+        let mut rng = SmallRng::seed_from_u64(1);
+
+        let pairs = RandomMating.select_pairs(&organisms, 3, &mut rng);
+
+        assert_eq!(pairs.len(), 2);
+        for (a, b) in pairs {
+            assert!(top_ids.contains(&a.id()));
+            assert!(top_ids.contains(&b.id()));
+        }
+    }
+
+    #[test]
+    fn given_empty_ranked_when_select_pairs_then_returns_empty() {
+        let mut rng = SmallRng::seed_from_u64(0);
+        assert!(RandomMating.select_pairs(&[], 2, &mut rng).is_empty());
+    }
+}
@@ -0,0 +1,150 @@
+use rand::RngCore;
+use std::sync::Arc;
+
+use super::{OrganismPairs, SelectionStrategy, sample_weighted_index};
+use crate::world::organisms::Organism;
+
+/// Linear ranking selection.
+///
+/// Selection probability depends only on rank, not on the magnitude of scores, which keeps
+/// selection pressure stable when scores span many orders of magnitude. For a region of `n`
+/// organisms the organism at rank `i` (0 = best) has probability
+/// `(2 - s + 2(s - 1)(n - 1 - i)/(n - 1)) / n`, where `s` is the selection pressure.
+///
+/// Selection is drawn from the whole ranked region.
+#[derive(Debug, Clone, Copy)]
+pub struct RankSelection {
+    selection_pressure: f64,
+}
+
+impl RankSelection {
+    /// Creates a linear ranking strategy.
+    ///
+    /// `selection_pressure` is the expected number of offspring of the best organism
+    /// relative to the average; 1.0 is uniform and 2.0 is the maximum.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `selection_pressure` is not within `[1.0, 2.0]`.
+    pub fn new(selection_pressure: f64) -> Self {
+        assert!(
+            (1.0..=2.0).contains(&selection_pressure),
+            "selection_pressure must be in [1.0, 2.0]"
+        );
+        Self { selection_pressure }
+    }
+
+    /// Returns the configured selection pressure.
+    pub fn selection_pressure(&self) -> f64 {
+        self.selection_pressure
+    }
+
+    /// Builds the cumulative (unnormalised) rank weight table for `len` organisms.
+    fn cumulative_weights(&self, len: usize) -> Vec<f64> {
+        let s = self.selection_pressure;
+        let denominator = (len.saturating_sub(1)).max(1) as f64;
+        let mut total = 0.0;
+        (0..len)
+            .map(|i| {
+                total += 2.0 - s + 2.0 * (s - 1.0) * (len - 1 - i) as f64 / denominator;
+                total
+            })
+            .collect()
+    }
+}
+
+impl Default for RankSelection {
+    fn default() -> Self {
+        Self::new(1.5)
+    }
+}
+
+impl SelectionStrategy for RankSelection {
+    fn select_pairs(
+        &self,
+        ranked: &[Arc<Organism>],
+        parents_required: usize,
+        rng: &mut dyn RngCore,
+    ) -> OrganismPairs {
+        if ranked.is_empty() {
+            return Vec::new();
+        }
+        let cumulative = self.cumulative_weights(ranked.len());
+        (0..parents_required.div_ceil(2))
+            .map(|_| {
+                let first = sample_weighted_index(&cumulative, rng);
+                let second = sample_weighted_index(&cumulative, rng);
+                (Arc::clone(&ranked[first]), Arc::clone(&ranked[second]))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::world::selection_strategy::tests::make_org;
+    use rand::SeedableRng;
+    use rand::rngs::SmallRng;
+
+    #[test]
+    #[should_panic(expected = "selection_pressure must be in [1.0, 2.0]")]
+    fn given_pressure_below_one_when_new_then_panics() {
+        RankSelection::new(0.99);
+    }
+
+    #[test]
+    #[should_panic(expected = "selection_pressure must be in [1.0, 2.0]")]
+    fn given_pressure_above_two_when_new_then_panics() {
+        RankSelection::new(2.01);
+    }
+
+    #[test]
+    fn given_boundary_pressures_when_new_then_accepted() {
+        assert_eq!(RankSelection::new(1.0).selection_pressure(), 1.0);
+        assert_eq!(RankSelection::new(2.0).selection_pressure(), 2.0);
+    }
+
+    #[test]
+    fn given_max_pressure_when_cumulative_weights_then_worst_has_zero_weight() {
+        let cumulative = RankSelection::new(2.0).cumulative_weights(3);
+
+        // Weights: [2.0, 1.0, 0.0]
+        assert_eq!(cumulative, vec![2.0, 3.0, 3.0]);
+    }
+
+    #[test]
+    fn given_unit_pressure_when_cumulative_weights_then_uniform() {
+        let cumulative = RankSelection::new(1.0).cumulative_weights(3);
+
+        assert_eq!(cumulative, vec![1.0, 2.0, 3.0]);
+    }
+
+    #[test]
+    fn given_single_organism_when_select_pairs_then_pairs_with_itself() {
+        let organisms = vec![make_org(1.0, 0, 0)];
+        let id = organisms[0].id();
+        let mut rng = SmallRng::seed_from_u64(0);
+
+        let pairs = RankSelection::default().select_pairs(&organisms, 1, &mut rng);
+
+        assert_eq!(pairs.len(), 1);
+        assert_eq!((pairs[0].0.id(), pairs[0].1.id()), (id, id));
+    }
+
+    #[test]
+    fn given_max_pressure_when_select_pairs_then_worst_never_selected() {
+        let organisms: Vec<_> = (0..3).map(|i| make_org(i as f64, 0, i)).collect();
+        let worst_id = organisms[2].id();
+        let mut rng = SmallRng::seed_from_u64(5);
+
+        let pairs = RankSelection::new(2.0).select_pairs(&organisms, 100, &mut rng);
+
+        assert_eq!(pairs.len(), 50);
+        assert!(
+            pairs
+                .iter()
+                .all(|(a, b)| a.id() != worst_id && b.id() != worst_id)
+        );
+    }
+}
@@ -0,0 +1,128 @@
+use rand::RngCore;
+use std::sync::Arc;
+
+use super::{OrganismPairs, SelectionStrategy, sample_weighted_index};
+use crate::world::organisms::Organism;
+
+/// Fitness-proportional (roulette wheel) selection adapted for minimisation.
+///
+/// Each organism's slice of the wheel is `worst_score - score`, plus a small share of the
+/// score spread so the worst organism keeps a non-zero chance of reproducing. Unscored or
+/// non-finite scores receive no slice. If every organism has the same score the wheel
+/// degenerates to uniform selection.
+///
+/// Selection is drawn from the whole ranked region.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RouletteWheelSelection;
+
+impl RouletteWheelSelection {
+    /// Fraction of the score spread added to every finite weight.
+    const WEIGHT_FLOOR_FRACTION: f64 = 0.01;
+
+    /// Builds the cumulative weight table for the ranked organisms.
+    fn cumulative_weights(ranked: &[Arc<Organism>]) -> Vec<f64> {
+        let finite_scores = ranked
+            .iter()
+            .filter_map(|o| o.score())
+            .filter(|s| s.is_finite());
+        let (best, worst) = finite_scores.fold((f64::INFINITY, f64::NEG_INFINITY), |(b, w), s| {
+            (b.min(s), w.max(s))
+        });
+        let floor = ((worst - best) * Self::WEIGHT_FLOOR_FRACTION).max(f64::MIN_POSITIVE);
+
+        let mut total = 0.0;
+        ranked
+            .iter()
+            .map(|o| {
+                let weight = match o.score() {
+                    Some(s) if s.is_finite() => worst - s + floor,
+                    _ => 0.0,
+                };
+                total += weight;
+                total
+            })
+            .collect()
+    }
+}
+
+impl SelectionStrategy for RouletteWheelSelection {
+    fn select_pairs(
+        &self,
+        ranked: &[Arc<Organism>],
+        parents_required: usize,
+        rng: &mut dyn RngCore,
+    ) -> OrganismPairs {
+        if ranked.is_empty() {
+            return Vec::new();
+        }
+        let cumulative = Self::cumulative_weights(ranked);
+        (0..parents_required.div_ceil(2))
+            .map(|_| {
+                let first = sample_weighted_index(&cumulative, rng);
+                let second = sample_weighted_index(&cumulative, rng);
+                (Arc::clone(&ranked[first]), Arc::clone(&ranked[second]))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::world::selection_strategy::tests::make_org;
+    use rand::SeedableRng;
+    use rand::rngs::SmallRng;
+
+    #[test]
+    fn given_scores_when_cumulative_weights_then_better_scores_get_larger_slices() {
+        let organisms = vec![
+            make_org(0.0, 0, 0),
+            make_org(5.0, 0, 1),
+            make_org(10.0, 0, 2),
+        ];
+
+        let cumulative = RouletteWheelSelection::cumulative_weights(&organisms);
+
+        // floor = 10 * 0.01 = 0.1 -> weights [10.1, 5.1, 0.1]
+        assert!((cumulative[0] - 10.1).abs() < 1e-12);
+        assert!((cumulative[1] - 15.2).abs() < 1e-12);
+        assert!((cumulative[2] - 15.3).abs() < 1e-12);
+    }
+
+    #[test]
+    fn given_unscored_organism_when_cumulative_weights_then_gets_no_slice() {
+        let organisms = vec![make_org(1.0, 0, 0), make_org(2.0, 0, 1)];
+        organisms[1].set_score(None);
+
+        let cumulative = RouletteWheelSelection::cumulative_weights(&organisms);
+
+        assert_eq!(cumulative[0], cumulative[1]);
+    }
+
+    #[test]
+    fn given_equal_scores_when_select_pairs_then_all_organisms_can_be_selected() {
+        let organisms: Vec<_> = (0..3).map(|i| make_org(1.0, 0, i)).collect();
+        let mut rng = SmallRng::seed_from_u64(3);
+
+        let pairs = RouletteWheelSelection.select_pairs(&organisms, 200, &mut rng);
+
+        assert_eq!(pairs.len(), 100);
+        for organism in &organisms {
+            assert!(
+                pairs
+                    .iter()
+                    .any(|(a, b)| a.id() == organism.id() || b.id() == organism.id())
+            );
+        }
+    }
+
+    #[test]
+    fn given_empty_ranked_when_select_pairs_then_returns_empty() {
+        let mut rng = SmallRng::seed_from_u64(0);
+        assert!(
+            RouletteWheelSelection
+                .select_pairs(&[], 2, &mut rng)
+                .is_empty()
+        );
+    }
+}
@@ -0,0 +1,139 @@
+use rand::{Rng, RngCore};
+use std::sync::Arc;
+
+use super::{OrganismPairs, SelectionStrategy};
+use crate::world::organisms::Organism;
+
+/// k-way tournament selection.
+///
+/// Each parent is chosen by drawing `tournament_size` organisms uniformly (with replacement)
+/// from the whole ranked region and keeping the best of them. Larger tournaments increase
+/// selection pressure; a size of 1 degenerates to uniform random selection.
+#[derive(Debug, Clone, Copy)]
+pub struct TournamentSelection {
+    tournament_size: usize,
+}
+
+impl TournamentSelection {
+    /// Creates a tournament selection strategy.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `tournament_size` is zero.
+    pub fn new(tournament_size: usize) -> Self {
+        assert!(
+            tournament_size > 0,
+            "tournament_size must be greater than 0"
+        );
+        Self { tournament_size }
+    }
+
+    /// Returns the number of contestants drawn per tournament.
+    pub fn tournament_size(&self) -> usize {
+        self.tournament_size
+    }
+
+    /// Runs a single tournament and returns the index of the winner.
+    ///
+    /// Because `ranked` is sorted best first, the winner is the smallest index drawn.
+    fn run_tournament(&self, len: usize, rng: &mut dyn RngCore) -> usize {
+        (0..self.tournament_size)
+            .map(|_| rng.random_range(0..len))
+            .min()
+            .expect("tournament_size is always greater than 0")
+    }
+}
+
+impl Default for TournamentSelection {
+    /// Binary tournament selection.
+    fn default() -> Self {
+        Self::new(2)
+    }
+}
+
+impl SelectionStrategy for TournamentSelection {
+    fn select_pairs(
+        &self,
+        ranked: &[Arc<Organism>],
+        parents_required: usize,
+        rng: &mut dyn RngCore,
+    ) -> OrganismPairs {
+        if ranked.is_empty() {
+            return Vec::new();
+        }
+        (0..parents_required.div_ceil(2))
+            .map(|_| {
+                let first = self.run_tournament(ranked.len(), rng);
+                let second = self.run_tournament(ranked.len(), rng);
+                (Arc::clone(&ranked[first]), Arc::clone(&ranked[second]))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::world::selection_strategy::tests::make_org;
+    use rand::SeedableRng;
+    use rand::rngs::SmallRng;
+
+    #[test]
+    #[should_panic(expected = "tournament_size must be greater than 0")]
+    fn given_zero_tournament_size_when_new_then_panics() {
+        TournamentSelection::new(0);
+    }
+
+    #[test]
+    fn given_default_when_tournament_size_then_is_binary() {
+        assert_eq!(TournamentSelection::default().tournament_size(), 2);
+    }
+
+    #[test]
+    fn given_empty_ranked_when_select_pairs_then_returns_empty() {
+        let mut rng = SmallRng::seed_from_u64(0);
+        let pairs = TournamentSelection::default().select_pairs(&[], 3, &mut rng);
+        assert!(pairs.is_empty());
+    }
+
+    #[test]
+    fn given_odd_parents_required_when_select_pairs_then_returns_rounded_up_pair_count() {
+        let organisms: Vec<_> = (0..5).map(|i| make_org(i as f64, 0, i)).collect();
+        let mut rng = SmallRng::seed_from_u64(0);
+
+        let pairs = TournamentSelection::new(2).select_pairs(&organisms, 5, &mut rng);
+
+        assert_eq!(pairs.len(), 3);
+    }
+
+    #[test]
+    fn given_huge_tournament_when_select_pairs_then_best_always_wins() {
+        let organisms: Vec<_> = (0..4).map(|i| make_org(i as f64, 0, i)).collect();
+        let best_id = organisms[0].id();
+        let mut rng = SmallRng::seed_from_u64(7);
+
+        let pairs = TournamentSelection::new(200).select_pairs(&organisms, 4, &mut rng);
+
+        for (a, b) in pairs {
+            assert_eq!(a.id(), best_id);
+            assert_eq!(b.id(), best_id);
+        }
+    }
+
+    #[test]
+    fn given_same_seed_when_select_pairs_then_same_pairs() {
+        let organisms: Vec<_> = (0..6).map(|i| make_org(i as f64, 0, i)).collect();
+        let strategy = TournamentSelection::new(3);
+
+        let ids = |seed| {
+            let mut rng = SmallRng::seed_from_u64(seed);
+            strategy
+                .select_pairs(&organisms, 6, &mut rng)
+                .iter()
+                .map(|(a, b)| (a.id(), b.id()))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(ids(11), ids(11));
+    }
+}
@@ -0,0 +1,75 @@
+use super::World;
+use super::crossover_operator::CrossoverOperator;
+
+impl World {
+    /// Replaces the operator used to recombine problem-parameter loci during reproduction.
+    ///
+    /// The default is [`CrossoverOperator::KPoint`], the behaviour described in the PDD.
+    /// System-parameter loci are unaffected by this setting.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the operator's parameters are out of range (e.g. a negative BLX `alpha`).
+    pub fn set_crossover_operator(&mut self, operator: CrossoverOperator) {
+        operator.validate();
+        self.crossover_operator = operator;
+    }
+
+    /// Returns the operator used to recombine problem-parameter loci.
+    pub fn crossover_operator(&self) -> CrossoverOperator {
+        self.crossover_operator
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::TrainingData;
+    use crate::parameters::global_constants::GlobalConstants;
+    use crate::world::World;
+    use crate::world::crossover_operator::CrossoverOperator;
+    use crate::world::world_function::WorldFunction;
+
+    #[derive(Debug)]
+    struct SumFn;
+    impl WorldFunction for SumFn {
+        fn run(&self, p: &[f64], _v: &[f64]) -> Vec<f64> {
+            vec![p.iter().map(|x| x.abs()).sum::<f64>() + 1.0]
+        }
+    }
+
+    fn world() -> World {
+        World::new(
+            &[-1.0..=1.0, -1.0..=1.0],
+            GlobalConstants::new(60, 4),
+            Box::new(SumFn),
+        )
+    }
+
+    #[test]
+    fn given_new_world_when_crossover_operator_then_defaults_to_k_point() {
+        assert_eq!(world().crossover_operator(), CrossoverOperator::KPoint);
+    }
+
+    #[test]
+    fn given_each_blending_operator_when_training_run_then_population_survives() {
+        for operator in [
+            CrossoverOperator::BlendAlpha { alpha: 0.5 },
+            CrossoverOperator::SimulatedBinary { eta: 5.0 },
+            CrossoverOperator::Arithmetic,
+        ] {
+            let mut world = world();
+            world.set_crossover_operator(operator);
+            for _ in 0..5 {
+                world.training_run(TrainingData::None { floor_value: 0.0 });
+            }
+            assert_eq!(world.crossover_operator(), operator);
+            assert!(!world.organisms.is_empty());
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "BlendAlpha alpha must be finite and non-negative")]
+    fn given_invalid_operator_when_set_crossover_operator_then_panics() {
+        world().set_crossover_operator(CrossoverOperator::BlendAlpha { alpha: -1.0 });
+    }
+}
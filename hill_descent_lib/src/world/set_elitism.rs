@@ -0,0 +1,77 @@
+use super::World;
+use super::elitism::Elitism;
+
+impl World {
+    /// Sets which organisms are exempt from truncation and death by old age.
+    ///
+    /// The default is [`Elitism::None`], the behaviour described in the PDD. The new
+    /// setting takes effect from the next call to [`training_run`](World::training_run).
+    pub fn set_elitism(&mut self, elitism: Elitism) {
+        self.elitism = elitism;
+    }
+
+    /// Returns the current elitism setting.
+    pub fn elitism(&self) -> Elitism {
+        self.elitism
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::TrainingData;
+    use crate::parameters::global_constants::GlobalConstants;
+    use crate::world::World;
+    use crate::world::elitism::Elitism;
+    use crate::world::world_function::WorldFunction;
+
+    #[derive(Debug)]
+    struct SumFn;
+    impl WorldFunction for SumFn {
+        fn run(&self, p: &[f64], _v: &[f64]) -> Vec<f64> {
+            vec![p.iter().map(|x| x.abs()).sum::<f64>() + 1.0]
+        }
+    }
+
+    fn world() -> World {
+        World::new(
+            &[-1.0..=1.0, -1.0..=1.0],
+            GlobalConstants::new_with_seed(60, 4, 7),
+            Box::new(SumFn),
+        )
+    }
+
+    #[test]
+    fn given_new_world_when_elitism_then_defaults_to_none() {
+        assert_eq!(world().elitism(), Elitism::None);
+    }
+
+    #[test]
+    fn given_global_elitism_when_training_then_best_score_never_worsens() {
+        let mut world = world();
+        world.set_elitism(Elitism::Global { count: 2 });
+
+        let mut previous = f64::MAX;
+        for _ in 0..30 {
+            world.training_run(TrainingData::None { floor_value: 0.0 });
+            let best = world.get_best_score();
+            assert!(
+                best <= previous,
+                "best score worsened: {previous} -> {best}"
+            );
+            previous = best;
+        }
+    }
+
+    #[test]
+    fn given_per_region_elitism_when_training_then_population_survives() {
+        let mut world = world();
+        world.set_elitism(Elitism::PerRegion { count: 1 });
+
+        for _ in 0..10 {
+            world.training_run(TrainingData::None { floor_value: 0.0 });
+        }
+
+        assert_eq!(world.elitism(), Elitism::PerRegion { count: 1 });
+        assert!(world.get_best_score().is_finite());
+    }
+}
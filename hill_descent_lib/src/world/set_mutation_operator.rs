@@ -0,0 +1,80 @@
+use super::World;
+use super::mutation_operator::MutationOperator;
+
+impl World {
+    /// Replaces the operator used to mutate problem-parameter loci during reproduction.
+    ///
+    /// The default is [`MutationOperator::DoublingHalving`], the behaviour described in the
+    /// PDD. System-parameter loci are unaffected by this setting.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the operator's parameters are out of range (e.g. a negative learning rate).
+    pub fn set_mutation_operator(&mut self, operator: MutationOperator) {
+        operator.validate();
+        self.mutation_operator = operator;
+    }
+
+    /// Returns the operator used to mutate problem-parameter loci.
+    pub fn mutation_operator(&self) -> MutationOperator {
+        self.mutation_operator
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::TrainingData;
+    use crate::parameters::global_constants::GlobalConstants;
+    use crate::world::World;
+    use crate::world::mutation_operator::MutationOperator;
+    use crate::world::world_function::WorldFunction;
+
+    #[derive(Debug)]
+    struct SumFn;
+    impl WorldFunction for SumFn {
+        fn run(&self, p: &[f64], _v: &[f64]) -> Vec<f64> {
+            vec![p.iter().map(|x| x.abs()).sum::<f64>() + 1.0]
+        }
+    }
+
+    fn world() -> World {
+        World::new(
+            &[-1.0..=1.0, -1.0..=1.0],
+            GlobalConstants::new(60, 4),
+            Box::new(SumFn),
+        )
+    }
+
+    #[test]
+    fn given_new_world_when_mutation_operator_then_defaults_to_doubling_halving() {
+        assert_eq!(
+            world().mutation_operator(),
+            MutationOperator::DoublingHalving
+        );
+    }
+
+    #[test]
+    fn given_each_alternative_operator_when_training_run_then_scores_stay_finite() {
+        for operator in [
+            MutationOperator::Gaussian { learning_rate: 0.3 },
+            MutationOperator::Cauchy { learning_rate: 0.3 },
+            MutationOperator::Polynomial { eta: 20.0 },
+        ] {
+            let mut world = world();
+            world.set_mutation_operator(operator);
+            for _ in 0..5 {
+                world.training_run(TrainingData::None { floor_value: 0.0 });
+            }
+            assert_eq!(world.mutation_operator(), operator);
+            assert!(world.get_best_score().is_finite());
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "learning_rate must be finite and non-negative")]
+    fn given_invalid_operator_when_set_mutation_operator_then_panics() {
+        world().set_mutation_operator(MutationOperator::Gaussian {
+            learning_rate: f64::NAN,
+        });
+    }
+}
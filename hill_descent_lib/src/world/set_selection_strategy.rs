@@ -0,0 +1,81 @@
+use super::World;
+use super::selection_strategy::SelectionStrategy;
+
+impl World {
+    /// Replaces the strategy used to pair parents within each region.
+    ///
+    /// The default is [`ExtremePairing`](super::selection_strategy::ExtremePairing), which
+    /// reproduces the behaviour described in the PDD. The new strategy takes effect from the
+    /// next call to [`training_run`](World::training_run).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hill_descent_lib::{GlobalConstants, SingleValuedFunction, setup_world};
+    /// use hill_descent_lib::world::selection_strategy::RankSelection;
+    ///
+    /// #[derive(Debug)]
+    /// struct Sphere;
+    ///
+    /// impl SingleValuedFunction for Sphere {
+    ///     fn single_run(&self, params: &[f64]) -> f64 {
+    ///         params.iter().map(|x| x * x).sum()
+    ///     }
+    /// }
+    ///
+    /// let mut world = setup_world(&[-1.0..=1.0], GlobalConstants::new(50, 5), Box::new(Sphere));
+    /// world.set_selection_strategy(Box::new(RankSelection::new(1.8)));
+    /// assert!(format!("{:?}", world.selection_strategy()).contains("RankSelection"));
+    /// ```
+    pub fn set_selection_strategy(&mut self, strategy: Box<dyn SelectionStrategy>) {
+        self.selection_strategy = strategy;
+    }
+
+    /// Returns the strategy currently used to pair parents within each region.
+    pub fn selection_strategy(&self) -> &dyn SelectionStrategy {
+        self.selection_strategy.as_ref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::TrainingData;
+    use crate::parameters::global_constants::GlobalConstants;
+    use crate::world::World;
+    use crate::world::selection_strategy::TournamentSelection;
+    use crate::world::world_function::WorldFunction;
+
+    #[derive(Debug)]
+    struct SumFn;
+    impl WorldFunction for SumFn {
+        fn run(&self, p: &[f64], _v: &[f64]) -> Vec<f64> {
+            vec![p.iter().map(|x| x.abs()).sum::<f64>() + 1.0]
+        }
+    }
+
+    #[test]
+    fn given_new_world_when_selection_strategy_then_defaults_to_extreme_pairing() {
+        let world = World::new(&[0.0..=1.0], GlobalConstants::new(20, 2), Box::new(SumFn));
+        assert_eq!(
+            format!("{:?}", world.selection_strategy()),
+            "ExtremePairing"
+        );
+    }
+
+    #[test]
+    fn given_tournament_strategy_when_training_run_then_population_evolves() {
+        let mut world = World::new(
+            &[-1.0..=1.0, -1.0..=1.0],
+            GlobalConstants::new(60, 4),
+            Box::new(SumFn),
+        );
+        world.set_selection_strategy(Box::new(TournamentSelection::new(3)));
+
+        for _ in 0..5 {
+            world.training_run(TrainingData::None { floor_value: 0.0 });
+        }
+
+        assert!(format!("{:?}", world.selection_strategy()).contains("TournamentSelection"));
+        assert!(!world.organisms.is_empty());
+    }
+}
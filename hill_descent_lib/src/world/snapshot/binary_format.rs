@@ -0,0 +1,275 @@
+//! The little-endian layout shared by the binary snapshot writers and [`SnapshotReader`].
+//!
+//! ```text
+//! magic          8 bytes  "HDSNAPSH"
+//! format         u32      BINARY_SNAPSHOT_VERSION
+//! schema         u32      SNAPSHOT_SCHEMA_VERSION
+//! epoch          u64
+//! sections       u8       bit 0 organisms, bit 1 regions
+//! dimensions     u64 count, then per dimension: f64 start, f64 end, u64 doublings
+//! regions        if present: u64 count, then one region each
+//! organisms      if present: u64 count, then one organism each
+//! ```
+//!
+//! Lengths and `usize` values are written as `u64`, options as a `u8` tag (0 for `None`)
+//! followed by the value, and booleans as a `u8` 0 or 1.
+//!
+//! [`SnapshotReader`]: super::SnapshotReader
+
+use std::io::{self, Read, Write};
+
+use super::{BINARY_SNAPSHOT_VERSION, DimensionSnapshot, RegionSnapshot, SnapshotError};
+
+/// The first bytes of every binary snapshot.
+pub(super) const MAGIC: [u8; 8] = *b"HDSNAPSH";
+
+const ORGANISMS: u8 = 1;
+const REGIONS: u8 = 1 << 1;
+
+/// Upper bound on the capacity reserved from a length read from the input, so a corrupt
+/// length fails at the end of the input rather than on allocation.
+const MAX_RESERVED: usize = 4096;
+
+/// Writes everything before the regions.
+pub(super) fn write_header(
+    writer: &mut impl Write,
+    schema_version: u32,
+    epoch: u64,
+    organisms: bool,
+    regions: bool,
+    dimensions: &[DimensionSnapshot],
+) -> io::Result<()> {
+    writer.write_all(&MAGIC)?;
+    write_u32(writer, BINARY_SNAPSHOT_VERSION)?;
+    write_u32(writer, schema_version)?;
+    write_u64(writer, epoch)?;
+    let mut sections = 0;
+    if organisms {
+        sections |= ORGANISMS;
+    }
+    if regions {
+        sections |= REGIONS;
+    }
+    write_u8(writer, sections)?;
+    write_len(writer, dimensions.len())?;
+    for dimension in dimensions {
+        dimension.write_binary(writer)?;
+    }
+    Ok(())
+}
+
+/// Everything before the regions, as written by [`write_header`].
+pub(super) struct Header {
+    pub schema_version: u32,
+    pub epoch: u64,
+    pub organisms: bool,
+    pub regions: bool,
+    pub dimensions: Vec<DimensionSnapshot>,
+}
+
+/// Reads the header, rejecting input that is not a binary snapshot or was written in a
+/// newer format.
+pub(super) fn read_header(reader: &mut impl Read) -> Result<Header, SnapshotError> {
+    let mut magic = [0; MAGIC.len()];
+    reader.read_exact(&mut magic)?;
+    if magic != MAGIC {
+        return Err(SnapshotError::NotBinary);
+    }
+    let format = read_u32(reader)?;
+    if format > BINARY_SNAPSHOT_VERSION {
+        return Err(SnapshotError::UnsupportedVersion {
+            found: format,
+            supported: BINARY_SNAPSHOT_VERSION,
+        });
+    }
+    let schema_version = read_u32(reader)?;
+    let epoch = read_u64(reader)?;
+    let sections = read_u8(reader)?;
+    let dimensions = read_vec(reader, DimensionSnapshot::read_binary)?;
+    Ok(Header {
+        schema_version,
+        epoch,
+        organisms: sections & ORGANISMS != 0,
+        regions: sections & REGIONS != 0,
+        dimensions,
+    })
+}
+
+/// Writes the regions section.
+pub(super) fn write_regions(writer: &mut impl Write, regions: &[RegionSnapshot]) -> io::Result<()> {
+    write_len(writer, regions.len())?;
+    for region in regions {
+        region.write_binary(writer)?;
+    }
+    Ok(())
+}
+
+pub(super) fn write_u8(writer: &mut impl Write, value: u8) -> io::Result<()> {
+    writer.write_all(&[value])
+}
+
+pub(super) fn write_u32(writer: &mut impl Write, value: u32) -> io::Result<()> {
+    writer.write_all(&value.to_le_bytes())
+}
+
+pub(super) fn write_u64(writer: &mut impl Write, value: u64) -> io::Result<()> {
+    writer.write_all(&value.to_le_bytes())
+}
+
+pub(super) fn write_f64(writer: &mut impl Write, value: f64) -> io::Result<()> {
+    writer.write_all(&value.to_le_bytes())
+}
+
+pub(super) fn write_bool(writer: &mut impl Write, value: bool) -> io::Result<()> {
+    write_u8(writer, u8::from(value))
+}
+
+/// Writes a length or other `usize` as a `u64`.
+pub(super) fn write_len(writer: &mut impl Write, value: usize) -> io::Result<()> {
+    write_u64(writer, value as u64)
+}
+
+/// Writes a `u8` tag, followed by the value if there is one.
+pub(super) fn write_option<W: Write, T>(
+    writer: &mut W,
+    value: Option<T>,
+    write: impl FnOnce(&mut W, T) -> io::Result<()>,
+) -> io::Result<()> {
+    match value {
+        Some(value) => {
+            write_u8(writer, 1)?;
+            write(writer, value)
+        }
+        None => write_u8(writer, 0),
+    }
+}
+
+/// Writes a length followed by each value.
+pub(super) fn write_f64s(writer: &mut impl Write, values: &[f64]) -> io::Result<()> {
+    write_len(writer, values.len())?;
+    values
+        .iter()
+        .try_for_each(|&value| write_f64(writer, value))
+}
+
+/// Writes a length followed by each value.
+pub(super) fn write_usizes(writer: &mut impl Write, values: &[usize]) -> io::Result<()> {
+    write_len(writer, values.len())?;
+    values
+        .iter()
+        .try_for_each(|&value| write_len(writer, value))
+}
+
+pub(super) fn read_u8(reader: &mut impl Read) -> Result<u8, SnapshotError> {
+    let mut bytes = [0; 1];
+    reader.read_exact(&mut bytes)?;
+    Ok(bytes[0])
+}
+
+pub(super) fn read_u32(reader: &mut impl Read) -> Result<u32, SnapshotError> {
+    let mut bytes = [0; 4];
+    reader.read_exact(&mut bytes)?;
+    Ok(u32::from_le_bytes(bytes))
+}
+
+pub(super) fn read_u64(reader: &mut impl Read) -> Result<u64, SnapshotError> {
+    let mut bytes = [0; 8];
+    reader.read_exact(&mut bytes)?;
+    Ok(u64::from_le_bytes(bytes))
+}
+
+pub(super) fn read_f64(reader: &mut impl Read) -> Result<f64, SnapshotError> {
+    let mut bytes = [0; 8];
+    reader.read_exact(&mut bytes)?;
+    Ok(f64::from_le_bytes(bytes))
+}
+
+pub(super) fn read_bool(reader: &mut impl Read) -> Result<bool, SnapshotError> {
+    match read_u8(reader)? {
+        0 => Ok(false),
+        1 => Ok(true),
+        other => Err(SnapshotError::Malformed(format!(
+            "expected a boolean, found {other}"
+        ))),
+    }
+}
+
+/// Reads a `usize` written by [`write_len`].
+pub(super) fn read_len(reader: &mut impl Read) -> Result<usize, SnapshotError> {
+    let value = read_u64(reader)?;
+    usize::try_from(value)
+        .map_err(|_| SnapshotError::Malformed(format!("{value} does not fit in a usize")))
+}
+
+/// Reads a value written by [`write_option`].
+pub(super) fn read_option<R: Read, T>(
+    reader: &mut R,
+    read: impl FnOnce(&mut R) -> Result<T, SnapshotError>,
+) -> Result<Option<T>, SnapshotError> {
+    if read_bool(reader)? {
+        read(reader).map(Some)
+    } else {
+        Ok(None)
+    }
+}
+
+/// Reads a length followed by that many values.
+pub(super) fn read_vec<R: Read, T>(
+    reader: &mut R,
+    mut read: impl FnMut(&mut R) -> Result<T, SnapshotError>,
+) -> Result<Vec<T>, SnapshotError> {
+    let len = read_len(reader)?;
+    let mut values = Vec::with_capacity(len.min(MAX_RESERVED));
+    for _ in 0..len {
+        values.push(read(reader)?);
+    }
+    Ok(values)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn given_primitives_when_written_then_read_back_little_endian() {
+        let mut bytes = Vec::new();
+        write_u32(&mut bytes, 1).unwrap();
+        write_f64(&mut bytes, -0.5).unwrap();
+        write_option(&mut bytes, Some(7), write_len).unwrap();
+        write_option(&mut bytes, None, write_len).unwrap();
+        write_f64s(&mut bytes, &[1.0, 2.0]).unwrap();
+
+        assert_eq!(&bytes[..4], &[1, 0, 0, 0]);
+        let mut reader = bytes.as_slice();
+        assert_eq!(read_u32(&mut reader).unwrap(), 1);
+        assert_eq!(read_f64(&mut reader).unwrap(), -0.5);
+        assert_eq!(read_option(&mut reader, read_len).unwrap(), Some(7));
+        assert_eq!(read_option(&mut reader, read_len).unwrap(), None);
+        assert_eq!(read_vec(&mut reader, read_f64).unwrap(), vec![1.0, 2.0]);
+        assert!(reader.is_empty());
+    }
+
+    #[test]
+    fn given_invalid_input_when_read_then_errors() {
+        assert!(matches!(
+            read_header(&mut &b"not a snapshot"[..]),
+            Err(SnapshotError::NotBinary)
+        ));
+        assert!(matches!(
+            read_bool(&mut &[2u8][..]),
+            Err(SnapshotError::Malformed(_))
+        ));
+        assert!(matches!(
+            read_vec(&mut &u64::MAX.to_le_bytes()[..], read_f64),
+            Err(SnapshotError::Io(_) | SnapshotError::Malformed(_))
+        ));
+
+        let mut newer = MAGIC.to_vec();
+        write_u32(&mut newer, BINARY_SNAPSHOT_VERSION + 1).unwrap();
+        assert!(matches!(
+            read_header(&mut newer.as_slice()),
+            Err(SnapshotError::UnsupportedVersion { found, .. })
+                if found == BINARY_SNAPSHOT_VERSION + 1
+        ));
+    }
+}
@@ -0,0 +1,45 @@
+//! Typed, versioned snapshots of a world's state.
+//!
+//! [`World::snapshot`] captures a [`WorldSnapshot`] holding the dimensions and, as selected
+//! by [`SnapshotOptions`], the regions, the organisms and their gametes. Snapshots are
+//! `Serialize + Deserialize`, so consumers can read the JSON of
+//! [`World::get_state`] back into the same types instead of guessing its schema.
+//!
+//! Every snapshot records the [`SNAPSHOT_SCHEMA_VERSION`] it was written with;
+//! [`WorldSnapshot::from_json`] rejects snapshots from a newer schema.
+//!
+//! For large populations, where the JSON runs to hundreds of megabytes, snapshots can also
+//! be written in a compact little-endian binary format. [`World::write_snapshot`] streams a
+//! world to any writer one organism at a time, and a [`SnapshotReader`] reads one back the
+//! same way, either as typed organisms or converted to JSON for debugging.
+//!
+//! [`World::snapshot`]: crate::World::snapshot
+//! [`World::get_state`]: crate::World::get_state
+//! [`World::write_snapshot`]: crate::World::write_snapshot
+
+mod binary_format;
+mod organism_snapshot;
+mod snapshot_error;
+mod snapshot_options;
+mod snapshot_reader;
+mod world_snapshot;
+
+pub use organism_snapshot::{
+    GameteSnapshot, LocusAdjustmentSnapshot, LocusSnapshot, OrganismSnapshot, PhenotypeSnapshot,
+    SystemParametersSnapshot, TravelDirection,
+};
+pub use snapshot_error::SnapshotError;
+pub use snapshot_options::SnapshotOptions;
+pub use snapshot_reader::SnapshotReader;
+pub use world_snapshot::{DimensionSnapshot, RegionSnapshot, WorldSnapshot};
+
+/// The schema version written into every [`WorldSnapshot`]. It is bumped whenever a field
+/// is removed or changes meaning; fields may be added without a bump.
+pub const SNAPSHOT_SCHEMA_VERSION: u32 = 1;
+
+/// The version of the binary layout written by [`World::write_snapshot`] and
+/// [`WorldSnapshot::write_binary`]. Unlike the JSON schema, it is bumped for any change to
+/// the layout, including added fields.
+///
+/// [`World::write_snapshot`]: crate::World::write_snapshot
+pub const BINARY_SNAPSHOT_VERSION: u32 = 1;
@@ -0,0 +1,412 @@
+use std::io::{self, Read, Write};
+
+use serde::{Deserialize, Serialize};
+
+use super::SnapshotError;
+use super::binary_format::{
+    read_bool, read_f64, read_len, read_option, read_u8, read_u64, read_vec, write_bool, write_f64,
+    write_f64s, write_len, write_option, write_u8, write_u64, write_usizes,
+};
+use crate::NUM_SYSTEM_PARAMETERS;
+use crate::gamete::Gamete;
+use crate::locus::Locus;
+use crate::locus::locus_adjustment::DirectionOfTravel;
+use crate::parameters::system_parameters::SystemParameters;
+use crate::phenotype::Phenotype;
+use crate::world::organisms::Organism;
+
+/// An organism in a [`WorldSnapshot`](super::WorldSnapshot).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OrganismSnapshot {
+    id: usize,
+    parent_ids: (Option<usize>, Option<usize>),
+    region_key: Option<Vec<usize>>,
+    age: usize,
+    score: Option<f64>,
+    is_dead: bool,
+    phenotype: PhenotypeSnapshot,
+}
+
+impl OrganismSnapshot {
+    pub(crate) fn new(organism: &Organism, gametes: bool) -> Self {
+        Self {
+            id: organism.id(),
+            parent_ids: organism.parent_ids(),
+            region_key: organism.region_key().map(Vec::<usize>::from),
+            age: organism.age(),
+            score: organism.score(),
+            is_dead: organism.is_dead(),
+            phenotype: PhenotypeSnapshot::new(organism.phenotype(), gametes),
+        }
+    }
+
+    /// Returns the organism's unique ID.
+    pub fn id(&self) -> usize {
+        self.id
+    }
+
+    /// Returns the parent IDs, as [`Organism::parent_ids`] does.
+    pub fn parent_ids(&self) -> (Option<usize>, Option<usize>) {
+        self.parent_ids
+    }
+
+    /// Returns the interval index in each dimension of the organism's region.
+    pub fn region_key(&self) -> Option<&[usize]> {
+        self.region_key.as_deref()
+    }
+
+    /// Returns the organism's age in epochs.
+    pub fn age(&self) -> usize {
+        self.age
+    }
+
+    /// Returns the organism's score, or `None` if it had not been evaluated.
+    pub fn score(&self) -> Option<f64> {
+        self.score
+    }
+
+    /// Returns `true` if the organism had been marked as dead.
+    pub fn is_dead(&self) -> bool {
+        self.is_dead
+    }
+
+    /// Returns the organism's phenotype.
+    pub fn phenotype(&self) -> &PhenotypeSnapshot {
+        &self.phenotype
+    }
+
+    pub(super) fn write_binary(&self, writer: &mut impl Write) -> io::Result<()> {
+        write_len(writer, self.id)?;
+        write_option(writer, self.parent_ids.0, write_len)?;
+        write_option(writer, self.parent_ids.1, write_len)?;
+        write_option(writer, self.region_key.as_deref(), write_usizes)?;
+        write_len(writer, self.age)?;
+        write_option(writer, self.score, write_f64)?;
+        write_bool(writer, self.is_dead)?;
+        self.phenotype.write_binary(writer)
+    }
+
+    pub(super) fn read_binary(reader: &mut impl Read) -> Result<Self, SnapshotError> {
+        Ok(Self {
+            id: read_len(reader)?,
+            parent_ids: (
+                read_option(reader, read_len)?,
+                read_option(reader, read_len)?,
+            ),
+            region_key: read_option(reader, |reader| read_vec(reader, read_len))?,
+            age: read_len(reader)?,
+            score: read_option(reader, read_f64)?,
+            is_dead: read_bool(reader)?,
+            phenotype: PhenotypeSnapshot::read_binary(reader)?,
+        })
+    }
+}
+
+/// The expressed values of an organism and, optionally, the gametes they came from.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PhenotypeSnapshot {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    gamete1: Option<GameteSnapshot>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    gamete2: Option<GameteSnapshot>,
+    expressed_values: Vec<f64>,
+    system_parameters: SystemParametersSnapshot,
+    expressed_hash: u64,
+}
+
+impl PhenotypeSnapshot {
+    fn new(phenotype: &Phenotype, gametes: bool) -> Self {
+        Self {
+            gamete1: gametes.then(|| GameteSnapshot::new(phenotype.gamete1())),
+            gamete2: gametes.then(|| GameteSnapshot::new(phenotype.gamete2())),
+            expressed_values: phenotype.expressed_values().to_vec(),
+            system_parameters: SystemParametersSnapshot::new(phenotype.system_parameters()),
+            expressed_hash: phenotype.expressed_hash(),
+        }
+    }
+
+    /// Returns the system parameters followed by the problem parameters.
+    pub fn expressed_values(&self) -> &[f64] {
+        &self.expressed_values
+    }
+
+    /// Returns the problem parameters alone.
+    pub fn problem_values(&self) -> &[f64] {
+        self.expressed_values
+            .get(NUM_SYSTEM_PARAMETERS..)
+            .unwrap_or_default()
+    }
+
+    /// Returns the expressed system parameters.
+    pub fn system_parameters(&self) -> &SystemParametersSnapshot {
+        &self.system_parameters
+    }
+
+    /// Returns the hash identifying the expressed values.
+    pub fn expressed_hash(&self) -> u64 {
+        self.expressed_hash
+    }
+
+    /// Returns both gametes, if the snapshot included them.
+    pub fn gametes(&self) -> Option<(&GameteSnapshot, &GameteSnapshot)> {
+        self.gamete1.as_ref().zip(self.gamete2.as_ref())
+    }
+
+    fn write_binary(&self, writer: &mut impl Write) -> io::Result<()> {
+        write_option(writer, self.gamete1.as_ref(), GameteSnapshot::write_binary)?;
+        write_option(writer, self.gamete2.as_ref(), GameteSnapshot::write_binary)?;
+        write_f64s(writer, &self.expressed_values)?;
+        self.system_parameters.write_binary(writer)?;
+        write_u64(writer, self.expressed_hash)
+    }
+
+    fn read_binary(reader: &mut impl Read) -> Result<Self, SnapshotError> {
+        Ok(Self {
+            gamete1: read_option(reader, GameteSnapshot::read_binary)?,
+            gamete2: read_option(reader, GameteSnapshot::read_binary)?,
+            expressed_values: read_vec(reader, read_f64)?,
+            system_parameters: SystemParametersSnapshot::read_binary(reader)?,
+            expressed_hash: read_u64(reader)?,
+        })
+    }
+}
+
+/// The expressed system parameters that steer mutation, ageing and crossover.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct SystemParametersSnapshot {
+    m1: f64,
+    m2: f64,
+    m3: f64,
+    m4: f64,
+    m5: f64,
+    max_age: f64,
+    crossover_points: f64,
+}
+
+impl SystemParametersSnapshot {
+    fn new(parameters: &SystemParameters) -> Self {
+        Self {
+            m1: parameters.m1(),
+            m2: parameters.m2(),
+            m3: parameters.m3(),
+            m4: parameters.m4(),
+            m5: parameters.m5(),
+            max_age: parameters.max_age(),
+            crossover_points: parameters.crossover_points(),
+        }
+    }
+
+    /// Returns the probability of a locus's apply-adjustment flag mutating from false to true.
+    pub fn m1(&self) -> f64 {
+        self.m1
+    }
+
+    /// Returns the probability of a locus's apply-adjustment flag mutating from true to false.
+    pub fn m2(&self) -> f64 {
+        self.m2
+    }
+
+    /// Returns the probability of an adjustment's doubling-or-halving flag mutating.
+    pub fn m3(&self) -> f64 {
+        self.m3
+    }
+
+    /// Returns the probability of an adjustment's direction of travel mutating.
+    pub fn m4(&self) -> f64 {
+        self.m4
+    }
+
+    /// Returns the probability of a locus value mutating.
+    pub fn m5(&self) -> f64 {
+        self.m5
+    }
+
+    /// Returns the age at which the organism dies.
+    pub fn max_age(&self) -> f64 {
+        self.max_age
+    }
+
+    /// Returns the number of crossover points used in reproduction.
+    pub fn crossover_points(&self) -> f64 {
+        self.crossover_points
+    }
+
+    fn write_binary(&self, writer: &mut impl Write) -> io::Result<()> {
+        [
+            self.m1,
+            self.m2,
+            self.m3,
+            self.m4,
+            self.m5,
+            self.max_age,
+            self.crossover_points,
+        ]
+        .into_iter()
+        .try_for_each(|value| write_f64(writer, value))
+    }
+
+    fn read_binary(reader: &mut impl Read) -> Result<Self, SnapshotError> {
+        Ok(Self {
+            m1: read_f64(reader)?,
+            m2: read_f64(reader)?,
+            m3: read_f64(reader)?,
+            m4: read_f64(reader)?,
+            m5: read_f64(reader)?,
+            max_age: read_f64(reader)?,
+            crossover_points: read_f64(reader)?,
+        })
+    }
+}
+
+/// One of an organism's two gametes.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GameteSnapshot {
+    loci: Vec<LocusSnapshot>,
+}
+
+impl GameteSnapshot {
+    fn new(gamete: &Gamete) -> Self {
+        Self {
+            loci: gamete.loci().iter().map(LocusSnapshot::new).collect(),
+        }
+    }
+
+    /// Returns the gamete's loci, system parameters first.
+    pub fn loci(&self) -> &[LocusSnapshot] {
+        &self.loci
+    }
+
+    fn write_binary<W: Write>(writer: &mut W, gamete: &Self) -> io::Result<()> {
+        write_len(writer, gamete.loci.len())?;
+        gamete
+            .loci
+            .iter()
+            .try_for_each(|locus| locus.write_binary(writer))
+    }
+
+    fn read_binary<R: Read>(reader: &mut R) -> Result<Self, SnapshotError> {
+        Ok(Self {
+            loci: read_vec(reader, LocusSnapshot::read_binary)?,
+        })
+    }
+}
+
+/// A locus: a value and the adjustment mutation may apply to it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LocusSnapshot {
+    value: f64,
+    adjustment: LocusAdjustmentSnapshot,
+    apply_adjustment_flag: bool,
+}
+
+impl LocusSnapshot {
+    fn new(locus: &Locus) -> Self {
+        let adjustment = locus.adjustment();
+        Self {
+            value: locus.value().get(),
+            adjustment: LocusAdjustmentSnapshot {
+                adjustment_value: adjustment.adjustment_value().get(),
+                direction_of_travel: match adjustment.direction_of_travel() {
+                    DirectionOfTravel::Add => TravelDirection::Add,
+                    DirectionOfTravel::Subtract => TravelDirection::Subtract,
+                },
+                doubling_or_halving_flag: adjustment.doubling_or_halving_flag(),
+                checksum: adjustment.checksum(),
+            },
+            apply_adjustment_flag: locus.apply_adjustment_flag(),
+        }
+    }
+
+    /// Returns the locus value.
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+
+    /// Returns the locus's adjustment.
+    pub fn adjustment(&self) -> &LocusAdjustmentSnapshot {
+        &self.adjustment
+    }
+
+    /// Returns `true` if the adjustment is applied when the locus value mutates.
+    pub fn apply_adjustment_flag(&self) -> bool {
+        self.apply_adjustment_flag
+    }
+
+    fn write_binary(&self, writer: &mut impl Write) -> io::Result<()> {
+        let adjustment = &self.adjustment;
+        write_f64(writer, self.value)?;
+        write_f64(writer, adjustment.adjustment_value)?;
+        write_u8(
+            writer,
+            match adjustment.direction_of_travel {
+                TravelDirection::Add => 0,
+                TravelDirection::Subtract => 1,
+            },
+        )?;
+        write_bool(writer, adjustment.doubling_or_halving_flag)?;
+        write_u64(writer, adjustment.checksum)?;
+        write_bool(writer, self.apply_adjustment_flag)
+    }
+
+    fn read_binary<R: Read>(reader: &mut R) -> Result<Self, SnapshotError> {
+        let value = read_f64(reader)?;
+        let adjustment_value = read_f64(reader)?;
+        let direction_of_travel = match read_u8(reader)? {
+            0 => TravelDirection::Add,
+            1 => TravelDirection::Subtract,
+            other => {
+                return Err(SnapshotError::Malformed(format!(
+                    "expected a direction of travel, found {other}"
+                )));
+            }
+        };
+        Ok(Self {
+            value,
+            adjustment: LocusAdjustmentSnapshot {
+                adjustment_value,
+                direction_of_travel,
+                doubling_or_halving_flag: read_bool(reader)?,
+                checksum: read_u64(reader)?,
+            },
+            apply_adjustment_flag: read_bool(reader)?,
+        })
+    }
+}
+
+/// The step mutation may add to or subtract from a locus value.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct LocusAdjustmentSnapshot {
+    adjustment_value: f64,
+    direction_of_travel: TravelDirection,
+    doubling_or_halving_flag: bool,
+    checksum: u64,
+}
+
+impl LocusAdjustmentSnapshot {
+    /// Returns the size of the step.
+    pub fn adjustment_value(&self) -> f64 {
+        self.adjustment_value
+    }
+
+    /// Returns whether the step is added or subtracted.
+    pub fn direction_of_travel(&self) -> TravelDirection {
+        self.direction_of_travel
+    }
+
+    /// Returns the flag choosing whether the step doubles or halves when it is resized.
+    pub fn doubling_or_halving_flag(&self) -> bool {
+        self.doubling_or_halving_flag
+    }
+
+    /// Returns the checksum of the adjustment.
+    pub fn checksum(&self) -> u64 {
+        self.checksum
+    }
+}
+
+/// Whether a locus adjustment is added or subtracted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TravelDirection {
+    Add,
+    Subtract,
+}
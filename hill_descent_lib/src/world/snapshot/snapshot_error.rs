@@ -0,0 +1,56 @@
+use std::{fmt, io};
+
+/// Why a [`WorldSnapshot`](super::WorldSnapshot) could not be read.
+#[derive(Debug)]
+pub enum SnapshotError {
+    /// The input is not a well-formed snapshot.
+    Json(serde_json::Error),
+    /// The snapshot was written with a newer schema, or binary format, than this version of
+    /// the library reads.
+    UnsupportedVersion { found: u32, supported: u32 },
+    /// A snapshot could not be read or written, for example because it is truncated.
+    Io(io::Error),
+    /// The input does not start with the binary snapshot header.
+    NotBinary,
+    /// A binary snapshot holds a value its layout does not allow.
+    Malformed(String),
+}
+
+impl fmt::Display for SnapshotError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SnapshotError::Json(error) => write!(f, "invalid snapshot: {error}"),
+            SnapshotError::UnsupportedVersion { found, supported } => write!(
+                f,
+                "snapshot version {found} is newer than the supported version {supported}"
+            ),
+            SnapshotError::Io(error) => write!(f, "snapshot I/O failed: {error}"),
+            SnapshotError::NotBinary => write!(f, "not a binary snapshot"),
+            SnapshotError::Malformed(reason) => write!(f, "malformed binary snapshot: {reason}"),
+        }
+    }
+}
+
+impl std::error::Error for SnapshotError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            SnapshotError::Json(error) => Some(error),
+            SnapshotError::Io(error) => Some(error),
+            SnapshotError::UnsupportedVersion { .. }
+            | SnapshotError::NotBinary
+            | SnapshotError::Malformed(_) => None,
+        }
+    }
+}
+
+impl From<serde_json::Error> for SnapshotError {
+    fn from(error: serde_json::Error) -> Self {
+        SnapshotError::Json(error)
+    }
+}
+
+impl From<io::Error> for SnapshotError {
+    fn from(error: io::Error) -> Self {
+        SnapshotError::Io(error)
+    }
+}
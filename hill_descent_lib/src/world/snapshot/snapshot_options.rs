@@ -0,0 +1,73 @@
+/// Which optional sections [`World::snapshot`](crate::World::snapshot) includes.
+///
+/// Dimensions are always included. By default regions and organisms are too, but gametes,
+/// which make up most of a full snapshot, are not.
+///
+/// ```
+/// use hill_descent_lib::world::snapshot::SnapshotOptions;
+///
+/// let options = SnapshotOptions::new().with_organisms(false);
+/// assert!(options.regions() && !options.organisms());
+/// assert!(SnapshotOptions::full().gametes());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SnapshotOptions {
+    organisms: bool,
+    regions: bool,
+    gametes: bool,
+}
+
+impl SnapshotOptions {
+    /// Regions and organisms without gametes.
+    pub fn new() -> Self {
+        Self {
+            organisms: true,
+            regions: true,
+            gametes: false,
+        }
+    }
+
+    /// Every section, including gametes.
+    pub fn full() -> Self {
+        Self::new().with_gametes(true)
+    }
+
+    /// Includes or omits the organisms.
+    pub fn with_organisms(mut self, organisms: bool) -> Self {
+        self.organisms = organisms;
+        self
+    }
+
+    /// Includes or omits the regions.
+    pub fn with_regions(mut self, regions: bool) -> Self {
+        self.regions = regions;
+        self
+    }
+
+    /// Includes or omits each organism's gametes. Has no effect without organisms.
+    pub fn with_gametes(mut self, gametes: bool) -> Self {
+        self.gametes = gametes;
+        self
+    }
+
+    /// Returns `true` if organisms are included.
+    pub fn organisms(&self) -> bool {
+        self.organisms
+    }
+
+    /// Returns `true` if regions are included.
+    pub fn regions(&self) -> bool {
+        self.regions
+    }
+
+    /// Returns `true` if gametes are included.
+    pub fn gametes(&self) -> bool {
+        self.gametes
+    }
+}
+
+impl Default for SnapshotOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,300 @@
+use std::io::{self, Read, Write};
+
+use super::binary_format::{read_header, read_len, read_vec};
+use super::{DimensionSnapshot, OrganismSnapshot, RegionSnapshot, SnapshotError, WorldSnapshot};
+
+/// Reads a binary snapshot one organism at a time, so a snapshot of a large population can
+/// be processed without holding every organism in memory.
+///
+/// [`new`](Self::new) reads everything except the organisms, which the reader then yields
+/// as an iterator. Wrap a file in a [`BufReader`](std::io::BufReader) before reading it.
+///
+/// ```
+/// use hill_descent_lib::world::snapshot::{SnapshotOptions, SnapshotReader};
+/// use hill_descent_lib::{GlobalConstants, SingleValuedFunction, TrainingData, setup_world};
+///
+/// #[derive(Debug)]
+/// struct Sphere;
+///
+/// impl SingleValuedFunction for Sphere {
+///     fn single_run(&self, params: &[f64]) -> f64 {
+///         params.iter().map(|x| x * x).sum()
+///     }
+/// }
+///
+/// let bounds = vec![-5.0..=5.0; 2];
+/// let mut world = setup_world(&bounds, GlobalConstants::new(100, 10), Box::new(Sphere));
+/// world.training_run(TrainingData::None { floor_value: 0.0 });
+///
+/// let mut bytes = Vec::new();
+/// world.write_snapshot(&mut bytes, &SnapshotOptions::new()).unwrap();
+///
+/// let reader = SnapshotReader::new(bytes.as_slice()).unwrap();
+/// assert_eq!(reader.epoch(), 1);
+/// assert_eq!(reader.remaining(), Some(world.organisms().len()));
+/// let scored = reader
+///     .map(|organism| organism.unwrap())
+///     .filter(|organism| organism.score().is_some())
+///     .count();
+/// assert!(scored > 0);
+/// ```
+#[derive(Debug)]
+pub struct SnapshotReader<R> {
+    reader: R,
+    schema_version: u32,
+    epoch: u64,
+    dimensions: Vec<DimensionSnapshot>,
+    regions: Option<Vec<RegionSnapshot>>,
+    remaining: Option<usize>,
+}
+
+impl<R: Read> SnapshotReader<R> {
+    /// Reads the header, dimensions and regions of a binary snapshot.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SnapshotError::NotBinary`] if the input is not a binary snapshot,
+    /// [`SnapshotError::UnsupportedVersion`] if it was written in a newer binary format,
+    /// [`SnapshotError::Malformed`] if it holds an invalid value and [`SnapshotError::Io`]
+    /// if it cannot be read or ends early.
+    pub fn new(mut reader: R) -> Result<Self, SnapshotError> {
+        let header = read_header(&mut reader)?;
+        let regions = if header.regions {
+            Some(read_vec(&mut reader, RegionSnapshot::read_binary)?)
+        } else {
+            None
+        };
+        let remaining = if header.organisms {
+            Some(read_len(&mut reader)?)
+        } else {
+            None
+        };
+        Ok(Self {
+            reader,
+            schema_version: header.schema_version,
+            epoch: header.epoch,
+            dimensions: header.dimensions,
+            regions,
+            remaining,
+        })
+    }
+
+    /// Returns the schema version the snapshot was written with.
+    pub fn schema_version(&self) -> u32 {
+        self.schema_version
+    }
+
+    /// Returns the number of epochs the world had completed.
+    pub fn epoch(&self) -> u64 {
+        self.epoch
+    }
+
+    /// Returns each problem parameter's dimension, in parameter order.
+    pub fn dimensions(&self) -> &[DimensionSnapshot] {
+        &self.dimensions
+    }
+
+    /// Returns the regions, or `None` if they were not included.
+    pub fn regions(&self) -> Option<&[RegionSnapshot]> {
+        self.regions.as_deref()
+    }
+
+    /// Returns the number of organisms not yet read, or `None` if they were not included.
+    pub fn remaining(&self) -> Option<usize> {
+        self.remaining
+    }
+
+    /// Reads the remaining organisms and returns the whole snapshot.
+    pub fn into_snapshot(mut self) -> Result<WorldSnapshot, SnapshotError> {
+        let organisms = match self.remaining {
+            Some(_) => Some(self.by_ref().collect::<Result<Vec<_>, _>>()?),
+            None => None,
+        };
+        Ok(WorldSnapshot::from_parts(
+            self.schema_version,
+            self.epoch,
+            self.dimensions,
+            organisms,
+            self.regions,
+        ))
+    }
+
+    /// Converts the rest of the snapshot to the JSON of [`WorldSnapshot::to_json`],
+    /// writing one organism at a time. Failures to write are returned as
+    /// [`SnapshotError::Io`].
+    pub fn write_json(mut self, mut writer: impl Write) -> Result<(), SnapshotError> {
+        write!(
+            writer,
+            r#"{{"schema_version":{},"epoch":{},"dimensions":"#,
+            self.schema_version, self.epoch
+        )?;
+        serde_json::to_writer(&mut writer, &self.dimensions).map_err(io::Error::from)?;
+        if self.remaining.is_some() {
+            write!(writer, r#","organisms":["#)?;
+            let mut first = true;
+            for organism in self.by_ref() {
+                if !first {
+                    write!(writer, ",")?;
+                }
+                first = false;
+                serde_json::to_writer(&mut writer, &organism?).map_err(io::Error::from)?;
+            }
+            write!(writer, "]")?;
+        }
+        if let Some(regions) = &self.regions {
+            write!(writer, r#","regions":"#)?;
+            serde_json::to_writer(&mut writer, regions).map_err(io::Error::from)?;
+        }
+        write!(writer, "}}")?;
+        Ok(())
+    }
+}
+
+impl<R: Read> Iterator for SnapshotReader<R> {
+    type Item = Result<OrganismSnapshot, SnapshotError>;
+
+    /// Reads the next organism. After an error the reader yields nothing more.
+    fn next(&mut self) -> Option<Self::Item> {
+        let remaining = self.remaining.filter(|&remaining| remaining > 0)?;
+        let organism = OrganismSnapshot::read_binary(&mut self.reader);
+        self.remaining = Some(if organism.is_ok() { remaining - 1 } else { 0 });
+        Some(organism)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.remaining.unwrap_or(0);
+        (0, Some(remaining))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TrainingData;
+    use crate::parameters::global_constants::GlobalConstants;
+    use crate::world::World;
+    use crate::world::snapshot::SnapshotOptions;
+    use crate::world::world_function::WorldFunction;
+
+    #[derive(Debug)]
+    struct SumFn;
+    impl WorldFunction for SumFn {
+        fn run(&self, p: &[f64], _v: &[f64]) -> Vec<f64> {
+            vec![p.iter().map(|x| x.abs()).sum::<f64>()]
+        }
+    }
+
+    fn trained_world() -> World {
+        let mut world = World::new(
+            &[-1.0..=1.0, -2.0..=2.0, 0.0..=3.0],
+            GlobalConstants::new_with_seed(40, 4, 11),
+            Box::new(SumFn),
+        );
+        world.training_run(TrainingData::None { floor_value: 0.0 });
+        world
+    }
+
+    fn binary(world: &World, options: &SnapshotOptions) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        world.write_snapshot(&mut bytes, options).unwrap();
+        bytes
+    }
+
+    #[test]
+    fn given_streamed_world_when_read_then_equals_captured_snapshot() {
+        let world = trained_world();
+        for options in [
+            SnapshotOptions::new(),
+            SnapshotOptions::full(),
+            SnapshotOptions::new().with_organisms(false),
+            SnapshotOptions::new().with_regions(false),
+        ] {
+            let bytes = binary(&world, &options);
+
+            let restored = WorldSnapshot::read_binary(bytes.as_slice()).unwrap();
+
+            assert_eq!(restored, world.snapshot(&options));
+        }
+    }
+
+    #[test]
+    fn given_snapshot_when_written_binary_then_same_bytes_as_streamed_world() {
+        let world = trained_world();
+        let options = SnapshotOptions::full();
+        let mut bytes = Vec::new();
+
+        world.snapshot(&options).write_binary(&mut bytes).unwrap();
+
+        assert_eq!(bytes, binary(&world, &options));
+    }
+
+    #[test]
+    fn given_binary_snapshot_when_read_organism_by_organism_then_counts_down() {
+        let world = trained_world();
+        let bytes = binary(&world, &SnapshotOptions::new());
+
+        let mut reader = SnapshotReader::new(bytes.as_slice()).unwrap();
+        let total = reader.remaining().unwrap();
+        let first = reader.next().unwrap().unwrap();
+
+        assert_eq!(total, world.organisms.len());
+        assert_eq!(reader.remaining(), Some(total - 1));
+        assert_eq!(first.id(), world.organisms.iter().next().unwrap().id());
+        assert_eq!(reader.by_ref().count(), total - 1);
+        assert!(reader.next().is_none());
+        assert_eq!(reader.regions().unwrap().len(), world.regions.len());
+    }
+
+    #[test]
+    fn given_binary_snapshot_when_converted_to_json_then_matches_to_json() {
+        let world = trained_world();
+        for options in [
+            SnapshotOptions::full(),
+            SnapshotOptions::new().with_organisms(false),
+        ] {
+            let bytes = binary(&world, &options);
+            let mut json = Vec::new();
+
+            SnapshotReader::new(bytes.as_slice())
+                .unwrap()
+                .write_json(&mut json)
+                .unwrap();
+
+            assert_eq!(
+                String::from_utf8(json).unwrap(),
+                world.snapshot(&options).to_json()
+            );
+        }
+    }
+
+    #[test]
+    fn given_truncated_snapshot_when_read_then_io_error_and_iteration_stops() {
+        let world = trained_world();
+        let bytes = binary(&world, &SnapshotOptions::new());
+        let truncated = &bytes[..bytes.len() - 3];
+
+        let mut reader = SnapshotReader::new(truncated).unwrap();
+        let results: Vec<_> = reader.by_ref().collect();
+
+        assert!(matches!(results.last(), Some(Err(SnapshotError::Io(_)))));
+        assert!(reader.next().is_none());
+        assert!(matches!(
+            WorldSnapshot::read_binary(truncated),
+            Err(SnapshotError::Io(_))
+        ));
+        assert!(matches!(
+            WorldSnapshot::read_binary(world.get_state().as_bytes()),
+            Err(SnapshotError::NotBinary)
+        ));
+    }
+
+    #[test]
+    fn given_binary_snapshot_when_compared_to_json_then_smaller() {
+        let world = trained_world();
+        let options = SnapshotOptions::full();
+
+        let bytes = binary(&world, &options);
+
+        assert!(bytes.len() < world.snapshot(&options).to_json().len() / 2);
+    }
+}
@@ -0,0 +1,386 @@
+use std::io::{self, Read, Write};
+use std::ops::RangeInclusive;
+
+use serde::{Deserialize, Serialize};
+
+use super::binary_format::{
+    read_f64, read_len, read_option, read_vec, write_f64, write_header, write_len, write_option,
+    write_regions, write_usizes,
+};
+use super::{
+    OrganismSnapshot, SNAPSHOT_SCHEMA_VERSION, SnapshotError, SnapshotOptions, SnapshotReader,
+};
+use crate::world::World;
+use crate::world::views::{DimensionView, RegionView};
+
+/// The state of a [`World`] at the end of an epoch, taken by [`World::snapshot`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WorldSnapshot {
+    schema_version: u32,
+    epoch: u64,
+    dimensions: Vec<DimensionSnapshot>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    organisms: Option<Vec<OrganismSnapshot>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    regions: Option<Vec<RegionSnapshot>>,
+}
+
+impl WorldSnapshot {
+    pub(crate) fn capture(world: &World, options: &SnapshotOptions) -> Self {
+        Self {
+            schema_version: SNAPSHOT_SCHEMA_VERSION,
+            epoch: world.epoch,
+            dimensions: world.dimensions().map(DimensionSnapshot::new).collect(),
+            organisms: options.organisms().then(|| {
+                world
+                    .organisms
+                    .iter()
+                    .map(|organism| OrganismSnapshot::new(organism, options.gametes()))
+                    .collect()
+            }),
+            regions: options
+                .regions()
+                .then(|| world.regions().map(RegionSnapshot::new).collect()),
+        }
+    }
+
+    /// Writes the binary form of `capture(world, options)` without capturing the organisms
+    /// first.
+    pub(crate) fn write_streamed(
+        world: &World,
+        writer: &mut impl Write,
+        options: &SnapshotOptions,
+    ) -> io::Result<()> {
+        let dimensions: Vec<DimensionSnapshot> =
+            world.dimensions().map(DimensionSnapshot::new).collect();
+        write_header(
+            writer,
+            SNAPSHOT_SCHEMA_VERSION,
+            world.epoch,
+            options.organisms(),
+            options.regions(),
+            &dimensions,
+        )?;
+        if options.regions() {
+            let regions: Vec<RegionSnapshot> = world.regions().map(RegionSnapshot::new).collect();
+            write_regions(writer, &regions)?;
+        }
+        if options.organisms() {
+            write_len(writer, world.organisms.len())?;
+            for organism in world.organisms.iter() {
+                OrganismSnapshot::new(organism, options.gametes()).write_binary(writer)?;
+            }
+        }
+        Ok(())
+    }
+
+    pub(super) fn from_parts(
+        schema_version: u32,
+        epoch: u64,
+        dimensions: Vec<DimensionSnapshot>,
+        organisms: Option<Vec<OrganismSnapshot>>,
+        regions: Option<Vec<RegionSnapshot>>,
+    ) -> Self {
+        Self {
+            schema_version,
+            epoch,
+            dimensions,
+            organisms,
+            regions,
+        }
+    }
+
+    /// Returns the schema version the snapshot was written with.
+    pub fn schema_version(&self) -> u32 {
+        self.schema_version
+    }
+
+    /// Returns the number of epochs the world had completed.
+    pub fn epoch(&self) -> u64 {
+        self.epoch
+    }
+
+    /// Returns each problem parameter's dimension, in parameter order.
+    pub fn dimensions(&self) -> &[DimensionSnapshot] {
+        &self.dimensions
+    }
+
+    /// Returns the organisms, or `None` if they were not included.
+    pub fn organisms(&self) -> Option<&[OrganismSnapshot]> {
+        self.organisms.as_deref()
+    }
+
+    /// Returns the regions, or `None` if they were not included.
+    pub fn regions(&self) -> Option<&[RegionSnapshot]> {
+        self.regions.as_deref()
+    }
+
+    /// Returns the snapshot as JSON.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("world snapshots are always serializable")
+    }
+
+    /// Reads a snapshot written by [`to_json`](Self::to_json).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SnapshotError::UnsupportedVersion`] for a snapshot written with a newer
+    /// schema, and [`SnapshotError::Json`] if the input is not a snapshot.
+    pub fn from_json(json: &str) -> Result<Self, SnapshotError> {
+        let value: serde_json::Value = serde_json::from_str(json)?;
+        if let Some(found) = value.get("schema_version").and_then(|v| v.as_u64()) {
+            let found = u32::try_from(found).unwrap_or(u32::MAX);
+            if found > SNAPSHOT_SCHEMA_VERSION {
+                return Err(SnapshotError::UnsupportedVersion {
+                    found,
+                    supported: SNAPSHOT_SCHEMA_VERSION,
+                });
+            }
+        }
+        Ok(serde_json::from_value(value)?)
+    }
+
+    /// Writes the snapshot in the binary format, which is far smaller and faster to write
+    /// than JSON. Wrap `writer` in a [`BufWriter`](std::io::BufWriter) when it is a file.
+    ///
+    /// To write a large world without first capturing it, use
+    /// [`World::write_snapshot`](crate::World::write_snapshot).
+    pub fn write_binary(&self, mut writer: impl Write) -> io::Result<()> {
+        write_header(
+            &mut writer,
+            self.schema_version,
+            self.epoch,
+            self.organisms.is_some(),
+            self.regions.is_some(),
+            &self.dimensions,
+        )?;
+        if let Some(regions) = &self.regions {
+            write_regions(&mut writer, regions)?;
+        }
+        if let Some(organisms) = &self.organisms {
+            write_len(&mut writer, organisms.len())?;
+            for organism in organisms {
+                organism.write_binary(&mut writer)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Reads a whole binary snapshot into memory. Use a [`SnapshotReader`] to read the
+    /// organisms one at a time instead.
+    ///
+    /// # Errors
+    ///
+    /// As [`SnapshotReader::new`], or [`SnapshotError::Io`] if the input ends early.
+    pub fn read_binary(reader: impl Read) -> Result<Self, SnapshotError> {
+        SnapshotReader::new(reader)?.into_snapshot()
+    }
+}
+
+/// A dimension in a [`WorldSnapshot`].
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct DimensionSnapshot {
+    range: (f64, f64),
+    number_of_doublings: usize,
+}
+
+impl DimensionSnapshot {
+    fn new(dimension: DimensionView<'_>) -> Self {
+        Self {
+            range: (*dimension.range().start(), *dimension.range().end()),
+            number_of_doublings: dimension.doublings(),
+        }
+    }
+
+    /// Returns the range the dimension spanned.
+    pub fn range(&self) -> RangeInclusive<f64> {
+        self.range.0..=self.range.1
+    }
+
+    /// Returns how many times the range had been halved.
+    pub fn number_of_doublings(&self) -> usize {
+        self.number_of_doublings
+    }
+
+    pub(super) fn write_binary(&self, writer: &mut impl Write) -> io::Result<()> {
+        write_f64(writer, self.range.0)?;
+        write_f64(writer, self.range.1)?;
+        write_len(writer, self.number_of_doublings)
+    }
+
+    pub(super) fn read_binary<R: Read>(reader: &mut R) -> Result<Self, SnapshotError> {
+        Ok(Self {
+            range: (read_f64(reader)?, read_f64(reader)?),
+            number_of_doublings: read_len(reader)?,
+        })
+    }
+}
+
+/// A region in a [`WorldSnapshot`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RegionSnapshot {
+    key: Vec<usize>,
+    bounds: Vec<(f64, f64)>,
+    min_score: Option<f64>,
+    carrying_capacity: Option<usize>,
+    organism_count: usize,
+}
+
+impl RegionSnapshot {
+    fn new(region: RegionView<'_>) -> Self {
+        Self {
+            key: region.key().to_vec(),
+            bounds: region
+                .bounds()
+                .into_iter()
+                .map(|range| (*range.start(), *range.end()))
+                .collect(),
+            min_score: region.min_score(),
+            carrying_capacity: region.carrying_capacity(),
+            organism_count: region.organism_count(),
+        }
+    }
+
+    /// Returns the region's interval index in each dimension.
+    pub fn key(&self) -> &[usize] {
+        &self.key
+    }
+
+    /// Returns the region's extent in each dimension.
+    pub fn bounds(&self) -> Vec<RangeInclusive<f64>> {
+        self.bounds
+            .iter()
+            .map(|&(start, end)| start..=end)
+            .collect()
+    }
+
+    /// Returns the best score in the region, if any organism in it had been scored.
+    pub fn min_score(&self) -> Option<f64> {
+        self.min_score
+    }
+
+    /// Returns the number of organisms the region could hold, once calculated.
+    pub fn carrying_capacity(&self) -> Option<usize> {
+        self.carrying_capacity
+    }
+
+    /// Returns the number of organisms in the region.
+    pub fn organism_count(&self) -> usize {
+        self.organism_count
+    }
+
+    pub(super) fn write_binary(&self, writer: &mut impl Write) -> io::Result<()> {
+        write_usizes(writer, &self.key)?;
+        write_len(writer, self.bounds.len())?;
+        for &(start, end) in &self.bounds {
+            write_f64(writer, start)?;
+            write_f64(writer, end)?;
+        }
+        write_option(writer, self.min_score, write_f64)?;
+        write_option(writer, self.carrying_capacity, write_len)?;
+        write_len(writer, self.organism_count)
+    }
+
+    pub(super) fn read_binary<R: Read>(reader: &mut R) -> Result<Self, SnapshotError> {
+        Ok(Self {
+            key: read_vec(reader, read_len)?,
+            bounds: read_vec(reader, |reader| Ok((read_f64(reader)?, read_f64(reader)?)))?,
+            min_score: read_option(reader, read_f64)?,
+            carrying_capacity: read_option(reader, read_len)?,
+            organism_count: read_len(reader)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TrainingData;
+    use crate::parameters::global_constants::GlobalConstants;
+    use crate::world::world_function::WorldFunction;
+
+    #[derive(Debug)]
+    struct SumFn;
+    impl WorldFunction for SumFn {
+        fn run(&self, p: &[f64], _v: &[f64]) -> Vec<f64> {
+            vec![p.iter().map(|x| x.abs()).sum::<f64>()]
+        }
+    }
+
+    fn trained_world() -> World {
+        let mut world = World::new(
+            &[-1.0..=1.0, -2.0..=2.0],
+            GlobalConstants::new_with_seed(40, 4, 5),
+            Box::new(SumFn),
+        );
+        world.training_run(TrainingData::None { floor_value: 0.0 });
+        world
+    }
+
+    #[test]
+    fn given_default_options_when_captured_then_regions_and_organisms_without_gametes() {
+        let world = trained_world();
+
+        let snapshot = WorldSnapshot::capture(&world, &SnapshotOptions::new());
+
+        assert_eq!(snapshot.schema_version(), SNAPSHOT_SCHEMA_VERSION);
+        assert_eq!(snapshot.epoch(), 1);
+        assert_eq!(snapshot.dimensions().len(), 2);
+        let organisms = snapshot.organisms().unwrap();
+        assert_eq!(organisms.len(), world.organisms.len());
+        assert!(organisms.iter().all(|o| o.phenotype().gametes().is_none()));
+        assert_eq!(organisms[0].phenotype().problem_values().len(), 2);
+        let regions = snapshot.regions().unwrap();
+        assert_eq!(regions.len(), world.regions.len());
+        assert!(regions.iter().all(|r| r.bounds().len() == 2));
+    }
+
+    #[test]
+    fn given_options_when_captured_then_only_selected_sections_included() {
+        let world = trained_world();
+
+        let summary = WorldSnapshot::capture(
+            &world,
+            &SnapshotOptions::new()
+                .with_organisms(false)
+                .with_regions(false),
+        );
+        let full = WorldSnapshot::capture(&world, &SnapshotOptions::full());
+
+        assert!(summary.organisms().is_none() && summary.regions().is_none());
+        assert!(!summary.to_json().contains("organisms"));
+        let (gamete1, gamete2) = full.organisms().unwrap()[0].phenotype().gametes().unwrap();
+        assert_eq!(gamete1.loci().len(), gamete2.loci().len());
+        assert_eq!(gamete1.loci().len(), crate::NUM_SYSTEM_PARAMETERS + 2);
+    }
+
+    #[test]
+    fn given_snapshot_when_round_tripped_through_json_then_equal() {
+        let world = trained_world();
+        let snapshot = WorldSnapshot::capture(&world, &SnapshotOptions::full());
+
+        let restored = WorldSnapshot::from_json(&snapshot.to_json()).unwrap();
+
+        assert_eq!(restored, snapshot);
+    }
+
+    #[test]
+    fn given_newer_schema_when_read_then_unsupported_version() {
+        let json = format!(
+            r#"{{"schema_version":{},"epoch":0,"dimensions":[]}}"#,
+            SNAPSHOT_SCHEMA_VERSION + 1
+        );
+
+        let error = WorldSnapshot::from_json(&json).unwrap_err();
+
+        assert!(matches!(
+            error,
+            SnapshotError::UnsupportedVersion { found, supported }
+                if found == SNAPSHOT_SCHEMA_VERSION + 1 && supported == SNAPSHOT_SCHEMA_VERSION
+        ));
+        assert!(matches!(
+            WorldSnapshot::from_json("{}"),
+            Err(SnapshotError::Json(_))
+        ));
+    }
+}
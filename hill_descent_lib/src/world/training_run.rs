@@ -187,8 +187,6 @@ impl World {
     /// - [`get_state`](World::get_state) - Full system state for analysis
     pub fn training_run(&mut self, data: TrainingData) -> bool {
         // Process training data and run the algorithm
-        let world_seed = self.global_constants.world_seed();
-
         match data {
             TrainingData::None { floor_value } => {
                 // Validate floor_value
@@ -200,12 +198,7 @@ impl World {
                 // For standard optimization, use empty inputs and floor as single output
                 // Use stack array to avoid heap allocation
                 let known_outputs = [floor_value];
-                self.organisms = self.regions.parallel_process_regions(
-                    self.world_function.as_ref(),
-                    &[],
-                    &known_outputs,
-                    world_seed,
-                );
+                self.run_epoch(true, &[], &known_outputs)
             }
             TrainingData::Supervised { inputs, outputs } => {
                 // Validate supervised data
@@ -242,18 +235,9 @@ impl World {
                 );
 
                 // Process with flattened data
-                self.organisms = self.regions.parallel_process_regions(
-                    self.world_function.as_ref(),
-                    &flat_inputs,
-                    &flat_outputs,
-                    world_seed,
-                );
+                self.run_epoch(true, &flat_inputs, &flat_outputs)
             }
         }
-
-        // SYNC PHASE: Global coordination
-        self.regions
-            .update(&mut self.organisms, &mut self.dimensions)
     }
 }
 
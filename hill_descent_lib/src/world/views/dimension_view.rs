@@ -0,0 +1,38 @@
+use std::ops::RangeInclusive;
+
+use crate::world::dimensions::dimension::Dimension;
+
+/// One problem parameter's axis of the region grid.
+#[derive(Debug, Clone, Copy)]
+pub struct DimensionView<'a> {
+    dimension: &'a Dimension,
+}
+
+impl<'a> DimensionView<'a> {
+    pub(crate) fn new(dimension: &'a Dimension) -> Self {
+        Self { dimension }
+    }
+
+    /// Returns the range the dimension currently spans. It starts as the parameter's
+    /// bounds and widens if organisms stray outside them.
+    pub fn range(&self) -> &'a RangeInclusive<f64> {
+        self.dimension.range()
+    }
+
+    /// Returns how many times the range has been halved.
+    pub fn doublings(&self) -> usize {
+        self.dimension.number_of_doublings()
+    }
+
+    /// Returns the number of intervals the range is divided into, `2^doublings`.
+    pub fn intervals(&self) -> usize {
+        self.dimension.num_intervals() as usize
+    }
+
+    /// Returns the bounds of interval `index`, or `None` if there is no such interval.
+    pub fn interval_bounds(&self, index: usize) -> Option<RangeInclusive<f64>> {
+        self.dimension
+            .interval_bounds(index)
+            .map(|(start, end)| start..=end)
+    }
+}
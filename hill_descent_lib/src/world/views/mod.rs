@@ -0,0 +1,18 @@
+//! Typed read-only views of a world's internals, for tooling that would otherwise parse
+//! [`World::get_state`].
+//!
+//! [`World::regions`], [`World::dimensions`] and [`World::organisms`] borrow the world, so
+//! the views are cheap to take and always describe its current state.
+//!
+//! [`World::get_state`]: crate::World::get_state
+//! [`World::regions`]: crate::World::regions
+//! [`World::dimensions`]: crate::World::dimensions
+//! [`World::organisms`]: crate::World::organisms
+
+mod dimension_view;
+mod organism_view;
+mod region_view;
+
+pub use dimension_view::DimensionView;
+pub use organism_view::OrganismView;
+pub use region_view::RegionView;
@@ -0,0 +1,43 @@
+use crate::world::organisms::Organism;
+
+/// An organism in the current population.
+#[derive(Debug, Clone, Copy)]
+pub struct OrganismView<'a> {
+    organism: &'a Organism,
+}
+
+impl<'a> OrganismView<'a> {
+    pub(crate) fn new(organism: &'a Organism) -> Self {
+        Self { organism }
+    }
+
+    /// Returns the organism's unique ID.
+    pub fn id(&self) -> usize {
+        self.organism.id()
+    }
+
+    /// Returns the parent IDs, as [`Organism::parent_ids`] does.
+    pub fn parent_ids(&self) -> (Option<usize>, Option<usize>) {
+        self.organism.parent_ids()
+    }
+
+    /// Returns the problem parameters the organism expresses.
+    pub fn params(&self) -> &'a [f64] {
+        self.organism.phenotype().expression_problem_values()
+    }
+
+    /// Returns the organism's score, or `None` if it has not been evaluated.
+    pub fn score(&self) -> Option<f64> {
+        self.organism.score()
+    }
+
+    /// Returns the organism's age in epochs.
+    pub fn age(&self) -> usize {
+        self.organism.age()
+    }
+
+    /// Returns the interval index in each dimension of the region the organism is in.
+    pub fn region_key(&self) -> Option<Vec<usize>> {
+        self.organism.region_key().map(|key| key.values().to_vec())
+    }
+}
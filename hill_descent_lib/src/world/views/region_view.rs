@@ -0,0 +1,66 @@
+use std::ops::RangeInclusive;
+
+use super::OrganismView;
+use crate::world::dimensions::Dimensions;
+use crate::world::regions::region::{Region, region_key::RegionKey};
+
+/// A region of the parameter space and the organisms in it.
+#[derive(Debug, Clone, Copy)]
+pub struct RegionView<'a> {
+    key: &'a RegionKey,
+    region: &'a Region,
+    dimensions: &'a Dimensions,
+}
+
+impl<'a> RegionView<'a> {
+    pub(crate) fn new(key: &'a RegionKey, region: &'a Region, dimensions: &'a Dimensions) -> Self {
+        Self {
+            key,
+            region,
+            dimensions,
+        }
+    }
+
+    /// Returns the region's interval index in each dimension.
+    pub fn key(&self) -> &'a [usize] {
+        self.key.values()
+    }
+
+    /// Returns the region's extent in each dimension.
+    pub fn bounds(&self) -> Vec<RangeInclusive<f64>> {
+        self.dimensions
+            .get_dimensions()
+            .iter()
+            .zip(self.key.values())
+            .map(|(dimension, &interval)| {
+                let (start, end) = dimension
+                    .interval_bounds(interval)
+                    .expect("region keys index intervals of the current dimensions");
+                start..=end
+            })
+            .collect()
+    }
+
+    /// Returns the number of organisms the region may hold, once it has been calculated.
+    pub fn carrying_capacity(&self) -> Option<usize> {
+        self.region.carrying_capacity()
+    }
+
+    /// Returns the best score in the region, if any organism in it has been scored.
+    pub fn min_score(&self) -> Option<f64> {
+        self.region.min_score()
+    }
+
+    /// Returns the number of organisms in the region.
+    pub fn organism_count(&self) -> usize {
+        self.region.organism_count()
+    }
+
+    /// Returns the organisms in the region.
+    pub fn organisms(&self) -> impl ExactSizeIterator<Item = OrganismView<'a>> + 'a {
+        self.region
+            .organisms()
+            .iter()
+            .map(|organism| OrganismView::new(organism))
+    }
+}
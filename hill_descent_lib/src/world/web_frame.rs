@@ -0,0 +1,194 @@
+use serde::Serialize;
+
+/// A region as it appears in a [`WebFrame`].
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct WebFrameRegion {
+    key: Vec<usize>,
+    bounds: WebFrameBounds,
+    carrying_capacity: usize,
+    min_score: Option<f64>,
+}
+
+impl WebFrameRegion {
+    pub(crate) fn new(
+        key: Vec<usize>,
+        bounds: WebFrameBounds,
+        carrying_capacity: usize,
+        min_score: Option<f64>,
+    ) -> Self {
+        Self {
+            key,
+            bounds,
+            carrying_capacity,
+            min_score,
+        }
+    }
+
+    /// Returns the region's interval index in each dimension.
+    pub fn key(&self) -> &[usize] {
+        &self.key
+    }
+
+    /// Returns the region's extent in the two visualised dimensions.
+    pub fn bounds(&self) -> &WebFrameBounds {
+        &self.bounds
+    }
+
+    /// Returns the number of organisms the region may hold.
+    pub fn carrying_capacity(&self) -> usize {
+        self.carrying_capacity
+    }
+
+    /// Returns the best score in the region, if any organism in it has been scored.
+    pub fn min_score(&self) -> Option<f64> {
+        self.min_score
+    }
+}
+
+/// A rectangle in the two visualised dimensions.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct WebFrameBounds {
+    x: (f64, f64),
+    y: (f64, f64),
+}
+
+impl WebFrameBounds {
+    pub(crate) fn new(x: (f64, f64), y: (f64, f64)) -> Self {
+        Self { x, y }
+    }
+
+    /// Returns the `(start, end)` of the first dimension.
+    pub fn x(&self) -> (f64, f64) {
+        self.x
+    }
+
+    /// Returns the `(start, end)` of the second dimension.
+    pub fn y(&self) -> (f64, f64) {
+        self.y
+    }
+}
+
+/// An organism's position and age as it appears in a [`WebFrame`].
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct WebFrameOrganism {
+    id: usize,
+    x: f64,
+    y: f64,
+    score: Option<f64>,
+    age: usize,
+    max_age: usize,
+}
+
+impl WebFrameOrganism {
+    pub(crate) fn new(
+        id: usize,
+        (x, y): (f64, f64),
+        score: Option<f64>,
+        age: usize,
+        max_age: usize,
+    ) -> Self {
+        Self {
+            id,
+            x,
+            y,
+            score,
+            age,
+            max_age,
+        }
+    }
+
+    /// Returns the organism's ID.
+    pub fn id(&self) -> usize {
+        self.id
+    }
+
+    /// Returns the organism's first problem parameter.
+    pub fn x(&self) -> f64 {
+        self.x
+    }
+
+    /// Returns the organism's second problem parameter.
+    pub fn y(&self) -> f64 {
+        self.y
+    }
+
+    /// Returns the organism's score, if it has been evaluated.
+    pub fn score(&self) -> Option<f64> {
+        self.score
+    }
+
+    /// Returns the organism's age in epochs.
+    pub fn age(&self) -> usize {
+        self.age
+    }
+
+    /// Returns the age at which the organism dies, rounded to whole epochs.
+    pub fn max_age(&self) -> usize {
+        self.max_age
+    }
+}
+
+/// A compact view of a world's first two dimensions for animating a run.
+///
+/// Unlike [`World::get_state_for_web`](super::World::get_state_for_web) a frame carries no
+/// genetic detail, only what is needed to draw one epoch, so it is cheap enough to produce
+/// and send after every epoch. Frames serialise with `serde`.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct WebFrame {
+    epoch: u64,
+    best_score: f64,
+    best_params: Vec<f64>,
+    world_bounds: WebFrameBounds,
+    regions: Vec<WebFrameRegion>,
+    organisms: Vec<WebFrameOrganism>,
+}
+
+impl WebFrame {
+    pub(crate) fn new(
+        epoch: u64,
+        best_score: f64,
+        best_params: Vec<f64>,
+        world_bounds: WebFrameBounds,
+        regions: Vec<WebFrameRegion>,
+        organisms: Vec<WebFrameOrganism>,
+    ) -> Self {
+        Self {
+            epoch,
+            best_score,
+            best_params,
+            world_bounds,
+            regions,
+            organisms,
+        }
+    }
+
+    /// Returns the number of training runs completed when the frame was taken.
+    pub fn epoch(&self) -> u64 {
+        self.epoch
+    }
+
+    /// Returns the best score in the population.
+    pub fn best_score(&self) -> f64 {
+        self.best_score
+    }
+
+    /// Returns the problem parameters of the best organism.
+    pub fn best_params(&self) -> &[f64] {
+        &self.best_params
+    }
+
+    /// Returns the extent of the world in the two visualised dimensions.
+    pub fn world_bounds(&self) -> &WebFrameBounds {
+        &self.world_bounds
+    }
+
+    /// Returns the regions, in the world's region order.
+    pub fn regions(&self) -> &[WebFrameRegion] {
+        &self.regions
+    }
+
+    /// Returns the living organisms.
+    pub fn organisms(&self) -> &[WebFrameOrganism] {
+        &self.organisms
+    }
+}
@@ -0,0 +1,49 @@
+use std::io::{self, Write};
+
+use super::World;
+use super::snapshot::{SnapshotOptions, WorldSnapshot};
+
+impl World {
+    /// Writes the world's state to `writer` in the binary snapshot format, with the sections
+    /// selected by `options`.
+    ///
+    /// Organisms are encoded and written one at a time, so a snapshot of a large population
+    /// never needs a second copy of it in memory. Wrap a file in a
+    /// [`BufWriter`](std::io::BufWriter) before passing it. Read the snapshot back with
+    /// [`SnapshotReader`](super::snapshot::SnapshotReader) or
+    /// [`WorldSnapshot::read_binary`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use hill_descent_lib::world::snapshot::{SnapshotOptions, WorldSnapshot};
+    /// use hill_descent_lib::{GlobalConstants, SingleValuedFunction, TrainingData, setup_world};
+    ///
+    /// #[derive(Debug)]
+    /// struct Sphere;
+    ///
+    /// impl SingleValuedFunction for Sphere {
+    ///     fn single_run(&self, params: &[f64]) -> f64 {
+    ///         params.iter().map(|x| x * x).sum()
+    ///     }
+    /// }
+    ///
+    /// let bounds = vec![-5.0..=5.0; 2];
+    /// let mut world = setup_world(&bounds, GlobalConstants::new(100, 10), Box::new(Sphere));
+    /// world.training_run(TrainingData::None { floor_value: 0.0 });
+    ///
+    /// let options = SnapshotOptions::full();
+    /// let mut bytes = Vec::new();
+    /// world.write_snapshot(&mut bytes, &options).unwrap();
+    ///
+    /// let restored = WorldSnapshot::read_binary(bytes.as_slice()).unwrap();
+    /// assert_eq!(restored, world.snapshot(&options));
+    /// ```
+    pub fn write_snapshot(
+        &self,
+        mut writer: impl Write,
+        options: &SnapshotOptions,
+    ) -> io::Result<()> {
+        WorldSnapshot::write_streamed(self, &mut writer, options)
+    }
+}
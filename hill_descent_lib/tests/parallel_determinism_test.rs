@@ -88,3 +88,44 @@ fn given_parallel_execution_when_same_seed_then_deterministic() {
         );
     }
 }
+
+#[derive(Debug)]
+struct Sphere;
+impl SingleValuedFunction for Sphere {
+    fn single_run(&self, params: &[f64]) -> f64 {
+        params.iter().map(|x| x * x).sum()
+    }
+}
+
+fn run_sphere_on_threads(threads: usize, seed: u64) -> (u64, f64, Vec<f64>) {
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(threads)
+        .build()
+        .expect("Failed to build thread pool");
+    pool.install(|| {
+        let bounds: Vec<RangeInclusive<f64>> = vec![-5.0..=5.0; 3];
+        let constants = parameters::global_constants::GlobalConstants::new_with_seed(200, 10, seed);
+        let mut world = setup_world(&bounds, constants, Box::new(Sphere));
+        for _ in 0..15 {
+            world.training_run(TrainingData::None { floor_value: 0.0 });
+        }
+        (
+            world.epoch(),
+            world.get_best_score(),
+            world.get_best_params(),
+        )
+    })
+}
+
+#[test]
+fn given_same_seed_when_run_on_different_thread_counts_then_identical_results() {
+    let single = run_sphere_on_threads(1, 777);
+    for threads in [2, 4, 8] {
+        assert_eq!(
+            single,
+            run_sphere_on_threads(threads, 777),
+            "Run on {threads} threads differs from single-threaded run"
+        );
+    }
+    assert_eq!(single.0, 15);
+}
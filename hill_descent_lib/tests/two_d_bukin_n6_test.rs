@@ -1,3 +1,4 @@
+use hill_descent_lib::benchmarks::BukinN6;
 use hill_descent_lib::{
     GlobalConstants, TrainingData, setup_world, world::single_valued_function::SingleValuedFunction,
 };
@@ -8,24 +9,6 @@ use std::ops::RangeInclusive;
 // f(x, y) = 100√|y - 0.01x²| + 0.01|x + 10|
 // The global minimum is 0 at (-10, 1).
 // Domain: x ∈ [-15, -5], y ∈ [-3, 3]
-#[derive(Debug)]
-struct BukinN6;
-
-impl SingleValuedFunction for BukinN6 {
-    fn single_run(&self, phenotype_expressed_values: &[f64]) -> f64 {
-        // This function is 2-dimensional.
-        assert_eq!(2, phenotype_expressed_values.len());
-
-        let x = phenotype_expressed_values[0];
-        let y = phenotype_expressed_values[1];
-
-        // f(x, y) = 100√|y - 0.01x²| + 0.01|x + 10|
-        let term1 = 100.0 * (y - 0.01 * x * x).abs().sqrt();
-        let term2 = 0.01 * (x + 10.0).abs();
-
-        term1 + term2
-    }
-}
 
 #[test]
 #[ignore] // This test is long-running and should be run explicitly.
@@ -1,3 +1,4 @@
+use hill_descent_lib::benchmarks::Himmelblau;
 use hill_descent_lib::{
     GlobalConstants, TrainingData, setup_world, world::single_valued_function::SingleValuedFunction,
 };
@@ -8,23 +9,6 @@ use std::ops::RangeInclusive;
 // ability to find one of several optimal solutions.
 // f(x, y) = (x^2 + y - 11)^2 + (x + y^2 - 7)^2
 // The global minimum is 0.
-#[derive(Debug)]
-struct Himmelblau;
-
-impl SingleValuedFunction for Himmelblau {
-    fn single_run(&self, phenotype_expressed_values: &[f64]) -> f64 {
-        // This function is 2-dimensional.
-        assert_eq!(2, phenotype_expressed_values.len());
-
-        let x = phenotype_expressed_values[0];
-        let y = phenotype_expressed_values[1];
-
-        // f(x, y) = (x^2 + y - 11)^2 + (x + y^2 - 7)^2
-        let term1 = (x.powi(2) + y - 11.0).powi(2);
-        let term2 = (x + y.powi(2) - 7.0).powi(2);
-        term1 + term2
-    }
-}
 
 #[test]
 #[ignore] // This test is long-running and should be run explicitly.
@@ -1,3 +1,4 @@
+use hill_descent_lib::benchmarks::LeviN13;
 use hill_descent_lib::{
     GlobalConstants, TrainingData, setup_world, world::single_valued_function::SingleValuedFunction,
 };
@@ -7,27 +8,6 @@ use std::ops::RangeInclusive;
 // It has a complex surface with many local minima but one global minimum.
 // f(x, y) = sin²(3πx) + (x-1)²[1 + sin²(3πy)] + (y-1)²[1 + sin²(2πy)]
 // The global minimum is 0 at (1, 1).
-#[derive(Debug)]
-struct LeviN13;
-
-impl SingleValuedFunction for LeviN13 {
-    fn single_run(&self, phenotype_expressed_values: &[f64]) -> f64 {
-        // This function is 2-dimensional.
-        assert_eq!(2, phenotype_expressed_values.len());
-
-        let x = phenotype_expressed_values[0];
-        let y = phenotype_expressed_values[1];
-
-        let pi = std::f64::consts::PI;
-
-        // f(x, y) = sin²(3πx) + (x-1)²[1 + sin²(3πy)] + (y-1)²[1 + sin²(2πy)]
-        let term1 = (3.0 * pi * x).sin().powi(2);
-        let term2 = (x - 1.0).powi(2) * (1.0 + (3.0 * pi * y).sin().powi(2));
-        let term3 = (y - 1.0).powi(2) * (1.0 + (2.0 * pi * y).sin().powi(2));
-
-        term1 + term2 + term3
-    }
-}
 
 #[test]
 #[ignore] // This test is long-running and should be run explicitly.
@@ -1,3 +1,4 @@
+use hill_descent_lib::benchmarks::Rastrigin;
 use hill_descent_lib::{
     GlobalConstants, TrainingData, setup_world, world::single_valued_function::SingleValuedFunction,
 };
@@ -7,19 +8,6 @@ use std::ops::RangeInclusive;
 // f(x, y) = 20 + (x^2 - 10 cos(2πx)) + (y^2 - 10 cos(2πy))
 // Global minimum at (0,0) with f = 0.0.
 // Many regularly spaced local minima make it a good test of exploration.
-#[derive(Debug)]
-struct Rastrigin;
-
-impl SingleValuedFunction for Rastrigin {
-    fn single_run(&self, phenotype_expressed_values: &[f64]) -> f64 {
-        assert_eq!(2, phenotype_expressed_values.len());
-        let x = phenotype_expressed_values[0];
-        let y = phenotype_expressed_values[1];
-
-        let two_pi = 2.0 * std::f64::consts::PI;
-        20.0 + (x * x - 10.0 * (two_pi * x).cos()) + (y * y - 10.0 * (two_pi * y).cos())
-    }
-}
 
 #[test]
 #[ignore]
@@ -34,14 +22,14 @@ pub fn execute() {
     // Population & regions tuned similarly to the Himmelblau test; adjust if convergence slow.
     let global_constants = GlobalConstants::new(500, 10);
 
-    let mut world = setup_world(&param_range, global_constants, Box::new(Rastrigin));
+    let mut world = setup_world(&param_range, global_constants, Box::new(Rastrigin::new(2)));
 
     let mut best_score = f64::MAX;
 
     // Allow sufficient epochs to traverse multiple local minima basins.
     for epoch in 0..3000 {
         let at_resolution_limit = world.training_run(TrainingData::None {
-            floor_value: Rastrigin.function_floor(),
+            floor_value: Rastrigin::new(2).function_floor(),
         });
         let current_best = world.get_best_score();
         if current_best < best_score {
@@ -1,3 +1,4 @@
+use hill_descent_lib::benchmarks::SchafferN2;
 use hill_descent_lib::{
     GlobalConstants, TrainingData, setup_world, world::single_valued_function::SingleValuedFunction,
 };
@@ -8,27 +9,6 @@ use std::ops::RangeInclusive;
 // between smooth regions and areas with high-frequency oscillations.
 // f(x, y) = 0.5 + (sin²(x² - y²) - 0.5) / (1 + 0.001(x² + y²))²
 // The global minimum is 0 at (0, 0).
-#[derive(Debug)]
-struct SchafferN2;
-
-impl SingleValuedFunction for SchafferN2 {
-    fn single_run(&self, phenotype_expressed_values: &[f64]) -> f64 {
-        // This function is 2-dimensional.
-        assert_eq!(2, phenotype_expressed_values.len());
-
-        let x = phenotype_expressed_values[0];
-        let y = phenotype_expressed_values[1];
-
-        // f(x, y) = 0.5 + (sin²(x² - y²) - 0.5) / (1 + 0.001(x² + y²))²
-        let x2_plus_y2 = x * x + y * y;
-        let x2_minus_y2 = x * x - y * y;
-
-        let numerator = x2_minus_y2.sin().powi(2) - 0.5;
-        let denominator = (1.0 + 0.001 * x2_plus_y2).powi(2);
-
-        0.5 + numerator / denominator
-    }
-}
 
 #[test]
 #[ignore] // This test is long-running and should be run explicitly.
@@ -1,3 +1,4 @@
+use hill_descent_lib::benchmarks::StyblinskiTang;
 use hill_descent_lib::{
     GlobalConstants, TrainingData, setup_world, world::single_valued_function::SingleValuedFunction,
 };
@@ -7,31 +8,10 @@ use std::ops::RangeInclusive;
 // Formula: f(x,y) = (x⁴ - 16x² + 5x)/2 + (y⁴ - 16y² + 5y)/2
 // The 1D global minimum is approximately -39.16616570377142 at x ≈ -2.903534.
 // For 2D the global minimum is twice that: approximately -78.33233140755284 at (-2.903534, -2.903534).
-#[derive(Debug)]
-struct StyblinskiTang;
-
-impl SingleValuedFunction for StyblinskiTang {
-    fn single_run(&self, phenotype_expressed_values: &[f64]) -> f64 {
-        // This function is 2-dimensional.
-        assert_eq!(2, phenotype_expressed_values.len());
-
-        let x = phenotype_expressed_values[0];
-        let y = phenotype_expressed_values[1];
-
-        let term_x = (x.powi(4) - 16.0 * x.powi(2) + 5.0 * x) / 2.0;
-        let term_y = (y.powi(4) - 16.0 * y.powi(2) + 5.0 * y) / 2.0;
-        term_x + term_y
-    }
-
-    fn function_floor(&self) -> f64 {
-        // 2D global minimum: 2 * -39.16616570377142
-        -78.332_331_407_552_84
-    }
-}
 
 #[test]
 fn given_styblinski_tang_when_evaluated_at_global_min_then_returns_minimum() {
-    let st = StyblinskiTang;
+    let st = StyblinskiTang::new(2);
     let v = st.single_run(&[-2.903534, -2.903534]);
     const EXPECTED_MIN: f64 = -78.332_331_407_552_84;
     assert!(
@@ -53,7 +33,11 @@ pub fn execute() {
     ];
     let global_constants = GlobalConstants::new(500, 10); // Larger population for 2D search
 
-    let mut world = setup_world(&param_range, global_constants, Box::new(StyblinskiTang));
+    let mut world = setup_world(
+        &param_range,
+        global_constants,
+        Box::new(StyblinskiTang::new(2)),
+    );
 
     let mut best_score = f64::MAX;
 
@@ -61,7 +45,7 @@ pub fn execute() {
     for i in 0..3000 {
         // Objective-function mode: use TrainingData::None with function floor
         let at_resolution_limit = world.training_run(TrainingData::None {
-            floor_value: StyblinskiTang.function_floor(),
+            floor_value: StyblinskiTang::new(2).function_floor(),
         });
 
         // Get the current best score from organisms
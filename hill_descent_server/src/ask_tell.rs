@@ -0,0 +1,69 @@
+//! Sessions whose objective is evaluated by the client.
+//!
+//! A session created with `function_type: "external"` never evaluates anything itself.
+//! Clients fetch the organisms awaiting evaluation with `POST /api/sessions/{id}/ask`,
+//! score them however they like and report the results with `POST /api/sessions/{id}/tell`.
+//! The world advances one epoch once every candidate has been told.
+
+use hill_descent_lib::{WorldFunction, world::ask_tell::Candidate};
+use serde::{Deserialize, Serialize};
+
+/// Largest `tell` body accepted, enough for the scores of a very large population.
+pub const MAX_TELL_BYTES: usize = 4 * 1024 * 1024;
+
+/// The world function of an `external` session: only its floor is used, since scores
+/// arrive through `tell`.
+#[derive(Debug, Clone, Copy)]
+pub struct ClientScores {
+    floor: f64,
+}
+
+impl ClientScores {
+    pub fn new(floor: f64) -> Self {
+        Self { floor }
+    }
+}
+
+impl WorldFunction for ClientScores {
+    fn run(&self, _phenotype_expressed_values: &[f64], _inputs: &[f64]) -> Vec<f64> {
+        unreachable!("external sessions are only advanced through ask/tell")
+    }
+
+    fn function_floor(&self) -> f64 {
+        self.floor
+    }
+}
+
+/// Response body for `POST /api/sessions/{id}/ask`.
+#[derive(Debug, Serialize)]
+pub struct AskResponse {
+    pub epoch: usize,
+    /// Every candidate not yet told. Empty when the epoch only needs an empty `tell`.
+    pub candidates: Vec<Candidate>,
+}
+
+/// Request body for `POST /api/sessions/{id}/tell`.
+#[derive(Debug, Deserialize)]
+pub struct TellRequest {
+    pub results: Vec<CandidateScore>,
+}
+
+/// The objective value of one candidate, to be minimised.
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub struct CandidateScore {
+    pub id: usize,
+    /// Must be finite and at or above the session's floor.
+    pub score: f64,
+}
+
+/// Response body for `POST /api/sessions/{id}/tell`.
+#[derive(Debug, Clone, Serialize)]
+pub struct TellResponse {
+    /// Candidates still awaiting scores; 0 once the epoch has run.
+    pub remaining: usize,
+    pub epoch_completed: bool,
+    pub epoch: usize,
+    pub best_score: f64,
+    pub best_params: Vec<f64>,
+    pub at_resolution_limit: bool,
+}
@@ -0,0 +1,232 @@
+//! Server-sent event stream of per-epoch world deltas.
+//!
+//! A session's worker runs the world continuously while playing and pushes a compact
+//! [`FrameDelta`] after every epoch to each connected client. Clients control playback
+//! (play, pause and speed) with separate requests.
+
+use std::{
+    convert::Infallible,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use actix_web::{
+    body::{BodySize, MessageBody},
+    web::Bytes,
+};
+use hill_descent_lib::world::web_frame::{
+    WebFrame, WebFrameBounds, WebFrameOrganism, WebFrameRegion,
+};
+use serde::{Deserialize, Serialize};
+use tokio::sync::mpsc::UnboundedReceiver;
+
+/// Fastest playback speed a client may request.
+pub const MAX_EPOCHS_PER_SECOND: f64 = 100.0;
+
+/// How a session's world is being played back.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct Playback {
+    pub playing: bool,
+    pub epochs_per_second: f64,
+}
+
+impl Default for Playback {
+    fn default() -> Self {
+        Self {
+            playing: false,
+            epochs_per_second: 5.0,
+        }
+    }
+}
+
+/// Request body for `POST /api/sessions/{id}/playback`; omitted fields are unchanged.
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub struct PlaybackUpdate {
+    pub playing: Option<bool>,
+    pub epochs_per_second: Option<f64>,
+}
+
+/// Changes between two frames, sent as a `frame` event after every epoch.
+///
+/// The first delta a client receives has `full` set and lists every region; after that
+/// only regions that were added or changed are listed, together with the keys of removed
+/// regions. Organism positions are always sent in full since most move every epoch.
+#[derive(Debug, Serialize)]
+pub struct FrameDelta<'a> {
+    full: bool,
+    epoch: u64,
+    best_score: f64,
+    best_params: &'a [f64],
+    world_bounds: &'a WebFrameBounds,
+    at_resolution_limit: bool,
+    regions_changed: Vec<&'a WebFrameRegion>,
+    regions_removed: Vec<&'a [usize]>,
+    organisms: &'a [WebFrameOrganism],
+}
+
+impl<'a> FrameDelta<'a> {
+    /// Describes `current` relative to `previous`, or in full when there is no previous frame.
+    pub fn between(
+        previous: Option<&'a WebFrame>,
+        current: &'a WebFrame,
+        at_resolution_limit: bool,
+    ) -> Self {
+        let (regions_changed, regions_removed) = match previous {
+            None => (current.regions().iter().collect(), Vec::new()),
+            Some(previous) => diff_by_key(previous.regions(), current.regions(), |r| r.key()),
+        };
+        Self {
+            full: previous.is_none(),
+            epoch: current.epoch(),
+            best_score: current.best_score(),
+            best_params: current.best_params(),
+            world_bounds: current.world_bounds(),
+            at_resolution_limit,
+            regions_changed,
+            regions_removed,
+            organisms: current.organisms(),
+        }
+    }
+}
+
+/// Returns the items of `current` that are new or differ from `previous`, and the keys of
+/// the items of `previous` whose key no longer appears in `current`.
+fn diff_by_key<'a, T: PartialEq>(
+    previous: &'a [T],
+    current: &'a [T],
+    key: impl Fn(&'a T) -> &'a [usize],
+) -> (Vec<&'a T>, Vec<&'a [usize]>) {
+    let changed = current.iter().filter(|c| !previous.contains(c)).collect();
+    let removed = previous
+        .iter()
+        .filter(|p| !current.iter().any(|c| key(c) == key(p)))
+        .map(&key)
+        .collect();
+    (changed, removed)
+}
+
+/// Encodes `data` as a single server-sent event named `event`.
+pub fn sse_event(event: &str, data: &impl Serialize) -> Bytes {
+    let json = serde_json::to_string(data).expect("stream events always serialise");
+    Bytes::from(format!("event: {event}\ndata: {json}\n\n"))
+}
+
+/// Response body that forwards events from a session's worker until it stops or the client
+/// disconnects (which drops the body and so unsubscribes).
+#[derive(Debug)]
+pub struct SseBody {
+    events: UnboundedReceiver<Bytes>,
+}
+
+impl SseBody {
+    pub fn new(events: UnboundedReceiver<Bytes>) -> Self {
+        Self { events }
+    }
+}
+
+impl MessageBody for SseBody {
+    type Error = Infallible;
+
+    fn size(&self) -> BodySize {
+        BodySize::Stream
+    }
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Bytes, Self::Error>>> {
+        self.events.poll_recv(cx).map(|event| event.map(Ok))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hill_descent_lib::{GlobalConstants, TrainingData, benchmarks::Rastrigin, setup_world};
+
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    struct Region {
+        key: Vec<usize>,
+        score: Option<f64>,
+    }
+
+    fn region(key: &[usize], score: Option<f64>) -> Region {
+        Region {
+            key: key.to_vec(),
+            score,
+        }
+    }
+
+    fn diff<'a>(
+        previous: &'a [Region],
+        current: &'a [Region],
+    ) -> (Vec<&'a Region>, Vec<&'a [usize]>) {
+        diff_by_key(previous, current, |r| r.key.as_slice())
+    }
+
+    #[test]
+    fn given_identical_regions_when_diffed_then_empty_delta() {
+        let regions = [region(&[0, 0], Some(1.0)), region(&[0, 1], None)];
+
+        let (changed, removed) = diff(&regions, &regions);
+
+        assert!(changed.is_empty());
+        assert!(removed.is_empty());
+    }
+
+    #[test]
+    fn given_new_region_when_diffed_then_only_it_is_changed() {
+        let previous = [region(&[0, 0], Some(1.0))];
+        let current = [region(&[0, 0], Some(1.0)), region(&[1, 0], Some(2.0))];
+
+        let (changed, removed) = diff(&previous, &current);
+
+        assert_eq!(changed, vec![&current[1]]);
+        assert!(removed.is_empty());
+    }
+
+    #[test]
+    fn given_vanished_region_when_diffed_then_only_its_key_is_removed() {
+        let previous = [region(&[0, 0], Some(1.0)), region(&[1, 0], Some(2.0))];
+        let current = [region(&[0, 0], Some(1.0))];
+
+        let (changed, removed) = diff(&previous, &current);
+
+        assert!(changed.is_empty());
+        assert_eq!(removed, vec![&[1, 0][..]]);
+    }
+
+    #[test]
+    fn given_score_change_when_diffed_then_region_changed_not_removed() {
+        let previous = [region(&[0, 0], Some(1.0)), region(&[1, 0], None)];
+        let current = [region(&[0, 0], Some(0.5)), region(&[1, 0], None)];
+
+        let (changed, removed) = diff(&previous, &current);
+
+        assert_eq!(changed, vec![&current[0]]);
+        assert!(removed.is_empty());
+    }
+
+    #[test]
+    fn given_world_frames_when_delta_taken_then_full_first_and_empty_when_unchanged() {
+        let mut world = setup_world(
+            &[-5.12..=5.12, -5.12..=5.12],
+            GlobalConstants::new_with_seed(40, 4, 1),
+            Box::new(Rastrigin::new(2)),
+        );
+        world.training_run(TrainingData::None { floor_value: 0.0 });
+        let frame = world.get_frame_for_web();
+        let same = world.get_frame_for_web();
+
+        let full = FrameDelta::between(None, &frame, false);
+        let unchanged = FrameDelta::between(Some(&frame), &same, true);
+
+        assert!(full.full);
+        assert_eq!(full.regions_changed.len(), frame.regions().len());
+        assert!(!unchanged.full && unchanged.at_resolution_limit);
+        assert!(unchanged.regions_changed.is_empty() && unchanged.regions_removed.is_empty());
+        // Organisms are always sent in full, so births and deaths need no bookkeeping
+        assert_eq!(unchanged.organisms.len(), same.organisms().len());
+    }
+}
@@ -1,11 +1,28 @@
 use actix_cors::Cors;
 use actix_files::Files;
-use actix_web::{App, HttpResponse, HttpServer, Result, middleware::Logger, web};
+use actix_web::{
+    App, HttpResponse, HttpResponseBuilder, HttpServer, Result, middleware::Logger, web,
+};
+use ask_tell::{MAX_TELL_BYTES, TellRequest};
+use frame_stream::{MAX_EPOCHS_PER_SECOND, PlaybackUpdate, SseBody};
 use hill_descent_lib::{
-    GlobalConstants, SingleValuedFunction, TrainingData, WorldFunction, setup_world,
+    WorldFunction,
+    benchmarks::{Ackley, BukinN6, Himmelblau, Rastrigin},
+    expression::{Expression, ExpressionFunction},
 };
 use serde::{Deserialize, Serialize};
-use std::{collections::HashMap, ops::RangeInclusive, sync::Mutex};
+use sessions::{MAX_SESSIONS, SESSION_TTL, SessionStore};
+use std::{
+    collections::HashMap,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+use world_session::WorldSession;
+
+mod ask_tell;
+mod frame_stream;
+mod sessions;
+mod world_session;
 
 /// Available optimization functions
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
@@ -15,77 +32,11 @@ pub enum FunctionType {
     Rastrigin,
     Ackley,
     BukinN6,
+    /// A user-defined expression supplied when the session is created.
+    Custom,
+    /// Scores computed by the client and reported through ask/tell.
+    External,
 }
-/// Bukin N.6 function implementation (2D, narrow curved valley)
-/// f(x, y) = 100 * sqrt(|y - 0.01x^2|) + 0.01 * |x + 10|
-/// Global minimum at (-10, 1) with f = 0.0
-#[derive(Debug, Clone)]
-struct BukinN6;
-
-impl SingleValuedFunction for BukinN6 {
-    fn single_run(&self, phenotype_expressed_values: &[f64]) -> f64 {
-        assert_eq!(2, phenotype_expressed_values.len());
-        let x = phenotype_expressed_values[0];
-        let y = phenotype_expressed_values[1];
-        let term1 = 100.0 * (y - 0.01 * x * x).abs().sqrt();
-        let term2 = 0.01 * (x + 10.0).abs();
-        term1 + term2
-    }
-}
-
-/// Himmelblau function implementation
-#[derive(Debug, Clone)]
-struct Himmelblau;
-
-impl SingleValuedFunction for Himmelblau {
-    fn single_run(&self, phenotype_expressed_values: &[f64]) -> f64 {
-        assert_eq!(2, phenotype_expressed_values.len());
-        let x = phenotype_expressed_values[0];
-        let y = phenotype_expressed_values[1];
-        let term1 = (x.powi(2) + y - 11.0).powi(2);
-        let term2 = (x + y.powi(2) - 7.0).powi(2);
-        term1 + term2
-    }
-}
-
-/// Rastrigin function implementation (2D multimodal benchmark)
-/// f(x, y) = 20 + (x^2 - 10 cos(2πx)) + (y^2 - 10 cos(2πy))
-/// Global minimum at (0,0) with f = 0.0
-#[derive(Debug, Clone)]
-struct Rastrigin;
-
-impl SingleValuedFunction for Rastrigin {
-    fn single_run(&self, phenotype_expressed_values: &[f64]) -> f64 {
-        assert_eq!(2, phenotype_expressed_values.len());
-        let x = phenotype_expressed_values[0];
-        let y = phenotype_expressed_values[1];
-
-        let two_pi = 2.0 * std::f64::consts::PI;
-        20.0 + (x * x - 10.0 * (two_pi * x).cos()) + (y * y - 10.0 * (two_pi * y).cos())
-    }
-}
-
-/// Ackley function implementation (2D multimodal benchmark)
-/// f(x, y) = -20 * exp(-0.2 * sqrt(0.5 * (x^2 + y^2))) - exp(0.5 * (cos(2πx) + cos(2πy))) + e + 20
-/// Global minimum at (0,0) with f = 0.0
-#[derive(Debug, Clone)]
-struct Ackley;
-
-impl SingleValuedFunction for Ackley {
-    fn single_run(&self, phenotype_expressed_values: &[f64]) -> f64 {
-        assert_eq!(2, phenotype_expressed_values.len());
-        let x = phenotype_expressed_values[0];
-        let y = phenotype_expressed_values[1];
-
-        let two_pi = 2.0 * std::f64::consts::PI;
-        let term1 = -20.0 * (-0.2 * (0.5 * (x * x + y * y)).sqrt()).exp();
-        let term2 = -(0.5 * ((two_pi * x).cos() + (two_pi * y).cos())).exp();
-        let e = std::f64::consts::E;
-
-        term1 + term2 + e + 20.0
-    }
-}
-
 /// Function metadata for the frontend
 #[derive(Debug, Clone, Serialize)]
 pub struct FunctionInfo {
@@ -167,9 +118,10 @@ impl FunctionRegistry {
     pub fn create_function(&self, function_type: &FunctionType) -> Option<Box<dyn WorldFunction>> {
         match function_type {
             FunctionType::Himmelblau => Some(Box::new(Himmelblau)),
-            FunctionType::Rastrigin => Some(Box::new(Rastrigin)),
-            FunctionType::Ackley => Some(Box::new(Ackley)),
+            FunctionType::Rastrigin => Some(Box::new(Rastrigin::new(2))),
+            FunctionType::Ackley => Some(Box::new(Ackley::new(2))),
             FunctionType::BukinN6 => Some(Box::new(BukinN6)),
+            FunctionType::Custom | FunctionType::External => None,
         }
     }
 }
@@ -179,6 +131,35 @@ struct StartRequest {
     population_size: Option<usize>,
     elite_size: Option<usize>,
     function_type: Option<FunctionType>,
+    /// Objective expression over `x0`, `x1`, ...; takes the place of `function_type`.
+    expression: Option<String>,
+    /// `(min, max)` of each parameter; required with `expression` and `external`.
+    bounds: Option<Vec<(f64, f64)>>,
+    /// Lowest value the objective can take; defaults to 0. Lower expression values are
+    /// clamped and lower external scores rejected.
+    floor: Option<f64>,
+}
+
+/// Query parameters for `POST /api/step`.
+#[derive(Deserialize)]
+struct StepQuery {
+    /// Number of epochs to run; defaults to 1.
+    n: Option<usize>,
+}
+
+/// Upper limit on the epochs a single step request may run.
+const MAX_EPOCHS_PER_STEP: usize = 10_000;
+
+/// Returns the number of epochs a step request asks for, or why it is out of range.
+fn step_epochs(query: &StepQuery) -> std::result::Result<usize, String> {
+    let epochs = query.n.unwrap_or(1);
+    if (1..=MAX_EPOCHS_PER_STEP).contains(&epochs) {
+        Ok(epochs)
+    } else {
+        Err(format!(
+            "n must be between 1 and {MAX_EPOCHS_PER_STEP}, got {epochs}"
+        ))
+    }
 }
 
 #[derive(Serialize, Clone, Debug)]
@@ -197,79 +178,303 @@ struct ApiResponse<T> {
     error: Option<String>,
 }
 
-// Configuration for recreating worlds
+// Configuration used to create a session's world
 #[derive(Debug, Clone)]
 struct Config {
     population_size: usize,
     elite_size: usize,
     param_ranges: Vec<(f64, f64)>,
     function_type: FunctionType,
+    /// The objective when `function_type` is `Custom`.
+    expression: Option<ExpressionFunction>,
+    /// The lowest score of a `Custom` or `External` objective.
+    floor: f64,
+}
+
+#[cfg(test)]
+impl Config {
+    /// A small two-dimensional world of `function_type`, quick to create in tests.
+    fn for_test(function_type: FunctionType) -> Self {
+        Self {
+            population_size: 20,
+            elite_size: 2,
+            param_ranges: vec![(-5.0, 5.0), (-5.0, 5.0)],
+            function_type,
+            expression: None,
+            floor: 0.0,
+        }
+    }
+}
+
+type AppState = SessionStore;
+
+/// Builds a failed `ApiResponse` with the given status.
+fn error_response(mut builder: HttpResponseBuilder, error: impl Into<String>) -> HttpResponse {
+    builder.json(ApiResponse {
+        success: false,
+        data: None::<()>,
+        error: Some(error.into()),
+    })
+}
+
+fn session_not_found(id: &str) -> HttpResponse {
+    error_response(
+        HttpResponse::NotFound(),
+        format!("Unknown or expired session: {id}"),
+    )
+}
+
+/// Looks up the world of a session that is driven by stepping (`ask_tell` false) or by
+/// ask/tell (`ask_tell` true), or returns the error response to send.
+fn driven_world(
+    app_state: &Mutex<AppState>,
+    id: &str,
+    ask_tell: bool,
+) -> std::result::Result<WorldSession, HttpResponse> {
+    let mut store = app_state.lock().unwrap();
+    let Some(session) = store.touch(id) else {
+        return Err(session_not_found(id));
+    };
+    let external = session.config().function_type == FunctionType::External;
+    if external != ask_tell {
+        let error = if external {
+            "This session is scored by its client; advance it with ask and tell"
+        } else {
+            "Only sessions created with function_type \"external\" support ask and tell"
+        };
+        return Err(error_response(HttpResponse::Conflict(), error));
+    }
+    Ok(session.world())
+}
+
+/// Response body for `POST /api/sessions`.
+#[derive(Serialize)]
+struct SessionCreated {
+    id: String,
+    state: StateResponse,
+}
+
+/// The function to optimise, as described by a create request.
+struct Objective {
+    function_type: FunctionType,
+    param_ranges: Vec<(f64, f64)>,
+    /// The objective of a `Custom` session.
+    expression: Option<ExpressionFunction>,
+    floor: f64,
+}
+
+/// Works out the function to optimise and its parameter ranges from a create request,
+/// or the error response to send.
+fn resolve_objective(req: &StartRequest) -> std::result::Result<Objective, HttpResponse> {
+    let Some(source) = &req.expression else {
+        let function_type = req
+            .function_type
+            .clone()
+            .unwrap_or(FunctionType::Himmelblau);
+        if function_type == FunctionType::External {
+            return Ok(Objective {
+                function_type,
+                param_ranges: resolve_bounds(req, 2)?,
+                expression: None,
+                floor: resolve_floor(req)?,
+            });
+        }
+        let registry = FunctionRegistry::new();
+        let Some(function_info) = registry.get_function_info(&function_type) else {
+            return Err(error_response(
+                HttpResponse::BadRequest(),
+                format!("Unknown function type: {:?}", function_type),
+            ));
+        };
+        return Ok(Objective {
+            function_type,
+            param_ranges: function_info.param_ranges.clone(),
+            expression: None,
+            floor: 0.0,
+        });
+    };
+
+    if req.function_type.is_some() {
+        return Err(error_response(
+            HttpResponse::BadRequest(),
+            "Give either function_type or expression, not both",
+        ));
+    }
+
+    let expression = match Expression::parse(source) {
+        Ok(expression) => expression,
+        // Syntax errors carry their position so clients can point at the mistake
+        Err(e) => {
+            return Err(HttpResponse::BadRequest().json(ApiResponse {
+                success: false,
+                error: Some(format!("Invalid expression: {e}")),
+                data: Some(e),
+            }));
+        }
+    };
+
+    let param_ranges = resolve_bounds(req, expression.variables().max(2))?;
+    let floor = resolve_floor(req)?;
+    Ok(Objective {
+        function_type: FunctionType::Custom,
+        param_ranges,
+        expression: Some(ExpressionFunction::new(expression, floor)),
+        floor,
+    })
 }
 
-#[derive(Debug, Clone, Default)]
-struct AppState {
-    current_state: Option<StateResponse>,
-    config: Option<Config>,
+/// Checks that a create request gives at least `dimensions` valid bounds.
+fn resolve_bounds(
+    req: &StartRequest,
+    dimensions: usize,
+) -> std::result::Result<Vec<(f64, f64)>, HttpResponse> {
+    let bounds = req.bounds.clone().unwrap_or_default();
+    if bounds.len() < dimensions {
+        return Err(error_response(
+            HttpResponse::BadRequest(),
+            format!(
+                "bounds must give at least {dimensions} (min, max) pairs for this objective, got {}",
+                bounds.len()
+            ),
+        ));
+    }
+    if let Some(i) = bounds
+        .iter()
+        .position(|(min, max)| !(min.is_finite() && max.is_finite() && min < max))
+    {
+        return Err(error_response(
+            HttpResponse::BadRequest(),
+            format!("bounds[{i}] must be finite with min < max"),
+        ));
+    }
+    Ok(bounds)
+}
+
+/// Returns a create request's floor, defaulting to 0.
+fn resolve_floor(req: &StartRequest) -> std::result::Result<f64, HttpResponse> {
+    let floor = req.floor.unwrap_or(0.0);
+    if !floor.is_finite() {
+        return Err(error_response(
+            HttpResponse::BadRequest(),
+            "floor must be finite",
+        ));
+    }
+    Ok(floor)
+}
+
+/// Returns a create request's population and elite sizes, defaulting to 100 and 10.
+///
+/// These become the world's population size and target regions, which the world
+/// requires to be positive with no more regions than organisms.
+fn resolve_sizes(req: &StartRequest) -> std::result::Result<(usize, usize), HttpResponse> {
+    let population_size = req.population_size.unwrap_or(100);
+    let elite_size = req.elite_size.unwrap_or(10);
+    if population_size == 0 || elite_size == 0 {
+        return Err(error_response(
+            HttpResponse::BadRequest(),
+            "population_size and elite_size must be positive",
+        ));
+    }
+    if elite_size > population_size {
+        return Err(error_response(
+            HttpResponse::BadRequest(),
+            format!(
+                "elite_size cannot exceed population_size, got {elite_size} > {population_size}"
+            ),
+        ));
+    }
+    Ok((population_size, elite_size))
 }
 
 // Actix Web handler functions
-async fn start_handler(
+async fn create_session_handler(
     app_state: web::Data<Mutex<AppState>>,
     req: web::Json<StartRequest>,
 ) -> Result<HttpResponse> {
-    let population_size = req.population_size.unwrap_or(100);
-    let elite_size = req.elite_size.unwrap_or(10);
-    let function_type = req
-        .function_type
-        .clone()
-        .unwrap_or(FunctionType::Himmelblau);
+    let (population_size, elite_size) = match resolve_sizes(&req) {
+        Ok(sizes) => sizes,
+        Err(response) => return Ok(response),
+    };
+    let objective = match resolve_objective(&req) {
+        Ok(objective) => objective,
+        Err(response) => return Ok(response),
+    };
 
-    let registry = FunctionRegistry::new();
-    let function_info = registry
-        .get_function_info(&function_type)
-        .ok_or_else(|| format!("Unknown function type: {:?}", function_type))
-        .map_err(actix_web::error::ErrorBadRequest)?;
+    if !app_state.lock().unwrap().has_capacity() {
+        return Ok(error_response(
+            HttpResponse::ServiceUnavailable(),
+            format!("Too many active sessions (limit {MAX_SESSIONS})"),
+        ));
+    }
 
     let config = Config {
         population_size,
         elite_size,
-        param_ranges: function_info.param_ranges.clone(),
-        function_type: function_type.clone(),
+        param_ranges: objective.param_ranges,
+        function_type: objective.function_type,
+        expression: objective.expression,
+        floor: objective.floor,
     };
 
-    // Create initial world
-    let param_range: Vec<RangeInclusive<f64>> = function_info
-        .param_ranges
-        .iter()
-        .map(|(min, max)| RangeInclusive::new(*min, *max))
-        .collect();
-
-    let function_impl = registry
-        .create_function(&function_type)
-        .ok_or("Failed to create function implementation")
+    // Create the world on its own worker thread; it lives until the session ends
+    let worker_config = config.clone();
+    let (session, state) = web::block(move || WorldSession::start(worker_config))
+        .await?
         .map_err(|e| {
             eprintln!("Error: {}", e);
             actix_web::error::ErrorInternalServerError(e)
         })?;
 
-    let global_constants = GlobalConstants::new(population_size, elite_size);
-    let world = setup_world(&param_range, global_constants, function_impl);
+    let id = app_state
+        .lock()
+        .unwrap()
+        .insert(session, config, state.clone());
+
+    Ok(HttpResponse::Ok().json(ApiResponse {
+        success: true,
+        data: Some(SessionCreated { id, state }),
+        error: None,
+    }))
+}
+
+async fn list_sessions_handler(app_state: web::Data<Mutex<AppState>>) -> Result<HttpResponse> {
+    let summaries = app_state.lock().unwrap().summaries(Instant::now());
 
-    let response_data = StateResponse {
-        epoch: 0,
-        best_score: world.get_best_score(),
-        // Return the web-shaped JSON for the frontend visualization
-        world_state: world.get_state_for_web(),
-        at_resolution_limit: false,
-        function_type,
+    Ok(HttpResponse::Ok().json(ApiResponse {
+        success: true,
+        data: Some(summaries),
+        error: None,
+    }))
+}
+
+async fn step_handler(
+    app_state: web::Data<Mutex<AppState>>,
+    path: web::Path<String>,
+    query: web::Query<StepQuery>,
+) -> Result<HttpResponse> {
+    let id = path.into_inner();
+    let epochs = match step_epochs(&query) {
+        Ok(epochs) => epochs,
+        Err(e) => return Ok(error_response(HttpResponse::BadRequest(), e)),
     };
 
-    // Update state
+    let world = match driven_world(&app_state, &id, false) {
+        Ok(world) => world,
+        Err(response) => return Ok(response),
+    };
+
+    // Advance the live world; this blocks until the worker finishes the requested epochs
+    let response_data = web::block(move || world.step(epochs)).await?.map_err(|e| {
+        eprintln!("Error: {}", e);
+        actix_web::error::ErrorInternalServerError(e)
+    })?;
+
+    if !app_state
+        .lock()
+        .unwrap()
+        .update_state(&id, response_data.clone())
     {
-        let mut state = app_state.lock().unwrap();
-        state.config = Some(config);
-        state.current_state = Some(response_data.clone());
-        // We do not persist the World here to keep server types Send/Sync-free
+        return Ok(session_not_found(&id));
     }
 
     Ok(HttpResponse::Ok().json(ApiResponse {
@@ -279,108 +484,214 @@ async fn start_handler(
     }))
 }
 
-async fn step_handler(app_state: web::Data<Mutex<AppState>>) -> Result<HttpResponse> {
-    let (config, current_epoch) = {
-        let state = app_state.lock().unwrap();
-        match (&state.config, &state.current_state) {
-            (Some(config), Some(current_state)) => (config.clone(), current_state.epoch),
-            _ => {
-                return Ok(HttpResponse::BadRequest().json(ApiResponse {
-                    success: false,
-                    data: None::<()>,
-                    error: Some("No optimization session started".to_string()),
-                }));
-            }
-        }
+async fn state_handler(
+    app_state: web::Data<Mutex<AppState>>,
+    path: web::Path<String>,
+) -> Result<HttpResponse> {
+    let id = path.into_inner();
+    let Some(world) = app_state.lock().unwrap().touch(&id).map(|s| s.world()) else {
+        return Ok(session_not_found(&id));
     };
 
-    // Recreate world and run to new epoch
-    let param_range: Vec<RangeInclusive<f64>> = config
-        .param_ranges
-        .into_iter()
-        .map(|(min, max)| RangeInclusive::new(min, max))
-        .collect();
+    // Ask the worker, since the world may have advanced through stream playback
+    let response_data = web::block(move || world.state())
+        .await?
+        .map_err(actix_web::error::ErrorInternalServerError)?;
 
-    let registry = FunctionRegistry::new();
-    let function_impl = registry
-        .create_function(&config.function_type)
-        .ok_or("Failed to create function implementation")
+    if !app_state
+        .lock()
+        .unwrap()
+        .update_state(&id, response_data.clone())
+    {
+        return Ok(session_not_found(&id));
+    }
+
+    Ok(HttpResponse::Ok().json(ApiResponse {
+        success: true,
+        data: Some(response_data),
+        error: None,
+    }))
+}
+
+/// Restarts a session's world from epoch 0 with its original configuration.
+async fn reset_handler(
+    app_state: web::Data<Mutex<AppState>>,
+    path: web::Path<String>,
+) -> Result<HttpResponse> {
+    let id = path.into_inner();
+    let Some(config) = app_state
+        .lock()
+        .unwrap()
+        .touch(&id)
+        .map(|s| s.config().clone())
+    else {
+        return Ok(session_not_found(&id));
+    };
+
+    let (world, response_data) = web::block(move || WorldSession::start(config))
+        .await?
         .map_err(|e| {
             eprintln!("Error: {}", e);
             actix_web::error::ErrorInternalServerError(e)
         })?;
 
-    // Get the function floor value for scoring
-    let floor = function_impl.function_floor();
+    // Replacing the handle stops the previous world's worker thread
+    if !app_state
+        .lock()
+        .unwrap()
+        .replace_world(&id, world, response_data.clone())
+    {
+        return Ok(session_not_found(&id));
+    }
 
-    let global_constants = GlobalConstants::new(config.population_size, config.elite_size);
-    let mut world = setup_world(&param_range, global_constants, function_impl);
+    Ok(HttpResponse::Ok().json(ApiResponse {
+        success: true,
+        data: Some(response_data),
+        error: None,
+    }))
+}
 
-    // Run to the new epoch
-    let mut at_resolution_limit = false;
-    for _ in 0..=current_epoch {
-        at_resolution_limit = world.training_run(TrainingData::None { floor_value: floor });
+async fn delete_session_handler(
+    app_state: web::Data<Mutex<AppState>>,
+    path: web::Path<String>,
+) -> Result<HttpResponse> {
+    let id = path.into_inner();
+    if !app_state.lock().unwrap().remove(&id) {
+        return Ok(session_not_found(&id));
     }
 
-    let response_data = StateResponse {
-        epoch: current_epoch + 1,
-        best_score: world.get_best_score(),
-        // Return the web-shaped JSON for the frontend visualization
-        world_state: world.get_state_for_web(),
-        at_resolution_limit,
-        function_type: config.function_type,
+    Ok(HttpResponse::Ok().json(ApiResponse {
+        success: true,
+        data: Some(()),
+        error: None,
+    }))
+}
+
+/// Streams per-epoch deltas of a session's world as server-sent events.
+async fn stream_handler(
+    app_state: web::Data<Mutex<AppState>>,
+    path: web::Path<String>,
+) -> Result<HttpResponse> {
+    let id = path.into_inner();
+    let Some(world) = app_state.lock().unwrap().touch(&id).map(|s| s.world()) else {
+        return Ok(session_not_found(&id));
     };
 
-    // Update state
+    let events = world
+        .subscribe()
+        .map_err(actix_web::error::ErrorInternalServerError)?;
+
+    Ok(HttpResponse::Ok()
+        .content_type("text/event-stream")
+        .insert_header(("Cache-Control", "no-cache"))
+        .body(SseBody::new(events)))
+}
+
+/// Plays, pauses or changes the speed of a session's stream.
+async fn playback_handler(
+    app_state: web::Data<Mutex<AppState>>,
+    path: web::Path<String>,
+    req: web::Json<PlaybackUpdate>,
+) -> Result<HttpResponse> {
+    let id = path.into_inner();
+    let update = req.into_inner();
+    if let Some(speed) = update.epochs_per_second
+        && !(speed > 0.0 && speed <= MAX_EPOCHS_PER_SECOND)
     {
-        let mut state = app_state.lock().unwrap();
-        state.current_state = Some(response_data.clone());
+        return Ok(error_response(
+            HttpResponse::BadRequest(),
+            format!(
+                "epochs_per_second must be greater than 0 and at most {MAX_EPOCHS_PER_SECOND}, got {speed}"
+            ),
+        ));
     }
 
+    let world = match driven_world(&app_state, &id, false) {
+        Ok(world) => world,
+        Err(response) => return Ok(response),
+    };
+
+    let playback = web::block(move || world.set_playback(update))
+        .await?
+        .map_err(actix_web::error::ErrorInternalServerError)?;
+
     Ok(HttpResponse::Ok().json(ApiResponse {
         success: true,
-        data: Some(response_data),
+        data: Some(playback),
         error: None,
     }))
 }
 
-async fn state_handler(app_state: web::Data<Mutex<AppState>>) -> Result<HttpResponse> {
-    let state = app_state.lock().unwrap();
+/// Returns the candidates of an `external` session that await scores.
+async fn ask_handler(
+    app_state: web::Data<Mutex<AppState>>,
+    path: web::Path<String>,
+) -> Result<HttpResponse> {
+    let id = path.into_inner();
+    let world = match driven_world(&app_state, &id, true) {
+        Ok(world) => world,
+        Err(response) => return Ok(response),
+    };
 
-    if let Some(ref current_state) = state.current_state {
-        Ok(HttpResponse::Ok().json(ApiResponse {
-            success: true,
-            data: Some(current_state.clone()),
-            error: None,
-        }))
-    } else {
-        Ok(HttpResponse::BadRequest().json(ApiResponse {
-            success: false,
-            data: None::<()>,
-            error: Some("No optimization session started".to_string()),
-        }))
-    }
+    let candidates = web::block(move || world.ask())
+        .await?
+        .map_err(actix_web::error::ErrorInternalServerError)?;
+
+    Ok(HttpResponse::Ok().json(ApiResponse {
+        success: true,
+        data: Some(candidates),
+        error: None,
+    }))
 }
 
-async fn functions_handler() -> Result<HttpResponse> {
-    let registry = FunctionRegistry::new();
-    let functions = registry.list_functions();
+/// Records client scores for an `external` session's candidates.
+async fn tell_handler(
+    app_state: web::Data<Mutex<AppState>>,
+    path: web::Path<String>,
+    req: web::Json<TellRequest>,
+) -> Result<HttpResponse> {
+    let id = path.into_inner();
+    let world = match driven_world(&app_state, &id, true) {
+        Ok(world) => world,
+        Err(response) => return Ok(response),
+    };
+
+    let results = req
+        .into_inner()
+        .results
+        .into_iter()
+        .map(|result| (result.id, vec![result.score]))
+        .collect();
+    let told = match web::block(move || world.tell(results))
+        .await?
+        .map_err(actix_web::error::ErrorInternalServerError)?
+    {
+        Ok(told) => told,
+        Err(e) => return Ok(error_response(HttpResponse::BadRequest(), e.to_string())),
+    };
+
+    if !app_state
+        .lock()
+        .unwrap()
+        .record_progress(&id, told.epoch, told.best_score)
+    {
+        return Ok(session_not_found(&id));
+    }
 
     Ok(HttpResponse::Ok().json(ApiResponse {
         success: true,
-        data: Some(functions),
+        data: Some(told),
         error: None,
     }))
 }
 
-async fn reset_handler(app_state: web::Data<Mutex<AppState>>) -> Result<HttpResponse> {
-    let mut state = app_state.lock().unwrap();
-    state.current_state = None;
-    state.config = None;
+async fn functions_handler() -> Result<HttpResponse> {
+    let registry = FunctionRegistry::new();
+    let functions = registry.list_functions();
 
     Ok(HttpResponse::Ok().json(ApiResponse {
         success: true,
-        data: Some(()),
+        data: Some(functions),
         error: None,
     }))
 }
@@ -391,6 +702,22 @@ async fn main() -> std::io::Result<()> {
 
     let app_state = web::Data::new(Mutex::new(AppState::default()));
 
+    // Periodically drop sessions nobody has used for a while
+    let expiry_state = app_state.clone();
+    actix_web::rt::spawn(async move {
+        let mut interval = actix_web::rt::time::interval(Duration::from_secs(60));
+        loop {
+            interval.tick().await;
+            let removed = expiry_state
+                .lock()
+                .unwrap()
+                .remove_expired(Instant::now(), SESSION_TTL);
+            if removed > 0 {
+                println!("Expired {removed} idle session(s)");
+            }
+        }
+    });
+
     println!("Server running on http://127.0.0.1:3000");
 
     HttpServer::new(move || {
@@ -403,10 +730,26 @@ async fn main() -> std::io::Result<()> {
                     .allow_any_method()
                     .allow_any_header(),
             )
-            .route("/api/start", web::post().to(start_handler))
-            .route("/api/step", web::post().to(step_handler))
-            .route("/api/state", web::get().to(state_handler))
-            .route("/api/reset", web::post().to(reset_handler))
+            .route("/api/sessions", web::post().to(create_session_handler))
+            .route("/api/sessions", web::get().to(list_sessions_handler))
+            .route(
+                "/api/sessions/{id}",
+                web::delete().to(delete_session_handler),
+            )
+            .route("/api/sessions/{id}/step", web::post().to(step_handler))
+            .route("/api/sessions/{id}/state", web::get().to(state_handler))
+            .route("/api/sessions/{id}/reset", web::post().to(reset_handler))
+            .route("/api/sessions/{id}/stream", web::get().to(stream_handler))
+            .route(
+                "/api/sessions/{id}/playback",
+                web::post().to(playback_handler),
+            )
+            .route("/api/sessions/{id}/ask", web::post().to(ask_handler))
+            .service(
+                web::resource("/api/sessions/{id}/tell")
+                    .app_data(web::JsonConfig::default().limit(MAX_TELL_BYTES))
+                    .route(web::post().to(tell_handler)),
+            )
             .route("/api/functions", web::get().to(functions_handler))
             .service({
                 // Serve static files from the server crate's web/ directory
@@ -418,3 +761,95 @@ async fn main() -> std::io::Result<()> {
     .run()
     .await
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::http::StatusCode;
+
+    /// A store holding one stepping and one externally-driven session, with their ids.
+    fn store_with_both_kinds() -> (Mutex<AppState>, String, String) {
+        let mut store = AppState::default();
+        let mut insert = |function_type| {
+            let config = Config::for_test(function_type);
+            let (world, state) = WorldSession::start(config.clone()).unwrap();
+            store.insert(world, config, state)
+        };
+        let stepping = insert(FunctionType::Rastrigin);
+        let external = insert(FunctionType::External);
+        (Mutex::new(store), stepping, external)
+    }
+
+    #[test]
+    fn given_step_query_when_validated_then_limited_to_one_to_max_epochs() {
+        let epochs = |n| step_epochs(&StepQuery { n });
+
+        assert_eq!(epochs(None), Ok(1));
+        assert_eq!(epochs(Some(MAX_EPOCHS_PER_STEP)), Ok(MAX_EPOCHS_PER_STEP));
+        assert!(epochs(Some(0)).is_err());
+        assert!(
+            epochs(Some(MAX_EPOCHS_PER_STEP + 1))
+                .unwrap_err()
+                .contains("between 1 and 10000")
+        );
+    }
+
+    fn start_request(json: &str) -> StartRequest {
+        serde_json::from_str(json).unwrap()
+    }
+
+    #[test]
+    fn given_no_sizes_when_resolved_then_defaults() {
+        assert_eq!(resolve_sizes(&start_request("{}")).unwrap(), (100, 10));
+        assert_eq!(
+            resolve_sizes(&start_request(r#"{"population_size": 5, "elite_size": 5}"#)).unwrap(),
+            (5, 5)
+        );
+    }
+
+    #[test]
+    fn given_zero_or_oversized_elite_when_resolved_then_bad_request() {
+        for json in [
+            r#"{"population_size": 0}"#,
+            r#"{"elite_size": 0}"#,
+            r#"{"population_size": 5, "elite_size": 6}"#,
+            r#"{"elite_size": 101}"#,
+        ] {
+            let response = resolve_sizes(&start_request(json)).unwrap_err();
+
+            assert_eq!(response.status(), StatusCode::BAD_REQUEST, "{json}");
+        }
+    }
+
+    #[test]
+    fn given_stepping_session_when_driven_by_ask_tell_then_conflict() {
+        let (store, stepping, _) = store_with_both_kinds();
+
+        let response = driven_world(&store, &stepping, true).unwrap_err();
+
+        assert_eq!(response.status(), StatusCode::CONFLICT);
+        assert!(driven_world(&store, &stepping, false).is_ok());
+    }
+
+    #[test]
+    fn given_external_session_when_stepped_then_conflict() {
+        let (store, _, external) = store_with_both_kinds();
+
+        let response = driven_world(&store, &external, false).unwrap_err();
+
+        assert_eq!(response.status(), StatusCode::CONFLICT);
+        let world = driven_world(&store, &external, true).unwrap();
+        assert_eq!(world.state().unwrap().epoch, 0);
+    }
+
+    #[test]
+    fn given_unknown_session_when_driven_then_not_found() {
+        let (store, _, _) = store_with_both_kinds();
+
+        for ask_tell in [false, true] {
+            let response = driven_world(&store, "no-such-session", ask_tell).unwrap_err();
+
+            assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        }
+    }
+}
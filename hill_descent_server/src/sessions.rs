@@ -0,0 +1,251 @@
+//! Concurrent optimisation sessions, each with its own live world.
+//!
+//! Sessions are identified by an opaque id handed out when they are created and expire
+//! after a period without requests, which stops their worker threads.
+
+use std::{
+    collections::HashMap,
+    hash::{BuildHasher, RandomState},
+    time::{Duration, Instant},
+};
+
+use serde::Serialize;
+
+use crate::{Config, FunctionType, StateResponse, world_session::WorldSession};
+
+/// How long a session may go without requests before it is removed.
+pub const SESSION_TTL: Duration = Duration::from_secs(30 * 60);
+
+/// Upper limit on concurrently live sessions, each of which owns a worker thread.
+pub const MAX_SESSIONS: usize = 32;
+
+/// A single user's optimisation run.
+#[derive(Debug)]
+pub struct Session {
+    world: WorldSession,
+    config: Config,
+    current_state: StateResponse,
+    last_active: Instant,
+}
+
+impl Session {
+    /// Returns a handle to the session's world.
+    pub fn world(&self) -> WorldSession {
+        self.world.clone()
+    }
+
+    /// Returns the configuration the session's world was created from.
+    pub fn config(&self) -> &Config {
+        &self.config
+    }
+}
+
+/// Summary of a session returned by `GET /api/sessions`.
+#[derive(Debug, Clone, Serialize)]
+pub struct SessionSummary {
+    pub id: String,
+    pub function_type: FunctionType,
+    /// The objective expression of a `custom` session.
+    pub expression: Option<String>,
+    pub epoch: usize,
+    pub best_score: f64,
+    pub idle_seconds: u64,
+}
+
+/// All live sessions, keyed by id.
+#[derive(Debug, Default)]
+pub struct SessionStore {
+    sessions: HashMap<String, Session>,
+    id_hasher: RandomState,
+    next_id: u64,
+}
+
+impl SessionStore {
+    /// Registers a new session and returns its id.
+    pub fn insert(&mut self, world: WorldSession, config: Config, state: StateResponse) -> String {
+        self.next_id += 1;
+        // Ids are unguessable so one user cannot drive another's session by counting
+        let id = format!(
+            "{:016x}{:04x}",
+            self.id_hasher.hash_one(self.next_id),
+            self.next_id & 0xffff
+        );
+        self.sessions.insert(
+            id.clone(),
+            Session {
+                world,
+                config,
+                current_state: state,
+                last_active: Instant::now(),
+            },
+        );
+        id
+    }
+
+    /// Returns `true` if another session may be created.
+    pub fn has_capacity(&self) -> bool {
+        self.sessions.len() < MAX_SESSIONS
+    }
+
+    /// Looks up a session and marks it as active.
+    pub fn touch(&mut self, id: &str) -> Option<&mut Session> {
+        let session = self.sessions.get_mut(id)?;
+        session.last_active = Instant::now();
+        Some(session)
+    }
+
+    /// Records the state reported by a session's latest step or reset.
+    ///
+    /// Returns `false` if the session no longer exists (it was closed or expired meanwhile).
+    pub fn update_state(&mut self, id: &str, state: StateResponse) -> bool {
+        match self.touch(id) {
+            Some(session) => {
+                session.current_state = state;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Records the epoch and best score reached through ask/tell, which advances the world
+    /// without a full state.
+    ///
+    /// Returns `false` if the session no longer exists.
+    pub fn record_progress(&mut self, id: &str, epoch: usize, best_score: f64) -> bool {
+        match self.touch(id) {
+            Some(session) => {
+                session.current_state.epoch = epoch;
+                session.current_state.best_score = best_score;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Replaces a session's world, e.g. after a reset.
+    pub fn replace_world(&mut self, id: &str, world: WorldSession, state: StateResponse) -> bool {
+        match self.touch(id) {
+            Some(session) => {
+                session.world = world;
+                session.current_state = state;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Removes a session, stopping its worker once in-flight requests finish.
+    pub fn remove(&mut self, id: &str) -> bool {
+        self.sessions.remove(id).is_some()
+    }
+
+    /// Removes every session idle for longer than `ttl` as of `now`.
+    ///
+    /// A session with a connected stream is never idle.
+    pub fn remove_expired(&mut self, now: Instant, ttl: Duration) -> usize {
+        let before = self.sessions.len();
+        self.sessions.retain(|_, session| {
+            session.world.is_streaming()
+                || now.saturating_duration_since(session.last_active) <= ttl
+        });
+        before - self.sessions.len()
+    }
+
+    /// Summarises all sessions, most recently active first.
+    pub fn summaries(&self, now: Instant) -> Vec<SessionSummary> {
+        let mut summaries: Vec<_> = self
+            .sessions
+            .iter()
+            .map(|(id, session)| SessionSummary {
+                id: id.clone(),
+                function_type: session.current_state.function_type.clone(),
+                expression: session
+                    .config
+                    .expression
+                    .as_ref()
+                    .map(|f| f.expression().source().to_string()),
+                epoch: session.current_state.epoch,
+                best_score: session.current_state.best_score,
+                idle_seconds: now.saturating_duration_since(session.last_active).as_secs(),
+            })
+            .collect();
+        summaries.sort_by_key(|s| s.idle_seconds);
+        summaries
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+
+    fn store_with_session() -> (SessionStore, String, WorldSession) {
+        let config = Config::for_test(FunctionType::Rastrigin);
+        let (world, state) = WorldSession::start(config.clone()).unwrap();
+        let mut store = SessionStore::default();
+        let id = store.insert(world.clone(), config, state);
+        (store, id, world)
+    }
+
+    #[test]
+    fn given_idle_session_when_ttl_reached_then_kept_until_exceeded() {
+        let (mut store, id, _) = store_with_session();
+        let last_active = store.sessions[&id].last_active;
+        let ttl = Duration::from_secs(60);
+
+        assert_eq!(store.remove_expired(last_active + ttl, ttl), 0);
+        assert_eq!(
+            store.remove_expired(last_active + ttl + Duration::from_nanos(1), ttl),
+            1
+        );
+        assert!(store.touch(&id).is_none());
+    }
+
+    #[test]
+    fn given_touched_session_when_expiring_then_only_idle_sessions_removed() {
+        let (mut store, stale, world) = store_with_session();
+        let config = Config::for_test(FunctionType::Rastrigin);
+        let state = world.state().unwrap();
+        let active = store.insert(world, config, state);
+        let ttl = Duration::from_secs(60);
+        let now = store.sessions[&stale].last_active + ttl + Duration::from_secs(1);
+        store.sessions.get_mut(&active).unwrap().last_active = now;
+
+        assert_eq!(store.remove_expired(now, ttl), 1);
+        assert!(store.touch(&active).is_some());
+        assert!(store.touch(&stale).is_none());
+    }
+
+    #[test]
+    fn given_streaming_session_when_long_idle_then_not_removed() {
+        let (mut store, id, world) = store_with_session();
+        let _events = world.subscribe().unwrap();
+        // A round trip guarantees the worker has registered the subscriber
+        world.state().unwrap();
+        let far_future = store.sessions[&id].last_active + Duration::from_secs(24 * 60 * 60);
+
+        assert!(world.is_streaming());
+        assert_eq!(store.remove_expired(far_future, SESSION_TTL), 0);
+        assert!(store.touch(&id).is_some());
+    }
+
+    #[test]
+    fn given_sessions_up_to_cap_when_checked_then_capacity_refused_with_unique_ids() {
+        let (mut store, first, world) = store_with_session();
+        let config = Config::for_test(FunctionType::Rastrigin);
+        let state = world.state().unwrap();
+        let mut ids = HashSet::from([first]);
+        while store.has_capacity() {
+            ids.insert(store.insert(world.clone(), config.clone(), state.clone()));
+        }
+
+        assert_eq!(ids.len(), MAX_SESSIONS);
+        assert!(ids.iter().all(|id| id.len() == 20));
+
+        let removed = ids.iter().next().unwrap().clone();
+        assert!(store.remove(&removed));
+        assert!(store.has_capacity());
+        assert!(!store.remove(&removed));
+    }
+}
@@ -0,0 +1,519 @@
+//! A live `World` owned by a dedicated worker thread.
+//!
+//! `World` holds a `Box<dyn WorldFunction>`, which is `Sync` but not `Send`, so it cannot be
+//! stored in actix's shared application state. Instead each session's world is created and
+//! advanced on its own thread, and handlers talk to it over a channel. The world is kept
+//! between requests, so stepping costs only the epochs requested.
+//!
+//! While playing, the worker also advances the world on its own at the requested speed and
+//! pushes a [`FrameDelta`] to every subscribed stream after each epoch. Worlds of `external`
+//! sessions advance instead when the last of their candidates is told.
+
+use std::{
+    ops::RangeInclusive,
+    sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+        mpsc::{self, RecvTimeoutError},
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use actix_web::web::Bytes;
+use hill_descent_lib::{
+    GlobalConstants, TrainingData, World, WorldFunction, setup_world,
+    world::{
+        ask_tell::{TellError, TellOutcome},
+        web_frame::WebFrame,
+    },
+};
+use tokio::sync::mpsc::{UnboundedReceiver, UnboundedSender, unbounded_channel};
+
+use crate::{
+    Config, FunctionRegistry, FunctionType, StateResponse,
+    ask_tell::{AskResponse, ClientScores, TellResponse},
+    frame_stream::{FrameDelta, Playback, PlaybackUpdate, sse_event},
+};
+
+/// Requests handled by the worker thread.
+#[derive(Debug)]
+enum Command {
+    Step {
+        epochs: usize,
+        reply: mpsc::Sender<StateResponse>,
+    },
+    Subscribe {
+        events: UnboundedSender<Bytes>,
+    },
+    SetPlayback {
+        update: PlaybackUpdate,
+        reply: mpsc::Sender<Playback>,
+    },
+    Ask {
+        reply: mpsc::Sender<AskResponse>,
+    },
+    Tell {
+        results: Vec<(usize, Vec<f64>)>,
+        reply: mpsc::Sender<Result<TellResponse, TellError>>,
+    },
+}
+
+/// Handle to a world running on its own worker thread.
+///
+/// Cloning the handle shares the same world. The worker thread exits once every handle has
+/// been dropped.
+#[derive(Debug, Clone)]
+pub struct WorldSession {
+    commands: mpsc::Sender<Command>,
+    subscribers: Arc<AtomicUsize>,
+}
+
+impl WorldSession {
+    /// Creates the world described by `config` on a new worker thread and returns a handle
+    /// together with the initial (epoch 0) state.
+    pub fn start(config: Config) -> Result<(Self, StateResponse), String> {
+        let (commands, receiver) = mpsc::channel();
+        let (initial_tx, initial_rx) = mpsc::channel();
+        let subscribers = Arc::new(AtomicUsize::new(0));
+        let worker_subscribers = subscribers.clone();
+
+        thread::Builder::new()
+            .name(format!("world-{:?}", config.function_type))
+            .spawn(move || {
+                let Some((world, floor)) = create_world(&config) else {
+                    return;
+                };
+                let worker = Worker {
+                    world,
+                    config,
+                    floor,
+                    at_resolution_limit: false,
+                    playback: Playback::default(),
+                    subscribers: Vec::new(),
+                    subscriber_count: worker_subscribers,
+                    last_frame: None,
+                };
+                if initial_tx.send(worker.snapshot()).is_err() {
+                    return;
+                }
+                worker.run(receiver);
+            })
+            .map_err(|e| format!("Failed to start world worker: {e}"))?;
+
+        let initial = initial_rx
+            .recv()
+            .map_err(|_| "Failed to create world".to_string())?;
+        Ok((
+            Self {
+                commands,
+                subscribers,
+            },
+            initial,
+        ))
+    }
+
+    /// Runs `epochs` training runs and returns the resulting state.
+    ///
+    /// Blocks until the worker has finished, so call it from a blocking context.
+    pub fn step(&self, epochs: usize) -> Result<StateResponse, String> {
+        let (reply, response) = mpsc::channel();
+        self.send(Command::Step { epochs, reply })?;
+        response.recv().map_err(|_| stopped())
+    }
+
+    /// Returns the current state without advancing the world.
+    pub fn state(&self) -> Result<StateResponse, String> {
+        self.step(0)
+    }
+
+    /// Subscribes to the world's stream of server-sent events.
+    ///
+    /// The first event is a full frame of the current state; after that a delta is sent
+    /// whenever the world advances, and a `playback` event whenever playback changes.
+    pub fn subscribe(&self) -> Result<UnboundedReceiver<Bytes>, String> {
+        let (events, receiver) = unbounded_channel();
+        self.send(Command::Subscribe { events })?;
+        Ok(receiver)
+    }
+
+    /// Changes playback and returns the resulting settings.
+    ///
+    /// Playback only runs while at least one stream is subscribed; it pauses when the last
+    /// stream disconnects or the world reaches its resolution limit.
+    pub fn set_playback(&self, update: PlaybackUpdate) -> Result<Playback, String> {
+        let (reply, response) = mpsc::channel();
+        self.send(Command::SetPlayback { update, reply })?;
+        response.recv().map_err(|_| stopped())
+    }
+
+    /// Returns the candidates awaiting scores.
+    pub fn ask(&self) -> Result<AskResponse, String> {
+        let (reply, response) = mpsc::channel();
+        self.send(Command::Ask { reply })?;
+        response.recv().map_err(|_| stopped())
+    }
+
+    /// Records the scores of candidates from [`ask`](Self::ask), running the epoch once
+    /// every candidate has been told.
+    ///
+    /// The outer error means the worker has stopped; the inner one that the scores were
+    /// rejected and nothing was recorded.
+    pub fn tell(
+        &self,
+        results: Vec<(usize, Vec<f64>)>,
+    ) -> Result<Result<TellResponse, TellError>, String> {
+        let (reply, response) = mpsc::channel();
+        self.send(Command::Tell { results, reply })?;
+        response.recv().map_err(|_| stopped())
+    }
+
+    /// Returns `true` while a client is subscribed to the world's stream.
+    pub fn is_streaming(&self) -> bool {
+        self.subscribers.load(Ordering::Relaxed) > 0
+    }
+
+    fn send(&self, command: Command) -> Result<(), String> {
+        self.commands.send(command).map_err(|_| stopped())
+    }
+}
+
+fn stopped() -> String {
+    "World worker has stopped".to_string()
+}
+
+/// State owned by a session's worker thread.
+struct Worker {
+    world: World,
+    config: Config,
+    floor: f64,
+    at_resolution_limit: bool,
+    playback: Playback,
+    subscribers: Vec<UnboundedSender<Bytes>>,
+    subscriber_count: Arc<AtomicUsize>,
+    // The frame subscribers last received, while there are any
+    last_frame: Option<WebFrame>,
+}
+
+impl Worker {
+    /// Handles commands until every handle is dropped, advancing the world between them
+    /// while playing.
+    fn run(mut self, commands: mpsc::Receiver<Command>) {
+        let mut next_tick = Instant::now();
+        loop {
+            let command = if self.playback.playing {
+                match commands.recv_timeout(next_tick.saturating_duration_since(Instant::now())) {
+                    Ok(command) => Some(command),
+                    Err(RecvTimeoutError::Timeout) => None,
+                    Err(RecvTimeoutError::Disconnected) => break,
+                }
+            } else {
+                match commands.recv() {
+                    Ok(command) => Some(command),
+                    Err(_) => break,
+                }
+            };
+
+            match command {
+                Some(command) => {
+                    let was_playing = self.playback.playing;
+                    self.handle(command);
+                    if self.playback.playing && !was_playing {
+                        next_tick = Instant::now();
+                    }
+                }
+                None => {
+                    self.advance(1);
+                    // Fall behind rather than bursting when epochs take longer than a tick
+                    let interval = Duration::from_secs_f64(1.0 / self.playback.epochs_per_second);
+                    next_tick = (next_tick + interval).max(Instant::now());
+                }
+            }
+        }
+    }
+
+    fn handle(&mut self, command: Command) {
+        match command {
+            Command::Step { epochs, reply } => {
+                self.advance(epochs);
+                let _ = reply.send(self.snapshot());
+            }
+            Command::Subscribe { events } => {
+                let frame = self.world.get_frame_for_web();
+                let delta = FrameDelta::between(None, &frame, self.at_resolution_limit);
+                let sent = events.send(sse_event("frame", &delta)).is_ok()
+                    && events.send(sse_event("playback", &self.playback)).is_ok();
+                if sent {
+                    self.subscribers.push(events);
+                    self.last_frame = Some(frame);
+                    self.subscriber_count
+                        .store(self.subscribers.len(), Ordering::Relaxed);
+                }
+            }
+            Command::SetPlayback { update, reply } => {
+                if let Some(epochs_per_second) = update.epochs_per_second {
+                    self.playback.epochs_per_second = epochs_per_second;
+                }
+                if let Some(playing) = update.playing {
+                    self.playback.playing =
+                        playing && !self.subscribers.is_empty() && !self.at_resolution_limit;
+                }
+                self.broadcast_playback();
+                let _ = reply.send(self.playback);
+            }
+            Command::Ask { reply } => {
+                let _ = reply.send(AskResponse {
+                    epoch: self.world.epoch() as usize,
+                    candidates: self.world.ask(),
+                });
+            }
+            Command::Tell { results, reply } => {
+                let _ = reply.send(self.tell(&results));
+            }
+        }
+    }
+
+    fn tell(&mut self, results: &[(usize, Vec<f64>)]) -> Result<TellResponse, TellError> {
+        let (remaining, epoch_completed) = match self.world.tell(results)? {
+            TellOutcome::Pending { remaining } => (remaining, false),
+            TellOutcome::EpochCompleted {
+                at_resolution_limit,
+            } => {
+                self.at_resolution_limit = at_resolution_limit;
+                self.publish();
+                (0, true)
+            }
+        };
+        Ok(TellResponse {
+            remaining,
+            epoch_completed,
+            epoch: self.world.epoch() as usize,
+            best_score: self.world.get_best_score(),
+            best_params: self.world.get_best_params(),
+            at_resolution_limit: self.at_resolution_limit,
+        })
+    }
+
+    /// Runs `epochs` training runs and sends the resulting delta to subscribers.
+    fn advance(&mut self, epochs: usize) {
+        if epochs == 0 {
+            return;
+        }
+        for _ in 0..epochs {
+            self.at_resolution_limit = self.world.training_run(TrainingData::None {
+                floor_value: self.floor,
+            });
+        }
+        self.publish();
+    }
+
+    /// Sends the delta since the last frame to subscribers, and stops playback at the
+    /// resolution limit.
+    fn publish(&mut self) {
+        if !self.subscribers.is_empty() {
+            let frame = self.world.get_frame_for_web();
+            let event = sse_event(
+                "frame",
+                &FrameDelta::between(self.last_frame.as_ref(), &frame, self.at_resolution_limit),
+            );
+            self.last_frame = Some(frame);
+            self.broadcast(event);
+        }
+
+        if self.at_resolution_limit && self.playback.playing {
+            self.playback.playing = false;
+            self.broadcast_playback();
+        }
+    }
+
+    fn broadcast_playback(&mut self) {
+        let event = sse_event("playback", &self.playback);
+        self.broadcast(event);
+    }
+
+    /// Sends `event` to every subscriber, dropping those that have disconnected.
+    fn broadcast(&mut self, event: Bytes) {
+        self.subscribers
+            .retain(|subscriber| subscriber.send(event.clone()).is_ok());
+        self.subscriber_count
+            .store(self.subscribers.len(), Ordering::Relaxed);
+        if self.subscribers.is_empty() {
+            self.playback.playing = false;
+            self.last_frame = None;
+        }
+    }
+
+    /// Captures the world's current state for the frontend.
+    fn snapshot(&self) -> StateResponse {
+        StateResponse {
+            epoch: self.world.epoch() as usize,
+            best_score: self.world.get_best_score(),
+            // Return the web-shaped JSON for the frontend visualization
+            world_state: self.world.get_state_for_web(),
+            at_resolution_limit: self.at_resolution_limit,
+            function_type: self.config.function_type.clone(),
+        }
+    }
+}
+
+/// Builds the world for `config` together with its function's floor value, or `None` if the
+/// function cannot be created.
+fn create_world(config: &Config) -> Option<(World, f64)> {
+    let function_impl: Box<dyn WorldFunction> = match (&config.function_type, &config.expression) {
+        (_, Some(expression)) => Box::new(expression.clone()),
+        (FunctionType::External, None) => Box::new(ClientScores::new(config.floor)),
+        (function_type, None) => FunctionRegistry::new().create_function(function_type)?,
+    };
+    // Get the function floor value for scoring
+    let floor = function_impl.function_floor();
+    let param_range: Vec<RangeInclusive<f64>> = config
+        .param_ranges
+        .iter()
+        .map(|(min, max)| RangeInclusive::new(*min, *max))
+        .collect();
+    let global_constants = GlobalConstants::new(config.population_size, config.elite_size);
+    Some((
+        setup_world(&param_range, global_constants, function_impl),
+        floor,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn start() -> WorldSession {
+        WorldSession::start(Config::for_test(FunctionType::Rastrigin))
+            .unwrap()
+            .0
+    }
+
+    /// A handle whose worker has gone, as after the worker thread panics.
+    fn stopped_session() -> WorldSession {
+        let (commands, _) = mpsc::channel();
+        WorldSession {
+            commands,
+            subscribers: Arc::new(AtomicUsize::new(0)),
+        }
+    }
+
+    #[test]
+    fn given_session_when_stepped_then_runs_requested_epochs() {
+        let session = start();
+
+        let stepped = session.step(3).unwrap();
+        let state = session.state().unwrap();
+
+        assert_eq!(stepped.epoch, 3);
+        assert_eq!(state.epoch, 3);
+        assert_eq!(session.step(2).unwrap().epoch, 5);
+        assert_eq!(state.function_type, FunctionType::Rastrigin);
+    }
+
+    #[test]
+    fn given_no_stream_when_played_then_speed_changes_but_stays_paused() {
+        let session = start();
+
+        let playback = session
+            .set_playback(PlaybackUpdate {
+                playing: Some(true),
+                epochs_per_second: Some(20.0),
+            })
+            .unwrap();
+
+        assert!(!playback.playing);
+        assert_eq!(playback.epochs_per_second, 20.0);
+        assert_eq!(session.state().unwrap().epoch, 0);
+    }
+
+    #[test]
+    fn given_stream_when_played_and_paused_then_events_sent_and_world_advances() {
+        let session = start();
+        let mut events = session.subscribe().unwrap();
+
+        let playing = session
+            .set_playback(PlaybackUpdate {
+                playing: Some(true),
+                epochs_per_second: Some(100.0),
+            })
+            .unwrap();
+        thread::sleep(Duration::from_millis(100));
+        let paused = session
+            .set_playback(PlaybackUpdate {
+                playing: Some(false),
+                epochs_per_second: None,
+            })
+            .unwrap();
+
+        assert!(playing.playing && !paused.playing);
+        assert_eq!(paused.epochs_per_second, 100.0);
+        assert!(session.is_streaming());
+        assert!(session.state().unwrap().epoch > 0);
+        let first = String::from_utf8(events.try_recv().unwrap().to_vec()).unwrap();
+        assert!(first.starts_with("event: frame\n") && first.contains(r#""full":true"#));
+        assert!(events.try_recv().unwrap().starts_with(b"event: playback\n"));
+    }
+
+    #[test]
+    fn given_stopped_worker_when_used_then_every_request_errors() {
+        let session = stopped_session();
+
+        assert_eq!(session.step(1).unwrap_err(), stopped());
+        assert_eq!(session.state().unwrap_err(), stopped());
+        assert!(session.subscribe().is_err());
+        assert!(session.ask().is_err());
+        assert!(session.tell(Vec::new()).is_err());
+        assert!(
+            session
+                .set_playback(PlaybackUpdate {
+                    playing: Some(true),
+                    epochs_per_second: None,
+                })
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn given_rejected_scores_when_told_then_error_and_nothing_recorded() {
+        let (session, _) = WorldSession::start(Config::for_test(FunctionType::External)).unwrap();
+        let asked = session.ask().unwrap();
+        let first = asked.candidates[0].id();
+
+        let unknown = session.tell(vec![(usize::MAX, vec![1.0])]).unwrap();
+        let wrong_count = session
+            .tell(vec![(first, vec![1.0]), (asked.candidates[1].id(), vec![])])
+            .unwrap();
+
+        assert_eq!(
+            unknown.unwrap_err(),
+            TellError::UnknownCandidate { id: usize::MAX }
+        );
+        assert!(matches!(
+            wrong_count.unwrap_err(),
+            TellError::WrongOutputCount { count: 0, .. }
+        ));
+        // The valid score in the rejected batch was not recorded either
+        let after = session.ask().unwrap();
+        assert_eq!(after.epoch, asked.epoch);
+        assert_eq!(after.candidates.len(), asked.candidates.len());
+        assert_eq!(session.state().unwrap().epoch, 0);
+    }
+
+    #[test]
+    fn given_every_candidate_told_when_tell_then_epoch_completed() {
+        let (session, _) = WorldSession::start(Config::for_test(FunctionType::External)).unwrap();
+        let results = session
+            .ask()
+            .unwrap()
+            .candidates
+            .iter()
+            .map(|candidate| (candidate.id(), vec![1.0]))
+            .collect();
+
+        let told = session.tell(results).unwrap().unwrap();
+
+        assert!(told.epoch_completed);
+        assert_eq!((told.remaining, told.epoch), (0, 1));
+        assert_eq!(session.state().unwrap().epoch, 1);
+    }
+}
@@ -79,10 +79,18 @@ class HillDescentClient {
         this.baseUrl = baseUrl;
         this.isRunning = false;
         this.autoInterval = null;
+        this.sessionId = null;
+    }
+
+    sessionUrl(path = '') {
+        if (!this.sessionId) {
+            throw new Error('No optimization session started');
+        }
+        return `${this.baseUrl}/api/sessions/${this.sessionId}${path}`;
     }
 
     async startOptimization(populationSize = 100, eliteSize = 10, functionType = 'himmelblau') {
-        const response = await fetch(`${this.baseUrl}/api/start`, {
+        const response = await fetch(`${this.baseUrl}/api/sessions`, {
             method: 'POST',
             headers: {
                 'Content-Type': 'application/json',
@@ -99,7 +107,8 @@ class HillDescentClient {
             throw new Error(data.error || 'Failed to start optimization');
         }
 
-        return data.data;
+        this.sessionId = data.data.id;
+        return data.data.state;
     }
 
     async getFunctions() {
@@ -114,7 +123,7 @@ class HillDescentClient {
     }
 
     async stepOptimization() {
-        const response = await fetch(`${this.baseUrl}/api/step`, {
+        const response = await fetch(this.sessionUrl('/step'), {
             method: 'POST',
         });
 
@@ -127,7 +136,7 @@ class HillDescentClient {
     }
 
     async getState() {
-        const response = await fetch(`${this.baseUrl}/api/state`);
+        const response = await fetch(this.sessionUrl('/state'));
         const data = await response.json();
 
         if (!data.success) {
@@ -137,11 +146,42 @@ class HillDescentClient {
         return data.data;
     }
 
-    async reset() {
-        const response = await fetch(`${this.baseUrl}/api/reset`, {
+    // Opens the session's event stream; the first frame is sent in full, later ones as deltas
+    openStream(onFrame, onPlayback) {
+        const source = new EventSource(this.sessionUrl('/stream'));
+        source.addEventListener('frame', (e) => onFrame(JSON.parse(e.data)));
+        source.addEventListener('playback', (e) => onPlayback(JSON.parse(e.data)));
+        return source;
+    }
+
+    async setPlayback(update) {
+        const response = await fetch(this.sessionUrl('/playback'), {
             method: 'POST',
+            headers: {
+                'Content-Type': 'application/json',
+            },
+            body: JSON.stringify(update),
         });
 
+        const data = await response.json();
+        if (!data.success) {
+            throw new Error(data.error || 'Failed to change playback');
+        }
+
+        return data.data;
+    }
+
+    async reset() {
+        if (!this.sessionId) {
+            return null;
+        }
+
+        // Closing the session stops its world on the server
+        const response = await fetch(this.sessionUrl(), {
+            method: 'DELETE',
+        });
+        this.sessionId = null;
+
         const data = await response.json();
         if (!data.success) {
             throw new Error(data.error || 'Failed to reset');
@@ -158,6 +198,8 @@ class OptimizationUI {
         this.isRunning = false;
         this.autoInterval = null; // legacy, no longer used for auto-run
         this.isAutoRunning = false;
+        this.eventSource = null;
+        this.streamRegions = new Map(); // region key -> region, rebuilt from frame deltas
         this._stepInFlight = false;
         this.bestScores = [];
         this.availableFunctions = {};
@@ -177,6 +219,7 @@ class OptimizationUI {
             startBtn: document.getElementById('start-btn'),
             stepBtn: document.getElementById('step-btn'),
             autoBtn: document.getElementById('auto-btn'),
+            speedInput: document.getElementById('speed'),
             resetBtn: document.getElementById('reset-btn'),
             epochSpan: document.getElementById('epoch'),
             bestScoreSpan: document.getElementById('best-score'),
@@ -324,6 +367,7 @@ class OptimizationUI {
         this.elements.stepBtn.addEventListener('click', () => this.step());
         this.elements.autoBtn.addEventListener('click', () => this.toggleAuto());
         this.elements.resetBtn.addEventListener('click', () => this.reset());
+        this.elements.speedInput.addEventListener('change', () => this.onSpeedChange());
         this.elements.closeRegionPanel.addEventListener('click', () => this.hideRegionPanel());
         this.elements.closeOrganismModal.addEventListener('click', () => this.hideOrganismModal());
         this.elements.organismModalOverlay.addEventListener('click', () => this.hideOrganismModal());
@@ -391,25 +435,105 @@ class OptimizationUI {
         }
     }
 
+    // Auto run streams epochs from the server instead of polling for each step
     startAuto() {
         if (this.isAutoRunning) return;
         this.isAutoRunning = true;
         this.elements.autoBtn.textContent = 'Stop Auto';
         this.elements.stepBtn.disabled = true;
-        this.runAutoLoop();
+
+        let started = false;
+        this.eventSource = this.client.openStream(
+            (frame) => {
+                this.applyFrame(frame);
+                // Play once the stream is connected, as playback needs a subscriber
+                if (!started) {
+                    started = true;
+                    this.client.setPlayback({ playing: true, epochs_per_second: this.speed() })
+                        .catch((error) => this.updateStatus(`Error: ${error.message}`));
+                }
+            },
+            (playback) => {
+                if (!playback.playing && started && this.isAutoRunning) {
+                    this.stopAuto();
+                }
+            },
+        );
+        this.eventSource.onerror = () => {
+            if (this.isAutoRunning) {
+                this.updateStatus('Stream disconnected');
+                this.stopAuto();
+            }
+        };
     }
 
     stopAuto() {
+        const wasRunning = this.isAutoRunning;
         this.isAutoRunning = false;
         this.elements.autoBtn.textContent = 'Auto Run';
         this.elements.stepBtn.disabled = false;
+
+        if (this.eventSource) {
+            this.eventSource.close();
+            this.eventSource = null;
+        }
+        if (wasRunning && this.client.sessionId) {
+            // Frames omit genetic detail, so fetch the full state once playback stops
+            this.client.setPlayback({ playing: false })
+                .then(() => this.client.getState())
+                .then((state) => this.updateUI(state))
+                .catch((error) => console.error('Stop auto error:', error));
+        }
+    }
+
+    speed() {
+        const value = parseFloat(this.elements.speedInput.value);
+        return Math.min(Math.max(Number.isFinite(value) ? value : 5, 1), 100);
     }
 
-    async runAutoLoop() {
-        const sleep = (ms) => new Promise(res => setTimeout(res, ms));
-        while (this.isAutoRunning) {
-            await this.step(); // waits for response before continuing
-            await sleep(500); // pacing
+    onSpeedChange() {
+        if (this.isAutoRunning) {
+            this.client.setPlayback({ epochs_per_second: this.speed() })
+                .catch((error) => this.updateStatus(`Error: ${error.message}`));
+        }
+    }
+
+    // Rebuilds a web-shaped state from a stream frame delta and renders it
+    applyFrame(frame) {
+        if (frame.full) {
+            this.streamRegions.clear();
+        }
+        frame.regions_removed.forEach((key) => this.streamRegions.delete(key.join(',')));
+        frame.regions_changed.forEach((region) => this.streamRegions.set(region.key.join(','), region));
+
+        const regions = [...this.streamRegions.values()];
+        const scores = regions.map((r) => r.min_score).filter((s) => s !== null);
+        const state = {
+            world_bounds: frame.world_bounds,
+            score_range: scores.length > 0
+                ? { min: Math.min(...scores), max: Math.max(...scores) }
+                : { min: 0, max: 0 },
+            regions,
+            organisms: frame.organisms.map((o) => ({
+                id: o.id,
+                params: { x: o.x, y: o.y },
+                score: o.score,
+                age: o.age,
+                max_age: o.max_age,
+            })),
+        };
+
+        this.elements.epochSpan.textContent = frame.epoch;
+        this.elements.bestScoreSpan.innerHTML = '';
+        this.elements.bestScoreSpan.appendChild(NumberFormatter.createFormattedElement(frame.best_score));
+        this.elements.roundCounter.textContent = frame.epoch;
+        if (!frame.full) {
+            this.bestScores.push(frame.best_score);
+        }
+        this.updateVisualization(state);
+
+        if (frame.at_resolution_limit) {
+            this.updateStatus('Resolution limit reached!');
         }
     }
 
@@ -681,6 +805,9 @@ class OptimizationUI {
 
             // Click handler for detailed organism view
             organismEl.addEventListener('click', () => {
+                if (!organism.phenotype) {
+                    return; // streamed frames carry no genetic detail
+                }
                 this.showOrganismModal();
                 this.updateOrganismModal(organism);
             });